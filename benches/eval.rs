@@ -0,0 +1,65 @@
+//! Evaluates expressions against every builtin target, which is the common
+//! case of finding the targets that a dependency applies to.
+//!
+//! This doesn't use a benchmarking framework, so it runs on stable with
+//! `cargo bench`, and prints the average time of each evaluation.
+
+use cfg_expr::{targets::ALL_BUILTINS, Expression, Predicate};
+use std::time::Instant;
+
+const EXPRESSIONS: &[(&str, &str)] = &[
+    ("single", r#"target_os = "linux""#),
+    ("family", "unix"),
+    (
+        "nested",
+        r#"all(any(target_os = "linux", target_os = "android", target_os = "macos"), not(target_env = "musl"), target_pointer_width = "64")"#,
+    ),
+    (
+        "mixed",
+        r#"any(all(windows, target_arch = "x86_64", feature = "simd"), all(unix, not(target_vendor = "apple"), target_has_atomic = "64"))"#,
+    ),
+    (
+        "custom",
+        r#"any(target_os = "my-kernel", target_arch = "my-arch", target_abi = "my-abi")"#,
+    ),
+];
+
+fn main() {
+    let iterations = 200;
+
+    for (name, expr) in EXPRESSIONS {
+        let expr = Expression::parse(expr).unwrap();
+
+        let eval = || {
+            ALL_BUILTINS
+                .iter()
+                .filter(|ti| {
+                    expr.eval(|pred| match pred {
+                        Predicate::Target(tp) => tp.matches(*ti),
+                        _ => false,
+                    })
+                })
+                .count()
+        };
+
+        // Warm up
+        let matching = eval();
+
+        // The results are summed so that the evaluations aren't optimized out
+        let start = Instant::now();
+        let mut total = 0;
+        for _ in 0..iterations {
+            total += eval();
+        }
+        let elapsed = start.elapsed();
+        assert_eq!(total, matching * iterations as usize);
+
+        let per_target = elapsed / (iterations * ALL_BUILTINS.len() as u32);
+        println!(
+            "{name:<8} {:>8.1?} per target, {:>10.1?} for {} targets",
+            per_target,
+            elapsed / iterations,
+            ALL_BUILTINS.len()
+        );
+    }
+}
@@ -0,0 +1,139 @@
+// Exporting functions to C can't be done without raw pointers
+#![allow(unsafe_code)]
+
+use crate::{expr::FeatureSet, targets::get_builtin_target_by_triple, Expression};
+use std::{
+    cell::RefCell,
+    ffi::{CStr, CString},
+    os::raw::{c_char, c_int},
+};
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = RefCell::new(None);
+}
+
+/// Sets the error returned by [`cfg_expr_last_error`] for the current thread
+fn set_error(error: impl ToString) {
+    // The messages don't contain nul bytes, but any input that is echoed in
+    // them could
+    let error = CString::new(error.to_string().replace('\0', "\\0")).unwrap();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(error));
+}
+
+/// Reads a nul terminated UTF-8 string, setting the error if it isn't valid
+unsafe fn read_str<'a>(s: *const c_char, what: &str) -> Option<&'a str> {
+    if s.is_null() {
+        set_error(format!("the {what} is null"));
+        return None;
+    }
+
+    match CStr::from_ptr(s).to_str() {
+        Ok(s) => Some(s),
+        Err(err) => {
+            set_error(format!("the {what} isn't valid UTF-8: {err}"));
+            None
+        }
+    }
+}
+
+/// Parses a nul terminated expression, returning null if it isn't valid, in
+/// which case the error is retrieved with [`cfg_expr_last_error`].
+///
+/// The expression is released with [`cfg_expr_free`].
+///
+/// # Safety
+///
+/// `expr` must be null, or a pointer to a nul terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn cfg_expr_parse(expr: *const c_char) -> *mut Expression {
+    let expr = match read_str(expr, "expression") {
+        Some(expr) => expr,
+        None => return std::ptr::null_mut(),
+    };
+
+    match Expression::parse(expr) {
+        Ok(expr) => Box::into_raw(Box::new(expr)),
+        Err(err) => {
+            set_error(err);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+/// Evaluates an expression against the builtin target with the nul
+/// terminated triple, with the `features_len` crate features in `features`
+/// enabled, see [`Expression::eval_target`].
+///
+/// Returns 1 if the expression is true, 0 if it is false, and -1 if the
+/// triple isn't known, or a string isn't valid, in which case the error is
+/// retrieved with [`cfg_expr_last_error`].
+///
+/// # Safety
+///
+/// `expr` must be an expression returned by [`cfg_expr_parse`] that hasn't
+/// been freed. `triple` must be null, or a pointer to a nul terminated
+/// string, and `features` must be a pointer to `features_len` of them, or
+/// null if `features_len` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn cfg_expr_eval_triple(
+    expr: *const Expression,
+    triple: *const c_char,
+    features: *const *const c_char,
+    features_len: usize,
+) -> c_int {
+    let expr = if let Some(expr) = expr.as_ref() {
+        expr
+    } else {
+        set_error("the expression is null");
+        return -1;
+    };
+    let triple = match read_str(triple, "triple") {
+        Some(triple) => triple,
+        None => return -1,
+    };
+    let target = if let Some(target) = get_builtin_target_by_triple(triple) {
+        target
+    } else {
+        set_error(format!("unknown target triple `{triple}`"));
+        return -1;
+    };
+
+    let mut enabled = FeatureSet::new();
+    if features_len > 0 {
+        for &feature in std::slice::from_raw_parts(features, features_len) {
+            match read_str(feature, "feature") {
+                Some(feature) => enabled.insert(feature),
+                None => return -1,
+            };
+        }
+    }
+
+    c_int::from(expr.eval_target(target, &enabled, &FeatureSet::new()))
+}
+
+/// Releases an expression returned by [`cfg_expr_parse`]. Null is ignored.
+///
+/// # Safety
+///
+/// `expr` must be null, or an expression returned by [`cfg_expr_parse`]
+/// that hasn't already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn cfg_expr_free(expr: *mut Expression) {
+    if !expr.is_null() {
+        drop(Box::from_raw(expr));
+    }
+}
+
+/// Retrieves the message of the last error on the current thread, as a nul
+/// terminated string, or null if there hasn't been an error.
+///
+/// The string is owned by the library, and is valid until the next error on
+/// the same thread.
+#[no_mangle]
+pub extern "C" fn cfg_expr_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(std::ptr::null(), |error| error.as_ptr())
+    })
+}
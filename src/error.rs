@@ -1,21 +1,28 @@
-use std::{error::Error, fmt};
+use alloc::string::String;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
 
 /// An error related to parsing of a cfg expression
 #[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct ParseError {
     /// The string that was parsed
     pub original: String,
     /// The range of characters in the original string that result
     /// in this error
-    pub span: std::ops::Range<usize>,
+    pub span: core::ops::Range<usize>,
     /// The specific reason for the error
     pub reason: Reason,
 }
 
 /// The particular reason for a `ParseError`
+///
+/// Each reason has a stable error code, see [`Reason::code`]
 #[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Reason {
-    /// not() takes exactly 1 predicate, unlike all() and any()
+    /// `not()` takes exactly 1 predicate, unlike `all()` and `any()`
     InvalidNot(usize),
     /// The characters are not valid in an cfg expression
     InvalidCharacters,
@@ -34,12 +41,137 @@ pub enum Reason {
     Unexpected(&'static [&'static str]),
     /// Failed to parse an integer value
     InvalidInteger,
-    /// The root cfg() may only contain a single predicate
+    /// The root `cfg()` may only contain a single predicate
     MultipleRootPredicates,
     /// A `target_has_atomic` predicate didn't correctly parse.
     InvalidHasAtomic,
     /// An element was not part of the builtin information in rustc
     UnknownBuiltin,
+    /// A key was not one of the known keys, and is either in a namespace that
+    /// only allows known keys, eg. `target_`, or, when the expression is
+    /// [validated](crate::Expression::validate), is very similar to one of
+    /// them, eg. `featurre`. Contains the closest known key, if there is one.
+    UnknownKey(Option<&'static str>),
+    /// The value of a target predicate is not used by any of the builtin
+    /// targets. Contains the closest known value, if there is one.
+    UnknownValue(Option<&'static str>),
+    /// A `version()` predicate didn't contain a valid version, eg. `"1.47"`
+    InvalidVersion,
+    /// The name of a predicate isn't expected by the
+    /// [`CheckCfg`](crate::expr::CheckCfg) it was validated against
+    UnexpectedCfgName,
+    /// The value of a predicate isn't expected by the
+    /// [`CheckCfg`](crate::expr::CheckCfg) it was validated against
+    UnexpectedCfgValue,
+    /// The expression is longer than the maximum length, in bytes, of the
+    /// [`ParseOptions`](crate::expr::ParseOptions) it was parsed with
+    TooLong(usize),
+    /// Parentheses are nested deeper than the maximum depth of the
+    /// [`ParseOptions`](crate::expr::ParseOptions) it was parsed with
+    TooDeep(usize),
+    /// The expression contains more than the maximum number of predicates of
+    /// the [`ParseOptions`](crate::expr::ParseOptions) it was parsed with
+    TooManyPredicates(usize),
+}
+
+impl Reason {
+    /// The stable code for this kind of error, eg. `E0003` for
+    /// [`Reason::UnclosedParens`], which won't change between versions even if
+    /// the wording of the message does
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidNot(_) => "E0001",
+            Self::InvalidCharacters => "E0002",
+            Self::UnclosedParens => "E0003",
+            Self::UnopenedParens => "E0004",
+            Self::UnclosedQuotes => "E0005",
+            Self::UnopenedQuotes => "E0006",
+            Self::Empty => "E0007",
+            Self::Unexpected(_) => "E0008",
+            Self::InvalidInteger => "E0009",
+            Self::MultipleRootPredicates => "E0010",
+            Self::InvalidHasAtomic => "E0011",
+            Self::UnknownBuiltin => "E0012",
+            Self::UnknownKey(_) => "E0013",
+            Self::UnknownValue(_) => "E0014",
+            Self::InvalidVersion => "E0015",
+            Self::UnexpectedCfgName => "E0016",
+            Self::UnexpectedCfgValue => "E0017",
+            Self::TooLong(_) => "E0018",
+            Self::TooDeep(_) => "E0019",
+            Self::TooManyPredicates(_) => "E0020",
+        }
+    }
+}
+
+impl ParseError {
+    /// The stable code for this error, see [`Reason::code`]
+    #[inline]
+    pub fn code(&self) -> &'static str {
+        self.reason.code()
+    }
+
+    /// Renders the error as a diagnostic, with a header containing the error
+    /// code and message, followed by the line of the original string that
+    /// contains the error, with the span underlined.
+    ///
+    /// Unlike the [`std::fmt::Display`] implementation, this handles original
+    /// strings that span multiple lines, and columns are counted in characters
+    /// rather than bytes.
+    ///
+    /// ```
+    /// let err = cfg_expr::Expression::parse("all(\n    unix,\n    target_os = linux,\n)").unwrap_err();
+    ///
+    /// assert_eq!(err.code(), "E0008");
+    /// assert_eq!(
+    ///     err.render(),
+    ///     "error[E0008]: expected a `\"` here
+    ///  --> 3:17
+    ///   |
+    ///   |     target_os = linux,
+    ///   |                 ^^^^^
+    /// "
+    /// );
+    /// ```
+    pub fn render(&self) -> String {
+        use fmt::Write;
+
+        let start = floor_char_boundary(&self.original, self.span.start);
+        let line_start = self.original[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = self.original[start..]
+            .find('\n')
+            .map_or(self.original.len(), |i| start + i);
+        let line = &self.original[line_start..line_end];
+
+        let line_num = self.original[..line_start].matches('\n').count() + 1;
+        let col = self.original[line_start..start].chars().count();
+        // The underline is clamped to the line, but is always at least 1
+        // character so that errors at the end of the string are visible
+        let len = self.original
+            [start..floor_char_boundary(&self.original, self.span.end.clamp(start, line_end))]
+            .chars()
+            .count()
+            .max(1);
+
+        let mut rendered = String::new();
+        let _ = writeln!(rendered, "error[{}]: {}", self.code(), self.reason);
+        let _ = writeln!(rendered, " --> {line_num}:{}", col + 1);
+        let _ = writeln!(rendered, "  |");
+        let _ = writeln!(rendered, "  | {line}");
+        let _ = writeln!(rendered, "  | {}{}", " ".repeat(col), "^".repeat(len));
+        rendered
+    }
+}
+
+/// Clamps a byte index to the string, and moves it back to the start of the
+/// character it is in, so that spans can't be used to slice in the middle of
+/// a multibyte character
+fn floor_char_boundary(s: &str, index: usize) -> usize {
+    let mut index = index.min(s.len());
+    while !s.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
 }
 
 impl fmt::Display for ParseError {
@@ -47,7 +179,11 @@ impl fmt::Display for ParseError {
         f.write_str(&self.original)?;
         f.write_str("\n")?;
 
-        for _ in 0..self.span.start {
+        // The span is in bytes, but the markers are aligned by characters
+        let start = floor_char_boundary(&self.original, self.span.start);
+        let end = floor_char_boundary(&self.original, self.span.end).max(start);
+
+        for _ in self.original[..start].chars() {
             f.write_str(" ")?;
         }
 
@@ -61,7 +197,7 @@ impl fmt::Display for ParseError {
                 f.write_fmt(format_args!("^ {r}"))
             }
             other => {
-                for _ in self.span.start..self.span.end {
+                for _ in self.original[start..end].chars() {
                     f.write_str("^")?;
                 }
 
@@ -74,9 +210,10 @@ impl fmt::Display for ParseError {
 impl fmt::Display for Reason {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use Reason::{
-            Empty, InvalidCharacters, InvalidHasAtomic, InvalidInteger, InvalidNot,
-            MultipleRootPredicates, UnclosedParens, UnclosedQuotes, Unexpected, UnknownBuiltin,
-            UnopenedParens, UnopenedQuotes,
+            Empty, InvalidCharacters, InvalidHasAtomic, InvalidInteger, InvalidNot, InvalidVersion,
+            MultipleRootPredicates, TooDeep, TooLong, TooManyPredicates, UnclosedParens,
+            UnclosedQuotes, Unexpected, UnexpectedCfgName, UnexpectedCfgValue, UnknownBuiltin,
+            UnknownKey, UnknownValue, UnopenedParens, UnopenedQuotes,
         };
 
         match self {
@@ -105,16 +242,32 @@ impl fmt::Display for Reason {
             MultipleRootPredicates => f.write_str("multiple root predicates"),
             InvalidHasAtomic => f.write_str("expected integer or \"ptr\""),
             UnknownBuiltin => f.write_str("unknown built-in"),
+            UnknownKey(Some(suggestion)) => {
+                f.write_fmt(format_args!("unknown key, did you mean `{suggestion}`?"))
+            }
+            UnknownKey(None) => f.write_str("unknown key"),
+            UnknownValue(Some(suggestion)) => {
+                f.write_fmt(format_args!("unknown value, did you mean `{suggestion}`?"))
+            }
+            UnknownValue(None) => f.write_str("unknown value"),
+            InvalidVersion => f.write_str("expected a version, eg. \"1.47\""),
+            UnexpectedCfgName => f.write_str("unexpected cfg name"),
+            UnexpectedCfgValue => f.write_str("unexpected cfg value"),
+            TooLong(max) => f.write_fmt(format_args!("expression is longer than {max} bytes")),
+            TooDeep(max) => f.write_fmt(format_args!("nested deeper than {max} levels")),
+            TooManyPredicates(max) => f.write_fmt(format_args!("more than {max} predicates")),
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl Error for ParseError {
     fn description(&self) -> &str {
         use Reason::{
-            Empty, InvalidCharacters, InvalidHasAtomic, InvalidInteger, InvalidNot,
-            MultipleRootPredicates, UnclosedParens, UnclosedQuotes, Unexpected, UnknownBuiltin,
-            UnopenedParens, UnopenedQuotes,
+            Empty, InvalidCharacters, InvalidHasAtomic, InvalidInteger, InvalidNot, InvalidVersion,
+            MultipleRootPredicates, TooDeep, TooLong, TooManyPredicates, UnclosedParens,
+            UnclosedQuotes, Unexpected, UnexpectedCfgName, UnexpectedCfgValue, UnknownBuiltin,
+            UnknownKey, UnknownValue, UnopenedParens, UnopenedQuotes,
         };
 
         match self.reason {
@@ -130,6 +283,14 @@ impl Error for ParseError {
             MultipleRootPredicates => "multiple root predicates",
             InvalidHasAtomic => "expected integer or \"ptr\"",
             UnknownBuiltin => "unknown built-in",
+            UnknownKey(_) => "unknown key",
+            UnknownValue(_) => "unknown value",
+            InvalidVersion => "invalid version",
+            UnexpectedCfgName => "unexpected cfg name",
+            UnexpectedCfgValue => "unexpected cfg value",
+            TooLong(_) => "expression is too long",
+            TooDeep(_) => "nested too deeply",
+            TooManyPredicates(_) => "too many predicates",
         }
     }
 }
@@ -146,4 +307,126 @@ impl fmt::Display for HasAtomicParseError {
     }
 }
 
+#[cfg(feature = "std")]
 impl Error for HasAtomicParseError {}
+
+/// Error parsing a [`RustVersion`](crate::expr::RustVersion).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionParseError {
+    pub(crate) input: String,
+}
+
+impl fmt::Display for VersionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected a version of the form \"<major>.<minor>[.<patch>]\", found {}",
+            self.input
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for VersionParseError {}
+
+/// Error building a [`TargetInfo`](crate::targets::TargetInfo) with a
+/// [`TargetInfoBuilder`](crate::targets::TargetInfoBuilder)
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TargetInfoError {
+    /// A field that doesn't have a default wasn't set
+    MissingField(&'static str),
+    /// The value of a field isn't used by any of the builtin targets
+    UnknownValue {
+        /// The name of the field
+        field: &'static str,
+        /// The value that was set
+        value: String,
+        /// The closest known value, if there is one
+        closest: Option<&'static str>,
+    },
+    /// The pointer width isn't used by any of the builtin targets
+    InvalidPointerWidth(u8),
+}
+
+impl fmt::Display for TargetInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingField(field) => write!(f, "the {field} was not set"),
+            Self::UnknownValue {
+                field,
+                value,
+                closest,
+            } => {
+                write!(f, "unknown {field} \"{value}\"")?;
+                if let Some(closest) = closest {
+                    write!(f, ", did you mean `{closest}`?")?;
+                }
+                Ok(())
+            }
+            Self::InvalidPointerWidth(pw) => {
+                write!(f, "expected a pointer width of 16, 32 or 64, found {pw}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for TargetInfoError {}
+
+/// Error querying an installed rustc for its targets
+#[cfg(feature = "rustc")]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum RustcError {
+    /// rustc couldn't be executed
+    Spawn {
+        /// The command that was run
+        command: String,
+        /// The underlying I/O error
+        error: std::io::Error,
+    },
+    /// rustc exited with a failure
+    Failed {
+        /// The command that was run
+        command: String,
+        /// The exit status of rustc
+        status: std::process::ExitStatus,
+        /// The output rustc printed to stderr
+        stderr: String,
+    },
+    /// The output of rustc wasn't in the expected format
+    InvalidOutput {
+        /// The command that was run
+        command: String,
+        /// A description of what was wrong with the output
+        reason: String,
+    },
+}
+
+#[cfg(feature = "rustc")]
+impl fmt::Display for RustcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Spawn { command, error } => write!(f, "failed to run `{command}`: {error}"),
+            Self::Failed {
+                command,
+                status,
+                stderr,
+            } => write!(f, "`{command}` failed with {status}: {}", stderr.trim()),
+            Self::InvalidOutput { command, reason } => {
+                write!(f, "unexpected output from `{command}`: {reason}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "rustc")]
+impl Error for RustcError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Spawn { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
@@ -1,12 +1,87 @@
+mod analysis;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+mod attr;
+/// Reduced ordered binary decision diagrams of expressions
+#[cfg(feature = "bdd")]
+pub mod bdd;
+mod borrowed;
+pub mod builder;
+mod cfg_set;
+mod check_cfg;
+#[cfg(feature = "codegen")]
+mod codegen;
+mod compiled;
+mod context;
+/// A lossless syntax tree of expressions, which keeps their layout
+pub mod cst;
+mod dot;
+mod eval;
+mod evaluator;
+mod explain;
+mod format;
+#[cfg(feature = "json")]
+mod json;
 pub mod lexer;
+mod normal;
+#[cfg(feature = "rayon")]
+mod par;
 mod parser;
+mod platform;
+#[cfg(feature = "std")]
+mod pool;
+mod rewrite;
+#[cfg(feature = "sat")]
+mod sat;
+mod simplify;
+mod stats;
+mod suggest;
+#[cfg(feature = "macros")]
+mod syntax;
+mod tree;
+mod validate;
+#[cfg(feature = "clap")]
+mod value_parser;
+mod visit;
 
+use alloc::{
+    borrow::{Cow, ToOwned},
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+pub use analysis::{Lint, LintKind, Unsatisfiable};
+pub use attr::CfgAttr;
+pub use borrowed::ExpressionRef;
+pub use cfg_set::CfgSet;
+pub use check_cfg::{CheckCfg, ExpectedValues};
+pub use compiled::CompiledMatcher;
+pub use context::{DependencyKind, EvalContext};
+use core::{fmt, ops::Range};
+pub use eval::{EvalOptions, FeatureSet};
+pub use evaluator::Evaluator;
+pub use explain::{ExplainedNode, Explanation};
+pub use format::FormatOptions;
+pub use parser::ParseOptions;
+pub use platform::Platform;
+#[cfg(feature = "std")]
+pub use pool::{ExprId, ExpressionPool};
+pub use rewrite::Rewrite;
 use smallvec::SmallVec;
-use std::ops::Range;
+pub use stats::{PredicateCounts, Stats};
+pub(crate) use suggest::levenshtein;
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub use syntax::{check_syntax, LazyExpression};
+pub use tree::ExprTree;
+pub(crate) use validate::known_values;
+#[cfg(feature = "clap")]
+pub use value_parser::{ExpressionValueParser, PlatformValueParser};
+pub use visit::Visitor;
 
 /// A predicate function, used to combine 1 or more predicates
 /// into a single value
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
 pub enum Func {
     /// `not()` with a configuration predicate. It is true if its predicate
     /// is false and false if its predicate is true.
@@ -28,7 +103,10 @@ pub enum Func {
 use crate::targets as targ;
 
 /// All predicates that pertains to a target, except for `target_feature`
-#[derive(Clone, PartialEq, Eq, Debug)]
+///
+/// Predicates are ordered by their kind, in the order of the variants, and
+/// then by their value.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub enum TargetPredicate {
     /// [target_abi](https://github.com/rust-lang/rust/issues/80970)
     Abi(targ::Abi),
@@ -44,9 +122,18 @@ pub enum TargetPredicate {
     Family(targ::Family),
     /// [target_has_atomic](https://doc.rust-lang.org/reference/conditional-compilation.html#target_has_atomic).
     HasAtomic(targ::HasAtomic),
+    /// [target_has_atomic_load_store](https://github.com/rust-lang/rust/issues/94039)
+    HasAtomicLoadStore(targ::HasAtomic),
+    /// [target_has_atomic_equal_alignment](https://github.com/rust-lang/rust/issues/93822)
+    HasAtomicEqualAlignment(targ::HasAtomic),
     /// [target_os](https://doc.rust-lang.org/reference/conditional-compilation.html#target_os)
     Os(targ::Os),
     /// [panic](https://doc.rust-lang.org/reference/conditional-compilation.html#panic)
+    ///
+    /// This doesn't have the `target_` prefix, but it is matched against the
+    /// target's default panic strategy, see [`TargetInfo::panic`](crate::targets::TargetInfo::panic),
+    /// so that expressions can be evaluated purely from the target. Crates
+    /// built with `-C panic` can override the default.
     Panic(targ::Panic),
     /// [target_pointer_width](https://doc.rust-lang.org/reference/conditional-compilation.html#target_pointer_width)
     PointerWidth(u8),
@@ -56,12 +143,23 @@ pub enum TargetPredicate {
 
 pub trait TargetMatcher {
     fn matches(&self, tp: &TargetPredicate) -> bool;
+
+    /// The version of the operating system of the target, if it is known,
+    /// eg. the deployment target of `x86_64-apple-macosx10.12.0`.
+    ///
+    /// None of the `cfg()` predicates depend on it, but custom predicates,
+    /// eg. ones that are evaluated with [`Expression::eval`], can use it to
+    /// only match the versions of an operating system that support a feature.
+    fn os_version(&self) -> Option<targ::OsVersion> {
+        None
+    }
 }
 
 impl TargetMatcher for targ::TargetInfo {
     fn matches(&self, tp: &TargetPredicate) -> bool {
         use TargetPredicate::{
-            Abi, Arch, Endian, Env, Family, HasAtomic, Os, Panic, PointerWidth, Vendor,
+            Abi, Arch, Endian, Env, Family, HasAtomic, HasAtomicEqualAlignment, HasAtomicLoadStore,
+            Os, Panic, PointerWidth, Vendor,
         };
 
         match tp {
@@ -79,6 +177,10 @@ impl TargetMatcher for targ::TargetInfo {
             },
             Family(fam) => self.families.contains(fam),
             HasAtomic(has_atomic) => self.has_atomics.contains(*has_atomic),
+            HasAtomicLoadStore(has_atomic) => self.has_atomics_load_store.contains(*has_atomic),
+            HasAtomicEqualAlignment(has_atomic) => {
+                self.has_atomics_equal_alignment.contains(*has_atomic)
+            }
             Os(os) => match &self.os {
                 Some(self_os) => os == self_os,
                 // os = "none" means it should be matched against None. Note that this is different
@@ -93,52 +195,132 @@ impl TargetMatcher for targ::TargetInfo {
             Panic(panic) => &self.panic == panic,
         }
     }
+
+    fn os_version(&self) -> Option<targ::OsVersion> {
+        targ::os_version::from_triple(self.triple.as_str())
+    }
 }
 
-#[cfg(feature = "targets")]
+impl TargetMatcher for targ::TargetInfoOwned {
+    fn matches(&self, tp: &TargetPredicate) -> bool {
+        use TargetPredicate::{
+            Abi, Arch, Endian, Env, Family, HasAtomic, HasAtomicEqualAlignment, HasAtomicLoadStore,
+            Os, Panic, PointerWidth, Vendor,
+        };
+
+        // The same as for `TargetInfo`, which treats missing fields as their
+        // default values
+        match tp {
+            Abi(abi) => self.abi.as_deref().unwrap_or("") == abi.as_str(),
+            Arch(arch) => self.arch == arch.as_str(),
+            Endian(end) => *end == self.endian,
+            Env(env) => self.env.as_deref().unwrap_or("") == env.as_str(),
+            Family(fam) => self.families.iter().any(|f| f == fam.as_str()),
+            HasAtomic(has_atomic) => self.has_atomics.contains(has_atomic),
+            HasAtomicLoadStore(has_atomic) => self.has_atomics_load_store.contains(has_atomic),
+            HasAtomicEqualAlignment(has_atomic) => {
+                self.has_atomics_equal_alignment.contains(has_atomic)
+            }
+            Os(os) => self.os.as_deref().unwrap_or("none") == os.as_str(),
+            PointerWidth(w) => *w == self.pointer_width,
+            Vendor(ven) => self.vendor.as_deref().unwrap_or("unknown") == ven.as_str(),
+            Panic(panic) => self.panic == panic.as_str(),
+        }
+    }
+
+    fn os_version(&self) -> Option<targ::OsVersion> {
+        targ::os_version::from_triple(&self.triple)
+    }
+}
+
+#[cfg(feature = "target-lexicon")]
 impl TargetMatcher for target_lexicon::Triple {
     #[allow(clippy::cognitive_complexity)]
     #[allow(clippy::match_same_arms)]
     fn matches(&self, tp: &TargetPredicate) -> bool {
         use target_lexicon::*;
         use TargetPredicate::{
-            Abi, Arch, Endian, Env, Family, HasAtomic, Os, Panic, PointerWidth, Vendor,
+            Abi, Arch, Endian, Env, Family, HasAtomic, HasAtomicEqualAlignment, HasAtomicLoadStore,
+            Os, Panic, PointerWidth, Vendor,
         };
 
         match tp {
-            Abi(_) => {
-                // `target_abi` is unstable. Assume false for this.
-                false
+            Abi(abi) => {
+                // target-lexicon doesn't have a separate abi component, but
+                // rustc derives it from the environment for most targets
+                let lexicon_abi = match self.environment {
+                    _ if self.vendor == target_lexicon::Vendor::Uwp => "uwp",
+                    // The x86 Apple mobile targets are all simulators
+                    Environment::Unknown
+                        if self.vendor == target_lexicon::Vendor::Apple
+                            && matches!(
+                                self.architecture,
+                                Architecture::X86_32(_) | Architecture::X86_64
+                            )
+                            && matches!(
+                                self.operating_system,
+                                OperatingSystem::Ios | OperatingSystem::Tvos
+                            ) =>
+                    {
+                        "sim"
+                    }
+                    // The 64-bit PowerPC targets use the ELF ABI version,
+                    // except for AIX
+                    _ if self.architecture == Architecture::Powerpc64 => {
+                        match (self.operating_system, self.environment) {
+                            (OperatingSystem::Aix, _) => "vec-extabi",
+                            (OperatingSystem::Linux, Environment::Gnu)
+                            | (OperatingSystem::VxWorks, _) => "elfv1",
+                            _ => "elfv2",
+                        }
+                    }
+                    _ if self.architecture == Architecture::Powerpc64le => "elfv2",
+                    // The hard float ABI is implied for ARM on some
+                    // operating systems
+                    _ if matches!(self.architecture, Architecture::Arm(_))
+                        && matches!(
+                            self.operating_system,
+                            OperatingSystem::Freebsd | OperatingSystem::Horizon
+                        ) =>
+                    {
+                        "eabihf"
+                    }
+                    Environment::Ohos if matches!(self.architecture, Architecture::Arm(_)) => {
+                        "eabi"
+                    }
+                    Environment::Musl if matches!(self.architecture, Architecture::Mips64(_)) => {
+                        "abi64"
+                    }
+                    Environment::Eabihf
+                    | Environment::Gnueabihf
+                    | Environment::Musleabihf
+                    | Environment::Uclibceabihf => "eabihf",
+                    Environment::Androideabi
+                    | Environment::Eabi
+                    | Environment::Gnueabi
+                    | Environment::Musleabi
+                    | Environment::Uclibceabi => "eabi",
+                    Environment::Gnuabi64 | Environment::Muslabi64 => "abi64",
+                    Environment::Gnuspe | Environment::Spe => "spe",
+                    Environment::Gnux32 => "x32",
+                    Environment::GnuIlp32 => "ilp32",
+                    Environment::GnuLlvm => "llvm",
+                    Environment::Macabi => "macabi",
+                    Environment::Sgx => "fortanix",
+                    Environment::Sim => "sim",
+                    Environment::Softfloat => "softfloat",
+                    _ => "",
+                };
+
+                abi.as_str() == lexicon_abi
             }
             Arch(arch) => {
-                if arch == &targ::Arch::x86 {
-                    matches!(self.architecture, Architecture::X86_32(_))
-                } else if arch == &targ::Arch::wasm32 {
-                    self.architecture == Architecture::Wasm32
-                        || self.architecture == Architecture::Asmjs
-                } else if arch == &targ::Arch::arm {
-                    matches!(self.architecture, Architecture::Arm(_))
-                } else if arch == &targ::Arch::bpf {
-                    self.architecture == Architecture::Bpfeb
-                        || self.architecture == Architecture::Bpfel
-                } else if arch == &targ::Arch::x86_64 {
-                    self.architecture == Architecture::X86_64
-                        || self.architecture == Architecture::X86_64h
-                } else {
-                    match arch.0.parse::<Architecture>() {
-                        Ok(a) => match (self.architecture, a) {
-                            (Architecture::Aarch64(_), Architecture::Aarch64(_))
-                            | (Architecture::Mips32(_), Architecture::Mips32(_))
-                            | (Architecture::Mips64(_), Architecture::Mips64(_))
-                            | (Architecture::Powerpc64le, Architecture::Powerpc64)
-                            | (Architecture::Riscv32(_), Architecture::Riscv32(_))
-                            | (Architecture::Riscv64(_), Architecture::Riscv64(_))
-                            | (Architecture::Sparcv9, Architecture::Sparc64) => true,
-                            (a, b) => a == b,
-                        },
-                        Err(_) => false,
-                    }
-                }
+                let sub_arch = self.architecture.into_str();
+
+                // Architectures that aren't known to this crate are assumed
+                // to have the same name in triples and `target_arch`
+                targ::Arch::from_sub_arch(&sub_arch)
+                    .map_or_else(|| arch.as_str() == sub_arch, |a| &a == arch)
             }
             Endian(end) => match self.architecture.endianness() {
                 Ok(endian) => matches!(
@@ -152,20 +334,34 @@ impl TargetMatcher for target_lexicon::Triple {
             Env(env) => {
                 // The environment is implied by some operating systems
                 match self.operating_system {
+                    // The Apple simulator and Mac Catalyst targets have their
+                    // own environment
+                    _ if self.vendor == target_lexicon::Vendor::Apple => {
+                        let apple_env = match self.environment {
+                            Environment::Sim => "sim",
+                            Environment::Macabi => "macabi",
+                            // The x86 Apple mobile targets are all simulators
+                            Environment::Unknown
+                                if matches!(
+                                    self.architecture,
+                                    Architecture::X86_32(_) | Architecture::X86_64
+                                ) && matches!(
+                                    self.operating_system,
+                                    OperatingSystem::Ios | OperatingSystem::Tvos
+                                ) =>
+                            {
+                                "sim"
+                            }
+                            _ => "",
+                        };
+
+                        env.as_str() == apple_env
+                    }
+                    OperatingSystem::WasiP1 => env.as_str() == "p1",
+                    OperatingSystem::WasiP2 => env.as_str() == "p2",
                     OperatingSystem::Redox => env == &targ::Env::relibc,
                     OperatingSystem::VxWorks => env == &targ::Env::gnu,
-                    OperatingSystem::Freebsd => match self.architecture {
-                        Architecture::Arm(ArmArchitecture::Armv6 | ArmArchitecture::Armv7) => {
-                            env == &targ::Env::gnueabihf
-                        }
-                        _ => env.0.is_empty(),
-                    },
-                    OperatingSystem::Netbsd => match self.architecture {
-                        Architecture::Arm(ArmArchitecture::Armv6 | ArmArchitecture::Armv7) => {
-                            env == &targ::Env::eabihf
-                        }
-                        _ => env.0.is_empty(),
-                    },
+                    OperatingSystem::Freebsd | OperatingSystem::Netbsd => env.0.is_empty(),
                     OperatingSystem::None_
                     | OperatingSystem::Cloudabi
                     | OperatingSystem::Hermit
@@ -183,7 +379,7 @@ impl TargetMatcher for target_lexicon::Triple {
                                     | Environment::Androideabi
                                     | Environment::Eabi
                                     | Environment::Eabihf
-                                    | Environment::Sim
+                                    | Environment::None
                             )
                         } else {
                             match env.0.parse::<Environment>() {
@@ -243,81 +439,55 @@ impl TargetMatcher for target_lexicon::Triple {
                 }
             }
             Family(fam) => {
-                use OperatingSystem::{
-                    Aix, AmdHsa, Bitrig, Cloudabi, Cuda, Darwin, Dragonfly, Emscripten, Espidf,
-                    Freebsd, Fuchsia, Haiku, Hermit, Horizon, Illumos, Ios, L4re, Linux, MacOSX,
-                    Nebulet, Netbsd, None_, Openbsd, Redox, Solaris, Tvos, Uefi, Unknown, VxWorks,
-                    Wasi, Watchos, Windows,
-                };
-                match self.operating_system {
-                    AmdHsa | Bitrig | Cloudabi | Cuda | Hermit | Nebulet | None_ | Uefi => false,
-                    Aix
-                    | Darwin
-                    | Dragonfly
-                    | Espidf
-                    | Freebsd
-                    | Fuchsia
-                    | Haiku
-                    | Illumos
-                    | Ios
-                    | L4re
-                    | MacOSX { .. }
-                    | Horizon
-                    | Netbsd
-                    | Openbsd
-                    | Redox
-                    | Solaris
-                    | Tvos
-                    | VxWorks
-                    | Watchos => fam == &crate::targets::Family::unix,
-                    Emscripten => {
-                        match self.architecture {
-                            // asmjs, wasm32 and wasm64 are part of both the wasm and unix families
-                            Architecture::Asmjs | Architecture::Wasm32 => {
-                                fam == &crate::targets::Family::wasm
-                                    || fam == &crate::targets::Family::unix
-                            }
-                            _ => false,
-                        }
+                let os = match self.operating_system {
+                    // The 'kernel' environment is treated specially as not-unix
+                    OperatingSystem::Linux if self.environment == Environment::Kernel => {
+                        return false
                     }
-                    Unknown => {
-                        // asmjs, wasm32 and wasm64 are part of the wasm family.
-                        match self.architecture {
-                            Architecture::Asmjs | Architecture::Wasm32 | Architecture::Wasm64 => {
-                                fam == &crate::targets::Family::wasm
-                            }
-                            _ => false,
-                        }
+                    OperatingSystem::Linux
+                        if matches!(
+                            self.environment,
+                            Environment::Android | Environment::Androideabi
+                        ) =>
+                    {
+                        Cow::Borrowed("android")
                     }
-                    Linux => {
-                        // The 'kernel' environment is treated specially as not-unix
-                        if self.environment != Environment::Kernel {
-                            fam == &crate::targets::Family::unix
-                        } else {
-                            false
-                        }
+                    OperatingSystem::Darwin | OperatingSystem::MacOSX { .. } => {
+                        Cow::Borrowed("macos")
                     }
-                    Wasi => fam == &crate::targets::Family::wasm,
-                    Windows => fam == &crate::targets::Family::windows,
-                    // I really dislike non-exhaustive :(
-                    _ => false,
-                }
+                    OperatingSystem::WasiP1 | OperatingSystem::WasiP2 => Cow::Borrowed("wasi"),
+                    os => os.into_str(),
+                };
+                let arch = self.architecture.into_str();
+                let arch = targ::Arch::from_sub_arch(&arch);
+
+                targ::Families::for_target(&os, arch.as_ref().map_or("", targ::Arch::as_str))
+                    .contains(fam)
             }
-            HasAtomic(_) => {
+            HasAtomic(_) | HasAtomicLoadStore(_) | HasAtomicEqualAlignment(_) => {
                 // atomic support depends on both the architecture and the OS. Assume false for
                 // this.
                 false
             }
             Os(os) => match os.0.parse::<OperatingSystem>() {
+                // The wasip1 and wasip2 targets are both `wasi`
+                Ok(OperatingSystem::Wasi) => matches!(
+                    self.operating_system,
+                    OperatingSystem::Wasi | OperatingSystem::WasiP1 | OperatingSystem::WasiP2
+                ),
                 Ok(o) => match self.environment {
                     Environment::HermitKernel => os == &targ::Os::hermit,
                     _ => self.operating_system == o,
                 },
                 Err(_) => {
                     // Handle special case for darwin/macos, where the triple is
-                    // "darwin", but rustc identifies the OS as "macos"
-                    if os == &targ::Os::macos && self.operating_system == OperatingSystem::Darwin {
-                        true
+                    // "darwin", or "macosx" with a version, but rustc
+                    // identifies the OS as "macos"
+                    if os == &targ::Os::macos {
+                        matches!(
+                            self.operating_system,
+                            OperatingSystem::Darwin | OperatingSystem::MacOSX { .. }
+                        )
                     } else {
                         // For android, the os is still linux, but the environment is android
                         os == &targ::Os::android
@@ -336,7 +506,8 @@ impl TargetMatcher for target_lexicon::Triple {
                     if self.vendor == v {
                         true
                     } else if let target_lexicon::Vendor::Custom(custom) = &self.vendor {
-                        custom.as_str() == "esp" && v == target_lexicon::Vendor::Espressif
+                        matches!(custom.as_str(), "esp" | "esp32" | "esp32s2" | "esp32s3")
+                            && v == target_lexicon::Vendor::Espressif
                     } else {
                         false
                     }
@@ -360,13 +531,38 @@ impl TargetMatcher for target_lexicon::Triple {
             }
         }
     }
+
+    fn os_version(&self) -> Option<targ::OsVersion> {
+        match self.operating_system {
+            target_lexicon::OperatingSystem::MacOSX {
+                major,
+                minor,
+                patch,
+            } => Some(targ::OsVersion {
+                major,
+                minor,
+                patch,
+            }),
+            _ => None,
+        }
+    }
 }
 
 impl TargetPredicate {
     /// Returns true of the predicate matches the specified target
     ///
+    /// When matching against a [`TargetInfo`](targ::TargetInfo), the
+    /// `target_has_atomic`, `target_has_atomic_load_store`, and
+    /// `target_has_atomic_equal_alignment` predicates match if the width is in
+    /// the target's [`has_atomics`](targ::TargetInfo::has_atomics),
+    /// [`has_atomics_load_store`](targ::TargetInfo::has_atomics_load_store), and
+    /// [`has_atomics_equal_alignment`](targ::TargetInfo::has_atomics_equal_alignment)
+    /// respectively.
+    ///
     /// Note that when matching against a [`target_lexicon::Triple`], the
-    /// `has_target_atomic` and `panic` predicates will _always_ return `false`.
+    /// `target_has_atomic`, `target_has_atomic_load_store`,
+    /// `target_has_atomic_equal_alignment`, and `panic` predicates will
+    /// _always_ return `false`.
     ///
     /// ```
     /// use cfg_expr::{targets::*, expr::TargetPredicate as tp};
@@ -377,6 +573,9 @@ impl TargetPredicate {
     ///     tp::Endian(Endian::little).matches(win) &&
     ///     tp::Env(Env::msvc).matches(win) &&
     ///     tp::Family(Family::windows).matches(win) &&
+    ///     tp::HasAtomic(HasAtomic::IntegerSize(64)).matches(win) &&
+    ///     tp::HasAtomicLoadStore(HasAtomic::Pointer).matches(win) &&
+    ///     tp::HasAtomicEqualAlignment(HasAtomic::IntegerSize(64)).matches(win) &&
     ///     tp::Os(Os::windows).matches(win) &&
     ///     tp::PointerWidth(64).matches(win) &&
     ///     tp::Vendor(Vendor::pc).matches(win)
@@ -388,6 +587,107 @@ impl TargetPredicate {
     {
         target.matches(self)
     }
+
+    /// Splits the predicate into its `cfg()` key and value, eg. `target_os`
+    /// and `linux`. Unlike [`Predicate::as_key_value`], the `unix` and
+    /// `windows` families are split into `target_family` and their value.
+    ///
+    /// ```
+    /// use cfg_expr::{expr::TargetPredicate as TP, targets::*};
+    ///
+    /// assert_eq!(TP::Os(Os::linux).into_parts(), ("target_os", "linux".into()));
+    /// assert_eq!(TP::Family(Family::unix).into_parts(), ("target_family", "unix".into()));
+    /// assert_eq!(TP::PointerWidth(64).into_parts(), ("target_pointer_width", "64".into()));
+    /// ```
+    pub fn into_parts(self) -> (&'static str, Cow<'static, str>) {
+        use TargetPredicate as TP;
+
+        match self {
+            TP::Abi(abi) => ("target_abi", abi.0),
+            TP::Arch(arch) => ("target_arch", arch.0),
+            TP::Endian(end) => (
+                "target_endian",
+                Cow::Borrowed(match end {
+                    targ::Endian::big => "big",
+                    targ::Endian::little => "little",
+                }),
+            ),
+            TP::Env(env) => ("target_env", env.0),
+            TP::Family(fam) => ("target_family", fam.0),
+            TP::HasAtomic(ha) => ("target_has_atomic", Cow::Owned(ha.to_string())),
+            TP::HasAtomicLoadStore(ha) => {
+                ("target_has_atomic_load_store", Cow::Owned(ha.to_string()))
+            }
+            TP::HasAtomicEqualAlignment(ha) => (
+                "target_has_atomic_equal_alignment",
+                Cow::Owned(ha.to_string()),
+            ),
+            TP::Os(os) => ("target_os", os.0),
+            TP::Panic(panic) => ("panic", panic.0),
+            TP::PointerWidth(pw) => ("target_pointer_width", Cow::Owned(pw.to_string())),
+            TP::Vendor(ven) => ("target_vendor", ven.0),
+        }
+    }
+
+    /// Splits the predicate into the key and value that it is written as in a
+    /// `cfg()` expression, see [`Predicate::as_key_value`]
+    pub(crate) fn key_value(&self) -> (&str, Option<Cow<'_, str>>) {
+        use TargetPredicate as TP;
+
+        match self {
+            TP::Abi(abi) => ("target_abi", Some(Cow::Borrowed(abi.as_str()))),
+            TP::Arch(arch) => ("target_arch", Some(Cow::Borrowed(arch.as_str()))),
+            TP::Endian(end) => (
+                "target_endian",
+                Some(Cow::Borrowed(match end {
+                    targ::Endian::big => "big",
+                    targ::Endian::little => "little",
+                })),
+            ),
+            TP::Env(env) => ("target_env", Some(Cow::Borrowed(env.as_str()))),
+            // The bare `unix` and `windows` predicates are the idiomatic
+            // way of writing these families
+            TP::Family(fam) if fam == &targ::Family::unix || fam == &targ::Family::windows => {
+                (fam.as_str(), None)
+            }
+            TP::Family(fam) => ("target_family", Some(Cow::Borrowed(fam.as_str()))),
+            TP::HasAtomic(ha) => ("target_has_atomic", Some(Cow::Owned(ha.to_string()))),
+            TP::HasAtomicLoadStore(ha) => (
+                "target_has_atomic_load_store",
+                Some(Cow::Owned(ha.to_string())),
+            ),
+            TP::HasAtomicEqualAlignment(ha) => (
+                "target_has_atomic_equal_alignment",
+                Some(Cow::Owned(ha.to_string())),
+            ),
+            TP::Os(os) => ("target_os", Some(Cow::Borrowed(os.as_str()))),
+            TP::Panic(panic) => ("panic", Some(Cow::Borrowed(panic.as_str()))),
+            TP::PointerWidth(pw) => ("target_pointer_width", Some(Cow::Owned(pw.to_string()))),
+            TP::Vendor(ven) => ("target_vendor", Some(Cow::Borrowed(ven.as_str()))),
+        }
+    }
+}
+
+/// Writes the predicate as it is written in a `cfg()` expression
+///
+/// ```
+/// use cfg_expr::{expr::TargetPredicate as TP, targets::*};
+///
+/// assert_eq!(TP::Os(Os::linux).to_string(), r#"target_os = "linux""#);
+/// assert_eq!(TP::PointerWidth(64).to_string(), r#"target_pointer_width = "64""#);
+/// assert_eq!(TP::Family(Family::unix).to_string(), "unix");
+/// ```
+impl fmt::Display for TargetPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (key, val) = self.key_value();
+        f.write_str(key)?;
+
+        if let Some(val) = val {
+            write!(f, " = \"{val}\"")?;
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -399,6 +699,8 @@ pub(crate) enum Which {
     Family,
     Os,
     HasAtomic(targ::HasAtomic),
+    HasAtomicLoadStore(targ::HasAtomic),
+    HasAtomicEqualAlignment(targ::HasAtomic),
     Panic,
     PointerWidth(u8),
     Vendor,
@@ -408,10 +710,83 @@ pub(crate) enum Which {
 pub(crate) struct InnerTarget {
     which: Which,
     span: Option<Range<usize>>,
+    // The predicate, materialized once when the expression is created rather
+    // than each time it is evaluated
+    pred: TargetPredicate,
+}
+
+impl InnerTarget {
+    /// Creates a target predicate, with the value at the span in the original
+    /// string, which is borrowed from the builtin targets if any of them use
+    /// it, so that cloning it for each evaluation doesn't allocate
+    pub(crate) fn new(which: Which, span: Option<Range<usize>>, original: &str) -> Self {
+        use TargetPredicate as TP;
+
+        let value = || {
+            let value = &original[span.clone().unwrap()];
+            builtin_value(&which, value).unwrap_or_else(|| Cow::Owned(value.to_owned()))
+        };
+
+        let pred = match &which {
+            Which::Abi => TP::Abi(targ::Abi(value())),
+            Which::Arch => TP::Arch(targ::Arch(value())),
+            Which::Endian(end) => TP::Endian(*end),
+            Which::Env => TP::Env(targ::Env(value())),
+            Which::Family => TP::Family(targ::Family(value())),
+            Which::Os => TP::Os(targ::Os(value())),
+            Which::HasAtomic(ha) => TP::HasAtomic(*ha),
+            Which::HasAtomicLoadStore(ha) => TP::HasAtomicLoadStore(*ha),
+            Which::HasAtomicEqualAlignment(ha) => TP::HasAtomicEqualAlignment(*ha),
+            Which::Panic => TP::Panic(targ::Panic(value())),
+            Which::PointerWidth(pw) => TP::PointerWidth(*pw),
+            Which::Vendor => TP::Vendor(targ::Vendor(value())),
+        };
+
+        Self { which, span, pred }
+    }
+}
+
+/// Finds the [known](targ::Arch::ALL_KNOWN) value that is the same as the
+/// specified value, so that it can be borrowed
+fn builtin_value(which: &Which, value: &str) -> Option<Cow<'static, str>> {
+    /// Binary searches the sorted known values
+    fn find<T>(
+        known: &'static [T],
+        value: &str,
+        inner: fn(&'static T) -> &'static Cow<'static, str>,
+    ) -> Option<&'static Cow<'static, str>> {
+        known
+            .binary_search_by(|val| inner(val).as_ref().cmp(value))
+            .ok()
+            .map(|i| inner(&known[i]))
+    }
+
+    // The values of targets without an ABI, environment or OS
+    if value.is_empty() {
+        return Some(Cow::Borrowed(""));
+    } else if value == "none" {
+        return Some(Cow::Borrowed("none"));
+    }
+
+    let found = match which {
+        Which::Abi => find(targ::Abi::ALL_KNOWN, value, |abi| &abi.0),
+        Which::Arch => find(targ::Arch::ALL_KNOWN, value, |arch| &arch.0),
+        Which::Env => find(targ::Env::ALL_KNOWN, value, |env| &env.0),
+        Which::Family => find(targ::Family::ALL_KNOWN, value, |fam| &fam.0),
+        Which::Os => find(targ::Os::ALL_KNOWN, value, |os| &os.0),
+        Which::Panic => find(targ::Panic::ALL_KNOWN, value, |panic| &panic.0),
+        Which::Vendor => find(targ::Vendor::ALL_KNOWN, value, |ven| &ven.0),
+        _ => None,
+    };
+
+    found.cloned()
 }
 
 /// A single predicate in a `cfg()` expression
-#[derive(Debug, PartialEq, Eq)]
+///
+/// Predicates are ordered by their kind, in the order of the variants, and
+/// then by their value.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Predicate<'a> {
     /// A target predicate, with the `target_` prefix
     Target(TargetPredicate),
@@ -421,16 +796,378 @@ pub enum Predicate<'a> {
     /// when compiling without optimizations.
     DebugAssertions,
     /// [Enabled](https://doc.rust-lang.org/reference/conditional-compilation.html#proc_macro) for
-    /// crates of the proc_macro type.
+    /// crates of the `proc_macro` type.
     ProcMacro,
+    /// [Enabled](https://github.com/rust-lang/rust/issues/29594) for targets
+    /// that support the `#[thread_local]` attribute.
+    TargetThreadLocal,
+    /// [Enabled](https://doc.rust-lang.org/rustdoc/advanced-features.html#cfgdoc-documenting-platform-specific-or-feature-specific-information)
+    /// when building documentation with rustdoc.
+    Doc,
+    /// [Enabled](https://doc.rust-lang.org/rustdoc/write-documentation/documentation-tests.html#include-items-only-when-collecting-doctests)
+    /// when rustdoc is collecting doctests.
+    Doctest,
+    /// Enabled when running under the [Miri](https://github.com/rust-lang/miri) interpreter.
+    Miri,
+    /// Enabled by [cargo-fuzz](https://github.com/rust-fuzz/cargo-fuzz) and
+    /// other fuzzing tools when building fuzz targets.
+    Fuzzing,
+    /// [Enabled](https://github.com/rust-lang/rust/issues/111466) when
+    /// compiling with integer overflow checks.
+    OverflowChecks,
+    /// [Enabled](https://github.com/rust-lang/rust/issues/123499) when the
+    /// standard library's undefined behavior checks are enabled.
+    UbChecks,
     /// A [`feature = "<name>"`](https://doc.rust-lang.org/nightly/cargo/reference/features.html)
     Feature(&'a str),
     /// [target_feature](https://doc.rust-lang.org/reference/conditional-compilation.html#target_feature)
     TargetFeature(&'a str),
+    /// A [`sanitize = "<sanitizer>"`](https://doc.rust-lang.org/unstable-book/compiler-flags/sanitizer.html)
+    /// predicate, eg. `sanitize = "address"`, which is true if the sanitizer
+    /// is enabled with `-Z sanitizer`, see [`Self::matches_sanitizers`]
+    Sanitize(&'a str),
+    /// A [`true` or `false`](https://github.com/rust-lang/rust/issues/131204)
+    /// literal, which is always true or false respectively
+    Boolean(bool),
     /// A generic bare predicate key that doesn't match one of the known options, eg `cfg(bare)`
     Flag(&'a str),
     /// A generic key = "value" predicate that doesn't match one of the known options, eg `cfg(foo = "bar")`
     KeyValue { key: &'a str, val: &'a str },
+    /// A [`version("<version>")`](https://github.com/rust-lang/rust/issues/64796)
+    /// predicate, which is true if the version of rustc is at least the
+    /// specified version
+    Version(RustVersion),
+    /// An [`accessible(<path>)`](https://github.com/rust-lang/rust/issues/64797)
+    /// predicate, which is true if the path, eg. `::std::arch::x86_64::_mm_foo`,
+    /// can be named by the crate. Only the caller can know which paths are
+    /// available, so it is up to them to evaluate it.
+    Accessible(&'a str),
+}
+
+/// The version in a `version()` predicate, eg. `1.47` or `1.47.1`
+///
+/// ```
+/// use cfg_expr::expr::RustVersion;
+///
+/// let required: RustVersion = "1.47".parse().unwrap();
+///
+/// assert!(required.matches(&"1.47.0".parse().unwrap()));
+/// assert!(required.matches(&"1.72.1".parse().unwrap()));
+/// assert!(!required.matches(&"1.46.0".parse().unwrap()));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RustVersion {
+    /// The major version
+    pub major: u16,
+    /// The minor version
+    pub minor: u16,
+    /// The patch version, if it was specified
+    pub patch: Option<u16>,
+}
+
+impl RustVersion {
+    /// Returns true if the version of rustc is at least this version. A
+    /// patch version that isn't specified is treated as 0.
+    pub fn matches(&self, rustc: &RustVersion) -> bool {
+        (rustc.major, rustc.minor, rustc.patch.unwrap_or(0))
+            >= (self.major, self.minor, self.patch.unwrap_or(0))
+    }
+}
+
+impl core::str::FromStr for RustVersion {
+    type Err = crate::error::VersionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || crate::error::VersionParseError {
+            input: s.to_owned(),
+        };
+
+        // Only plain numbers are allowed, eg. no `+` or leading whitespace
+        let num = |part: &str| {
+            if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(err());
+            }
+            part.parse::<u16>().map_err(|_err| err())
+        };
+
+        let mut parts = s.split('.');
+        let major = num(parts.next().ok_or_else(err)?)?;
+        let minor = num(parts.next().ok_or_else(err)?)?;
+        let patch = parts.next().map(num).transpose()?;
+
+        if parts.next().is_some() {
+            return Err(err());
+        }
+
+        Ok(Self {
+            major,
+            minor,
+            patch,
+        })
+    }
+}
+
+impl fmt::Display for RustVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)?;
+        if let Some(patch) = self.patch {
+            write!(f, ".{patch}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> Predicate<'a> {
+    /// Evaluates a [`Self::Sanitize`] predicate against the sanitizers that
+    /// are enabled, returning `None` if this is any other predicate
+    ///
+    /// ```
+    /// use cfg_expr::{Expression, Predicate};
+    ///
+    /// let expr = Expression::parse(r#"all(sanitize = "address", not(sanitize = "leak"))"#).unwrap();
+    ///
+    /// let eval = |enabled: &[&str]| {
+    ///     expr.eval(|pred| pred.matches_sanitizers(enabled).unwrap_or(false))
+    /// };
+    ///
+    /// assert!(eval(&["address"]));
+    /// assert!(!eval(&["address", "leak"]));
+    /// assert!(!eval(&[]));
+    /// ```
+    pub fn matches_sanitizers(&self, enabled: &[&str]) -> Option<bool> {
+        match self {
+            Self::Sanitize(sanitizer) => Some(enabled.contains(sanitizer)),
+            _ => None,
+        }
+    }
+
+    /// Splits the predicate into the key and optional value that it is
+    /// written as in a `cfg()` expression, eg. so that tooling can handle
+    /// every predicate the same way without matching on each kind.
+    ///
+    /// The value is only owned for the predicates whose value isn't a
+    /// string, eg. `target_pointer_width` or `version()`. The `unix` and
+    /// `windows` families are split into their bare form, without a value,
+    /// and `version()` and `accessible()` into their name and argument.
+    ///
+    /// ```
+    /// use cfg_expr::Expression;
+    ///
+    /// let expr = Expression::parse(r#"all(target_os = "linux", unix, feature = "a", target_pointer_width = "64", my_flag)"#).unwrap();
+    /// let parts: Vec<_> = expr
+    ///     .predicates()
+    ///     .map(|pred| {
+    ///         let (key, val) = pred.as_key_value();
+    ///         (key.to_owned(), val.map(|val| val.into_owned()))
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     parts,
+    ///     [
+    ///         ("target_os".to_owned(), Some("linux".to_owned())),
+    ///         ("unix".to_owned(), None),
+    ///         ("feature".to_owned(), Some("a".to_owned())),
+    ///         ("target_pointer_width".to_owned(), Some("64".to_owned())),
+    ///         ("my_flag".to_owned(), None),
+    ///     ]
+    /// );
+    /// ```
+    pub fn as_key_value(&self) -> (&str, Option<Cow<'_, str>>) {
+        match self {
+            Self::Target(tp) => tp.key_value(),
+            Self::Test => ("test", None),
+            Self::DebugAssertions => ("debug_assertions", None),
+            Self::ProcMacro => ("proc_macro", None),
+            Self::TargetThreadLocal => ("target_thread_local", None),
+            Self::Doc => ("doc", None),
+            Self::Doctest => ("doctest", None),
+            Self::Miri => ("miri", None),
+            Self::Fuzzing => ("fuzzing", None),
+            Self::OverflowChecks => ("overflow_checks", None),
+            Self::UbChecks => ("ub_checks", None),
+            Self::Feature(feat) => ("feature", Some(Cow::Borrowed(feat))),
+            Self::TargetFeature(feat) => ("target_feature", Some(Cow::Borrowed(feat))),
+            Self::Sanitize(sanitizer) => ("sanitize", Some(Cow::Borrowed(sanitizer))),
+            Self::Boolean(true) => ("true", None),
+            Self::Boolean(false) => ("false", None),
+            Self::Flag(flag) => (flag, None),
+            Self::KeyValue { key, val } => (key, Some(Cow::Borrowed(val))),
+            Self::Version(version) => ("version", Some(Cow::Owned(version.to_string()))),
+            Self::Accessible(path) => ("accessible", Some(Cow::Borrowed(path))),
+        }
+    }
+
+    /// Writes the predicate in its canonical `cfg()` form, eg `target_os = "linux"`
+    pub(crate) fn write_cfg(&self, w: &mut impl fmt::Write) -> fmt::Result {
+        // version() and accessible() are the only predicates written as a call
+        match self {
+            Self::Version(version) => return write!(w, "version(\"{version}\")"),
+            Self::Accessible(path) => return write!(w, "accessible({path})"),
+            _ => {}
+        }
+
+        let (key, val) = self.as_key_value();
+        w.write_str(key)?;
+
+        if let Some(val) = val {
+            write!(w, " = \"{val}\"")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes the predicate in its canonical `cfg()` form
+///
+/// ```
+/// use cfg_expr::{Expression, Predicate};
+///
+/// let expr = Expression::parse(r#"all(target_family = "unix", feature="a", version("1.70"))"#).unwrap();
+/// let preds: Vec<_> = expr.predicates().map(|pred| pred.to_string()).collect();
+///
+/// assert_eq!(preds, ["unix", r#"feature = "a""#, r#"version("1.70")"#]);
+/// ```
+impl fmt::Display for Predicate<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_cfg(f)
+    }
+}
+
+/// An owned version of [`Predicate`], which doesn't borrow from the
+/// [`Expression`] it came from and so can be retained after it is dropped
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PredicateOwned {
+    /// A target predicate, with the `target_` prefix
+    Target(TargetPredicate),
+    /// Whether rustc's test harness is enabled, see [`Predicate::Test`]
+    Test,
+    /// Enabled when compiling without optimizations, see [`Predicate::DebugAssertions`]
+    DebugAssertions,
+    /// Enabled for crates of the `proc_macro` type, see [`Predicate::ProcMacro`]
+    ProcMacro,
+    /// See [`Predicate::TargetThreadLocal`]
+    TargetThreadLocal,
+    /// See [`Predicate::Doc`]
+    Doc,
+    /// See [`Predicate::Doctest`]
+    Doctest,
+    /// See [`Predicate::Miri`]
+    Miri,
+    /// See [`Predicate::Fuzzing`]
+    Fuzzing,
+    /// See [`Predicate::OverflowChecks`]
+    OverflowChecks,
+    /// See [`Predicate::UbChecks`]
+    UbChecks,
+    /// A `feature = "<name>"`
+    Feature(String),
+    /// A `target_feature = "<name>"`
+    TargetFeature(String),
+    /// A `sanitize = "<sanitizer>"`, see [`Predicate::Sanitize`]
+    Sanitize(String),
+    /// A `true` or `false` literal, see [`Predicate::Boolean`]
+    Boolean(bool),
+    /// A generic bare predicate key that doesn't match one of the known options, eg `cfg(bare)`
+    Flag(String),
+    /// A generic key = "value" predicate that doesn't match one of the known options, eg `cfg(foo = "bar")`
+    KeyValue { key: String, val: String },
+    /// A `version("<version>")` predicate, see [`Predicate::Version`]
+    Version(RustVersion),
+    /// An `accessible(<path>)` predicate, see [`Predicate::Accessible`]
+    Accessible(String),
+}
+
+impl PredicateOwned {
+    /// Borrows the owned predicate as a [`Predicate`]
+    ///
+    /// ```
+    /// use cfg_expr::{expr::PredicateOwned, Expression, Predicate};
+    ///
+    /// let owned: PredicateOwned = {
+    ///     let expr = Expression::parse(r#"feature = "serde""#).unwrap();
+    ///     let pred = expr.predicates().next().unwrap();
+    ///     pred.into()
+    /// };
+    ///
+    /// assert_eq!(owned.as_predicate(), Predicate::Feature("serde"));
+    /// ```
+    pub fn as_predicate(&self) -> Predicate<'_> {
+        match self {
+            Self::Target(tp) => Predicate::Target(tp.clone()),
+            Self::Test => Predicate::Test,
+            Self::DebugAssertions => Predicate::DebugAssertions,
+            Self::ProcMacro => Predicate::ProcMacro,
+            Self::TargetThreadLocal => Predicate::TargetThreadLocal,
+            Self::Doc => Predicate::Doc,
+            Self::Doctest => Predicate::Doctest,
+            Self::Miri => Predicate::Miri,
+            Self::Fuzzing => Predicate::Fuzzing,
+            Self::OverflowChecks => Predicate::OverflowChecks,
+            Self::UbChecks => Predicate::UbChecks,
+            Self::Feature(feat) => Predicate::Feature(feat),
+            Self::TargetFeature(feat) => Predicate::TargetFeature(feat),
+            Self::Sanitize(sanitizer) => Predicate::Sanitize(sanitizer),
+            Self::Boolean(value) => Predicate::Boolean(*value),
+            Self::Flag(flag) => Predicate::Flag(flag),
+            Self::KeyValue { key, val } => Predicate::KeyValue { key, val },
+            Self::Version(version) => Predicate::Version(*version),
+            Self::Accessible(path) => Predicate::Accessible(path),
+        }
+    }
+}
+
+impl<'a> From<&Predicate<'a>> for PredicateOwned {
+    fn from(pred: &Predicate<'a>) -> Self {
+        match pred {
+            Predicate::Target(tp) => Self::Target(tp.clone()),
+            Predicate::Test => Self::Test,
+            Predicate::DebugAssertions => Self::DebugAssertions,
+            Predicate::ProcMacro => Self::ProcMacro,
+            Predicate::TargetThreadLocal => Self::TargetThreadLocal,
+            Predicate::Doc => Self::Doc,
+            Predicate::Doctest => Self::Doctest,
+            Predicate::Miri => Self::Miri,
+            Predicate::Fuzzing => Self::Fuzzing,
+            Predicate::OverflowChecks => Self::OverflowChecks,
+            Predicate::UbChecks => Self::UbChecks,
+            Predicate::Feature(feat) => Self::Feature((*feat).to_owned()),
+            Predicate::TargetFeature(feat) => Self::TargetFeature((*feat).to_owned()),
+            Predicate::Sanitize(sanitizer) => Self::Sanitize((*sanitizer).to_owned()),
+            Predicate::Boolean(value) => Self::Boolean(*value),
+            Predicate::Flag(flag) => Self::Flag((*flag).to_owned()),
+            Predicate::KeyValue { key, val } => Self::KeyValue {
+                key: (*key).to_owned(),
+                val: (*val).to_owned(),
+            },
+            Predicate::Version(version) => Self::Version(*version),
+            Predicate::Accessible(path) => Self::Accessible((*path).to_owned()),
+        }
+    }
+}
+
+impl<'a> From<Predicate<'a>> for PredicateOwned {
+    fn from(pred: Predicate<'a>) -> Self {
+        Self::from(&pred)
+    }
+}
+
+impl<'a> From<&'a PredicateOwned> for Predicate<'a> {
+    fn from(pred: &'a PredicateOwned) -> Self {
+        pred.as_predicate()
+    }
+}
+
+impl<'a> PartialEq<Predicate<'a>> for PredicateOwned {
+    fn eq(&self, other: &Predicate<'a>) -> bool {
+        self.as_predicate() == *other
+    }
+}
+
+impl<'a> PartialEq<PredicateOwned> for Predicate<'a> {
+    fn eq(&self, other: &PredicateOwned) -> bool {
+        *self == other.as_predicate()
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -439,12 +1176,23 @@ pub(crate) enum InnerPredicate {
     Test,
     DebugAssertions,
     ProcMacro,
+    TargetThreadLocal,
+    Doc,
+    Doctest,
+    Miri,
+    Fuzzing,
+    OverflowChecks,
+    UbChecks,
     Feature(Range<usize>),
     TargetFeature(Range<usize>),
+    Sanitize(Range<usize>),
+    Boolean(bool),
     Other {
         identifier: Range<usize>,
         value: Option<Range<usize>>,
     },
+    Version(RustVersion),
+    Accessible(Range<usize>),
 }
 
 impl InnerPredicate {
@@ -455,37 +1203,21 @@ impl InnerPredicate {
         };
 
         match self {
-            IP::Target(it) => match &it.which {
-                Which::Abi => Target(TargetPredicate::Abi(targ::Abi::new(
-                    s[it.span.clone().unwrap()].to_owned(),
-                ))),
-                Which::Arch => Target(TargetPredicate::Arch(targ::Arch::new(
-                    s[it.span.clone().unwrap()].to_owned(),
-                ))),
-                Which::Os => Target(TargetPredicate::Os(targ::Os::new(
-                    s[it.span.clone().unwrap()].to_owned(),
-                ))),
-                Which::Vendor => Target(TargetPredicate::Vendor(targ::Vendor::new(
-                    s[it.span.clone().unwrap()].to_owned(),
-                ))),
-                Which::Env => Target(TargetPredicate::Env(targ::Env::new(
-                    s[it.span.clone().unwrap()].to_owned(),
-                ))),
-                Which::Family => Target(TargetPredicate::Family(targ::Family::new(
-                    s[it.span.clone().unwrap()].to_owned(),
-                ))),
-                Which::Endian(end) => Target(TargetPredicate::Endian(*end)),
-                Which::HasAtomic(has_atomic) => Target(TargetPredicate::HasAtomic(*has_atomic)),
-                Which::Panic => Target(TargetPredicate::Panic(targ::Panic::new(
-                    s[it.span.clone().unwrap()].to_owned(),
-                ))),
-                Which::PointerWidth(pw) => Target(TargetPredicate::PointerWidth(*pw)),
-            },
+            IP::Target(it) => Target(it.pred.clone()),
             IP::Test => Test,
             IP::DebugAssertions => DebugAssertions,
             IP::ProcMacro => ProcMacro,
+            IP::TargetThreadLocal => Predicate::TargetThreadLocal,
+            IP::Doc => Predicate::Doc,
+            IP::Doctest => Predicate::Doctest,
+            IP::Miri => Predicate::Miri,
+            IP::Fuzzing => Predicate::Fuzzing,
+            IP::OverflowChecks => Predicate::OverflowChecks,
+            IP::UbChecks => Predicate::UbChecks,
             IP::Feature(rng) => Feature(&s[rng.clone()]),
             IP::TargetFeature(rng) => TargetFeature(&s[rng.clone()]),
+            IP::Sanitize(rng) => Predicate::Sanitize(&s[rng.clone()]),
+            IP::Boolean(value) => Predicate::Boolean(*value),
             IP::Other { identifier, value } => match value {
                 Some(vs) => KeyValue {
                     key: &s[identifier.clone()],
@@ -493,6 +1225,8 @@ impl InnerPredicate {
                 },
                 None => Flag(&s[identifier.clone()]),
             },
+            IP::Version(version) => Predicate::Version(*version),
+            IP::Accessible(rng) => Predicate::Accessible(&s[rng.clone()]),
         }
     }
 }
@@ -507,6 +1241,8 @@ pub(crate) enum ExprNode {
 #[derive(Clone, Debug)]
 pub struct Expression {
     pub(crate) expr: SmallVec<[ExprNode; 5]>,
+    // The span in the original string of each node in `expr`
+    pub(crate) spans: SmallVec<[Range<usize>; 5]>,
     // We keep the original string around for providing the arbitrary
     // strings that can make up an expression
     pub(crate) original: String,
@@ -524,9 +1260,115 @@ impl Expression {
         })
     }
 
+    /// An iterator over each distinct target predicate in the expression, in
+    /// the order they first appear, including the bare `unix` and `windows`
+    ///
+    /// ```
+    /// use cfg_expr::{expr::TargetPredicate as TP, targets::*, Expression};
+    ///
+    /// let expr = Expression::parse(r#"any(unix, all(target_os = "linux", feature = "a"), target_family = "unix")"#).unwrap();
+    ///
+    /// assert_eq!(
+    ///     expr.target_predicates().collect::<Vec<_>>(),
+    ///     [TP::Family(Family::unix), TP::Os(Os::linux)]
+    /// );
+    /// ```
+    pub fn target_predicates(&self) -> impl Iterator<Item = TargetPredicate> + '_ {
+        unique(self.predicates().filter_map(|pred| match pred {
+            Predicate::Target(tp) => Some(tp),
+            _ => None,
+        }))
+    }
+
+    /// An iterator over each distinct `feature = "<name>"` in the expression,
+    /// in the order they first appear
+    ///
+    /// ```
+    /// use cfg_expr::Expression;
+    ///
+    /// let expr = Expression::parse(r#"any(feature = "b", all(feature = "a", not(feature = "b")))"#).unwrap();
+    ///
+    /// assert_eq!(expr.features().collect::<Vec<_>>(), ["b", "a"]);
+    /// ```
+    pub fn features(&self) -> impl Iterator<Item = &str> + '_ {
+        unique(self.predicates().filter_map(|pred| match pred {
+            Predicate::Feature(feature) => Some(feature),
+            _ => None,
+        }))
+    }
+
+    /// An iterator over each distinct `target_feature = "<name>"` in the
+    /// expression, in the order they first appear
+    ///
+    /// ```
+    /// use cfg_expr::Expression;
+    ///
+    /// let expr = Expression::parse(r#"any(target_feature = "avx2", all(target_feature = "sse2", feature = "simd"))"#).unwrap();
+    ///
+    /// assert_eq!(expr.target_features().collect::<Vec<_>>(), ["avx2", "sse2"]);
+    /// ```
+    pub fn target_features(&self) -> impl Iterator<Item = &str> + '_ {
+        unique(self.predicates().filter_map(|pred| match pred {
+            Predicate::TargetFeature(feature) => Some(feature),
+            _ => None,
+        }))
+    }
+
+    /// An iterator over each predicate in the expression, along with the span
+    /// of the whole predicate in [`Self::original`], eg. `target_os = "linux"`
+    ///
+    /// ```
+    /// use cfg_expr::{Expression, Predicate};
+    ///
+    /// let expr = Expression::parse(r#"all(test, feature = "foo")"#).unwrap();
+    /// let mut preds = expr.predicates_with_spans();
+    ///
+    /// assert_eq!(preds.next(), Some((Predicate::Test, 4..8)));
+    /// assert_eq!(preds.next(), Some((Predicate::Feature("foo"), 10..25)));
+    /// assert_eq!(preds.next(), None);
+    /// ```
+    pub fn predicates_with_spans(
+        &self,
+    ) -> impl Iterator<Item = (Predicate<'_>, Range<usize>)> + '_ {
+        self.expr
+            .iter()
+            .zip(self.spans.iter())
+            .filter_map(move |(item, span)| match item {
+                ExprNode::Predicate(pred) => Some((pred.to_pred(&self.original), span.clone())),
+                ExprNode::Fn(_) => None,
+            })
+    }
+
+    /// An iterator over each function in the expression, along with the span
+    /// of the whole function call in [`Self::original`], from its name to its
+    /// closing parenthesis.
+    ///
+    /// Like the evaluation order, nested functions come before the functions
+    /// that contain them.
+    ///
+    /// ```
+    /// use cfg_expr::{expr::Func, Expression};
+    ///
+    /// let expr = Expression::parse("any(unix, not(test))").unwrap();
+    /// let mut funcs = expr.functions_with_spans();
+    ///
+    /// assert_eq!(funcs.next(), Some((Func::Not, 10..19)));
+    /// assert_eq!(funcs.next(), Some((Func::Any(2), 0..20)));
+    /// assert_eq!(funcs.next(), None);
+    /// ```
+    pub fn functions_with_spans(&self) -> impl Iterator<Item = (Func, Range<usize>)> + '_ {
+        self.expr
+            .iter()
+            .zip(self.spans.iter())
+            .filter_map(|(item, span)| match item {
+                ExprNode::Fn(func) => Some((*func, span.clone())),
+                ExprNode::Predicate(_) => None,
+            })
+    }
+
     /// Evaluates the expression, using the provided closure to determine the value of
     /// each predicate, which are then combined into a final result depending on the
-    /// functions not(), all(), or any() in the expression.
+    /// functions `not()`, `all()`, or `any()` in the expression.
     ///
     /// `eval_predicate` typically returns `bool`, but may return any type that implements
     /// the `Logic` trait.
@@ -576,56 +1418,12 @@ impl Expression {
     /// // evaluates to true.
     /// assert_eq!(eval(&expr, linux_musl), Some(true));
     /// ```
-    pub fn eval<EP, T>(&self, mut eval_predicate: EP) -> T
+    pub fn eval<EP, T>(&self, eval_predicate: EP) -> T
     where
         EP: FnMut(&Predicate<'_>) -> T,
-        T: Logic + std::fmt::Debug,
+        T: Logic + core::fmt::Debug,
     {
-        let mut result_stack = SmallVec::<[T; 8]>::new();
-
-        // We store the expression as postfix, so just evaluate each license
-        // requirement in the order it comes, and then combining the previous
-        // results according to each operator as it comes
-        for node in self.expr.iter() {
-            match node {
-                ExprNode::Predicate(pred) => {
-                    let pred = pred.to_pred(&self.original);
-
-                    result_stack.push(eval_predicate(&pred));
-                }
-                ExprNode::Fn(Func::All(count)) => {
-                    // all() with a comma separated list of configuration predicates.
-                    let mut result = T::top();
-
-                    for _ in 0..*count {
-                        let r = result_stack.pop().unwrap();
-                        result = result.and(r);
-                    }
-
-                    result_stack.push(result);
-                }
-                ExprNode::Fn(Func::Any(count)) => {
-                    // any() with a comma separated list of configuration predicates.
-                    let mut result = T::bottom();
-
-                    for _ in 0..*count {
-                        let r = result_stack.pop().unwrap();
-                        result = result.or(r);
-                    }
-
-                    result_stack.push(result);
-                }
-                ExprNode::Fn(Func::Not) => {
-                    // not() with a configuration predicate.
-                    // It is true if its predicate is false
-                    // and false if its predicate is true.
-                    let r = result_stack.pop().unwrap();
-                    result_stack.push(r.not());
-                }
-            }
-        }
-
-        result_stack.pop().unwrap()
+        eval_postfix(&self.expr, &self.original, eval_predicate)
     }
 
     /// The original string which has been parsed to produce this [`Expression`].
@@ -644,9 +1442,83 @@ impl Expression {
     }
 }
 
-/// [`PartialEq`] will do a **syntactical** comparison, so will just check if both
-/// expressions have been parsed from the same string, **not** if they are semantically
-/// equivalent.
+/// Removes the duplicates of the items, keeping the first of each. The
+/// predicates of an expression are few enough that this is cheaper than
+/// hashing them.
+fn unique<T: PartialEq>(items: impl Iterator<Item = T>) -> impl Iterator<Item = T> {
+    let mut unique = Vec::new();
+    for item in items {
+        if !unique.contains(&item) {
+            unique.push(item);
+        }
+    }
+    unique.into_iter()
+}
+
+/// Evaluates the nodes of an expression, in postfix order, with the spans of
+/// their predicates pointing into `original`
+fn eval_postfix<EP, T>(expr: &[ExprNode], original: &str, mut eval_predicate: EP) -> T
+where
+    EP: FnMut(&Predicate<'_>) -> T,
+    T: Logic + core::fmt::Debug,
+{
+    let mut result_stack = SmallVec::<[T; 8]>::new();
+
+    // We store the expression as postfix, so just evaluate each license
+    // requirement in the order it comes, and then combining the previous
+    // results according to each operator as it comes
+    for node in expr.iter() {
+        match node {
+            // Literals are constant, so they are folded rather than
+            // passed to the caller
+            ExprNode::Predicate(InnerPredicate::Boolean(value)) => {
+                result_stack.push(if *value { T::top() } else { T::bottom() });
+            }
+            ExprNode::Predicate(pred) => {
+                let pred = pred.to_pred(original);
+
+                result_stack.push(eval_predicate(&pred));
+            }
+            ExprNode::Fn(Func::All(count)) => {
+                // all() with a comma separated list of configuration predicates.
+                let mut result = T::top();
+
+                for _ in 0..*count {
+                    let r = result_stack.pop().unwrap();
+                    result = result.and(r);
+                }
+
+                result_stack.push(result);
+            }
+            ExprNode::Fn(Func::Any(count)) => {
+                // any() with a comma separated list of configuration predicates.
+                let mut result = T::bottom();
+
+                for _ in 0..*count {
+                    let r = result_stack.pop().unwrap();
+                    result = result.or(r);
+                }
+
+                result_stack.push(result);
+            }
+            ExprNode::Fn(Func::Not) => {
+                // not() with a configuration predicate.
+                // It is true if its predicate is false
+                // and false if its predicate is true.
+                let r = result_stack.pop().unwrap();
+                result_stack.push(r.not());
+            }
+        }
+    }
+
+    result_stack.pop().unwrap()
+}
+
+/// [`PartialEq`] does a **structural** comparison, so two expressions are equal
+/// if they have the same functions and predicates in the same order, regardless
+/// of insignificant differences in the original strings such as whitespace, or
+/// a bare `unix` versus `target_family = "unix"`. It does **not** check if both
+/// expressions are semantically equivalent.
 ///
 /// ```
 /// use cfg_expr::Expression;
@@ -655,14 +1527,176 @@ impl Expression {
 ///     Expression::parse("any()").unwrap(),
 ///     Expression::parse("any()").unwrap()
 /// );
+/// assert_eq!(
+///     Expression::parse("all(unix,test)").unwrap(),
+///     Expression::parse("all( target_family = \"unix\", test, )").unwrap()
+/// );
 /// assert_ne!(
 ///     Expression::parse("any()").unwrap(),
 ///     Expression::parse("unix").unwrap()
 /// );
+/// assert_ne!(
+///     Expression::parse("all(unix, test)").unwrap(),
+///     Expression::parse("all(test, unix)").unwrap()
+/// );
 /// ```
 impl PartialEq for Expression {
     fn eq(&self, other: &Self) -> bool {
-        self.original.eq(&other.original)
+        self.expr.len() == other.expr.len()
+            && self
+                .expr
+                .iter()
+                .zip(other.expr.iter())
+                .all(|(a, b)| match (a, b) {
+                    (ExprNode::Fn(a), ExprNode::Fn(b)) => a == b,
+                    (ExprNode::Predicate(a), ExprNode::Predicate(b)) => {
+                        a.to_pred(&self.original) == b.to_pred(&other.original)
+                    }
+                    _ => false,
+                })
+    }
+}
+
+impl Eq for Expression {}
+
+/// Hashes the structure of the expression, consistent with its [`PartialEq`]
+/// implementation
+impl core::hash::Hash for Expression {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(self.expr.len());
+
+        for node in &self.expr {
+            match node {
+                ExprNode::Fn(func) => {
+                    state.write_u8(0);
+                    func.hash(state);
+                }
+                ExprNode::Predicate(pred) => {
+                    state.write_u8(1);
+                    pred.to_pred(&self.original).hash(state);
+                }
+            }
+        }
+    }
+}
+
+/// Writes the expression in its canonical form, which can be parsed by
+/// [`Expression::parse`] to yield a semantically identical expression.
+///
+/// Note that the canonical form never includes the outer `cfg()`, and that
+/// insignificant whitespace and trailing commas from the original string
+/// are not preserved.
+///
+/// ```
+/// use cfg_expr::Expression;
+///
+/// let expr = Expression::parse(r#"cfg(all( unix,target_arch="x86_64", not(feature = "foo",),))"#).unwrap();
+/// let canonical = expr.to_string();
+///
+/// assert_eq!(canonical, r#"all(unix, target_arch = "x86_64", not(feature = "foo"))"#);
+/// assert_eq!(Expression::parse(&canonical).unwrap().to_string(), canonical);
+/// ```
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut stack = SmallVec::<[String; 8]>::new();
+
+        // The expression is stored as postfix, so each function just combines
+        // the strings of the operands that were most recently pushed
+        for node in self.expr.iter() {
+            match node {
+                ExprNode::Predicate(pred) => {
+                    let mut ps = String::new();
+                    pred.to_pred(&self.original).write_cfg(&mut ps)?;
+                    stack.push(ps);
+                }
+                ExprNode::Fn(func) => {
+                    let (name, count) = match func {
+                        Func::Not => ("not", 1),
+                        Func::All(count) => ("all", *count),
+                        Func::Any(count) => ("any", *count),
+                    };
+
+                    let operands = stack.drain(stack.len() - count..).collect::<Vec<_>>();
+                    stack.push(format!("{name}({})", operands.join(", ")));
+                }
+            }
+        }
+
+        f.write_str(&stack.pop().unwrap_or_default())
+    }
+}
+
+/// Parses the expression via [`Expression::parse`], so that it can be used
+/// with [`str::parse`]
+///
+/// ```
+/// use cfg_expr::Expression;
+///
+/// let expr: Expression = "cfg(all(unix, feature = \"a\"))".parse().unwrap();
+/// assert_eq!(expr.original(), "all(unix, feature = \"a\")");
+/// ```
+impl core::str::FromStr for Expression {
+    type Err = crate::error::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for Expression {
+    type Error = crate::error::ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+/// Parses the expression, reusing the string as the [`Expression::original`]
+/// if it isn't wrapped in `cfg()`
+impl TryFrom<String> for Expression {
+    type Error = crate::error::ParseError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        let ExpressionRef {
+            expr,
+            spans,
+            original,
+        } = Self::parse_borrowed(&s)?;
+
+        let original = if original.len() == s.len() {
+            s
+        } else {
+            original.to_owned()
+        };
+
+        Ok(Self {
+            expr,
+            spans,
+            original,
+        })
+    }
+}
+
+/// Serializes the expression as its original string
+#[cfg(feature = "serde")]
+impl serde::Serialize for Expression {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.original)
+    }
+}
+
+/// Deserializes the expression from a string via [`Expression::parse`]
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Expression {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::parse(&s).map_err(serde::de::Error::custom)
     }
 }
 
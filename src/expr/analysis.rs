@@ -0,0 +1,311 @@
+use crate::{
+    expr::{
+        normal::{dnf_clauses, Literal},
+        tree::ExprTree,
+        ExprNode, Expression, Func, Predicate, TargetPredicate,
+    },
+    targets::{TargetInfo, ALL_BUILTINS},
+};
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::ops::Range;
+
+/// The reason an expression can't be satisfied by any target
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Unsatisfiable {
+    /// The expression is false for every possible configuration, regardless
+    /// of the target, eg. `all(unix, not(unix))`, or
+    /// `all(target_os = "linux", target_os = "windows")`
+    Contradiction,
+    /// The expression could be true for some configuration, but none of the
+    /// targets match its target predicates, eg. `all(windows, target_os = "linux")`
+    NoMatchingTarget,
+}
+
+/// A subexpression that was found to be constant by [`Expression::lints`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lint {
+    /// The kind of lint
+    pub kind: LintKind,
+    /// The span of the subexpression in [`Expression::original`]
+    pub span: Range<usize>,
+}
+
+/// The kinds of [`Lint`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LintKind {
+    /// The subexpression is true for every builtin target, eg. `any(unix, not(unix))`
+    AlwaysTrue,
+    /// The subexpression is false for every builtin target, eg.
+    /// `all(windows, target_os = "linux")`
+    AlwaysFalse,
+}
+
+/// A subexpression along with its span and the subexpressions of its operands
+struct SpannedTree<'a> {
+    tree: ExprTree<'a>,
+    span: Range<usize>,
+    operands: Vec<SpannedTree<'a>>,
+}
+
+impl<'a> SpannedTree<'a> {
+    /// Pushes every subexpression that is constant for all of the targets,
+    /// without descending into subexpressions that are already constant
+    fn lints(&self, targets: &[TargetInfo], lints: &mut Vec<Lint>) {
+        // Empty all() and any(), and true and false, are the idiomatic ways
+        // of writing constants, so they are assumed to be intentional
+        let is_literal = matches!(&self.tree, ExprTree::All(ops) | ExprTree::Any(ops) if ops.is_empty())
+            || matches!(&self.tree, ExprTree::Predicate(Predicate::Boolean(_)));
+
+        if !is_literal {
+            if let Some(value) = constant_value(&self.tree, targets) {
+                lints.push(Lint {
+                    kind: if value {
+                        LintKind::AlwaysTrue
+                    } else {
+                        LintKind::AlwaysFalse
+                    },
+                    span: self.span.clone(),
+                });
+                return;
+            }
+        }
+
+        for operand in &self.operands {
+            operand.lints(targets, lints);
+        }
+    }
+}
+
+/// Returns the value of the tree if it is the same for every target,
+/// regardless of the value of the predicates that aren't target predicates
+fn constant_value(tree: &ExprTree<'_>, targets: &[TargetInfo]) -> Option<bool> {
+    let mut constant = None;
+
+    for target in targets {
+        let grounded = tree
+            .substitute(&mut |pred| match pred {
+                Predicate::Target(tp) => Some(tp.matches(target)),
+                _ => None,
+            })
+            .simplify();
+
+        let value = if !is_satisfiable(&grounded) {
+            false
+        } else if !is_satisfiable(&ExprTree::Not(Box::new(grounded))) {
+            true
+        } else {
+            return None;
+        };
+
+        if *constant.get_or_insert(value) != value {
+            return None;
+        }
+    }
+
+    constant
+}
+
+/// Returns true if both predicates can't be true at the same time, as they
+/// are for a target property that only has a single value per target, eg.
+/// `target_os = "linux"` and `target_os = "windows"`.
+///
+/// Target families, atomics, and features are multi-valued, eg. a target can
+/// be both `unix` and `wasm`, so they are never exclusive.
+pub(crate) fn is_exclusive(a: &Predicate<'_>, b: &Predicate<'_>) -> bool {
+    use TargetPredicate as TP;
+
+    let (a, b) = match (a, b) {
+        (Predicate::Target(a), Predicate::Target(b)) => (a, b),
+        _ => return false,
+    };
+
+    match (a, b) {
+        (TP::Abi(a), TP::Abi(b)) => a != b,
+        (TP::Arch(a), TP::Arch(b)) => a != b,
+        (TP::Endian(a), TP::Endian(b)) => a != b,
+        (TP::Env(a), TP::Env(b)) => a != b,
+        (TP::Os(a), TP::Os(b)) => a != b,
+        (TP::Panic(a), TP::Panic(b)) => a != b,
+        (TP::PointerWidth(a), TP::PointerWidth(b)) => a != b,
+        (TP::Vendor(a), TP::Vendor(b)) => a != b,
+        _ => false,
+    }
+}
+
+/// Returns true if the clause of a DNF doesn't contain both a predicate and
+/// its negation, or two different values for a single-valued target property
+fn is_consistent(clause: &[Literal<'_>]) -> bool {
+    clause.iter().enumerate().all(|(i, (pred, negated))| {
+        !clause[i + 1..].iter().any(|(other, other_negated)| {
+            (pred == other && negated != other_negated)
+                || (!negated && !other_negated && is_exclusive(pred, other))
+        })
+    })
+}
+
+/// Returns true if there is at least one assignment of the predicates in the
+/// tree that makes it true, treating every distinct predicate as an
+/// independent variable, other than the values of single-valued target
+/// properties, which are mutually exclusive
+pub(crate) fn is_satisfiable(tree: &ExprTree<'_>) -> bool {
+    dnf_clauses(tree, false)
+        .iter()
+        .any(|clause| is_consistent(clause))
+}
+
+impl Expression {
+    /// Returns true if every configuration that satisfies this expression also
+    /// satisfies `other`, ie. this expression is at least as narrow as `other`.
+    ///
+    /// Predicates are treated as independent of each other, with the exception
+    /// of target properties that only have a single value per target, so
+    /// `target_os = "linux"` implies `not(target_os = "windows")`. Other
+    /// relationships between predicates, eg. that `target_os = "linux"`
+    /// implies `unix`, are not taken into account, see
+    /// [`Self::is_satisfiable_by_any_builtin`] for that.
+    ///
+    /// ```
+    /// use cfg_expr::Expression;
+    ///
+    /// let narrow = Expression::parse(r#"all(unix, target_arch = "x86_64")"#).unwrap();
+    /// let wide = Expression::parse(r#"any(unix, windows)"#).unwrap();
+    ///
+    /// assert!(narrow.implies(&wide));
+    /// assert!(!wide.implies(&narrow));
+    ///
+    /// let linux = Expression::parse(r#"target_os = "linux""#).unwrap();
+    /// let not_windows = Expression::parse(r#"not(target_os = "windows")"#).unwrap();
+    ///
+    /// assert!(linux.implies(&not_windows));
+    /// ```
+    pub fn implies(&self, other: &Expression) -> bool {
+        // A implies B if there is no configuration where A is true and B is false
+        !is_satisfiable(&ExprTree::All(vec![
+            self.root(),
+            ExprTree::Not(Box::new(other.root())),
+        ]))
+    }
+
+    /// Checks if at least one of the [builtin](crate::targets::ALL_BUILTINS)
+    /// targets can make the expression true, returning the first one that does.
+    ///
+    /// Target predicates are evaluated against each target, while all other
+    /// predicates, including `target_feature`, are assumed to be free to take
+    /// any value.
+    ///
+    /// ```
+    /// use cfg_expr::{expr::Unsatisfiable, Expression};
+    ///
+    /// let expr = Expression::parse(r#"all(unix, target_arch = "x86_64", feature = "foo")"#).unwrap();
+    /// assert!(expr.is_satisfiable_by_any_builtin().is_ok());
+    ///
+    /// let expr = Expression::parse(r#"all(windows, target_os = "linux")"#).unwrap();
+    /// assert_eq!(expr.is_satisfiable_by_any_builtin(), Err(Unsatisfiable::NoMatchingTarget));
+    ///
+    /// let expr = Expression::parse(r#"all(feature = "foo", not(feature = "foo"))"#).unwrap();
+    /// assert_eq!(expr.is_satisfiable_by_any_builtin(), Err(Unsatisfiable::Contradiction));
+    /// ```
+    pub fn is_satisfiable_by_any_builtin(&self) -> Result<&'static TargetInfo, Unsatisfiable> {
+        self.is_satisfiable_by_any(ALL_BUILTINS)
+    }
+
+    /// Checks if at least one of the specified targets can make the expression
+    /// true, returning the first one that does.
+    ///
+    /// See [`Self::is_satisfiable_by_any_builtin`] for more details.
+    pub fn is_satisfiable_by_any<'t>(
+        &self,
+        targets: &'t [TargetInfo],
+    ) -> Result<&'t TargetInfo, Unsatisfiable> {
+        let tree = self.root();
+
+        if !is_satisfiable(&tree) {
+            return Err(Unsatisfiable::Contradiction);
+        }
+
+        targets
+            .iter()
+            .find(|target| {
+                let grounded = tree.substitute(&mut |pred| match pred {
+                    Predicate::Target(tp) => Some(tp.matches(*target)),
+                    _ => None,
+                });
+
+                is_satisfiable(&grounded.simplify())
+            })
+            .ok_or(Unsatisfiable::NoMatchingTarget)
+    }
+
+    /// Finds the subexpressions that have the same value for every
+    /// [builtin](crate::targets::ALL_BUILTINS) target, which are almost
+    /// certainly mistakes.
+    ///
+    /// Like [`Self::is_satisfiable_by_any_builtin`], predicates other than
+    /// target predicates are assumed to be free to take any value, so
+    /// `any(feature = "a", not(feature = "a"))` is always true, but
+    /// `feature = "a"` is not constant.
+    ///
+    /// Only the outermost constant subexpressions are reported, and the empty
+    /// `all()` and `any()`, and `true` and `false`, are assumed to be
+    /// intentional constants.
+    ///
+    /// ```
+    /// use cfg_expr::{expr::LintKind, Expression};
+    ///
+    /// let expr = Expression::parse(r#"any(feature = "a", all(windows, target_os = "linux"), all(test, any(unix, not(unix))))"#).unwrap();
+    ///
+    /// let lints: Vec<_> = expr
+    ///     .lints()
+    ///     .into_iter()
+    ///     .map(|lint| (lint.kind, &expr.original()[lint.span]))
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     lints,
+    ///     [
+    ///         (LintKind::AlwaysFalse, r#"all(windows, target_os = "linux")"#),
+    ///         (LintKind::AlwaysTrue, "any(unix, not(unix))"),
+    ///     ]
+    /// );
+    /// ```
+    pub fn lints(&self) -> Vec<Lint> {
+        let mut stack: Vec<SpannedTree<'_>> = Vec::new();
+
+        for (node, span) in self.expr.iter().zip(self.spans.iter()) {
+            let span = span.clone();
+
+            let spanned = match node {
+                ExprNode::Predicate(pred) => SpannedTree {
+                    tree: ExprTree::Predicate(pred.to_pred(&self.original)),
+                    span,
+                    operands: Vec::new(),
+                },
+                ExprNode::Fn(func) => {
+                    let count = match func {
+                        Func::All(count) | Func::Any(count) => *count,
+                        Func::Not => 1,
+                    };
+
+                    let operands = stack.split_off(stack.len() - count);
+                    let mut trees = operands.iter().map(|op| op.tree.clone());
+
+                    SpannedTree {
+                        tree: match func {
+                            Func::All(_) => ExprTree::All(trees.collect()),
+                            Func::Any(_) => ExprTree::Any(trees.collect()),
+                            Func::Not => ExprTree::Not(Box::new(trees.next().unwrap())),
+                        },
+                        span,
+                        operands,
+                    }
+                }
+            };
+
+            stack.push(spanned);
+        }
+
+        let mut lints = Vec::new();
+        stack.pop().unwrap().lints(ALL_BUILTINS, &mut lints);
+        lints
+    }
+}
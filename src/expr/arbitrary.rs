@@ -0,0 +1,114 @@
+use crate::expr::Expression;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// The deepest that functions are nested in generated expressions
+const MAX_DEPTH: u32 = 4;
+
+/// The most operands of a generated `all()` or `any()`
+const MAX_OPERANDS: u32 = 4;
+
+/// Bare names, including ones that are also target predicates
+const NAMES: &[&str] = &[
+    "unix",
+    "windows",
+    "test",
+    "debug_assertions",
+    "proc_macro",
+    "doc",
+    "miri",
+    "tokio_unstable",
+];
+
+/// Names with values, including ones that are target predicates, and values
+/// that aren't known for them
+const KEY_VALUES: &[(&str, &[&str])] = &[
+    (
+        "target_arch",
+        &["x86_64", "aarch64", "wasm32", "arm", "my-arch"],
+    ),
+    ("target_endian", &["little", "big"]),
+    ("target_env", &["", "gnu", "msvc", "musl"]),
+    ("target_family", &["unix", "windows", "wasm"]),
+    ("target_has_atomic", &["8", "64", "ptr"]),
+    ("target_os", &["linux", "windows", "macos", "none", "my-os"]),
+    ("target_pointer_width", &["16", "32", "64"]),
+    ("target_vendor", &["unknown", "apple", "pc"]),
+    ("panic", &["unwind", "abort"]),
+    ("feature", &["std", "alloc", "serde"]),
+    ("target_feature", &["sse2", "avx2", "neon"]),
+    ("my_key", &["a", "b"]),
+];
+
+fn write_predicate(u: &mut Unstructured<'_>, out: &mut String) -> Result<()> {
+    match u.int_in_range(0..=9)? {
+        0 => out.push_str(u.choose(&["true", "false"])?),
+        1 => out.push_str(u.choose(&[r#"version("1.70")"#, "accessible(std::ptr)"])?),
+        2..=4 => out.push_str(u.choose(NAMES)?),
+        _ => {
+            let (key, values) = u.choose(KEY_VALUES)?;
+            let value = u.choose(values)?;
+            out.push_str(&format!("{key} = \"{value}\""));
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes a random expression, with functions nested at most `depth` deep
+fn write_expr(u: &mut Unstructured<'_>, depth: u32, out: &mut String) -> Result<()> {
+    // Once the data runs out, every choice is the first one, which is a
+    // predicate, so that the expression is always complete
+    let func = if depth == 0 {
+        0
+    } else {
+        u.int_in_range(0..=3)?
+    };
+
+    let name = match func {
+        0 => return write_predicate(u, out),
+        1 => "not",
+        2 => "all",
+        _ => "any",
+    };
+
+    let count = if name == "not" {
+        1
+    } else {
+        u.int_in_range(0..=MAX_OPERANDS)?
+    };
+
+    out.push_str(name);
+    out.push('(');
+    for i in 0..count {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        write_expr(u, depth - 1, out)?;
+    }
+    out.push(')');
+
+    Ok(())
+}
+
+/// Generates a random, valid expression, made up of well known predicates,
+/// including target predicates with both known and unknown values, and
+/// arbitrary flags and key-value pairs, so that downstream crates can
+/// property test their evaluators, and round trips can be fuzzed.
+///
+/// ```
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use cfg_expr::Expression;
+///
+/// let mut u = Unstructured::new(&[2, 3, 0, 7, 2, 9, 5, 1, 1, 4]);
+/// let expr = Expression::arbitrary(&mut u).unwrap();
+///
+/// assert_eq!(Expression::parse(&expr.to_string()).unwrap(), expr);
+/// ```
+impl<'a> Arbitrary<'a> for Expression {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut original = String::new();
+        write_expr(u, MAX_DEPTH, &mut original)?;
+
+        Ok(Expression::parse(&original).expect("generated expressions are valid"))
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{
+    error::{ParseError, Reason},
+    expr::Expression,
+};
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+
+/// A parsed `#[cfg_attr(<predicate>, <attributes>...)]` attribute
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CfgAttr {
+    /// The predicate that determines if the attributes are applied
+    pub predicate: Expression,
+    /// The raw text of each of the attributes that are applied if the
+    /// predicate is true, without any surrounding whitespace, eg. `derive(Debug)`
+    pub attributes: Vec<String>,
+}
+
+/// Finds the byte index of the first `,` that isn't nested inside brackets or
+/// a string literal
+fn find_top_level_comma(text: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut in_str = false;
+    let mut escaped = false;
+
+    for (i, c) in text.char_indices() {
+        if in_str {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_str = false,
+                _ => {}
+            }
+
+            continue;
+        }
+
+        match c {
+            '"' => in_str = true,
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => return Some(i),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Strips the `#[<name>(` and `)]` shell from an attribute, returning the
+/// contents of the parentheses and their offset in the attribute
+fn strip_attribute<'a>(
+    attr: &'a str,
+    name: &str,
+    expected: &'static [&'static str],
+) -> Result<(&'a str, usize), ParseError> {
+    let err = |span| ParseError {
+        original: attr.to_owned(),
+        span,
+        reason: Reason::Unexpected(expected),
+    };
+
+    let mut offset = 0;
+
+    // Consumes leading whitespace and the expected token, if it's present
+    let mut eat = |token: &str| -> bool {
+        let rest = &attr[offset..];
+        let trimmed = rest.trim_start();
+        if let Some(after) = trimmed.strip_prefix(token) {
+            offset += rest.len() - after.len();
+            true
+        } else {
+            false
+        }
+    };
+
+    if !eat("#") {
+        return Err(err(0..attr.len()));
+    }
+
+    // Inner attributes are equivalent for our purposes
+    eat("!");
+
+    if !(eat("[") && eat(name) && eat("(")) {
+        return Err(err(0..attr.len()));
+    }
+
+    let start = offset;
+
+    // The closing `)]` must be at the very end of the attribute
+    let end = attr
+        .trim_end()
+        .strip_suffix(']')
+        .and_then(|rest| rest.trim_end().strip_suffix(')'))
+        .map(str::len)
+        .filter(|end| *end >= start)
+        .ok_or_else(|| err(start..attr.len()))?;
+
+    Ok((&attr[start..end], start))
+}
+
+/// Parses the expression, adjusting any error so that it points into the
+/// full attribute
+fn parse_inner(attr: &str, inner: &str, offset: usize) -> Result<Expression, ParseError> {
+    let trimmed = inner.trim_start();
+    let offset = offset + inner.len() - trimmed.len();
+
+    Expression::parse(trimmed.trim_end()).map_err(|err| ParseError {
+        original: attr.to_owned(),
+        span: err.span.start + offset..err.span.end + offset,
+        reason: err.reason,
+    })
+}
+
+impl Expression {
+    /// Parses the expression inside of a `#[cfg(...)]` attribute, as it
+    /// would be written in Rust source. Inner attributes, ie. `#![cfg(...)]`,
+    /// are also accepted.
+    ///
+    /// The [`Self::original`] of the expression is only the predicate inside
+    /// the attribute, but the spans of any errors point into the full attribute.
+    ///
+    /// ```
+    /// use cfg_expr::Expression;
+    ///
+    /// let expr = Expression::parse_attribute(r#"#[cfg(all(unix, feature = "x"))]"#).unwrap();
+    /// assert_eq!(expr.original(), r#"all(unix, feature = "x")"#);
+    ///
+    /// let err = Expression::parse_attribute(r#"#[cfg(all(unix, feature))]"#).unwrap_err();
+    /// assert_eq!(err.span, 16..23);
+    /// ```
+    pub fn parse_attribute(attr: &str) -> Result<Self, ParseError> {
+        let (inner, offset) = strip_attribute(attr, "cfg", &["#[cfg(<predicate>)]"])?;
+        parse_inner(attr, inner, offset)
+    }
+}
+
+impl CfgAttr {
+    /// Parses a `#[cfg_attr(<predicate>, <attributes>...)]` attribute, as
+    /// it would be written in Rust source. Inner attributes, ie.
+    /// `#![cfg_attr(...)]`, are also accepted.
+    ///
+    /// The attributes aren't parsed, other than splitting them on commas.
+    ///
+    /// ```
+    /// use cfg_expr::expr::CfgAttr;
+    ///
+    /// let attr = CfgAttr::parse(r#"#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(rename = "a,b"))]"#).unwrap();
+    ///
+    /// assert_eq!(attr.predicate.original(), r#"feature = "serde""#);
+    /// assert_eq!(attr.attributes, ["derive(Serialize, Deserialize)", r#"serde(rename = "a,b")"#]);
+    /// ```
+    pub fn parse(attr: &str) -> Result<Self, ParseError> {
+        let (inner, offset) = strip_attribute(
+            attr,
+            "cfg_attr",
+            &["#[cfg_attr(<predicate>, <attributes>...)]"],
+        )?;
+
+        let comma = find_top_level_comma(inner).ok_or_else(|| ParseError {
+            original: attr.to_owned(),
+            span: offset + inner.len()..offset + inner.len(),
+            reason: Reason::Unexpected(&[","]),
+        })?;
+
+        let predicate = parse_inner(attr, &inner[..comma], offset)?;
+
+        let mut attributes = Vec::new();
+        let mut rest = &inner[comma + 1..];
+
+        loop {
+            let (attribute, next) = match find_top_level_comma(rest) {
+                Some(comma) => (&rest[..comma], Some(&rest[comma + 1..])),
+                None => (rest, None),
+            };
+
+            // A trailing comma is allowed, as is an empty attribute list
+            let attribute = attribute.trim();
+            if !attribute.is_empty() {
+                attributes.push(attribute.to_owned());
+            }
+
+            match next {
+                Some(next) => rest = next,
+                None => break,
+            }
+        }
+
+        Ok(Self {
+            predicate,
+            attributes,
+        })
+    }
+}
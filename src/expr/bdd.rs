@@ -0,0 +1,356 @@
+use crate::expr::{ExprNode, Expression, Func, InnerPredicate, Predicate};
+use std::collections::HashMap;
+
+/// The cfg names that only have a single value per target, so that two of
+/// their predicates with different values can't both be true
+const SINGLE_VALUED: &[&str] = &[
+    "panic",
+    "target_abi",
+    "target_arch",
+    "target_endian",
+    "target_env",
+    "target_os",
+    "target_pointer_width",
+    "target_vendor",
+];
+
+/// A reduced ordered binary decision diagram, which is a handle to a node in
+/// the [`Universe`] that created it.
+///
+/// Diagrams are canonical, so two diagrams from the same universe are equal
+/// if and only if they are equivalent, ignoring the relationships between
+/// predicates, see [`Universe::equivalent`] for those.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Bdd(u32);
+
+impl Bdd {
+    /// The diagram that is always false
+    pub const FALSE: Self = Self(0);
+    /// The diagram that is always true
+    pub const TRUE: Self = Self(1);
+
+    /// Returns true if the diagram is [`Self::TRUE`] or [`Self::FALSE`]
+    pub fn is_constant(self) -> bool {
+        self == Self::FALSE || self == Self::TRUE
+    }
+}
+
+/// A decision on a variable, which leads to `low` if it is false, and `high`
+/// if it is true
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+struct Node {
+    var: u32,
+    low: Bdd,
+    high: Bdd,
+}
+
+/// A variable of the universe, which is a single predicate
+#[derive(Clone, Debug)]
+struct Variable {
+    /// The canonical `cfg()` form of the predicate, eg. `target_os = "linux"`
+    cfg: String,
+    /// The name of the predicate, eg. `target_os`
+    name: String,
+}
+
+/// The set of predicates, and the diagrams built from them, that expressions
+/// are compiled into.
+///
+/// Each distinct predicate is a variable, ordered by when it was first seen.
+/// Building a diagram can take time exponential in the number of predicates
+/// in the worst case, but once built, equivalence is a comparison, and
+/// implication and satisfiability are cached operations on the diagrams, so
+/// a universe is suited to answering many queries about the same
+/// expressions.
+///
+/// As with [`Expression::implies`], predicates are independent of each other,
+/// other than target properties that only have a single value per target, so
+/// `target_os = "linux"` implies `not(target_os = "windows")`.
+///
+/// ```
+/// use cfg_expr::{expr::bdd::Universe, Expression};
+///
+/// let mut universe = Universe::new();
+///
+/// let a = universe.compile(&Expression::parse(r#"all(unix, any(feature = "a", feature = "b"))"#).unwrap());
+/// let b = universe.compile(&Expression::parse(r#"any(all(feature = "b", unix), all(unix, feature = "a"))"#).unwrap());
+/// let wide = universe.compile(&Expression::parse(r#"any(unix, windows)"#).unwrap());
+///
+/// assert_eq!(a, b);
+/// assert!(universe.implies(a, wide));
+/// assert!(!universe.implies(wide, a));
+///
+/// let linux = universe.compile(&Expression::parse(r#"target_os = "linux""#).unwrap());
+/// let windows = universe.compile(&Expression::parse(r#"target_os = "windows""#).unwrap());
+/// let both = universe.and(linux, windows);
+/// assert!(!universe.is_satisfiable(both));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Universe {
+    vars: Vec<Variable>,
+    var_indices: HashMap<String, u32>,
+    nodes: Vec<Node>,
+    unique: HashMap<Node, Bdd>,
+    ite_cache: HashMap<(Bdd, Bdd, Bdd), Bdd>,
+    /// The assignments that are possible, ie. where no two values of a
+    /// single-valued target property are both true
+    domain: Bdd,
+}
+
+impl Default for Universe {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Universe {
+    /// Creates a universe without any predicates
+    pub fn new() -> Self {
+        // The terminals aren't decisions, so they have a variable that is
+        // after every other one
+        let terminal = |value| Node {
+            var: u32::MAX,
+            low: value,
+            high: value,
+        };
+
+        Self {
+            vars: Vec::new(),
+            var_indices: HashMap::new(),
+            nodes: vec![terminal(Bdd::FALSE), terminal(Bdd::TRUE)],
+            unique: HashMap::new(),
+            ite_cache: HashMap::new(),
+            domain: Bdd::TRUE,
+        }
+    }
+
+    /// The number of nodes in the universe, including the terminals
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Iterates over the predicates of the universe, in their canonical
+    /// `cfg()` form, in the order of their variables
+    pub fn predicates(&self) -> impl Iterator<Item = &str> + '_ {
+        self.vars.iter().map(|var| var.cfg.as_str())
+    }
+
+    fn node(&self, bdd: Bdd) -> Node {
+        self.nodes[bdd.0 as usize]
+    }
+
+    /// Creates the node for a decision, unless both branches are the same
+    fn make(&mut self, var: u32, low: Bdd, high: Bdd) -> Bdd {
+        if low == high {
+            return low;
+        }
+
+        let node = Node { var, low, high };
+        if let Some(bdd) = self.unique.get(&node) {
+            return *bdd;
+        }
+
+        let bdd = Bdd(self.nodes.len() as u32);
+        self.nodes.push(node);
+        self.unique.insert(node, bdd);
+        bdd
+    }
+
+    /// The branches of the diagram when the variable is false and true
+    fn cofactors(&self, bdd: Bdd, var: u32) -> (Bdd, Bdd) {
+        let node = self.node(bdd);
+        if node.var == var {
+            (node.low, node.high)
+        } else {
+            (bdd, bdd)
+        }
+    }
+
+    /// If-then-else, ie. `g` where `f` is true, and `h` where it is false,
+    /// which every other operation is built on
+    fn ite(&mut self, f: Bdd, g: Bdd, h: Bdd) -> Bdd {
+        if f == Bdd::TRUE || g == h {
+            return g;
+        }
+        if f == Bdd::FALSE {
+            return h;
+        }
+        if g == Bdd::TRUE && h == Bdd::FALSE {
+            return f;
+        }
+
+        if let Some(bdd) = self.ite_cache.get(&(f, g, h)) {
+            return *bdd;
+        }
+
+        let var = self.node(f).var.min(self.node(g).var).min(self.node(h).var);
+
+        let (f_low, f_high) = self.cofactors(f, var);
+        let (g_low, g_high) = self.cofactors(g, var);
+        let (h_low, h_high) = self.cofactors(h, var);
+
+        let low = self.ite(f_low, g_low, h_low);
+        let high = self.ite(f_high, g_high, h_high);
+        let bdd = self.make(var, low, high);
+
+        self.ite_cache.insert((f, g, h), bdd);
+        bdd
+    }
+
+    /// `all()` of both diagrams
+    pub fn and(&mut self, a: Bdd, b: Bdd) -> Bdd {
+        self.ite(a, b, Bdd::FALSE)
+    }
+
+    /// `any()` of both diagrams
+    pub fn or(&mut self, a: Bdd, b: Bdd) -> Bdd {
+        self.ite(a, Bdd::TRUE, b)
+    }
+
+    /// `not()` of the diagram
+    pub fn not(&mut self, bdd: Bdd) -> Bdd {
+        self.ite(bdd, Bdd::FALSE, Bdd::TRUE)
+    }
+
+    /// Retrieves the diagram of a single predicate, adding it to the universe
+    /// if it isn't already in it
+    pub fn predicate(&mut self, pred: &Predicate<'_>) -> Bdd {
+        match pred {
+            Predicate::Boolean(true) => return Bdd::TRUE,
+            Predicate::Boolean(false) => return Bdd::FALSE,
+            _ => {}
+        }
+
+        let mut cfg = String::new();
+        pred.write_cfg(&mut cfg).unwrap();
+
+        if let Some(var) = self.var_indices.get(&cfg) {
+            return self.make(*var, Bdd::FALSE, Bdd::TRUE);
+        }
+
+        let var = self.vars.len() as u32;
+        let (name, value) = pred.as_key_value();
+        self.var_indices.insert(cfg.clone(), var);
+        self.vars.push(Variable {
+            cfg,
+            name: name.to_owned(),
+        });
+
+        let bdd = self.make(var, Bdd::FALSE, Bdd::TRUE);
+
+        // A new value of a single-valued property excludes each of the
+        // values that are already known
+        if value.is_some() && SINGLE_VALUED.contains(&name) {
+            for other in 0..var {
+                if self.vars[other as usize].name != name {
+                    continue;
+                }
+
+                let other = self.make(other, Bdd::FALSE, Bdd::TRUE);
+                let both = self.and(bdd, other);
+                let neither = self.not(both);
+                self.domain = self.and(self.domain, neither);
+            }
+        }
+
+        bdd
+    }
+
+    /// Compiles the expression into a diagram
+    pub fn compile(&mut self, expr: &Expression) -> Bdd {
+        let mut stack = Vec::<Bdd>::new();
+
+        for node in expr.expr.iter() {
+            let bdd = match node {
+                ExprNode::Predicate(InnerPredicate::Boolean(value)) => {
+                    if *value {
+                        Bdd::TRUE
+                    } else {
+                        Bdd::FALSE
+                    }
+                }
+                ExprNode::Predicate(pred) => self.predicate(&pred.to_pred(&expr.original)),
+                ExprNode::Fn(Func::All(count)) => {
+                    let operands = stack.split_off(stack.len() - count);
+                    operands
+                        .into_iter()
+                        .fold(Bdd::TRUE, |acc, op| self.and(acc, op))
+                }
+                ExprNode::Fn(Func::Any(count)) => {
+                    let operands = stack.split_off(stack.len() - count);
+                    operands
+                        .into_iter()
+                        .fold(Bdd::FALSE, |acc, op| self.or(acc, op))
+                }
+                ExprNode::Fn(Func::Not) => {
+                    let operand = stack.pop().unwrap();
+                    self.not(operand)
+                }
+            };
+
+            stack.push(bdd);
+        }
+
+        stack.pop().unwrap()
+    }
+
+    /// Returns true if there is at least one possible assignment of the
+    /// predicates that makes the diagram true
+    pub fn is_satisfiable(&mut self, bdd: Bdd) -> bool {
+        self.and(bdd, self.domain) != Bdd::FALSE
+    }
+
+    /// Returns true if every possible assignment of the predicates makes the
+    /// diagram true
+    pub fn is_tautology(&mut self, bdd: Bdd) -> bool {
+        self.implies(Bdd::TRUE, bdd)
+    }
+
+    /// Returns true if every possible assignment of the predicates that makes
+    /// `a` true also makes `b` true
+    pub fn implies(&mut self, a: Bdd, b: Bdd) -> bool {
+        let not_b = self.not(b);
+        let counter = self.and(a, not_b);
+        !self.is_satisfiable(counter)
+    }
+
+    /// Returns true if the diagrams are true for the same possible
+    /// assignments of the predicates
+    pub fn equivalent(&mut self, a: Bdd, b: Bdd) -> bool {
+        a == b || (self.implies(a, b) && self.implies(b, a))
+    }
+
+    /// Finds a possible assignment of the predicates that makes the diagram
+    /// true, with the value of each predicate that it depends on, in their
+    /// canonical `cfg()` form
+    ///
+    /// ```
+    /// use cfg_expr::{expr::bdd::Universe, Expression};
+    ///
+    /// let mut universe = Universe::new();
+    /// let bdd = universe.compile(&Expression::parse(r#"all(unix, not(feature = "std"))"#).unwrap());
+    ///
+    /// assert_eq!(
+    ///     universe.satisfying_assignment(bdd).unwrap(),
+    ///     [("unix", true), (r#"feature = "std""#, false)],
+    /// );
+    /// ```
+    pub fn satisfying_assignment(&mut self, bdd: Bdd) -> Option<Vec<(&str, bool)>> {
+        let mut bdd = self.and(bdd, self.domain);
+        if bdd == Bdd::FALSE {
+            return None;
+        }
+
+        // Every node other than the false terminal leads to the true one, so
+        // either branch that isn't false can be taken
+        let mut assignment = Vec::new();
+        while bdd != Bdd::TRUE {
+            let node = self.node(bdd);
+            let value = node.low == Bdd::FALSE;
+            assignment.push((self.vars[node.var as usize].cfg.as_str(), value));
+            bdd = if value { node.high } else { node.low };
+        }
+
+        Some(assignment)
+    }
+}
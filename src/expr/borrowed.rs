@@ -0,0 +1,91 @@
+use crate::{
+    error::ParseError,
+    expr::{eval_postfix, ExprNode, Expression, Logic, Predicate},
+};
+use alloc::{borrow::ToOwned, vec::Vec};
+use core::ops::Range;
+use smallvec::SmallVec;
+
+/// A parsed `cfg()` expression that borrows the string it was parsed from,
+/// rather than copying it like [`Expression`], see
+/// [`Expression::parse_borrowed`]
+#[derive(Clone, Debug)]
+pub struct ExpressionRef<'a> {
+    pub(crate) expr: SmallVec<[ExprNode; 5]>,
+    // The span in the original string of each node in `expr`
+    pub(crate) spans: SmallVec<[Range<usize>; 5]>,
+    pub(crate) original: &'a str,
+}
+
+impl Expression {
+    /// Parses the expression like [`Self::parse`], but without copying the
+    /// string, for callers that already keep it around, eg. the metadata of
+    /// every crate in a registry index, where copying each expression would
+    /// be the bulk of the allocations
+    ///
+    /// ```
+    /// use cfg_expr::{Expression, Predicate};
+    ///
+    /// let metadata = String::from(r#"cfg(all(unix, feature = "a"))"#);
+    /// let expr = Expression::parse_borrowed(&metadata).unwrap();
+    ///
+    /// // The predicates borrow the string, rather than the expression
+    /// let features: Vec<_> = expr
+    ///     .predicates()
+    ///     .filter_map(|pred| match pred {
+    ///         Predicate::Feature(feature) => Some(feature),
+    ///         _ => None,
+    ///     })
+    ///     .collect();
+    /// drop(expr);
+    ///
+    /// assert_eq!(features, ["a"]);
+    /// ```
+    pub fn parse_borrowed(original: &str) -> Result<ExpressionRef<'_>, ParseError> {
+        let mut errors = Vec::new();
+        Self::parse_impl(original, &mut errors, false).ok_or_else(|| errors.pop().unwrap())
+    }
+}
+
+impl<'a> ExpressionRef<'a> {
+    /// An iterator over each predicate in the expression, which borrow the
+    /// original string rather than the expression
+    pub fn predicates(&self) -> impl Iterator<Item = Predicate<'a>> + '_ {
+        let original = self.original;
+        self.expr.iter().filter_map(move |item| match item {
+            ExprNode::Predicate(pred) => Some(pred.to_pred(original)),
+            ExprNode::Fn(_) => None,
+        })
+    }
+
+    /// Evaluates the expression, see [`Expression::eval`]
+    pub fn eval<EP, T>(&self, eval_predicate: EP) -> T
+    where
+        EP: FnMut(&Predicate<'_>) -> T,
+        T: Logic + core::fmt::Debug,
+    {
+        eval_postfix(&self.expr, self.original, eval_predicate)
+    }
+
+    /// The string the expression was parsed from, excluding any `cfg()`
+    /// wrapper, see [`Expression::original`]
+    pub fn original(&self) -> &'a str {
+        self.original
+    }
+
+    /// Copies the original string, so that the expression no longer borrows
+    /// it
+    pub fn into_owned(self) -> Expression {
+        Expression {
+            expr: self.expr,
+            spans: self.spans,
+            original: self.original.to_owned(),
+        }
+    }
+}
+
+impl<'a> From<ExpressionRef<'a>> for Expression {
+    fn from(expr: ExpressionRef<'a>) -> Self {
+        expr.into_owned()
+    }
+}
@@ -0,0 +1,127 @@
+//! Functions for constructing [`Expression`]s programmatically, rather than
+//! formatting a string and parsing it.
+//!
+//! ```
+//! use cfg_expr::{
+//!     expr::builder::{all, any, not, pred},
+//!     targets::{Arch, Family},
+//!     Predicate, TargetPredicate,
+//! };
+//!
+//! let expr = all([
+//!     pred(Predicate::Target(TargetPredicate::Family(Family::unix))),
+//!     any([
+//!         pred(Predicate::Target(TargetPredicate::Arch(Arch::x86_64))),
+//!         pred(Predicate::Target(TargetPredicate::Arch(Arch::aarch64))),
+//!     ]),
+//!     not(pred(Predicate::Feature("slow"))),
+//! ]);
+//!
+//! assert_eq!(
+//!     expr.to_string(),
+//!     r#"all(unix, any(target_arch = "x86_64", target_arch = "aarch64"), not(feature = "slow"))"#
+//! );
+//! ```
+
+use crate::{
+    error::ParseError,
+    expr::{tree::ExprTree, Expression, Predicate},
+};
+use alloc::{boxed::Box, vec::Vec};
+
+/// Creates an expression consisting of a single predicate.
+///
+/// Panics if the predicate can't be written in a form that parses again, eg.
+/// if a value contains `"`, or a key isn't an identifier, see [`try_pred`]
+/// for a fallible version.
+pub fn pred(pred: Predicate<'_>) -> Expression {
+    match try_pred(pred) {
+        Ok(expr) => expr,
+        Err(err) => panic!("{err}"),
+    }
+}
+
+/// Creates an expression consisting of a single predicate, or returns the
+/// error of parsing it if the predicate, written in its canonical form,
+/// isn't a valid expression, so that the [`original`](Expression::original)
+/// and [`Display`](core::fmt::Display) output of a built expression can
+/// always be parsed again.
+///
+/// ```
+/// use cfg_expr::{expr::builder::try_pred, error::Reason, Predicate};
+///
+/// assert!(try_pred(Predicate::Feature("foo")).is_ok());
+///
+/// let err = try_pred(Predicate::Feature(r#"fo"o"#)).unwrap_err();
+/// assert_eq!(err.original, r#"feature = "fo"o""#);
+///
+/// let err = try_pred(Predicate::Flag("a key")).unwrap_err();
+/// assert_eq!(err.reason, Reason::Unexpected(&["=", ",", ")"]));
+/// ```
+pub fn try_pred(pred: Predicate<'_>) -> Result<Expression, ParseError> {
+    let expr = Expression::from_tree(&ExprTree::Predicate(pred));
+    Expression::parse(expr.original())?;
+    Ok(expr)
+}
+
+/// Creates an `all()` expression, which is true if all of the operands are
+/// true, or if there are no operands.
+pub fn all(operands: impl IntoIterator<Item = Expression>) -> Expression {
+    let operands: Vec<_> = operands.into_iter().collect();
+    Expression::from_tree(&ExprTree::All(operands.iter().map(|o| o.root()).collect()))
+}
+
+/// Creates an `any()` expression, which is true if any of the operands are
+/// true, and false if there are no operands.
+pub fn any(operands: impl IntoIterator<Item = Expression>) -> Expression {
+    let operands: Vec<_> = operands.into_iter().collect();
+    Expression::from_tree(&ExprTree::Any(operands.iter().map(|o| o.root()).collect()))
+}
+
+/// Creates a `not()` expression, which is true if the operand is false.
+pub fn not(operand: Expression) -> Expression {
+    Expression::from_tree(&ExprTree::Not(Box::new(operand.root())))
+}
+
+impl Expression {
+    /// Combines this expression with another into `all(self, other)`.
+    ///
+    /// ```
+    /// use cfg_expr::Expression;
+    ///
+    /// let unix = Expression::parse("unix").unwrap();
+    /// let x64 = Expression::parse(r#"target_arch = "x86_64""#).unwrap();
+    ///
+    /// assert_eq!(unix.and(x64).to_string(), r#"all(unix, target_arch = "x86_64")"#);
+    /// ```
+    pub fn and(self, other: Expression) -> Expression {
+        all([self, other])
+    }
+
+    /// Combines this expression with another into `any(self, other)`.
+    ///
+    /// ```
+    /// use cfg_expr::Expression;
+    ///
+    /// let unix = Expression::parse("unix").unwrap();
+    /// let windows = Expression::parse("windows").unwrap();
+    ///
+    /// assert_eq!(unix.or(windows).to_string(), "any(unix, windows)");
+    /// ```
+    pub fn or(self, other: Expression) -> Expression {
+        any([self, other])
+    }
+
+    /// Negates this expression into `not(self)`.
+    ///
+    /// ```
+    /// use cfg_expr::Expression;
+    ///
+    /// let unix = Expression::parse("unix").unwrap();
+    ///
+    /// assert_eq!(unix.negate().to_string(), "not(unix)");
+    /// ```
+    pub fn negate(self) -> Expression {
+        not(self)
+    }
+}
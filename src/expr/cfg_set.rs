@@ -0,0 +1,438 @@
+use crate::{
+    error::{ParseError, Reason},
+    expr::{
+        lexer::{Lexer, Token},
+        Expression, FeatureSet, Predicate,
+    },
+    targets::{expand_features, Endian, TargetInfo},
+};
+use alloc::{
+    borrow::ToOwned,
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// The full set of cfgs that a build sees, in the same form as rustc, ie.
+/// bare names such as `unix` or `test`, and `name="value"` pairs such as
+/// `target_os="linux"` or `feature="std"`, which can have multiple values for
+/// the same name.
+///
+/// ```
+/// use cfg_expr::{expr::{CfgSet, FeatureSet}, targets::get_builtin_target_by_triple, Expression};
+///
+/// let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+/// let features: FeatureSet = ["std"].into_iter().collect();
+///
+/// let mut cfgs = CfgSet::for_target(linux, &features, &FeatureSet::new());
+/// cfgs.set("debug_assertions", true);
+/// cfgs.insert_cfg("tokio_unstable").unwrap();
+///
+/// let expr = Expression::parse(
+///     r#"all(unix, feature = "std", debug_assertions, tokio_unstable, not(test))"#,
+/// ).unwrap();
+/// assert!(expr.eval_cfg_set(&cfgs));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CfgSet {
+    /// The cfgs keyed by name, so that they can be looked up without
+    /// allocating. Names without any cfgs are removed.
+    cfgs: BTreeMap<String, CfgValues>,
+}
+
+/// The cfgs that are set with the same name
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+struct CfgValues {
+    /// Whether the bare name is set, eg. `unix`
+    bare: bool,
+    /// The values of the `name="value"` pairs
+    values: BTreeSet<String>,
+}
+
+impl CfgValues {
+    fn len(&self) -> usize {
+        usize::from(self.bare) + self.values.len()
+    }
+}
+
+impl CfgSet {
+    /// Creates an empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates the set of cfgs for a target, with the specified crate
+    /// features and target features enabled.
+    ///
+    /// The target features also include those that are enabled by default
+    /// for the target, and those that are implied by the enabled target
+    /// features, see [`expand_features`]. Build specific cfgs, eg. `test` or
+    /// `debug_assertions`, aren't set.
+    pub fn for_target(
+        target: &TargetInfo,
+        crate_features: &FeatureSet,
+        target_features: &FeatureSet,
+    ) -> Self {
+        let mut cfgs = Self::new();
+
+        // rustc sets empty values for an ABI and environment, but not for
+        // an OS or vendor, which are `none` and `unknown` instead
+        let target_cfgs = [
+            ("target_arch", target.arch.as_str()),
+            (
+                "target_abi",
+                target.abi.as_ref().map_or("", |abi| abi.as_str()),
+            ),
+            (
+                "target_env",
+                target.env.as_ref().map_or("", |env| env.as_str()),
+            ),
+            (
+                "target_os",
+                target.os.as_ref().map_or("none", |os| os.as_str()),
+            ),
+            (
+                "target_vendor",
+                target.vendor.as_ref().map_or("unknown", |ven| ven.as_str()),
+            ),
+            (
+                "target_endian",
+                match target.endian {
+                    Endian::big => "big",
+                    Endian::little => "little",
+                },
+            ),
+            ("panic", target.panic.as_str()),
+        ];
+
+        for (name, value) in target_cfgs {
+            cfgs.insert(name, Some(value));
+        }
+
+        cfgs.insert(
+            "target_pointer_width",
+            Some(&target.pointer_width.to_string()),
+        );
+
+        for family in target.families.iter() {
+            cfgs.insert("target_family", Some(family.as_str()));
+
+            // The unix and windows families are also set as bare names
+            if matches!(family.as_str(), "unix" | "windows") {
+                cfgs.insert(family.as_str(), None);
+            }
+        }
+
+        for (name, atomics) in [
+            ("target_has_atomic", &target.has_atomics),
+            (
+                "target_has_atomic_load_store",
+                &target.has_atomics_load_store,
+            ),
+            (
+                "target_has_atomic_equal_alignment",
+                &target.has_atomics_equal_alignment,
+            ),
+        ] {
+            for has_atomic in atomics.iter() {
+                cfgs.insert(name, Some(&has_atomic.to_string()));
+            }
+        }
+
+        for feature in crate_features.iter() {
+            cfgs.insert("feature", Some(feature));
+        }
+
+        let enabled = target_features.iter().collect::<Vec<_>>();
+        for feature in target
+            .default_features()
+            .iter()
+            .copied()
+            .chain(expand_features(&target.arch, &enabled))
+        {
+            cfgs.insert("target_feature", Some(feature));
+        }
+
+        cfgs
+    }
+
+    /// Adds a cfg, where `None` is a bare name, eg. `test`, rather than a
+    /// `name="value"` pair. Returns false if the cfg was already set.
+    pub fn insert(&mut self, name: &str, value: Option<&str>) -> bool {
+        let values = match self.cfgs.get_mut(name) {
+            Some(values) => values,
+            None => self.cfgs.entry(name.to_owned()).or_default(),
+        };
+
+        match value {
+            Some(value) if values.values.contains(value) => false,
+            Some(value) => values.values.insert(value.to_owned()),
+            None => !core::mem::replace(&mut values.bare, true),
+        }
+    }
+
+    /// Removes a cfg, returning false if it wasn't set
+    pub fn remove(&mut self, name: &str, value: Option<&str>) -> bool {
+        let values = match self.cfgs.get_mut(name) {
+            Some(values) => values,
+            None => return false,
+        };
+
+        let removed = match value {
+            Some(value) => values.values.remove(value),
+            None => core::mem::replace(&mut values.bare, false),
+        };
+
+        if !values.bare && values.values.is_empty() {
+            self.cfgs.remove(name);
+        }
+
+        removed
+    }
+
+    /// Sets or removes a bare name, eg. `test` or `debug_assertions`
+    pub fn set(&mut self, name: &str, enabled: bool) {
+        if enabled {
+            self.insert(name, None);
+        } else {
+            self.remove(name, None);
+        }
+    }
+
+    /// Returns true if the cfg is set
+    pub fn contains(&self, name: &str, value: Option<&str>) -> bool {
+        self.cfgs.get(name).map_or(false, |values| match value {
+            Some(value) => values.values.contains(value),
+            None => values.bare,
+        })
+    }
+
+    /// Adds a cfg in the same form as rustc's
+    /// [`--cfg`](https://doc.rust-lang.org/rustc/command-line-arguments.html#--cfg-configure-the-compilation-environment)
+    /// flag, ie. either `name` or `name="value"`
+    pub fn insert_cfg(&mut self, cfg: &str) -> Result<(), ParseError> {
+        let error = |span: core::ops::Range<usize>, reason| ParseError {
+            original: cfg.to_owned(),
+            span,
+            reason,
+        };
+
+        // Unlike an expression, a cfg can't be wrapped in `cfg()`
+        let mut tokens = Lexer::new_unwrapped(cfg)
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter();
+
+        let name = match tokens.next() {
+            Some(lt) => match lt.token {
+                Token::Key(name) => name,
+                _ => return Err(error(lt.span, Reason::Unexpected(&["<name>"]))),
+            },
+            None => return Err(error(0..cfg.len(), Reason::Empty)),
+        };
+
+        let value = match tokens.next() {
+            None => None,
+            Some(lt) if lt.token == Token::Equals => match tokens.next() {
+                Some(lt) => match lt.token {
+                    Token::Value(value) => Some(value),
+                    _ => return Err(error(lt.span, Reason::Unexpected(&["\"<value>\""]))),
+                },
+                None => {
+                    return Err(error(
+                        lt.span.end..lt.span.end,
+                        Reason::Unexpected(&["\"<value>\""]),
+                    ))
+                }
+            },
+            Some(lt) => return Err(error(lt.span, Reason::Unexpected(&["="]))),
+        };
+
+        if let Some(lt) = tokens.next() {
+            return Err(error(lt.span, Reason::MultipleRootPredicates));
+        }
+
+        self.insert(name, value);
+        Ok(())
+    }
+
+    /// Parses the output of `rustc --print cfg`, which has one cfg per line,
+    /// in the same form as [`Self::insert_cfg`], so that expressions can be
+    /// evaluated against the exact cfgs of a specific rustc and target.
+    ///
+    /// ```
+    /// use cfg_expr::{expr::CfgSet, Expression};
+    ///
+    /// let cfgs = CfgSet::from_rustc_print_cfg(r#"debug_assertions
+    /// panic="unwind"
+    /// target_arch="x86_64"
+    /// target_feature="sse2"
+    /// target_os="linux"
+    /// unix
+    /// "#).unwrap();
+    ///
+    /// let expr = Expression::parse(r#"all(unix, target_feature = "sse2", debug_assertions)"#).unwrap();
+    /// assert!(expr.eval_cfg_set(&cfgs));
+    /// ```
+    pub fn from_rustc_print_cfg(output: &str) -> Result<Self, ParseError> {
+        let mut cfgs = Self::new();
+        let mut offset = 0;
+
+        for line in output.split_inclusive('\n') {
+            let cfg = line.trim();
+            if !cfg.is_empty() {
+                // Errors are reported for the whole output rather than the line
+                cfgs.insert_cfg(cfg).map_err(|err| {
+                    let start = offset + line.find(cfg).unwrap_or(0);
+                    ParseError {
+                        original: output.to_owned(),
+                        span: err.span.start + start..err.span.end + start,
+                        reason: err.reason,
+                    }
+                })?;
+            }
+
+            offset += line.len();
+        }
+
+        Ok(cfgs)
+    }
+
+    /// Creates the set of cfgs that cargo passes to a build script, ie. the
+    /// [`CARGO_CFG_*`](https://doc.rust-lang.org/cargo/reference/environment-variables.html#environment-variables-cargo-sets-for-build-scripts)
+    /// environment variables for the target being compiled, and the
+    /// `CARGO_FEATURE_*` variables for the enabled features.
+    ///
+    /// See [`Self::from_cargo_env_vars`] for how the variables are mapped to
+    /// cfgs.
+    #[cfg(feature = "std")]
+    pub fn from_cargo_env() -> Self {
+        Self::from_cargo_env_vars(
+            std::env::vars_os()
+                .filter_map(|(key, val)| Some((key.into_string().ok()?, val.into_string().ok()?))),
+        )
+    }
+
+    /// Creates the set of cfgs from the specified environment variables, in
+    /// the form that cargo passes them to build scripts.
+    ///
+    /// * `CARGO_CFG_<NAME>` is split on `,` into a value for each cfg with the
+    ///   lower cased name. An empty value is a bare name, eg. `unix`, apart
+    ///   from `target_` names, such as an empty `target_env`.
+    /// * `CARGO_FEATURE_<NAME>` is a `feature` cfg with the lower cased
+    ///   name, where cargo has replaced `-` with `_`. If `CARGO_CFG_FEATURE`
+    ///   is set, which contains the exact names, these are ignored.
+    ///
+    /// ```
+    /// use cfg_expr::{expr::CfgSet, Expression};
+    ///
+    /// let cfgs = CfgSet::from_cargo_env_vars([
+    ///     ("CARGO_CFG_TARGET_OS", "linux"),
+    ///     ("CARGO_CFG_TARGET_ENV", ""),
+    ///     ("CARGO_CFG_TARGET_FEATURE", "fxsr,sse,sse2"),
+    ///     ("CARGO_CFG_UNIX", ""),
+    ///     ("CARGO_FEATURE_SERDE_JSON", "1"),
+    ///     ("PATH", "/usr/bin"),
+    /// ]);
+    ///
+    /// let expr = Expression::parse(
+    ///     r#"all(unix, target_os = "linux", target_env = "", target_feature = "sse2", feature = "serde_json")"#,
+    /// ).unwrap();
+    /// assert!(expr.eval_cfg_set(&cfgs));
+    /// ```
+    pub fn from_cargo_env_vars<K, V>(vars: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let mut cfgs = Self::new();
+        let mut features = Vec::new();
+        let mut has_cfg_feature = false;
+
+        for (key, val) in vars {
+            let (key, val) = (key.as_ref(), val.as_ref());
+
+            if let Some(name) = key.strip_prefix("CARGO_CFG_") {
+                let name = name.to_ascii_lowercase();
+                has_cfg_feature |= name == "feature";
+
+                if val.is_empty() {
+                    let value =
+                        (name.starts_with("target_") && name != "target_thread_local").then(|| "");
+                    cfgs.insert(&name, value);
+                } else {
+                    for value in val.split(',') {
+                        cfgs.insert(&name, Some(value));
+                    }
+                }
+            } else if let Some(name) = key.strip_prefix("CARGO_FEATURE_") {
+                features.push(name.to_ascii_lowercase());
+            }
+        }
+
+        if !has_cfg_feature {
+            for feature in features {
+                cfgs.insert("feature", Some(&feature));
+            }
+        }
+
+        cfgs
+    }
+
+    /// Iterates over the cfgs, sorted by name and then value
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Option<&str>)> + '_ {
+        self.cfgs.iter().flat_map(|(name, values)| {
+            values
+                .bare
+                .then(|| (name.as_str(), None))
+                .into_iter()
+                .chain(
+                    values
+                        .values
+                        .iter()
+                        .map(move |value| (name.as_str(), Some(value.as_str()))),
+                )
+        })
+    }
+
+    /// The number of cfgs that are set
+    pub fn len(&self) -> usize {
+        self.cfgs.values().map(CfgValues::len).sum()
+    }
+
+    /// Returns true if no cfgs are set
+    pub fn is_empty(&self) -> bool {
+        self.cfgs.is_empty()
+    }
+}
+
+impl Expression {
+    /// Evaluates the expression against a set of cfgs, where each predicate
+    /// is true if the set contains it.
+    ///
+    /// `version()` and `accessible()` predicates aren't cfgs, and so are
+    /// always false.
+    pub fn eval_cfg_set(&self, cfgs: &CfgSet) -> bool {
+        self.eval(|pred| match pred {
+            Predicate::Version(_) | Predicate::Accessible(_) => false,
+            pred => {
+                let (name, value) = pred.as_key_value();
+                cfgs.contains(name, value.as_deref())
+            }
+        })
+    }
+
+    /// Evaluates the expression in a build script, against the cfgs of the
+    /// target being compiled, see [`CfgSet::from_cargo_env`]
+    ///
+    /// ```no_run
+    /// let expr = cfg_expr::Expression::parse(r#"all(unix, feature = "simd")"#).unwrap();
+    ///
+    /// if expr.eval_in_build_script() {
+    ///     println!("cargo:rustc-cfg=unix_simd");
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn eval_in_build_script(&self) -> bool {
+        self.eval_cfg_set(&CfgSet::from_cargo_env())
+    }
+}
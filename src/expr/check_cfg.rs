@@ -0,0 +1,351 @@
+use crate::{
+    error::{ParseError, Reason},
+    expr::{
+        lexer::{Lexer, LexerToken, Token},
+        ExprNode, Expression, InnerPredicate,
+    },
+};
+use alloc::{
+    borrow::ToOwned,
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+use core::{iter::Peekable, ops::Range};
+
+/// The values that are expected for a cfg name in a [`CheckCfg`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExpectedValues {
+    /// Any value, as well as no value, is expected, ie. `values(any())`
+    Any,
+    /// Only these values are expected, where `None` is the name without a
+    /// value, ie. `values(none())`
+    Some(BTreeSet<Option<String>>),
+}
+
+impl ExpectedValues {
+    /// Returns true if the value is expected, where `None` is the name
+    /// without a value, eg. `cfg(foo)` rather than `cfg(foo = "bar")`
+    pub fn contains(&self, value: Option<&str>) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Some(values) => values.contains(&value.map(str::to_owned)),
+        }
+    }
+
+    fn union(&mut self, other: Self) {
+        match (&mut *self, other) {
+            (Self::Any, _) => {}
+            (_, Self::Any) => *self = Self::Any,
+            (Self::Some(values), Self::Some(other)) => values.extend(other),
+        }
+    }
+}
+
+/// The cfg names and values that are expected, as specified with one or more
+/// of rustc's [`--check-cfg`](https://doc.rust-lang.org/rustc/check-cfg.html)
+/// specs, eg. `cfg(foo, values("a", "b"))`.
+///
+/// This is used by [`Expression::validate_against`] to report the same
+/// predicates as rustc's `unexpected_cfgs` lint.
+///
+/// ```
+/// use cfg_expr::expr::CheckCfg;
+///
+/// let mut check_cfg = CheckCfg::parse(r#"cfg(feature, values("std", "serde"))"#).unwrap();
+/// check_cfg.add("cfg(docsrs, tokio_unstable)").unwrap();
+///
+/// assert!(check_cfg.is_expected("feature", Some("std")));
+/// assert!(!check_cfg.is_expected("feature", Some("alloc")));
+/// assert!(check_cfg.is_expected("docsrs", None));
+/// assert!(!check_cfg.is_expected("docsrs", Some("yes")));
+/// assert!(!check_cfg.is_expected("nightly", None));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CheckCfg {
+    /// The expected names, and the values that are expected for each of them
+    pub names: BTreeMap<String, ExpectedValues>,
+    /// Whether every name is expected, ie. `cfg(any())`, in which case names
+    /// that aren't in [`Self::names`] can have any value
+    pub any_name: bool,
+}
+
+type Tokens<'a> = Peekable<alloc::vec::IntoIter<LexerToken<'a>>>;
+
+impl CheckCfg {
+    /// Parses a single `--check-cfg` spec
+    pub fn parse(spec: &str) -> Result<Self, ParseError> {
+        let mut check_cfg = Self::default();
+        check_cfg.add(spec)?;
+        Ok(check_cfg)
+    }
+
+    /// Adds the names and values of another `--check-cfg` spec, which are
+    /// combined with the existing ones in the same way as rustc does when
+    /// it is passed multiple specs
+    pub fn add(&mut self, spec: &str) -> Result<(), ParseError> {
+        let lexer = Lexer::new(spec);
+        let offset = lexer.start;
+
+        let error = |span: Range<usize>, reason| ParseError {
+            original: spec.to_owned(),
+            span: span.start + offset..span.end + offset,
+            reason,
+        };
+
+        // Unlike expressions, the `cfg()` is required
+        if offset == 0 {
+            return Err(ParseError {
+                original: spec.to_owned(),
+                span: 0..spec.len(),
+                reason: Reason::Unexpected(&["cfg("]),
+            });
+        }
+
+        let tokens = lexer
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| error(err.span, err.reason))?;
+        let end = tokens.last().map_or(0, |lt| lt.span.end);
+        let mut tokens = tokens.into_iter().peekable();
+
+        let mut names = Vec::new();
+        let mut any_name = false;
+        // A name without `values()` is only expected without a value
+        let mut values = None;
+
+        while let Some(lt) = tokens.next() {
+            match lt.token {
+                Token::Key("values") if next_is(&mut tokens, &Token::OpenParen) => {
+                    values = Some(
+                        parse_values(&mut tokens, lt.span.start, end)
+                            .map_err(|(span, reason)| error(span, reason))?,
+                    );
+
+                    // values() must be the last argument
+                    if next_is(&mut tokens, &Token::Comma) {
+                        tokens.next();
+                    }
+
+                    if let Some(lt) = tokens.next() {
+                        return Err(error(lt.span, Reason::Unexpected(&[")"])));
+                    }
+
+                    break;
+                }
+                Token::Key(name) => names.push(name),
+                Token::Any => {
+                    expect_empty_call(&mut tokens, lt.span.end)
+                        .map_err(|(span, reason)| error(span, reason))?;
+                    any_name = true;
+                }
+                _ => {
+                    return Err(error(
+                        lt.span,
+                        Reason::Unexpected(&["<name>", "any()", "values()"]),
+                    ))
+                }
+            }
+
+            match tokens.next() {
+                None => break,
+                Some(LexerToken {
+                    token: Token::Comma,
+                    ..
+                }) => {}
+                Some(lt) => return Err(error(lt.span, Reason::Unexpected(&[",", ")"]))),
+            }
+        }
+
+        self.any_name |= any_name;
+
+        let values =
+            values.unwrap_or_else(|| ExpectedValues::Some(core::iter::once(None).collect()));
+
+        for name in names {
+            match self.names.get_mut(name) {
+                Some(existing) => existing.union(values.clone()),
+                None => {
+                    self.names.insert(name.to_owned(), values.clone());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the values that are expected for the name, or `None` if the
+    /// name isn't expected at all
+    pub fn expected_values(&self, name: &str) -> Option<&ExpectedValues> {
+        match self.names.get(name) {
+            Some(values) => Some(values),
+            None if self.any_name => Some(&ExpectedValues::Any),
+            None => None,
+        }
+    }
+
+    /// Returns true if the name is expected with the value, where `None` is
+    /// the name without a value
+    pub fn is_expected(&self, name: &str, value: Option<&str>) -> bool {
+        self.expected_values(name)
+            .map_or(false, |values| values.contains(value))
+    }
+}
+
+fn next_is(tokens: &mut Tokens<'_>, token: &Token<'_>) -> bool {
+    tokens.peek().map_or(false, |lt| lt.token == *token)
+}
+
+/// Consumes the `()` of `any()` or `none()`
+fn expect_empty_call(
+    tokens: &mut Tokens<'_>,
+    name_end: usize,
+) -> Result<(), (Range<usize>, Reason)> {
+    for expected in [Token::OpenParen, Token::CloseParen] {
+        match tokens.next() {
+            Some(lt) if lt.token == expected => {}
+            Some(lt) => {
+                return Err((
+                    lt.span,
+                    Reason::Unexpected(if expected == Token::OpenParen {
+                        &["("]
+                    } else {
+                        &[")"]
+                    }),
+                ))
+            }
+            None => return Err((name_end..name_end, Reason::UnclosedParens)),
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses the contents of `values(...)`, after the opening parenthesis
+fn parse_values(
+    tokens: &mut Tokens<'_>,
+    start: usize,
+    end: usize,
+) -> Result<ExpectedValues, (Range<usize>, Reason)> {
+    tokens.next();
+
+    let mut values = ExpectedValues::Some(BTreeSet::new());
+
+    loop {
+        let lt = match tokens.next() {
+            Some(lt) => lt,
+            None => return Err((start..end, Reason::UnclosedParens)),
+        };
+
+        let value = match lt.token {
+            Token::CloseParen => return Ok(values),
+            Token::Value(value) => {
+                ExpectedValues::Some(core::iter::once(Some(value.to_owned())).collect())
+            }
+            Token::Key("none") => {
+                expect_empty_call(tokens, lt.span.end)?;
+                ExpectedValues::Some(core::iter::once(None).collect())
+            }
+            Token::Any => {
+                expect_empty_call(tokens, lt.span.end)?;
+                ExpectedValues::Any
+            }
+            _ => {
+                return Err((
+                    lt.span,
+                    Reason::Unexpected(&["\"<value>\"", "none()", "any()", ")"]),
+                ))
+            }
+        };
+
+        values.union(value);
+
+        match tokens.next() {
+            Some(LexerToken {
+                token: Token::Comma,
+                ..
+            }) => {}
+            Some(LexerToken {
+                token: Token::CloseParen,
+                ..
+            }) => return Ok(values),
+            Some(lt) => return Err((lt.span, Reason::Unexpected(&[",", ")"]))),
+            None => return Err((start..end, Reason::UnclosedParens)),
+        }
+    }
+}
+
+impl Expression {
+    /// Checks that the name and value of every predicate is expected by the
+    /// [`CheckCfg`], returning an error for each one that isn't, like rustc's
+    /// `unexpected_cfgs` lint.
+    ///
+    /// Only `feature` and custom predicates are checked, as the well-known
+    /// names that are set by rustc itself, eg. `unix` or `target_os`, are
+    /// always expected. Use [`Self::validate`] to check the values of target
+    /// predicates.
+    ///
+    /// ```
+    /// use cfg_expr::{error::Reason, expr::CheckCfg, Expression};
+    ///
+    /// let check_cfg = CheckCfg::parse(r#"cfg(feature, values("std"))"#).unwrap();
+    /// let expr = Expression::parse(r#"all(unix, feature = "alloc", nightly)"#).unwrap();
+    ///
+    /// let errors = expr.validate_against(&check_cfg).unwrap_err();
+    /// assert_eq!(errors[0].reason, Reason::UnexpectedCfgValue);
+    /// assert_eq!(&expr.original()[errors[0].span.clone()], "alloc");
+    /// assert_eq!(errors[1].reason, Reason::UnexpectedCfgName);
+    /// assert_eq!(&expr.original()[errors[1].span.clone()], "nightly");
+    /// assert_eq!(errors.len(), 2);
+    /// ```
+    pub fn validate_against(&self, check_cfg: &CheckCfg) -> Result<(), Vec<ParseError>> {
+        let errors: Vec<_> = self
+            .expr
+            .iter()
+            .zip(self.spans.iter())
+            .filter_map(|(node, span)| {
+                let (name, name_span, value_span) = match node {
+                    // The predicate starts with the `feature` key
+                    ExprNode::Predicate(InnerPredicate::Feature(value)) => (
+                        "feature",
+                        span.start..span.start + "feature".len(),
+                        Some(value),
+                    ),
+                    ExprNode::Predicate(InnerPredicate::Other { identifier, value }) => (
+                        &self.original[identifier.clone()],
+                        identifier.clone(),
+                        value.as_ref(),
+                    ),
+                    _ => return None,
+                };
+
+                let (span, reason) = match check_cfg.expected_values(name) {
+                    None => (name_span, Reason::UnexpectedCfgName),
+                    Some(expected) => {
+                        let value = value_span.map(|span| &self.original[span.clone()]);
+
+                        if expected.contains(value) {
+                            return None;
+                        }
+
+                        (
+                            value_span.cloned().unwrap_or(name_span),
+                            Reason::UnexpectedCfgValue,
+                        )
+                    }
+                };
+
+                Some(ParseError {
+                    original: self.original.clone(),
+                    span,
+                    reason,
+                })
+            })
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use crate::{
+    expr::{ExprTree, Expression, Predicate, TargetPredicate},
+    targets::{Endian, HasAtomic},
+};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::{quote, ToTokens};
+
+/// Writes the target predicate as the Rust expression that constructs it
+impl ToTokens for TargetPredicate {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let has_atomic = |ha: &HasAtomic| match ha {
+            HasAtomic::IntegerSize(size) => {
+                quote!(::cfg_expr::targets::HasAtomic::IntegerSize(#size))
+            }
+            HasAtomic::Pointer => quote!(::cfg_expr::targets::HasAtomic::Pointer),
+        };
+
+        let tp = match self {
+            Self::Abi(abi) => {
+                let abi = abi.as_str();
+                quote!(Abi(::cfg_expr::targets::Abi::new_const(#abi)))
+            }
+            Self::Arch(arch) => {
+                let arch = arch.as_str();
+                quote!(Arch(::cfg_expr::targets::Arch::new_const(#arch)))
+            }
+            Self::Endian(Endian::big) => quote!(Endian(::cfg_expr::targets::Endian::big)),
+            Self::Endian(Endian::little) => quote!(Endian(::cfg_expr::targets::Endian::little)),
+            Self::Env(env) => {
+                let env = env.as_str();
+                quote!(Env(::cfg_expr::targets::Env::new_const(#env)))
+            }
+            Self::Family(fam) => {
+                let fam = fam.as_str();
+                quote!(Family(::cfg_expr::targets::Family::new_const(#fam)))
+            }
+            Self::HasAtomic(ha) => {
+                let ha = has_atomic(ha);
+                quote!(HasAtomic(#ha))
+            }
+            Self::HasAtomicLoadStore(ha) => {
+                let ha = has_atomic(ha);
+                quote!(HasAtomicLoadStore(#ha))
+            }
+            Self::HasAtomicEqualAlignment(ha) => {
+                let ha = has_atomic(ha);
+                quote!(HasAtomicEqualAlignment(#ha))
+            }
+            Self::Os(os) => {
+                let os = os.as_str();
+                quote!(Os(::cfg_expr::targets::Os::new_const(#os)))
+            }
+            Self::Panic(panic) => {
+                let panic = panic.as_str();
+                quote!(Panic(::cfg_expr::targets::Panic::new_const(#panic)))
+            }
+            Self::PointerWidth(pw) => quote!(PointerWidth(#pw)),
+            Self::Vendor(ven) => {
+                let ven = ven.as_str();
+                quote!(Vendor(::cfg_expr::targets::Vendor::new_const(#ven)))
+            }
+        };
+
+        tokens.extend(quote!(::cfg_expr::TargetPredicate::#tp));
+    }
+}
+
+/// Writes the predicate as the Rust expression that constructs it
+impl ToTokens for Predicate<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let pred = match self {
+            Self::Target(tp) => quote!(Target(#tp)),
+            Self::Test => quote!(Test),
+            Self::DebugAssertions => quote!(DebugAssertions),
+            Self::ProcMacro => quote!(ProcMacro),
+            Self::TargetThreadLocal => quote!(TargetThreadLocal),
+            Self::Doc => quote!(Doc),
+            Self::Doctest => quote!(Doctest),
+            Self::Miri => quote!(Miri),
+            Self::Fuzzing => quote!(Fuzzing),
+            Self::OverflowChecks => quote!(OverflowChecks),
+            Self::UbChecks => quote!(UbChecks),
+            Self::Feature(feature) => quote!(Feature(#feature)),
+            Self::TargetFeature(feature) => quote!(TargetFeature(#feature)),
+            Self::Sanitize(sanitizer) => quote!(Sanitize(#sanitizer)),
+            Self::Boolean(value) => quote!(Boolean(#value)),
+            Self::Flag(flag) => quote!(Flag(#flag)),
+            Self::KeyValue { key, val } => quote!(KeyValue { key: #key, val: #val }),
+            Self::Version(version) => {
+                let (major, minor) = (version.major, version.minor);
+                let patch = version.patch.map_or_else(
+                    || quote!(::std::option::Option::None),
+                    |patch| quote!(::std::option::Option::Some(#patch)),
+                );
+                quote!(Version(::cfg_expr::expr::RustVersion {
+                    major: #major,
+                    minor: #minor,
+                    patch: #patch,
+                }))
+            }
+            Self::Accessible(path) => quote!(Accessible(#path)),
+        };
+
+        tokens.extend(quote!(::cfg_expr::Predicate::#pred));
+    }
+}
+
+fn tree_tokens(tree: &ExprTree<'_>) -> TokenStream {
+    let (func, operands) = match tree {
+        ExprTree::Predicate(pred) => return quote!(::cfg_expr::expr::builder::pred(#pred)),
+        ExprTree::Not(operand) => {
+            let operand = tree_tokens(operand);
+            return quote!(::cfg_expr::expr::builder::not(#operand));
+        }
+        ExprTree::All(operands) => (quote!(all), operands),
+        ExprTree::Any(operands) => (quote!(any), operands),
+    };
+
+    // The type of an empty array can't be inferred
+    if operands.is_empty() {
+        return quote!(::cfg_expr::expr::builder::#func(
+            ::std::iter::empty::<::cfg_expr::Expression>()
+        ));
+    }
+
+    let operands = operands.iter().map(tree_tokens);
+    quote!(::cfg_expr::expr::builder::#func([#(#operands),*]))
+}
+
+/// Writes the predicate in its `cfg()` form, with the values as string
+/// literals, so that any characters in them are escaped
+fn cfg_pred_tokens(pred: &Predicate<'_>) -> TokenStream {
+    match pred {
+        Predicate::Version(version) => {
+            let version = Literal::string(&version.to_string());
+            return quote!(version(#version));
+        }
+        Predicate::Accessible(path) => {
+            let (leading, path) = match path.strip_prefix("::") {
+                Some(path) => (quote!(::), path),
+                None => (TokenStream::new(), *path),
+            };
+            let segments = path
+                .split("::")
+                .map(|segment| Ident::new(segment, Span::call_site()));
+            return quote!(accessible(#leading #(#segments)::*));
+        }
+        _ => {}
+    }
+
+    let (key, val) = pred.as_key_value();
+    let key = Ident::new(key, Span::call_site());
+
+    if let Some(val) = val {
+        let val = Literal::string(&val);
+        quote!(#key = #val)
+    } else {
+        quote!(#key)
+    }
+}
+
+fn cfg_tree_tokens(tree: &ExprTree<'_>) -> TokenStream {
+    let (func, operands) = match tree {
+        ExprTree::Predicate(pred) => return cfg_pred_tokens(pred),
+        ExprTree::Not(operand) => {
+            let operand = cfg_tree_tokens(operand);
+            return quote!(not(#operand));
+        }
+        ExprTree::All(operands) => (quote!(all), operands),
+        ExprTree::Any(operands) => (quote!(any), operands),
+    };
+
+    let operands = operands.iter().map(cfg_tree_tokens);
+    quote!(#func(#(#operands),*))
+}
+
+/// Writes the expression as the Rust expression that builds it with the
+/// functions of [`builder`](crate::expr::builder), so that code generators
+/// can embed expressions in the code they generate without parsing them at
+/// runtime.
+///
+/// The generated code refers to this crate as `::cfg_expr`.
+///
+/// ```
+/// use cfg_expr::Expression;
+/// use quote::{quote, ToTokens};
+///
+/// let expr = Expression::parse(r#"any(feature = "a", not(test))"#).unwrap();
+///
+/// assert_eq!(
+///     expr.to_token_stream().to_string(),
+///     quote! {
+///         ::cfg_expr::expr::builder::any([
+///             ::cfg_expr::expr::builder::pred(::cfg_expr::Predicate::Feature("a")),
+///             ::cfg_expr::expr::builder::not(::cfg_expr::expr::builder::pred(::cfg_expr::Predicate::Test))
+///         ])
+///     }
+///     .to_string()
+/// );
+/// ```
+impl ToTokens for Expression {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(tree_tokens(&self.root()));
+    }
+}
+
+impl Expression {
+    /// Writes the expression as the tokens of a `cfg` attribute or `cfg!()`
+    /// macro, eg. `#[cfg(#tokens)]`
+    ///
+    /// ```
+    /// use cfg_expr::Expression;
+    /// use quote::quote;
+    ///
+    /// let expr = Expression::parse(r#"cfg(all(unix,target_arch="x86_64"))"#).unwrap();
+    /// let tokens = expr.to_cfg_tokens();
+    ///
+    /// assert_eq!(
+    ///     quote!(#[cfg(#tokens)]).to_string(),
+    ///     quote!(#[cfg(all(unix, target_arch = "x86_64"))]).to_string()
+    /// );
+    /// ```
+    pub fn to_cfg_tokens(&self) -> TokenStream {
+        cfg_tree_tokens(&self.root())
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{
+    expr::{ExprNode, Expression, Func, InnerPredicate, TargetPredicate},
+    targets::TargetInfo,
+};
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use smallvec::SmallVec;
+
+/// A single operation of a [`CompiledMatcher`], in postfix order
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Op {
+    /// A value that doesn't depend on the target
+    Const(bool),
+    /// A target predicate
+    Target(TargetPredicate),
+    /// A `target_feature`, which is true if it is enabled by default
+    TargetFeature(String),
+    All(usize),
+    Any(usize),
+    Not,
+}
+
+/// An [`Expression`] compiled for matching against many targets, created by
+/// [`Expression::compile_target_matcher`].
+///
+/// The target predicates are resolved once, and the predicates that don't
+/// depend on the target are folded into constants, so matching a target is
+/// only the comparisons against its values.
+///
+/// ```
+/// use cfg_expr::{targets::ALL_BUILTINS, Expression};
+///
+/// let expr = Expression::parse(r#"all(unix, target_pointer_width = "64", not(test))"#).unwrap();
+/// let matcher = expr.compile_target_matcher();
+///
+/// let count = ALL_BUILTINS.iter().filter(|ti| matcher.matches(ti)).count();
+/// assert!(count > 0);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompiledMatcher {
+    ops: Vec<Op>,
+}
+
+impl CompiledMatcher {
+    /// Returns true if the expression is true for the target
+    pub fn matches(&self, target: &TargetInfo) -> bool {
+        let mut stack = SmallVec::<[bool; 16]>::new();
+
+        for op in &self.ops {
+            let value = match op {
+                Op::Const(value) => *value,
+                Op::Target(tp) => tp.matches(target),
+                Op::TargetFeature(feat) => target.has_default_feature(feat),
+                // Draining the operands drops the ones that aren't checked
+                Op::All(count) => stack.drain(stack.len() - count..).all(|value| value),
+                Op::Any(count) => stack.drain(stack.len() - count..).any(|value| value),
+                Op::Not => !stack.pop().unwrap(),
+            };
+
+            stack.push(value);
+        }
+
+        stack.pop().unwrap()
+    }
+
+    /// Returns the value of the expression if it is the same for every
+    /// target, eg. `all(unix, test)` is always false, as `test` is
+    pub fn constant(&self) -> Option<bool> {
+        match self.ops.as_slice() {
+            [Op::Const(value)] => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl Expression {
+    /// Compiles the expression for matching against many targets, see
+    /// [`CompiledMatcher`].
+    ///
+    /// As with [`Self::eval_target`] without any enabled features,
+    /// `target_feature` predicates are true for the features enabled by
+    /// default for the target, and every other predicate that isn't a target
+    /// predicate is false.
+    pub fn compile_target_matcher(&self) -> CompiledMatcher {
+        let mut ops = Vec::with_capacity(self.expr.len());
+
+        for node in self.expr.iter() {
+            let op = match node {
+                ExprNode::Predicate(InnerPredicate::Target(it)) => Op::Target(it.pred.clone()),
+                ExprNode::Predicate(InnerPredicate::TargetFeature(span)) => {
+                    Op::TargetFeature(self.original[span.clone()].to_owned())
+                }
+                ExprNode::Predicate(InnerPredicate::Boolean(value)) => Op::Const(*value),
+                ExprNode::Predicate(_) => Op::Const(false),
+                ExprNode::Fn(Func::All(count)) => fold(&mut ops, *count, true),
+                ExprNode::Fn(Func::Any(count)) => fold(&mut ops, *count, false),
+                ExprNode::Fn(Func::Not) => match ops.last_mut() {
+                    Some(Op::Const(value)) => {
+                        *value = !*value;
+                        continue;
+                    }
+                    Some(Op::Not) => {
+                        ops.pop();
+                        continue;
+                    }
+                    _ => Op::Not,
+                },
+            };
+
+            ops.push(op);
+        }
+
+        CompiledMatcher { ops }
+    }
+}
+
+/// Folds the constant operands of an `all()`, when `identity` is true, or an
+/// `any()`, which are the last `count` subexpressions of the operations
+fn fold(ops: &mut Vec<Op>, count: usize, identity: bool) -> Op {
+    // Find the start of each operand, so that constant operands, which are
+    // always a single operation, can be removed
+    let mut starts = SmallVec::<[usize; 8]>::new();
+    let mut end = ops.len();
+    for _ in 0..count {
+        let mut needed = 1;
+        let mut start = end;
+        while needed > 0 {
+            start -= 1;
+            needed -= 1;
+            needed += match &ops[start] {
+                Op::All(count) | Op::Any(count) => *count,
+                Op::Not => 1,
+                _ => 0,
+            };
+        }
+        starts.push(start);
+        end = start;
+    }
+
+    let mut remaining = count;
+    for start in starts {
+        if let Op::Const(value) = ops[start] {
+            // A constant that isn't the identity decides the result
+            if value != identity {
+                ops.truncate(end);
+                return Op::Const(value);
+            }
+
+            ops.remove(start);
+            remaining -= 1;
+        }
+    }
+
+    match remaining {
+        0 => Op::Const(identity),
+        // A single operand is the result, so it doesn't need a function
+        1 => ops.pop().unwrap(),
+        count if identity => Op::All(count),
+        count => Op::Any(count),
+    }
+}
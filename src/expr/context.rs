@@ -0,0 +1,119 @@
+use crate::{
+    expr::{EvalOptions, Expression, Platform},
+    targets::TargetInfo,
+};
+
+/// Which of the `[dependencies]` tables of a manifest a dependency is
+/// declared in
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DependencyKind {
+    /// `[dependencies]`
+    Normal,
+    /// `[dev-dependencies]`
+    Development,
+    /// `[build-dependencies]`
+    Build,
+}
+
+impl Default for DependencyKind {
+    fn default() -> Self {
+        Self::Normal
+    }
+}
+
+/// Where a dependency is declared when cross compiling, which decides
+/// whether its platform and `cfg()` expressions are evaluated against the
+/// host or the target, the same way as cargo.
+///
+/// Build dependencies are always compiled for the host, as are proc-macro
+/// crates and build scripts, and so all of their dependencies. Every other
+/// dependency is compiled for the target.
+///
+/// ```
+/// use cfg_expr::{
+///     expr::{DependencyKind, EvalContext, EvalOptions},
+///     targets::get_builtin_target_by_triple,
+///     Expression,
+/// };
+///
+/// let host = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+/// let target = get_builtin_target_by_triple("aarch64-linux-android").unwrap();
+/// let linux = Expression::parse(r#"target_os = "linux""#).unwrap();
+/// let options = EvalOptions::cargo_compat();
+///
+/// let normal = EvalContext::new(host, target, DependencyKind::Normal);
+/// assert!(!normal.eval(&linux, &options));
+///
+/// let build = EvalContext::new(host, target, DependencyKind::Build);
+/// assert!(build.eval(&linux, &options));
+///
+/// // The normal dependencies of a proc-macro crate are also for the host
+/// let proc_macro = normal.transitive(true, DependencyKind::Normal);
+/// assert!(proc_macro.eval(&linux, &options));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct EvalContext<'t> {
+    /// The target of the machine that runs the build
+    pub host: &'t TargetInfo,
+    /// The target that is being compiled for
+    pub target: &'t TargetInfo,
+    /// The kind of the dependency
+    pub kind: DependencyKind,
+    /// True if the crate that declares the dependency is compiled for the
+    /// host, ie. it is a proc-macro crate, a build dependency, or a
+    /// dependency of one of them
+    pub declared_for_host: bool,
+}
+
+impl<'t> EvalContext<'t> {
+    /// The context of a dependency declared by a crate that is compiled for
+    /// the target, eg. a member of the workspace
+    pub fn new(host: &'t TargetInfo, target: &'t TargetInfo, kind: DependencyKind) -> Self {
+        Self {
+            host,
+            target,
+            kind,
+            declared_for_host: false,
+        }
+    }
+
+    /// Returns true if the dependency is compiled for the host
+    pub fn is_host(&self) -> bool {
+        self.declared_for_host || self.kind == DependencyKind::Build
+    }
+
+    /// The target that the dependency is compiled for, and that expressions
+    /// are evaluated against, which is [`Self::host`] if
+    /// [`Self::is_host`], and [`Self::target`] otherwise
+    pub fn compile_target(&self) -> &'t TargetInfo {
+        if self.is_host() {
+            self.host
+        } else {
+            self.target
+        }
+    }
+
+    /// The context of a dependency of the dependency in this context, eg. to
+    /// walk a dependency graph, where `proc_macro` is true if the dependency
+    /// in this context is a proc-macro crate, and `kind` is the kind of its
+    /// dependency
+    pub fn transitive(&self, proc_macro: bool, kind: DependencyKind) -> Self {
+        Self {
+            kind,
+            declared_for_host: self.is_host() || proc_macro,
+            ..*self
+        }
+    }
+
+    /// Evaluates the expression against [`Self::compile_target`], see
+    /// [`Expression::eval_with`]
+    pub fn eval(&self, expr: &Expression, options: &EvalOptions) -> bool {
+        expr.eval_with(self.compile_target(), options)
+    }
+
+    /// Returns true if the platform of a `[target.<platform>]` table applies
+    /// to [`Self::compile_target`], see [`Platform::matches_with`]
+    pub fn matches(&self, platform: &Platform, options: &EvalOptions) -> bool {
+        platform.matches_with(self.compile_target(), options)
+    }
+}
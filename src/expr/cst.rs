@@ -0,0 +1,322 @@
+use crate::{
+    error::ParseError,
+    expr::{
+        lexer::{Lexer, Token},
+        Expression,
+    },
+};
+use alloc::{string::String, vec::Vec};
+use core::{fmt, ops::Range};
+
+/// The kind of a [`SyntaxToken`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TokenKind<'a> {
+    /// A run of whitespace
+    Whitespace,
+    /// The `cfg` of a `cfg()` wrapper
+    Cfg,
+    /// A token of the expression, or a parenthesis of the `cfg()` wrapper
+    Token(Token<'a>),
+}
+
+/// A token of a [`SyntaxTree`], including whitespace
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyntaxToken<'a> {
+    /// The kind of token
+    pub kind: TokenKind<'a>,
+    /// The span of the token in the text
+    pub span: Range<usize>,
+}
+
+/// The kind of a [`SyntaxNode`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NodeKind {
+    /// A single predicate, eg. `unix`, `target_os = "linux"` or
+    /// `version("1.70")`
+    Predicate,
+    /// `all()`
+    All,
+    /// `any()`
+    Any,
+    /// `not()`
+    Not,
+}
+
+/// A predicate or function of a [`SyntaxTree`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyntaxNode {
+    /// The kind of node
+    pub kind: NodeKind,
+    /// The span of the node in the text, from its first to its last token
+    pub span: Range<usize>,
+    /// The indices of the tokens of the node in [`SyntaxTree::tokens`]
+    pub tokens: Range<usize>,
+    /// The operands of a function
+    pub children: Vec<SyntaxNode>,
+}
+
+/// A lossless syntax tree of an expression, where every byte of the text,
+/// including whitespace and the `cfg()` wrapper, is part of exactly one
+/// token, and the nodes are the spans of the predicates and functions.
+///
+/// Rewriting the spans of nodes with [`Self::edit`] changes only those
+/// parts of the text, eg. to sort the operands of a function.
+///
+/// ```
+/// use cfg_expr::expr::cst::SyntaxTree;
+///
+/// let text = "cfg(any(\n    windows,\n    unix ,\n))";
+/// let tree = SyntaxTree::parse(text).unwrap();
+/// assert_eq!(tree.to_string(), text);
+///
+/// // Swap the operands, keeping the layout around them
+/// let (windows, unix) = (&tree.root().children[0], &tree.root().children[1]);
+/// let sorted = tree.edit([
+///     (windows.span.clone(), tree.node_text(unix)),
+///     (unix.span.clone(), tree.node_text(windows)),
+/// ]);
+///
+/// assert_eq!(sorted, "cfg(any(\n    unix,\n    windows ,\n))");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyntaxTree<'a> {
+    text: &'a str,
+    tokens: Vec<SyntaxToken<'a>>,
+    root: SyntaxNode,
+}
+
+impl<'a> SyntaxTree<'a> {
+    /// Parses the text, which must be a valid expression, see
+    /// [`Expression::parse`]
+    pub fn parse(text: &'a str) -> Result<Self, ParseError> {
+        Expression::parse(text)?;
+
+        let lexer = Lexer::new(text);
+        let start = lexer.start();
+        let end = start + lexer.inner.len();
+
+        let mut tokens = Vec::new();
+        wrapper_tokens(text, 0..start, &mut tokens);
+
+        let mut pos = start;
+        for lt in lexer {
+            // The expression is valid, so it lexes without errors
+            let lt = lt.expect("the expression was already parsed");
+            let span = lt.span.start + start..lt.span.end + start;
+
+            if span.start > pos {
+                tokens.push(SyntaxToken {
+                    kind: TokenKind::Whitespace,
+                    span: pos..span.start,
+                });
+            }
+            pos = span.end;
+
+            tokens.push(SyntaxToken {
+                kind: TokenKind::Token(lt.token),
+                span,
+            });
+        }
+
+        if end > pos {
+            tokens.push(SyntaxToken {
+                kind: TokenKind::Whitespace,
+                span: pos..end,
+            });
+        }
+
+        let first = tokens.len();
+        wrapper_tokens(text, end..text.len(), &mut tokens);
+
+        // The nodes are built from the tokens of the expression, skipping
+        // the wrapper and whitespace
+        let mut builder = Builder {
+            tokens: &tokens,
+            pos: tokens
+                .iter()
+                .position(|tok| tok.span.start >= start && tok.kind != TokenKind::Whitespace)
+                .unwrap_or(first),
+        };
+        let root = builder.node();
+
+        Ok(Self { text, tokens, root })
+    }
+
+    /// The text that was parsed
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// The tokens of the text, in order
+    pub fn tokens(&self) -> &[SyntaxToken<'a>] {
+        &self.tokens
+    }
+
+    /// The root node of the expression
+    pub fn root(&self) -> &SyntaxNode {
+        &self.root
+    }
+
+    /// The text of a node
+    pub fn node_text(&self, node: &SyntaxNode) -> &'a str {
+        &self.text[node.span.clone()]
+    }
+
+    /// Replaces each span of the text with its replacement, eg. the spans of
+    /// nodes, leaving the rest of the text unchanged. The spans must not
+    /// overlap.
+    pub fn edit<I, S>(&self, edits: I) -> String
+    where
+        I: IntoIterator<Item = (Range<usize>, S)>,
+        S: AsRef<str>,
+    {
+        let mut edits: Vec<_> = edits.into_iter().collect();
+        edits.sort_by_key(|(span, _)| span.start);
+
+        let mut edited = String::with_capacity(self.text.len());
+        let mut pos = 0;
+        for (span, replacement) in edits {
+            assert!(span.start >= pos, "edits must not overlap");
+
+            edited.push_str(&self.text[pos..span.start]);
+            edited.push_str(replacement.as_ref());
+            pos = span.end;
+        }
+        edited.push_str(&self.text[pos..]);
+
+        edited
+    }
+}
+
+/// Writes the text of every token, which is the original text
+impl<'a> fmt::Display for SyntaxTree<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.tokens
+            .iter()
+            .try_for_each(|tok| f.write_str(&self.text[tok.span.clone()]))
+    }
+}
+
+/// Splits the text around the expression, ie. the `cfg(` and `)` of the
+/// wrapper, into tokens
+fn wrapper_tokens(text: &str, span: Range<usize>, tokens: &mut Vec<SyntaxToken<'_>>) {
+    let mut pos = span.start;
+
+    while pos < span.end {
+        let rest = &text[pos..span.end];
+        let (kind, len) = if let Some(cfg) = rest.strip_prefix("cfg") {
+            (TokenKind::Cfg, rest.len() - cfg.len())
+        } else if rest.starts_with('(') {
+            (TokenKind::Token(Token::OpenParen), 1)
+        } else if rest.starts_with(')') {
+            (TokenKind::Token(Token::CloseParen), 1)
+        } else {
+            let len = rest
+                .find(|c: char| !c.is_whitespace())
+                .unwrap_or(rest.len());
+            (TokenKind::Whitespace, len)
+        };
+
+        tokens.push(SyntaxToken {
+            kind,
+            span: pos..pos + len,
+        });
+        pos += len;
+    }
+}
+
+/// Builds the nodes from the tokens of a valid expression
+struct Builder<'t, 'a> {
+    tokens: &'t [SyntaxToken<'a>],
+    pos: usize,
+}
+
+impl<'t, 'a> Builder<'t, 'a> {
+    fn skip_whitespace(&mut self) {
+        while self
+            .tokens
+            .get(self.pos)
+            .map_or(false, |tok| tok.kind == TokenKind::Whitespace)
+        {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<&'t TokenKind<'a>> {
+        self.skip_whitespace();
+        self.tokens.get(self.pos).map(|tok| &tok.kind)
+    }
+
+    /// Skips the tokens of a predicate after its key
+    fn predicate_rest(&mut self) {
+        match self.peek() {
+            Some(TokenKind::Token(Token::Equals)) => {
+                self.pos += 1;
+                self.skip_whitespace();
+                self.pos += 1;
+            }
+            // A call, eg. `version("1.70")`, or the `target()` shorthand
+            Some(TokenKind::Token(Token::OpenParen)) => {
+                let mut depth = 0;
+                loop {
+                    match self.peek() {
+                        Some(TokenKind::Token(Token::OpenParen)) => depth += 1,
+                        Some(TokenKind::Token(Token::CloseParen)) => depth -= 1,
+                        _ => {}
+                    }
+                    self.pos += 1;
+
+                    if depth == 0 {
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn node(&mut self) -> SyntaxNode {
+        self.skip_whitespace();
+        let first = self.pos;
+
+        let kind = match &self.tokens[first].kind {
+            TokenKind::Token(Token::All) => NodeKind::All,
+            TokenKind::Token(Token::Any) => NodeKind::Any,
+            TokenKind::Token(Token::Not) => NodeKind::Not,
+            _ => NodeKind::Predicate,
+        };
+        self.pos += 1;
+
+        let mut children = Vec::new();
+        if kind == NodeKind::Predicate {
+            self.predicate_rest();
+        } else {
+            // The open parenthesis
+            self.peek();
+            self.pos += 1;
+
+            loop {
+                match self.peek() {
+                    Some(TokenKind::Token(Token::CloseParen)) => break,
+                    Some(TokenKind::Token(Token::Comma)) => self.pos += 1,
+                    _ => children.push(self.node()),
+                }
+            }
+
+            self.pos += 1;
+        }
+
+        // Looking for the end of a predicate can skip the whitespace after it
+        let mut end = self.pos;
+        while self.tokens[end - 1].kind == TokenKind::Whitespace {
+            end -= 1;
+        }
+
+        SyntaxNode {
+            kind,
+            span: self.tokens[first].span.start..self.tokens[end - 1].span.end,
+            tokens: first..end,
+            children,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::expr::{ExprNode, Expression, Func};
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+
+/// Escapes a label for a quoted DOT string
+fn escape(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+impl Expression {
+    /// Writes the tree of functions and predicates as a
+    /// [DOT](https://graphviz.org/doc/info/lang.html) graph, eg. to render
+    /// it with Graphviz to see the structure of a large expression.
+    ///
+    /// Each node is labeled with its function or predicate, and its span in
+    /// [`Self::original`], and the edges go from each function to its
+    /// operands, in order.
+    ///
+    /// ```
+    /// use cfg_expr::Expression;
+    ///
+    /// let expr = Expression::parse(r#"any(unix, not(feature = "a"))"#).unwrap();
+    ///
+    /// assert_eq!(
+    ///     expr.to_dot(),
+    ///     r#"digraph {
+    ///     n0 [label="unix\n4..8"];
+    ///     n1 [label="feature = \"a\"\n14..27"];
+    ///     n2 [label="not\n10..28"];
+    ///     n3 [label="any\n0..29"];
+    ///     n2 -> n1;
+    ///     n3 -> n0;
+    ///     n3 -> n2;
+    /// }
+    /// "#
+    /// );
+    /// ```
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n");
+        let mut edges = String::new();
+
+        // The nodes of the operands that haven't been consumed by a function
+        let mut stack = Vec::new();
+
+        for (i, (node, span)) in self.expr.iter().zip(self.spans.iter()).enumerate() {
+            let (label, count) = match node {
+                ExprNode::Predicate(pred) => (pred.to_pred(&self.original).to_string(), 0),
+                ExprNode::Fn(Func::All(count)) => ("all".to_owned(), *count),
+                ExprNode::Fn(Func::Any(count)) => ("any".to_owned(), *count),
+                ExprNode::Fn(Func::Not) => ("not".to_owned(), 1),
+            };
+
+            let _ = writeln!(
+                dot,
+                "    n{i} [label=\"{}\\n{}..{}\"];",
+                escape(&label),
+                span.start,
+                span.end
+            );
+
+            for operand in stack.drain(stack.len() - count..) {
+                let _ = writeln!(edges, "    n{i} -> n{operand};");
+            }
+            stack.push(i);
+        }
+
+        dot.push_str(&edges);
+        dot.push_str("}\n");
+        dot
+    }
+}
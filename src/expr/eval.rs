@@ -0,0 +1,368 @@
+use crate::{
+    expr::{CfgSet, ExprNode, Expression, Func, InnerPredicate, Predicate},
+    targets::{TargetInfo, ALL_BUILTINS},
+};
+use alloc::{collections::BTreeSet, string::String};
+use smallvec::SmallVec;
+
+/// A set of enabled features, either the `feature`s of a crate, or the
+/// `target_feature`s of a target
+///
+/// ```
+/// use cfg_expr::expr::FeatureSet;
+///
+/// let features: FeatureSet = ["std", "serde"].into_iter().collect();
+///
+/// assert!(features.contains("std"));
+/// assert!(!features.contains("alloc"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FeatureSet {
+    features: BTreeSet<String>,
+}
+
+impl FeatureSet {
+    /// Creates an empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables a feature, returning false if it was already enabled
+    pub fn insert(&mut self, feature: impl Into<String>) -> bool {
+        self.features.insert(feature.into())
+    }
+
+    /// Returns true if the feature is enabled
+    pub fn contains(&self, feature: &str) -> bool {
+        self.features.contains(feature)
+    }
+
+    /// Iterates over the enabled features, in sorted order
+    pub fn iter(&self) -> impl Iterator<Item = &str> + '_ {
+        self.features.iter().map(String::as_str)
+    }
+
+    /// The number of enabled features
+    pub fn len(&self) -> usize {
+        self.features.len()
+    }
+
+    /// Returns true if no features are enabled
+    pub fn is_empty(&self) -> bool {
+        self.features.is_empty()
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for FeatureSet {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        Self {
+            features: iter.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<S: Into<String>> Extend<S> for FeatureSet {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, iter: I) {
+        self.features.extend(iter.into_iter().map(Into::into));
+    }
+}
+
+/// Options for evaluating an expression against a target with
+/// [`Expression::eval_with`], which describe how the crate is built
+///
+/// The default options are the same as [`Expression::eval_target`] without
+/// any enabled features, ie. only the target's cfgs are set.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EvalOptions {
+    /// The crate features that are enabled, ie. `feature = "<name>"`
+    pub crate_features: FeatureSet,
+    /// The target features that are enabled in addition to the target's
+    /// defaults, eg. with `-C target-feature`
+    pub target_features: FeatureSet,
+    /// If true, `debug_assertions` is set
+    pub debug_assertions: bool,
+    /// If true, `test` is set
+    pub test: bool,
+    /// Any other cfgs that are set, eg. with `--cfg tokio_unstable`
+    pub cfgs: CfgSet,
+}
+
+impl EvalOptions {
+    /// Options that evaluate expressions the same way as cargo does for the
+    /// platforms of `[target.'cfg(..)'.dependencies]` tables, and the
+    /// `cargo-platform` crate, so that the same dependencies are selected.
+    ///
+    /// Cargo matches platforms against the cfgs printed by
+    /// `rustc --print cfg` for the target, which means that
+    ///
+    /// * `debug_assertions` is always set, regardless of the profile, as
+    ///   rustc is run without `-O`
+    /// * `test`, `proc_macro`, `doc`, `doctest` and other cfgs that depend on
+    ///   how a crate is built are never set, so `cfg(test)` never matches
+    /// * `feature` predicates never match, as features aren't known until the
+    ///   dependencies are resolved
+    /// * Only the target features that are enabled by default are set, and
+    ///   cfgs that aren't known are only set if they're passed with `--cfg`
+    ///   in `RUSTFLAGS`, which can be added to [`Self::target_features`] and
+    ///   [`Self::cfgs`]
+    ///
+    /// Cargo warns about platforms that use `feature`, `test`,
+    /// `debug_assertions` or `proc_macro`, as they don't work as expected.
+    ///
+    /// ```
+    /// use cfg_expr::{expr::EvalOptions, targets::get_builtin_target_by_triple, Expression};
+    ///
+    /// let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    /// let cargo = EvalOptions::cargo_compat();
+    ///
+    /// let eval = |expr| Expression::parse(expr).unwrap().eval_with(linux, &cargo);
+    ///
+    /// assert!(eval("all(unix, debug_assertions)"));
+    /// assert!(!eval("test"));
+    /// assert!(!eval(r#"feature = "std""#));
+    /// ```
+    pub fn cargo_compat() -> Self {
+        Self {
+            debug_assertions: true,
+            ..Self::default()
+        }
+    }
+
+    /// The set of cfgs for the target, with these options
+    pub fn cfg_set(&self, target: &TargetInfo) -> CfgSet {
+        let mut cfgs = CfgSet::for_target(target, &self.crate_features, &self.target_features);
+        cfgs.set("debug_assertions", self.debug_assertions);
+        cfgs.set("test", self.test);
+
+        for (name, value) in self.cfgs.iter() {
+            cfgs.insert(name, value);
+        }
+
+        cfgs
+    }
+}
+
+impl Expression {
+    /// Evaluates the expression for a target, in the same way as cargo
+    /// resolves `[target.'cfg(..)'.dependencies]`, see [`CfgSet::for_target`].
+    ///
+    /// * Target predicates are matched against the target
+    /// * `feature` predicates are true if the crate feature is enabled
+    /// * `target_feature` predicates are true if the target feature is
+    ///   enabled by default for the target, see
+    ///   [`TargetInfo::default_features`], or if it is enabled, or implied by
+    ///   an enabled feature, see
+    ///   [`expand_features`](crate::targets::expand_features)
+    /// * Every other predicate, eg. `test`, `debug_assertions` or a custom
+    ///   `--cfg`, is false, as they depend on how the crate is built rather
+    ///   than the target
+    ///
+    /// ```
+    /// use cfg_expr::{expr::FeatureSet, targets::get_builtin_target_by_triple, Expression};
+    ///
+    /// let expr = Expression::parse(
+    ///     r#"all(target_os = "linux", feature = "simd", target_feature = "sse4.1", not(test))"#,
+    /// ).unwrap();
+    ///
+    /// let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    /// let simd: FeatureSet = ["simd"].into_iter().collect();
+    /// let avx2: FeatureSet = ["avx2"].into_iter().collect();
+    ///
+    /// // sse4.1 isn't enabled by default, but is implied by avx2
+    /// assert!(!expr.eval_target(linux, &simd, &FeatureSet::new()));
+    /// assert!(expr.eval_target(linux, &simd, &avx2));
+    /// assert!(!expr.eval_target(linux, &FeatureSet::new(), &avx2));
+    /// ```
+    pub fn eval_target(
+        &self,
+        target: &TargetInfo,
+        enabled_crate_features: &FeatureSet,
+        enabled_target_features: &FeatureSet,
+    ) -> bool {
+        self.eval_cfg_set(&CfgSet::for_target(
+            target,
+            enabled_crate_features,
+            enabled_target_features,
+        ))
+    }
+
+    /// Evaluates the expression against the target, with the cfgs of a build
+    /// with the options, see [`EvalOptions::cfg_set`]
+    ///
+    /// ```
+    /// use cfg_expr::{expr::EvalOptions, targets::get_builtin_target_by_triple, Expression};
+    ///
+    /// let expr = Expression::parse("all(windows, any(test, tokio_unstable))").unwrap();
+    /// let windows = get_builtin_target_by_triple("x86_64-pc-windows-msvc").unwrap();
+    ///
+    /// let mut options = EvalOptions::default();
+    /// assert!(!expr.eval_with(windows, &options));
+    ///
+    /// options.cfgs.insert("tokio_unstable", None);
+    /// assert!(expr.eval_with(windows, &options));
+    /// ```
+    pub fn eval_with(&self, target: &TargetInfo, options: &EvalOptions) -> bool {
+        self.eval_cfg_set(&options.cfg_set(target))
+    }
+
+    /// Evaluates the expression against every [builtin](ALL_BUILTINS)
+    /// target, yielding the targets it is true for, in the order of their
+    /// triples.
+    ///
+    /// Target predicates are matched against each target, and every other
+    /// predicate, eg. `feature` or `target_feature`, is evaluated by
+    /// `eval_extras` with the target being evaluated.
+    ///
+    /// ```
+    /// use cfg_expr::{Expression, Predicate};
+    ///
+    /// let expr = Expression::parse(
+    ///     r#"all(target_os = "linux", target_arch = "x86_64", target_feature = "sse2", feature = "std")"#,
+    /// ).unwrap();
+    ///
+    /// let matching = expr
+    ///     .matching_builtin_targets(|ti, pred| match pred {
+    ///         Predicate::TargetFeature(feat) => ti.has_default_feature(feat),
+    ///         Predicate::Feature(feat) => *feat == "std",
+    ///         _ => false,
+    ///     })
+    ///     .map(|ti| ti.triple.as_str())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert!(matching.contains(&"x86_64-unknown-linux-gnu"));
+    /// assert!(matching.contains(&"x86_64-unknown-linux-musl"));
+    /// assert!(!matching.contains(&"aarch64-unknown-linux-gnu"));
+    /// ```
+    pub fn matching_builtin_targets<'a, F>(
+        &'a self,
+        mut eval_extras: F,
+    ) -> impl Iterator<Item = &'static TargetInfo> + 'a
+    where
+        F: FnMut(&TargetInfo, &Predicate<'_>) -> bool + 'a,
+    {
+        ALL_BUILTINS.iter().filter(move |ti| {
+            self.eval(|pred| match pred {
+                Predicate::Target(tp) => tp.matches(*ti),
+                pred => eval_extras(ti, pred),
+            })
+        })
+    }
+
+    /// Evaluates the expression with a fallible predicate evaluator, eg. one
+    /// that does I/O, returning the first error.
+    ///
+    /// Unlike [`Self::eval`], the operands of `all()` and `any()` are
+    /// evaluated from left to right, stopping at the first operand that is
+    /// false or true respectively, so predicates that can't change the
+    /// result aren't evaluated, and so can't fail.
+    ///
+    /// ```
+    /// use cfg_expr::{Expression, Predicate};
+    ///
+    /// let expr = Expression::parse(r#"any(feature = "std", feature = "missing")"#).unwrap();
+    ///
+    /// let lookup = |enabled: &'static [&'static str]| {
+    ///     move |pred: &Predicate<'_>| match pred {
+    ///         Predicate::Feature("missing") => Err("unknown feature"),
+    ///         Predicate::Feature(feat) => Ok(enabled.contains(feat)),
+    ///         _ => Ok(false),
+    ///     }
+    /// };
+    ///
+    /// // The second predicate is never evaluated, as the first one is true
+    /// assert_eq!(expr.eval_result(lookup(&["std"])), Ok(true));
+    /// assert_eq!(expr.eval_result(lookup(&[])), Err("unknown feature"));
+    /// ```
+    pub fn eval_result<E, F>(&self, mut eval_predicate: F) -> Result<bool, E>
+    where
+        F: FnMut(&Predicate<'_>) -> Result<bool, E>,
+    {
+        // The index of the first node of the subexpression ending at each
+        // node, so that the operands of a function can be found
+        let mut starts = SmallVec::<[usize; 8]>::with_capacity(self.expr.len());
+        for (i, node) in self.expr.iter().enumerate() {
+            let count = match node {
+                ExprNode::Predicate(_) => 0,
+                ExprNode::Fn(Func::Not) => 1,
+                ExprNode::Fn(Func::All(count) | Func::Any(count)) => *count,
+            };
+
+            let mut start = i;
+            for _ in 0..count {
+                start = starts[start - 1];
+            }
+            starts.push(start);
+        }
+
+        self.eval_short_circuit(self.expr.len() - 1, &starts, &mut eval_predicate)
+    }
+
+    /// Evaluates the expression like [`Self::eval`], but only evaluates the
+    /// predicates that can change the result, eg. the operands of an `any()`
+    /// after one that is true are skipped, which matters when evaluating
+    /// predicates is expensive, eg. spawning processes.
+    ///
+    /// The operands of `all()` and `any()` are evaluated from left to right,
+    /// see [`Self::eval_result`].
+    ///
+    /// ```
+    /// use cfg_expr::{Expression, Predicate};
+    ///
+    /// let expr = Expression::parse(r#"any(unix, feature = "slow", all(windows, feature = "slower"))"#).unwrap();
+    ///
+    /// let mut evaluated = 0;
+    /// assert!(expr.eval_lazy(|pred| {
+    ///     evaluated += 1;
+    ///     matches!(pred, Predicate::Target(_))
+    /// }));
+    /// assert_eq!(evaluated, 1);
+    /// ```
+    pub fn eval_lazy<F>(&self, mut eval_predicate: F) -> bool
+    where
+        F: FnMut(&Predicate<'_>) -> bool,
+    {
+        match self.eval_result(|pred| Ok::<_, core::convert::Infallible>(eval_predicate(pred))) {
+            Ok(value) => value,
+            Err(never) => match never {},
+        }
+    }
+
+    fn eval_short_circuit<E, F>(
+        &self,
+        i: usize,
+        starts: &[usize],
+        eval_predicate: &mut F,
+    ) -> Result<bool, E>
+    where
+        F: FnMut(&Predicate<'_>) -> Result<bool, E>,
+    {
+        let (count, short_circuit) = match &self.expr[i] {
+            ExprNode::Predicate(InnerPredicate::Boolean(value)) => return Ok(*value),
+            ExprNode::Predicate(pred) => return eval_predicate(&pred.to_pred(&self.original)),
+            ExprNode::Fn(Func::Not) => {
+                return self
+                    .eval_short_circuit(i - 1, starts, eval_predicate)
+                    .map(|value| !value)
+            }
+            ExprNode::Fn(Func::All(count)) => (*count, false),
+            ExprNode::Fn(Func::Any(count)) => (*count, true),
+        };
+
+        // The operands end at the node before the function, and at the node
+        // before the start of each following operand
+        let mut operands = SmallVec::<[usize; 8]>::new();
+        let mut end = i;
+        for _ in 0..count {
+            operands.push(end - 1);
+            end = starts[end - 1];
+        }
+
+        for operand in operands.into_iter().rev() {
+            if self.eval_short_circuit(operand, starts, eval_predicate)? == short_circuit {
+                return Ok(short_circuit);
+            }
+        }
+
+        Ok(!short_circuit)
+    }
+}
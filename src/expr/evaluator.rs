@@ -0,0 +1,141 @@
+use crate::{
+    expr::{CfgSet, Expression, FeatureSet, Predicate},
+    targets::TargetInfo,
+};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    collections::BTreeMap,
+    string::String,
+    vec::Vec,
+};
+
+/// The cached results of the predicates for a single cfg name
+#[derive(Clone, Debug, Default)]
+struct CachedName {
+    /// The result of the bare name, eg. `unix`
+    bare: Option<bool>,
+    /// The results of `name = "value"` pairs, by value
+    values: BTreeMap<String, bool>,
+}
+
+/// Evaluates many expressions against the same set of cfgs, caching the
+/// result of each predicate by its name and value, so that a predicate that
+/// appears in many expressions, eg. `unix` or `target_os = "linux"` when
+/// resolving the dependencies of a whole workspace, is only looked up once.
+///
+/// As with [`Expression::eval_cfg_set`], `version()` and `accessible()`
+/// predicates are always false.
+///
+/// ```
+/// use cfg_expr::{expr::{Evaluator, FeatureSet}, targets::get_builtin_target_by_triple, Expression};
+///
+/// let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+/// let features: FeatureSet = ["std"].into_iter().collect();
+/// let mut evaluator = Evaluator::for_target(linux, &features, &FeatureSet::new());
+///
+/// let exprs = [
+///     r#"all(unix, feature = "std")"#,
+///     r#"any(windows, target_os = "macos")"#,
+///     r#"all(unix, target_pointer_width = "64")"#,
+/// ]
+/// .map(|expr| Expression::parse(expr).unwrap());
+///
+/// assert_eq!(evaluator.eval_all(&exprs), [true, false, true]);
+/// // `unix` is only looked up once
+/// assert_eq!(evaluator.cached(), 5);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Evaluator<'c> {
+    cfgs: Cow<'c, CfgSet>,
+    cache: BTreeMap<String, CachedName>,
+}
+
+impl<'c> Evaluator<'c> {
+    /// Creates an evaluator for the set of cfgs
+    pub fn new(cfgs: &'c CfgSet) -> Self {
+        Self {
+            cfgs: Cow::Borrowed(cfgs),
+            cache: BTreeMap::new(),
+        }
+    }
+
+    /// Creates an evaluator for a target, with the cfgs from
+    /// [`CfgSet::for_target`]
+    pub fn for_target(
+        target: &TargetInfo,
+        crate_features: &FeatureSet,
+        target_features: &FeatureSet,
+    ) -> Evaluator<'static> {
+        Evaluator {
+            cfgs: Cow::Owned(CfgSet::for_target(target, crate_features, target_features)),
+            cache: BTreeMap::new(),
+        }
+    }
+
+    /// The set of cfgs that expressions are evaluated against
+    pub fn cfgs(&self) -> &CfgSet {
+        &self.cfgs
+    }
+
+    /// Evaluates an expression, returning the same result as
+    /// [`Expression::eval_cfg_set`].
+    ///
+    /// Only the predicates that can change the result are evaluated, see
+    /// [`Expression::eval_lazy`].
+    pub fn eval(&mut self, expr: &Expression) -> bool {
+        let cfgs = &self.cfgs;
+        let cache = &mut self.cache;
+
+        expr.eval_lazy(|pred| {
+            if matches!(pred, Predicate::Version(_) | Predicate::Accessible(_)) {
+                return false;
+            }
+
+            let (name, value) = pred.as_key_value();
+
+            // Only allocate on a cache miss
+            if let Some(cached) = cache.get(name) {
+                let result = match &value {
+                    Some(value) => cached.values.get(value.as_ref()).copied(),
+                    None => cached.bare,
+                };
+
+                if let Some(result) = result {
+                    return result;
+                }
+            }
+
+            let result = cfgs.contains(name, value.as_deref());
+            let cached = cache.entry(name.to_owned()).or_default();
+            match value {
+                Some(value) => {
+                    cached.values.insert(value.into_owned(), result);
+                }
+                None => cached.bare = Some(result),
+            }
+
+            result
+        })
+    }
+
+    /// Evaluates each of the expressions, in order
+    pub fn eval_all<'e, I>(&mut self, exprs: I) -> Vec<bool>
+    where
+        I: IntoIterator<Item = &'e Expression>,
+    {
+        exprs.into_iter().map(|expr| self.eval(expr)).collect()
+    }
+
+    /// The number of distinct predicates whose results are cached
+    pub fn cached(&self) -> usize {
+        self.cache
+            .values()
+            .map(|cached| usize::from(cached.bare.is_some()) + cached.values.len())
+            .sum()
+    }
+
+    /// Clears the cached results, eg. after they are no longer needed
+    pub fn clear_cache(&mut self) {
+        self.cache.clear();
+    }
+}
@@ -0,0 +1,250 @@
+use crate::{
+    expr::{ExprNode, Expression, Func, InnerPredicate, Logic, Predicate},
+    targets::TargetInfo,
+};
+use alloc::{boxed::Box, vec::Vec};
+use core::{fmt, ops::Range};
+
+/// What a node of an [`Explanation`] is
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExplainedNode<'a> {
+    /// A single predicate
+    Predicate(Predicate<'a>),
+    /// `all()` with the explanations of its operands
+    All(Vec<Explanation<'a>>),
+    /// `any()` with the explanations of its operands
+    Any(Vec<Explanation<'a>>),
+    /// `not()` with the explanation of its operand
+    Not(Box<Explanation<'a>>),
+}
+
+/// A trace of how an [`Expression`] was evaluated, with the value of every
+/// predicate and function, created by [`Expression::explain`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Explanation<'a> {
+    /// The node that was evaluated
+    pub node: ExplainedNode<'a>,
+    /// The value the node evaluated to
+    pub value: bool,
+    /// The span of the node in the original string
+    pub span: Range<usize>,
+    /// The text of the node in the original string
+    pub text: &'a str,
+}
+
+impl<'a> Explanation<'a> {
+    /// Retrieves the predicates that decided the value of the expression,
+    /// ie. the false operands of an `all()` that is false, or the true
+    /// operands of an `any()` that is true, and every operand otherwise
+    ///
+    /// ```
+    /// use cfg_expr::{targets::get_builtin_target_by_triple, Expression};
+    ///
+    /// let expr = Expression::parse(r#"all(unix, target_arch = "aarch64", target_pointer_width = "64")"#).unwrap();
+    /// let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    ///
+    /// let explanation = expr.explain(linux, |_| false);
+    /// assert!(!explanation.value);
+    ///
+    /// let causes = explanation.causes().iter().map(|ex| ex.text).collect::<Vec<_>>();
+    /// assert_eq!(causes, [r#"target_arch = "aarch64""#]);
+    /// ```
+    pub fn causes(&self) -> Vec<&Explanation<'a>> {
+        let mut causes = Vec::new();
+        self.collect_causes(&mut causes);
+        causes
+    }
+
+    fn collect_causes<'e>(&'e self, causes: &mut Vec<&'e Explanation<'a>>) {
+        match &self.node {
+            ExplainedNode::Predicate(_) => causes.push(self),
+            ExplainedNode::All(operands) | ExplainedNode::Any(operands) => {
+                // The operands that have the same value as the function are
+                // the ones that decided it
+                for operand in operands.iter().filter(|op| op.value == self.value) {
+                    operand.collect_causes(causes);
+                }
+            }
+            ExplainedNode::Not(operand) => operand.collect_causes(causes),
+        }
+    }
+
+    /// The value of the node when only target predicates are known, and
+    /// every other predicate could be either true or false
+    fn target_value(&self) -> Option<bool> {
+        match &self.node {
+            ExplainedNode::Predicate(Predicate::Target(_) | Predicate::Boolean(_)) => {
+                Some(self.value)
+            }
+            ExplainedNode::Predicate(_) => None,
+            ExplainedNode::All(operands) => operands
+                .iter()
+                .map(Self::target_value)
+                .fold(Logic::top(), Logic::and),
+            ExplainedNode::Any(operands) => operands
+                .iter()
+                .map(Self::target_value)
+                .fold(Logic::bottom(), Logic::or),
+            ExplainedNode::Not(operand) => operand.target_value().map(|value| !value),
+        }
+    }
+
+    /// Finds the smallest subexpression that forces this node, which has
+    /// the specified value, to have that value
+    fn core(&self, value: bool) -> &Self {
+        fn smallest<'e, 'a>(
+            operands: &'e [Explanation<'a>],
+            value: bool,
+        ) -> Option<&'e Explanation<'a>> {
+            operands
+                .iter()
+                .filter(|op| op.target_value() == Some(value))
+                .map(|op| op.core(value))
+                .min_by_key(|core| core.span.len())
+        }
+
+        match &self.node {
+            ExplainedNode::Predicate(_) => self,
+            ExplainedNode::Not(operand) => operand.core(!value),
+            // A single operand with the same value as an all() or any()
+            // forces it to that value
+            ExplainedNode::All(operands) if !value => smallest(operands, value).unwrap_or(self),
+            ExplainedNode::Any(operands) if value => smallest(operands, value).unwrap_or(self),
+            // Otherwise every operand is needed
+            ExplainedNode::All(operands) | ExplainedNode::Any(operands) => {
+                match operands.as_slice() {
+                    [operand] => operand.core(value),
+                    _ => self,
+                }
+            }
+        }
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        writeln!(
+            f,
+            "{:indent$}{:<5} {}",
+            "",
+            self.value,
+            self.text,
+            indent = depth * 2
+        )?;
+
+        match &self.node {
+            ExplainedNode::Predicate(_) => Ok(()),
+            ExplainedNode::All(operands) | ExplainedNode::Any(operands) => operands
+                .iter()
+                .try_for_each(|operand| operand.write(f, depth + 1)),
+            ExplainedNode::Not(operand) => operand.write(f, depth + 1),
+        }
+    }
+}
+
+/// Writes each node on its own line, indented by its depth, after its value
+///
+/// ```text
+/// false all(unix, target_arch = "aarch64")
+///   true  unix
+///   false target_arch = "aarch64"
+/// ```
+impl<'a> fmt::Display for Explanation<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, 0)
+    }
+}
+
+impl Expression {
+    /// Evaluates the expression against a target, recording the value of
+    /// every predicate and how it propagated through each `all()`, `any()`
+    /// and `not()`, eg. to show which predicate excluded a target.
+    ///
+    /// Target predicates are matched against the target, and every other
+    /// predicate is evaluated by `eval_extras`.
+    ///
+    /// ```
+    /// use cfg_expr::{targets::get_builtin_target_by_triple, Expression};
+    ///
+    /// let expr = Expression::parse(r#"all(unix, target_arch = "aarch64")"#).unwrap();
+    /// let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    ///
+    /// assert_eq!(
+    ///     expr.explain(linux, |_| false).to_string(),
+    ///     "false all(unix, target_arch = \"aarch64\")\n  true  unix\n  false target_arch = \"aarch64\"\n",
+    /// );
+    /// ```
+    pub fn explain<F>(&self, target: &TargetInfo, mut eval_extras: F) -> Explanation<'_>
+    where
+        F: FnMut(&Predicate<'_>) -> bool,
+    {
+        let mut stack = Vec::<Explanation<'_>>::new();
+
+        for (node, span) in self.expr.iter().zip(self.spans.iter()) {
+            let (node, value) = match node {
+                ExprNode::Predicate(InnerPredicate::Boolean(value)) => {
+                    (ExplainedNode::Predicate(Predicate::Boolean(*value)), *value)
+                }
+                ExprNode::Predicate(pred) => {
+                    let pred = pred.to_pred(&self.original);
+                    let value = match &pred {
+                        Predicate::Target(tp) => tp.matches(target),
+                        pred => eval_extras(pred),
+                    };
+
+                    (ExplainedNode::Predicate(pred), value)
+                }
+                ExprNode::Fn(Func::All(count)) => {
+                    let operands = stack.split_off(stack.len() - count);
+                    let value = operands.iter().all(|op| op.value);
+                    (ExplainedNode::All(operands), value)
+                }
+                ExprNode::Fn(Func::Any(count)) => {
+                    let operands = stack.split_off(stack.len() - count);
+                    let value = operands.iter().any(|op| op.value);
+                    (ExplainedNode::Any(operands), value)
+                }
+                ExprNode::Fn(Func::Not) => {
+                    let operand = stack.pop().unwrap();
+                    let value = !operand.value;
+                    (ExplainedNode::Not(Box::new(operand)), value)
+                }
+            };
+
+            stack.push(Explanation {
+                node,
+                value,
+                span: span.clone(),
+                text: &self.original[span.clone()],
+            });
+        }
+
+        stack.pop().unwrap()
+    }
+
+    /// Finds the smallest subexpression, by span, whose falsity forces the
+    /// expression to be false for the target, eg. to report that a target is
+    /// blocked by `target_env = "musl"`.
+    ///
+    /// Only target predicates are known, so this is `None` if the expression
+    /// could be true for the target depending on the other predicates, eg.
+    /// `feature`s.
+    ///
+    /// ```
+    /// use cfg_expr::{targets::get_builtin_target_by_triple, Expression};
+    ///
+    /// let expr = Expression::parse(
+    ///     r#"all(unix, any(target_env = "musl", target_os = "macos"), feature = "static")"#,
+    /// ).unwrap();
+    ///
+    /// let gnu = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    /// let core = expr.failing_core(gnu).unwrap();
+    /// assert_eq!(core.text, r#"any(target_env = "musl", target_os = "macos")"#);
+    ///
+    /// let musl = get_builtin_target_by_triple("x86_64-unknown-linux-musl").unwrap();
+    /// assert!(expr.failing_core(musl).is_none());
+    /// ```
+    pub fn failing_core(&self, target: &TargetInfo) -> Option<Explanation<'_>> {
+        let explanation = self.explain(target, |_| false);
+
+        (explanation.target_value() == Some(false)).then(|| explanation.core(false).clone())
+    }
+}
@@ -0,0 +1,198 @@
+use crate::expr::{ExprTree, Expression, Predicate};
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::Write;
+
+/// Options for writing an [`Expression`] with [`Expression::format`]
+///
+/// The default options write the same single line as the [`Display`](std::fmt::Display)
+/// implementation of [`Expression`].
+#[derive(Clone, Debug)]
+pub struct FormatOptions {
+    /// The number of spaces that the operands of a function that is split
+    /// across multiple lines are indented by
+    pub indent: usize,
+    /// The widest that a line can be, including its indentation. Functions
+    /// that don't fit are split with each of their operands on a line of
+    /// their own. If `None`, the expression is always written on one line.
+    pub max_width: Option<usize>,
+    /// If true, the operands of `all()` and `any()` are sorted by their
+    /// written form, so that expressions that only differ in the order of
+    /// their operands are written the same way
+    pub sort_operands: bool,
+    /// If true, the `=` of key-value predicates is surrounded by spaces, eg.
+    /// `feature = "a"` rather than `feature="a"`
+    pub spaces_around_equals: bool,
+    /// If true, the operands of functions on a single line are separated by
+    /// `, ` rather than `,`
+    pub space_after_comma: bool,
+    /// If true, the last operand of an `all()` or `any()` that is split
+    /// across multiple lines is followed by a comma
+    pub trailing_comma: bool,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            indent: 4,
+            max_width: None,
+            sort_operands: false,
+            spaces_around_equals: true,
+            space_after_comma: true,
+            trailing_comma: true,
+        }
+    }
+}
+
+impl FormatOptions {
+    /// Options for making large expressions readable, which split functions
+    /// that don't fit in 80 columns across multiple lines, and sort their
+    /// operands
+    pub fn pretty() -> Self {
+        Self {
+            max_width: Some(80),
+            sort_operands: true,
+            ..Self::default()
+        }
+    }
+}
+
+/// An expression with the single line form of each of its nodes, so that it
+/// is only written once regardless of how deep the node is
+struct Node {
+    flat: String,
+    func: Option<(&'static str, Vec<Node>)>,
+}
+
+impl Node {
+    fn new(tree: &ExprTree<'_>, options: &FormatOptions) -> Self {
+        let (name, operands) = match tree {
+            ExprTree::Predicate(pred) => {
+                return Self {
+                    flat: predicate(pred, options),
+                    func: None,
+                };
+            }
+            ExprTree::All(operands) => ("all", operands.as_slice()),
+            ExprTree::Any(operands) => ("any", operands.as_slice()),
+            ExprTree::Not(operand) => ("not", core::slice::from_ref(operand.as_ref())),
+        };
+
+        let mut operands: Vec<_> = operands.iter().map(|op| Self::new(op, options)).collect();
+        if options.sort_operands && name != "not" {
+            operands.sort_by(|a, b| a.flat.cmp(&b.flat));
+        }
+
+        let separator = if options.space_after_comma { ", " } else { "," };
+        let flat = format!(
+            "{name}({})",
+            operands
+                .iter()
+                .map(|op| op.flat.as_str())
+                .collect::<Vec<_>>()
+                .join(separator)
+        );
+
+        Self {
+            flat,
+            func: Some((name, operands)),
+        }
+    }
+
+    /// Writes the node, which starts at the column of the indentation, and
+    /// is followed by `suffix` on the same line
+    fn write(&self, options: &FormatOptions, column: usize, suffix: &str, out: &mut String) {
+        let fits = options
+            .max_width
+            .map_or(true, |max| column + self.flat.len() + suffix.len() <= max);
+
+        let (name, operands) = match &self.func {
+            Some((name, operands)) if !fits && !operands.is_empty() => (*name, operands),
+            _ => {
+                out.push_str(&self.flat);
+                return;
+            }
+        };
+
+        out.push_str(name);
+        out.push_str("(\n");
+
+        let inner = column + options.indent;
+        for (i, op) in operands.iter().enumerate() {
+            let comma = if i + 1 < operands.len() || (options.trailing_comma && name != "not") {
+                ","
+            } else {
+                ""
+            };
+
+            out.extend(core::iter::repeat(' ').take(inner));
+            op.write(options, inner, comma, out);
+            out.push_str(comma);
+            out.push('\n');
+        }
+
+        out.extend(core::iter::repeat(' ').take(column));
+        out.push(')');
+    }
+}
+
+fn predicate(pred: &Predicate<'_>, options: &FormatOptions) -> String {
+    let mut out = String::new();
+
+    match pred {
+        Predicate::Version(_) | Predicate::Accessible(_) => {
+            pred.write_cfg(&mut out).unwrap();
+        }
+        _ => {
+            let (key, val) = pred.as_key_value();
+            out.push_str(key);
+
+            if let Some(val) = val {
+                let equals = if options.spaces_around_equals {
+                    " = "
+                } else {
+                    "="
+                };
+                write!(out, "{equals}\"{val}\"").unwrap();
+            }
+        }
+    }
+
+    out
+}
+
+impl Expression {
+    /// Writes the expression in its canonical form, with the layout of the
+    /// options, eg. to make large, machine generated expressions readable.
+    ///
+    /// The output only depends on the predicates and functions of the
+    /// expression, not on the layout of the string it was parsed from, and
+    /// is itself a valid expression.
+    ///
+    /// ```
+    /// use cfg_expr::{expr::FormatOptions, Expression};
+    ///
+    /// let expr = Expression::parse(r#"any(target_os="macos",all(unix,target_arch="x86_64",not(target_os="macos"),feature="a-rather-long-feature"))"#).unwrap();
+    ///
+    /// assert_eq!(
+    ///     expr.format(&FormatOptions::default()),
+    ///     r#"any(target_os = "macos", all(unix, target_arch = "x86_64", not(target_os = "macos"), feature = "a-rather-long-feature"))"#
+    /// );
+    /// assert_eq!(
+    ///     expr.format(&FormatOptions::pretty()),
+    ///     r#"any(
+    ///     all(
+    ///         feature = "a-rather-long-feature",
+    ///         not(target_os = "macos"),
+    ///         target_arch = "x86_64",
+    ///         unix,
+    ///     ),
+    ///     target_os = "macos",
+    /// )"#
+    /// );
+    /// ```
+    pub fn format(&self, options: &FormatOptions) -> String {
+        let mut out = String::new();
+        Node::new(&self.root(), options).write(options, 0, "", &mut out);
+        out
+    }
+}
@@ -0,0 +1,133 @@
+use crate::expr::{ExprTree, Expression, Predicate};
+use serde::{de::Error as _, Deserialize, Serialize};
+
+/// A node of the JSON syntax tree, see [`Expression::to_json_ast`]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+enum Node {
+    All(Vec<Node>),
+    Any(Vec<Node>),
+    Not(Box<Node>),
+    Cfg {
+        key: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        value: Option<String>,
+    },
+    Version(String),
+    Accessible(String),
+}
+
+impl Node {
+    fn new(tree: &ExprTree<'_>) -> Self {
+        match tree {
+            ExprTree::All(operands) => Self::All(operands.iter().map(Self::new).collect()),
+            ExprTree::Any(operands) => Self::Any(operands.iter().map(Self::new).collect()),
+            ExprTree::Not(operand) => Self::Not(Box::new(Self::new(operand))),
+            ExprTree::Predicate(Predicate::Version(version)) => Self::Version(version.to_string()),
+            ExprTree::Predicate(Predicate::Accessible(path)) => {
+                Self::Accessible((*path).to_owned())
+            }
+            ExprTree::Predicate(pred) => {
+                let (key, value) = pred.as_key_value();
+                Self::Cfg {
+                    key: key.to_owned(),
+                    value: value.map(|value| value.into_owned()),
+                }
+            }
+        }
+    }
+
+    /// Writes the node as a `cfg()` expression, which is then parsed, so that
+    /// the tree is validated the same way as any other expression
+    fn write(&self, out: &mut String) {
+        let (name, operands) = match self {
+            Self::All(operands) => ("all", operands.as_slice()),
+            Self::Any(operands) => ("any", operands.as_slice()),
+            Self::Not(operand) => ("not", core::slice::from_ref(operand.as_ref())),
+            Self::Cfg { key, value } => {
+                out.push_str(key);
+                if let Some(value) = value {
+                    out.push_str(" = \"");
+                    out.push_str(value);
+                    out.push('"');
+                }
+                return;
+            }
+            Self::Version(version) => {
+                out.push_str("version(\"");
+                out.push_str(version);
+                out.push_str("\")");
+                return;
+            }
+            Self::Accessible(path) => {
+                out.push_str("accessible(");
+                out.push_str(path);
+                out.push(')');
+                return;
+            }
+        };
+
+        out.push_str(name);
+        out.push('(');
+        for (i, operand) in operands.iter().enumerate() {
+            if i > 0 {
+                out.push_str(", ");
+            }
+            operand.write(out);
+        }
+        out.push(')');
+    }
+}
+
+impl Expression {
+    /// Writes the parsed tree of the expression as JSON, so that tools that
+    /// aren't written in Rust can consume expressions without parsing them.
+    ///
+    /// Each node is an object with a single field, which is one of
+    ///
+    /// * `"all"` or `"any"`, with an array of the operands
+    /// * `"not"`, with the single operand
+    /// * `"cfg"`, with an object with the `"key"` of the predicate, and its
+    ///   `"value"`, which is omitted for bare predicates, eg. `unix` or
+    ///   `test`
+    /// * `"version"`, with the version of a `version()` predicate
+    /// * `"accessible"`, with the path of an `accessible()` predicate
+    ///
+    /// The keys and values are in their canonical form, see
+    /// [`Predicate::as_key_value`].
+    ///
+    /// ```
+    /// use cfg_expr::Expression;
+    ///
+    /// let expr = Expression::parse(r#"all(unix, not(target_os = "macos"), version("1.70"))"#).unwrap();
+    ///
+    /// assert_eq!(
+    ///     expr.to_json_ast(),
+    ///     r#"{"all":[{"cfg":{"key":"unix"}},{"not":{"cfg":{"key":"target_os","value":"macos"}}},{"version":"1.70"}]}"#
+    /// );
+    /// ```
+    pub fn to_json_ast(&self) -> String {
+        // Serializing a tree of strings can't fail
+        serde_json::to_string(&Node::new(&self.root())).unwrap()
+    }
+
+    /// Reads an expression from the JSON written by [`Self::to_json_ast`].
+    /// The predicates are validated the same way as [`Self::parse`].
+    ///
+    /// ```
+    /// use cfg_expr::Expression;
+    ///
+    /// let expr = Expression::from_json_ast(r#"{"any": [{"cfg": {"key": "windows"}}, {"cfg": {"key": "feature", "value": "a"}}]}"#).unwrap();
+    /// assert_eq!(expr.original(), r#"any(windows, feature = "a")"#);
+    ///
+    /// assert!(Expression::from_json_ast(r#"{"cfg": {"key": "not a key"}}"#).is_err());
+    /// ```
+    pub fn from_json_ast(json: &str) -> Result<Self, serde_json::Error> {
+        let node: Node = serde_json::from_str(json)?;
+
+        let mut original = String::new();
+        node.write(&mut original);
+
+        Self::parse(&original).map_err(serde_json::Error::custom)
+    }
+}
@@ -1,4 +1,5 @@
 use crate::error::{ParseError, Reason};
+use alloc::borrow::ToOwned;
 
 /// A single token in a cfg expression
 /// <https://doc.rust-lang.org/reference/conditional-compilation.html>
@@ -10,11 +11,11 @@ pub enum Token<'a> {
     Value(&'a str),
     /// A '=', joining a key and a value
     Equals,
-    /// Beginning of an all() predicate list
+    /// Beginning of an `all()` predicate list
     All,
-    /// Beginning of an any() predicate list
+    /// Beginning of an `any()` predicate list
     Any,
-    /// Beginning of a not() predicate
+    /// Beginning of a `not()` predicate
     Not,
     /// A `(` for starting a predicate list
     OpenParen,
@@ -24,60 +25,345 @@ pub enum Token<'a> {
     Comma,
 }
 
-impl<'a> std::fmt::Display for Token<'a> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Debug::fmt(self, f)
+impl<'a> core::fmt::Display for Token<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self, f)
     }
 }
 
 impl<'a> Token<'a> {
-    fn len(&self) -> usize {
+    pub(super) fn kind(&self) -> Kind {
         match self {
-            Token::Key(s) => s.len(),
-            Token::Value(s) => s.len() + 2,
-            Token::Equals | Token::OpenParen | Token::CloseParen | Token::Comma => 1,
-            Token::All | Token::Any | Token::Not => 3,
+            Token::Key(key) => Kind::Key(Call::of(key)),
+            Token::Value(_) => Kind::Value,
+            Token::Equals => Kind::Equals,
+            Token::All => Kind::All,
+            Token::Any => Kind::Any,
+            Token::Not => Kind::Not,
+            Token::OpenParen => Kind::OpenParen,
+            Token::CloseParen => Kind::CloseParen,
+            Token::Comma => Kind::Comma,
         }
     }
 }
 
+/// The kind of a [`Token`], without its text.
+///
+/// Lexing is done by `const` functions on the kinds of tokens, as is the
+/// [grammar](super::parser::follows), so that the
+/// [`cfg_expr!`](crate::cfg_expr) macro can check expressions at compile time
+/// with the same rules as the parser.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Kind {
+    /// A key, and the call it starts if it is followed by `(`
+    Key(Call),
+    Value,
+    Equals,
+    All,
+    Any,
+    Not,
+    OpenParen,
+    CloseParen,
+    Comma,
+}
+
+/// The keys that can be followed by parentheses
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Call {
+    /// Any other key, which can't be
+    None,
+    /// `version("1.47")`
+    Version,
+    /// `accessible(::std::arch::x86_64)`
+    Accessible,
+    /// The `target(os = "linux")` shorthand for an `all()` of target
+    /// predicates
+    Target,
+}
+
+impl Call {
+    pub(super) fn of(key: &str) -> Self {
+        call(key.as_bytes(), 0, key.len())
+    }
+}
+
+/// The token at the start of some text, see [`scan`]
+pub(crate) enum Scan {
+    /// A token, and its length in bytes
+    Token(Kind, usize),
+    /// A `"` without a closing `"`
+    UnclosedQuotes,
+    /// A character that can't start a token, and its length in bytes
+    Invalid(usize),
+}
+
+const fn is_ident_start(b: u8) -> bool {
+    b == b'_' || b.is_ascii_lowercase() || b.is_ascii_uppercase()
+}
+
+const fn is_ident_rest(b: u8) -> bool {
+    is_ident_start(b) || b.is_ascii_digit()
+}
+
+/// Returns true if there is a `::` path separator followed by another
+/// identifier at `i`
+const fn is_path_sep(b: &[u8], i: usize, end: usize) -> bool {
+    i + 2 < end && b[i] == b':' && b[i + 1] == b':' && is_ident_start(b[i + 2])
+}
+
+/// Returns true if the bytes from `start` to `end` are the string
+const fn eq(b: &[u8], start: usize, end: usize, s: &str) -> bool {
+    let s = s.as_bytes();
+    if end - start != s.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < s.len() {
+        if b[start + i] != s[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn call(b: &[u8], start: usize, end: usize) -> Call {
+    if eq(b, start, end, "version") {
+        Call::Version
+    } else if eq(b, start, end, "accessible") {
+        Call::Accessible
+    } else if eq(b, start, end, "target") {
+        Call::Target
+    } else {
+        Call::None
+    }
+}
+
+/// Returns the length in bytes of the whitespace character at `i`, or 0 if
+/// it isn't one, the same as [`char::is_whitespace`], which isn't `const`
+const fn whitespace_len(b: &[u8], i: usize, end: usize) -> usize {
+    if i >= end {
+        return 0;
+    }
+
+    match b[i] {
+        b'\t'..=b'\r' | b' ' => 1,
+        // U+0085 and U+00A0
+        0xc2 if i + 1 < end && matches!(b[i + 1], 0x85 | 0xa0) => 2,
+        // U+1680
+        0xe1 if i + 2 < end && b[i + 1] == 0x9a && b[i + 2] == 0x80 => 3,
+        // U+2000 to U+200A, U+2028, U+2029, U+202F, and U+205F
+        0xe2 if i + 2 < end => match (b[i + 1], b[i + 2]) {
+            (0x80, 0x80..=0x8a | 0xa8 | 0xa9 | 0xaf) | (0x81, 0x9f) => 3,
+            _ => 0,
+        },
+        // U+3000
+        0xe3 if i + 2 < end && b[i + 1] == 0x80 && b[i + 2] == 0x80 => 3,
+        _ => 0,
+    }
+}
+
+/// Skips the whitespace from `i`, returning the index of the first
+/// character that isn't whitespace, or `end`
+pub(crate) const fn skip_whitespace(b: &[u8], mut i: usize, end: usize) -> usize {
+    loop {
+        let len = whitespace_len(b, i, end);
+        if len == 0 {
+            return i;
+        }
+        i += len;
+    }
+}
+
+/// Returns the range of the expression in the text, without the `cfg()`
+/// around it, if there is one. Whitespace is allowed around the `cfg()`, and
+/// between `cfg` and `(`.
+pub(crate) const fn unwrap_cfg(b: &[u8]) -> (usize, usize) {
+    let start = skip_whitespace(b, 0, b.len());
+
+    // Trims the whitespace from the end, where the whitespace characters
+    // are at most 3 bytes long
+    let mut end = b.len();
+    let mut len = 1;
+    while len <= 3 && len <= end - start {
+        if whitespace_len(b, end - len, end) == len {
+            end -= len;
+            len = 1;
+        } else {
+            len += 1;
+        }
+    }
+
+    if end - start > 3 && eq(b, start, start + 3, "cfg") {
+        let open = skip_whitespace(b, start + 3, end);
+        if open + 1 < end && b[open] == b'(' && b[end - 1] == b')' {
+            return (open + 1, end - 1);
+        }
+    }
+
+    (0, b.len())
+}
+
+/// Scans the token at `i`, which must be before `end`, and not whitespace
+pub(crate) const fn scan(b: &[u8], i: usize, end: usize) -> Scan {
+    match b[i] {
+        b'=' => Scan::Token(Kind::Equals, 1),
+        b'(' => Scan::Token(Kind::OpenParen, 1),
+        b')' => Scan::Token(Kind::CloseParen, 1),
+        b',' => Scan::Token(Kind::Comma, 1),
+        b'"' => {
+            let mut j = i + 1;
+            while j < end && b[j] != b'"' {
+                j += 1;
+            }
+
+            if j == end {
+                Scan::UnclosedQuotes
+            } else {
+                Scan::Token(Kind::Value, j + 1 - i)
+            }
+        }
+        // Identifiers can also be paths, eg. `::std::arch::x86_64`, which are
+        // only valid as the argument of `accessible()`
+        c if is_ident_start(c) || is_path_sep(b, i, end) => {
+            let mut j = if c == b':' { i + 2 } else { i };
+            loop {
+                while j < end && is_ident_rest(b[j]) {
+                    j += 1;
+                }
+
+                if is_path_sep(b, j, end) {
+                    j += 2;
+                } else {
+                    break;
+                }
+            }
+
+            let kind = if eq(b, i, j, "all") {
+                Kind::All
+            } else if eq(b, i, j, "any") {
+                Kind::Any
+            } else if eq(b, i, j, "not") {
+                Kind::Not
+            } else {
+                Kind::Key(call(b, i, j))
+            };
+
+            Scan::Token(kind, j - i)
+        }
+        // The length of the UTF-8 character from its first byte
+        0x00..=0x7f => Scan::Invalid(1),
+        0xc0..=0xdf => Scan::Invalid(2),
+        0xe0..=0xef => Scan::Invalid(3),
+        _ => Scan::Invalid(4),
+    }
+}
+
 /// Allows iteration through a cfg expression, yielding
 /// a token or a `ParseError`.
 ///
 /// Prefer to use `Expression::parse` rather than directly
-/// using the lexer
+/// using the lexer, unless access to the individual tokens is needed, eg.
+/// for syntax highlighting. Each token has the span of its characters,
+/// including the quotes of a [`Token::Value`], so every character other than
+/// whitespace is covered by exactly one token.
+///
+/// If lexing fails, the error has the span of the invalid characters, and
+/// lexing continues after them.
+///
+/// ```
+/// use cfg_expr::expr::lexer::{Lexer, Token};
+///
+/// let tokens = Lexer::new(r#"all(unix, target_os = "linux")"#)
+///     .map(|lt| lt.map(<(Token<'_>, std::ops::Range<usize>)>::from))
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+///
+/// assert_eq!(
+///     tokens,
+///     [
+///         (Token::All, 0..3),
+///         (Token::OpenParen, 3..4),
+///         (Token::Key("unix"), 4..8),
+///         (Token::Comma, 8..9),
+///         (Token::Key("target_os"), 10..19),
+///         (Token::Equals, 20..21),
+///         (Token::Value("linux"), 22..29),
+///         (Token::CloseParen, 29..30),
+///     ]
+/// );
+/// ```
 pub struct Lexer<'a> {
     pub(super) inner: &'a str,
     original: &'a str,
     offset: usize,
+    /// The offset of the expression in the text passed to [`Lexer::new`],
+    /// which is non-zero if it was wrapped in `cfg()`
+    pub(super) start: usize,
 }
 
 impl<'a> Lexer<'a> {
     /// Creates a Lexer over a cfg expression, it can either be
     /// a raw expression eg `key` or in attribute form, eg `cfg(key)`
+    ///
+    /// Note that the spans of the tokens are relative to the expression
+    /// inside of the `cfg()`, if there is one.
     pub fn new(text: &'a str) -> Self {
-        let text = if text.starts_with("cfg(") && text.ends_with(')') {
-            &text[4..text.len() - 1]
-        } else {
-            text
-        };
+        let (start, end) = unwrap_cfg(text.as_bytes());
+
+        Self {
+            inner: &text[start..end],
+            original: &text[start..end],
+            offset: 0,
+            start,
+        }
+    }
 
+    /// Creates a Lexer over the text as is, without stripping a `cfg()`
+    /// around it, eg. for cfgs rather than expressions
+    pub(super) fn new_unwrapped(text: &'a str) -> Self {
         Self {
             inner: text,
             original: text,
             offset: 0,
+            start: 0,
         }
     }
+
+    /// The offset of the expression in the text passed to [`Lexer::new`],
+    /// which is added to the spans of the tokens to make them relative to the
+    /// full text when the expression is wrapped in `cfg()`
+    ///
+    /// ```
+    /// use cfg_expr::expr::lexer::Lexer;
+    ///
+    /// let text = "cfg(unix)";
+    /// let mut lexer = Lexer::new(text);
+    /// let start = lexer.start();
+    /// let span = lexer.next().unwrap().unwrap().span;
+    ///
+    /// assert_eq!(&text[span.start + start..span.end + start], "unix");
+    /// ```
+    pub fn start(&self) -> usize {
+        self.start
+    }
 }
 
 /// A wrapper around a particular token that includes the span of the characters
 /// in the original string, for diagnostic purposes
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct LexerToken<'a> {
     /// The token that was lexed
     pub token: Token<'a>,
     /// The range of the token characters in the original license expression
-    pub span: std::ops::Range<usize>,
+    pub span: core::ops::Range<usize>,
+}
+
+impl<'a> From<LexerToken<'a>> for (Token<'a>, core::ops::Range<usize>) {
+    fn from(lt: LexerToken<'a>) -> Self {
+        (lt.token, lt.span)
+    }
 }
 
 impl<'a> Iterator for Lexer<'a> {
@@ -85,77 +371,65 @@ impl<'a> Iterator for Lexer<'a> {
 
     fn next(&mut self) -> Option<Self::Item> {
         // Jump over any whitespace, updating `self.inner` and `self.offset` appropriately
-        let non_whitespace_index = match self.inner.find(|c: char| !c.is_whitespace()) {
-            Some(idx) => idx,
-            None => self.inner.len(),
-        };
-
-        self.inner = &self.inner[non_whitespace_index..];
-        self.offset += non_whitespace_index;
+        let bytes = self.original.as_bytes();
+        let start = skip_whitespace(bytes, self.offset, bytes.len());
+        self.inner = &self.inner[start - self.offset..];
+        self.offset = start;
 
-        #[inline]
-        fn is_ident_start(ch: char) -> bool {
-            ch == '_' || ch.is_ascii_lowercase() || ch.is_ascii_uppercase()
+        if start == bytes.len() {
+            return None;
         }
 
-        #[inline]
-        fn is_ident_rest(ch: char) -> bool {
-            is_ident_start(ch) || ch.is_ascii_digit()
-        }
+        let (token, len) = match scan(bytes, start, bytes.len()) {
+            Scan::Token(kind, len) => {
+                let text = &self.inner[..len];
+                let token = match kind {
+                    Kind::Key(_) => Token::Key(text),
+                    Kind::Value => Token::Value(&text[1..len - 1]),
+                    Kind::Equals => Token::Equals,
+                    Kind::All => Token::All,
+                    Kind::Any => Token::Any,
+                    Kind::Not => Token::Not,
+                    Kind::OpenParen => Token::OpenParen,
+                    Kind::CloseParen => Token::CloseParen,
+                    Kind::Comma => Token::Comma,
+                };
+                (token, len)
+            }
+            Scan::UnclosedQuotes => {
+                let err = ParseError {
+                    original: self.original.to_owned(),
+                    span: self.offset..self.original.len(),
+                    reason: Reason::UnclosedQuotes,
+                };
 
-        match self.inner.chars().next() {
-            None => None,
-            Some('=') => Some(Ok(Token::Equals)),
-            Some('(') => Some(Ok(Token::OpenParen)),
-            Some(')') => Some(Ok(Token::CloseParen)),
-            Some(',') => Some(Ok(Token::Comma)),
-            Some(c) => {
-                if c == '"' {
-                    match self.inner[1..].find('"') {
-                        Some(ind) => Some(Ok(Token::Value(&self.inner[1..=ind]))),
-                        None => Some(Err(ParseError {
-                            original: self.original.to_owned(),
-                            span: self.offset..self.original.len(),
-                            reason: Reason::UnclosedQuotes,
-                        })),
-                    }
-                } else if is_ident_start(c) {
-                    let substr = match self.inner[1..].find(|c: char| !is_ident_rest(c)) {
-                        Some(ind) => &self.inner[..=ind],
-                        None => self.inner,
-                    };
-
-                    match substr {
-                        "all" => Some(Ok(Token::All)),
-                        "any" => Some(Ok(Token::Any)),
-                        "not" => Some(Ok(Token::Not)),
-                        other => Some(Ok(Token::Key(other))),
-                    }
-                } else {
-                    // clippy tries to help here, but we need
-                    // a Range here, not a RangeInclusive<>
-                    #[allow(clippy::range_plus_one)]
-                    Some(Err(ParseError {
-                        original: self.original.to_owned(),
-                        span: self.offset..self.offset + 1,
-                        reason: Reason::Unexpected(&["<key>", "all", "any", "not"]),
-                    }))
-                }
+                // Skip the rest of the string so that lexing can't
+                // continue from inside the unclosed value
+                self.offset = self.original.len();
+                self.inner = "";
+                return Some(Err(err));
             }
-        }
-        .map(|tok| {
-            tok.map(|tok| {
-                let len = tok.len();
+            Scan::Invalid(len) => {
+                let err = ParseError {
+                    original: self.original.to_owned(),
+                    span: self.offset..self.offset + len,
+                    reason: Reason::Unexpected(&["<key>", "all", "any", "not"]),
+                };
 
-                let start = self.offset;
+                // Skip the invalid character so that lexing can continue
+                // after it
                 self.inner = &self.inner[len..];
                 self.offset += len;
+                return Some(Err(err));
+            }
+        };
 
-                LexerToken {
-                    token: tok,
-                    span: start..self.offset,
-                }
-            })
-        })
+        self.inner = &self.inner[len..];
+        self.offset += len;
+
+        Some(Ok(LexerToken {
+            token,
+            span: start..self.offset,
+        }))
     }
 }
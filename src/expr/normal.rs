@@ -0,0 +1,142 @@
+use crate::expr::{tree::ExprTree, Expression, Predicate};
+use alloc::{boxed::Box, vec, vec::Vec};
+
+/// A predicate, or its negation if the boolean is true
+pub(super) type Literal<'a> = (Predicate<'a>, bool);
+
+/// Converts the tree (negated if `negated` is true) into disjunctive normal
+/// form, returning the list of clauses joined by `any()`, where each clause is
+/// the list of literals joined by `all()`
+pub(super) fn dnf_clauses<'a>(tree: &ExprTree<'a>, negated: bool) -> Vec<Vec<Literal<'a>>> {
+    let (operands, is_all) = match tree {
+        // A true literal is a single empty clause, and a false one has no clauses
+        ExprTree::Predicate(Predicate::Boolean(value)) if *value != negated => {
+            return vec![Vec::new()]
+        }
+        ExprTree::Predicate(Predicate::Boolean(_)) => return Vec::new(),
+        ExprTree::Predicate(pred) => return vec![vec![(pred.clone(), negated)]],
+        ExprTree::Not(operand) => return dnf_clauses(operand, !negated),
+        // De Morgan's laws: a negated all() is an any() of negated operands
+        // and vice versa
+        ExprTree::All(operands) => (operands, !negated),
+        ExprTree::Any(operands) => (operands, negated),
+    };
+
+    if is_all {
+        // all() distributes over the clauses of each of its operands
+        operands.iter().fold(vec![Vec::new()], |acc, operand| {
+            let operand = dnf_clauses(operand, negated);
+            let mut product = Vec::with_capacity(acc.len() * operand.len());
+
+            for left in &acc {
+                for right in &operand {
+                    let mut clause = left.clone();
+                    for lit in right {
+                        if !clause.contains(lit) {
+                            clause.push(lit.clone());
+                        }
+                    }
+                    product.push(clause);
+                }
+            }
+
+            product
+        })
+    } else {
+        operands
+            .iter()
+            .flat_map(|operand| dnf_clauses(operand, negated))
+            .collect()
+    }
+}
+
+fn literal(pred: Predicate<'_>, negated: bool) -> ExprTree<'_> {
+    let pred = ExprTree::Predicate(pred);
+    if negated {
+        ExprTree::Not(Box::new(pred))
+    } else {
+        pred
+    }
+}
+
+impl Expression {
+    /// Rewrites the expression into
+    /// [disjunctive normal form](https://en.wikipedia.org/wiki/Disjunctive_normal_form),
+    /// ie. a single `any()` of `all()`s, where each `all()` only contains
+    /// predicates or `not()`s of predicates.
+    ///
+    /// The shape of the result is always the same, even for trivial expressions,
+    /// so a lone predicate becomes `any(all(<predicate>))`, an expression that is
+    /// always true becomes `any(all())`, and one that is always false becomes `any()`.
+    ///
+    /// Note that the size of the result can grow exponentially with the number
+    /// of `any()`s nested inside `all()`s.
+    ///
+    /// ```
+    /// use cfg_expr::Expression;
+    ///
+    /// let expr = Expression::parse(r#"all(unix, any(target_arch = "x86", target_arch = "arm"), not(any(test, feature = "a")))"#).unwrap();
+    ///
+    /// assert_eq!(
+    ///     expr.to_dnf().to_string(),
+    ///     r#"any(all(unix, target_arch = "x86", not(test), not(feature = "a")), all(unix, target_arch = "arm", not(test), not(feature = "a")))"#,
+    /// );
+    /// ```
+    pub fn to_dnf(&self) -> Expression {
+        let clauses = dnf_clauses(&self.root(), false);
+
+        Expression::from_tree(&ExprTree::Any(
+            clauses
+                .into_iter()
+                .map(|clause| {
+                    ExprTree::All(
+                        clause
+                            .into_iter()
+                            .map(|(pred, negated)| literal(pred, negated))
+                            .collect(),
+                    )
+                })
+                .collect(),
+        ))
+    }
+
+    /// Rewrites the expression into
+    /// [conjunctive normal form](https://en.wikipedia.org/wiki/Conjunctive_normal_form),
+    /// ie. a single `all()` of `any()`s, where each `any()` only contains
+    /// predicates or `not()`s of predicates.
+    ///
+    /// Like [`Self::to_dnf`], the shape of the result is always the same, so a
+    /// lone predicate becomes `all(any(<predicate>))`, an expression that is
+    /// always true becomes `all()`, and one that is always false becomes
+    /// `all(any())`.
+    ///
+    /// ```
+    /// use cfg_expr::Expression;
+    ///
+    /// let expr = Expression::parse(r#"any(windows, all(unix, target_pointer_width = "64"))"#).unwrap();
+    ///
+    /// assert_eq!(
+    ///     expr.to_cnf().to_string(),
+    ///     r#"all(any(windows, unix), any(windows, target_pointer_width = "64"))"#,
+    /// );
+    /// ```
+    pub fn to_cnf(&self) -> Expression {
+        // The CNF of an expression is the negation of the DNF of its negation,
+        // with the negation pushed down to the literals
+        let clauses = dnf_clauses(&self.root(), true);
+
+        Expression::from_tree(&ExprTree::All(
+            clauses
+                .into_iter()
+                .map(|clause| {
+                    ExprTree::Any(
+                        clause
+                            .into_iter()
+                            .map(|(pred, negated)| literal(pred, !negated))
+                            .collect(),
+                    )
+                })
+                .collect(),
+        ))
+    }
+}
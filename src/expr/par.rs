@@ -0,0 +1,58 @@
+use crate::{
+    expr::{CfgSet, Expression, FeatureSet},
+    targets::TargetInfo,
+};
+use rayon::prelude::*;
+
+impl Expression {
+    /// Finds the targets that the expression matches, evaluating them in
+    /// parallel, in the same order as `targets`.
+    ///
+    /// As with [`Self::compile_target_matcher`], which the targets are
+    /// matched with, `target_feature` predicates are true for the features
+    /// enabled by default for the target, and every other predicate that
+    /// isn't a target predicate is false.
+    ///
+    /// ```
+    /// use cfg_expr::{targets::ALL_BUILTINS, Expression};
+    ///
+    /// let expr = Expression::parse(r#"all(unix, target_arch = "aarch64")"#).unwrap();
+    /// let targets = expr.par_matching_targets(ALL_BUILTINS);
+    ///
+    /// assert!(targets.iter().any(|ti| ti.triple.as_str() == "aarch64-unknown-linux-gnu"));
+    /// assert!(targets.iter().all(|ti| ti.arch == cfg_expr::targets::Arch::aarch64));
+    /// ```
+    pub fn par_matching_targets<'t>(&self, targets: &'t [TargetInfo]) -> Vec<&'t TargetInfo> {
+        let matcher = self.compile_target_matcher();
+
+        targets
+            .par_iter()
+            .filter(|target| matcher.matches(target))
+            .collect()
+    }
+
+    /// Evaluates each of the expressions against the target in parallel,
+    /// returning their results in the same order, eg. for the cfgs of every
+    /// dependency edge in a workspace.
+    ///
+    /// This is the same as [`Self::eval_target`] without any enabled crate
+    /// or target features.
+    ///
+    /// ```
+    /// use cfg_expr::{targets::get_builtin_target_by_triple, Expression};
+    ///
+    /// let exprs = ["unix", "windows", r#"target_os = "linux""#]
+    ///     .map(|expr| Expression::parse(expr).unwrap());
+    /// let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    ///
+    /// assert_eq!(Expression::evaluate_all_par(&exprs, linux), [true, false, true]);
+    /// ```
+    pub fn evaluate_all_par(exprs: &[Expression], target: &TargetInfo) -> Vec<bool> {
+        let cfgs = CfgSet::for_target(target, &FeatureSet::new(), &FeatureSet::new());
+
+        exprs
+            .par_iter()
+            .map(|expr| expr.eval_cfg_set(&cfgs))
+            .collect()
+    }
+}
@@ -1,43 +1,311 @@
 use crate::{
     error::{ParseError, Reason},
     expr::{
-        lexer::{Lexer, Token},
-        ExprNode, Expression, Func, InnerPredicate,
+        lexer::{Call, Kind, Lexer, LexerToken, Token},
+        suggest::{closest, KNOWN_KEYS},
+        ExprNode, Expression, ExpressionRef, Func, InnerPredicate,
     },
 };
+use alloc::{borrow::ToOwned, format, vec::Vec};
 use smallvec::SmallVec;
 
+/// Options for parsing an [`Expression`] with [`Expression::parse_with_options`]
+///
+/// Options may be added in the future, so they are created with
+/// [`Default`], or [`ParseOptions::untrusted`], and changed with the methods
+/// of the same name as each field.
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct ParseOptions {
+    /// If true, the value of every target predicate must be known, see
+    /// [`Expression::validate`]
+    pub validate_target_values: bool,
+    /// If true, custom keys must not be almost the same as a known key, eg.
+    /// `featurre`, see [`Expression::validate`]
+    pub validate_keys: bool,
+    /// The longest expression, in bytes, that can be parsed
+    pub max_len: Option<usize>,
+    /// The deepest that parentheses can be nested, eg. `all(not(unix))` has a
+    /// depth of 2
+    pub max_depth: Option<usize>,
+    /// The most predicates that the expression can contain
+    pub max_predicates: Option<usize>,
+}
+
+impl ParseOptions {
+    /// Limits that are suitable for parsing expressions from untrusted
+    /// sources, eg. registry metadata, which are far larger than any real
+    /// expression, but bound the memory and time it takes to parse and
+    /// evaluate one
+    pub fn untrusted() -> Self {
+        Self {
+            validate_target_values: false,
+            validate_keys: false,
+            max_len: Some(64 * 1024),
+            max_depth: Some(64),
+            max_predicates: Some(1024),
+        }
+    }
+
+    /// Sets whether the value of every target predicate must be known
+    pub fn validate_target_values(mut self, validate: bool) -> Self {
+        self.validate_target_values = validate;
+        self
+    }
+
+    /// Sets whether custom keys must not be almost the same as a known key
+    pub fn validate_keys(mut self, validate: bool) -> Self {
+        self.validate_keys = validate;
+        self
+    }
+
+    /// Sets the longest expression, in bytes, that can be parsed
+    pub fn max_len(mut self, max_len: Option<usize>) -> Self {
+        self.max_len = max_len;
+        self
+    }
+
+    /// Sets the deepest that parentheses can be nested
+    pub fn max_depth(mut self, max_depth: Option<usize>) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets the most predicates that the expression can contain
+    pub fn max_predicates(mut self, max_predicates: Option<usize>) -> Self {
+        self.max_predicates = max_predicates;
+        self
+    }
+
+    /// Checks the limits against the tokens of the expression, before it is
+    /// parsed. Errors in the tokens are left for the parser to report.
+    fn check_limits(&self, original: &str) -> Result<(), ParseError> {
+        let error = |span, reason| ParseError {
+            original: original.to_owned(),
+            span,
+            reason,
+        };
+
+        if let Some(max_len) = self.max_len {
+            if original.len() > max_len {
+                // The span starts at a character, so that it can be rendered
+                let mut start = max_len;
+                while !original.is_char_boundary(start) {
+                    start -= 1;
+                }
+
+                return Err(error(start..original.len(), Reason::TooLong(max_len)));
+            }
+        }
+
+        if self.max_depth.is_none() && self.max_predicates.is_none() {
+            return Ok(());
+        }
+
+        let lexer = Lexer::new(original);
+        let offset = lexer.start;
+        let mut depth = 0;
+        let mut predicates = 0;
+        let mut tokens = lexer.map_while(Result::ok).peekable();
+
+        while let Some(lt) = tokens.next() {
+            let span = lt.span.start + offset..lt.span.end + offset;
+
+            match lt.token {
+                Token::OpenParen => {
+                    depth += 1;
+                    if let Some(max) = self.max_depth.filter(|max| depth > *max) {
+                        return Err(error(span, Reason::TooDeep(max)));
+                    }
+
+                    // The value of a `version("1.47")` call
+                    if let Some(LexerToken {
+                        token: Token::Value(_),
+                        ..
+                    }) = tokens.peek()
+                    {
+                        predicates += 1;
+                    }
+                }
+                Token::CloseParen => depth = depth.saturating_sub(1),
+                // Keys that are followed by parentheses are calls, eg.
+                // `version()`, and their arguments are counted instead
+                Token::Key(_)
+                    if !matches!(
+                        tokens.peek(),
+                        Some(LexerToken {
+                            token: Token::OpenParen,
+                            ..
+                        })
+                    ) =>
+                {
+                    predicates += 1;
+                }
+                _ => continue,
+            }
+
+            if let Some(max) = self.max_predicates.filter(|max| predicates > *max) {
+                return Err(error(span, Reason::TooManyPredicates(max)));
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl Expression {
-    /// Given a cfg() expression (the cfg( and ) are optional), attempts to
+    /// Given a `cfg()` expression (the cfg( and ) are optional), attempts to
     /// parse it into a form where it can be evaluated
     ///
+    /// If the expression is wrapped in `cfg()`, eg. as in Cargo's
+    /// `[target.'cfg(windows)'.dependencies]`, the [`Self::original`] of the
+    /// expression excludes the wrapper, but the spans of any errors point into
+    /// the full string.
+    ///
     /// ```
-    /// assert!(cfg_expr::Expression::parse(r#"cfg(all(unix, target_arch = "x86_64"))"#).is_ok());
+    /// let expr = cfg_expr::Expression::parse(r#"cfg(all(unix, target_arch = "x86_64"))"#).unwrap();
+    /// assert_eq!(expr.original(), r#"all(unix, target_arch = "x86_64")"#);
     /// ```
     pub fn parse(original: &str) -> Result<Self, ParseError> {
+        let mut errors = Vec::new();
+        Self::parse_impl(original, &mut errors, false)
+            .map(ExpressionRef::into_owned)
+            .ok_or_else(|| errors.pop().unwrap())
+    }
+
+    /// Parses the expression like [`Self::parse`], with additional options
+    ///
+    /// ```
+    /// use cfg_expr::{error::Reason, expr::ParseOptions, Expression};
+    ///
+    /// let strict = ParseOptions::default()
+    ///     .validate_target_values(true)
+    ///     .validate_keys(true);
+    ///
+    /// assert!(Expression::parse_with_options(r#"target_os = "linux""#, &strict).is_ok());
+    /// assert_eq!(
+    ///     Expression::parse_with_options(r#"target_os = "linuxx""#, &strict).unwrap_err().reason,
+    ///     Reason::UnknownValue(Some("linux")),
+    /// );
+    ///
+    /// // Custom keys are only rejected if they are similar to a known key
+    /// assert!(Expression::parse("tests").is_ok());
+    /// assert!(Expression::parse_with_options("docsrs", &strict).is_ok());
+    /// assert_eq!(
+    ///     Expression::parse_with_options("tests", &strict).unwrap_err().reason,
+    ///     Reason::UnknownKey(Some("test")),
+    /// );
+    /// ```
+    pub fn parse_with_options(original: &str, options: &ParseOptions) -> Result<Self, ParseError> {
+        options.check_limits(original)?;
+        let expr = Self::parse(original)?;
+
+        if options.validate_target_values || options.validate_keys {
+            if let Err(errors) = expr.validate() {
+                let enabled = errors.into_iter().find(|err| match err.reason {
+                    Reason::UnknownKey(_) => options.validate_keys,
+                    _ => options.validate_target_values,
+                });
+
+                if let Some(err) = enabled {
+                    return Err(err);
+                }
+            }
+        }
+
+        Ok(expr)
+    }
+
+    /// Parses the expression like [`Self::parse`], but rather than stopping at
+    /// the first error, attempts to recover from it and continue parsing so
+    /// that every error in the expression is reported at once.
+    ///
+    /// After a syntax error, the rest of the current predicate is skipped,
+    /// up to the next `,` or the `)` of the enclosing function.
+    ///
+    /// The expression is only returned if there were no errors.
+    ///
+    /// ```
+    /// use cfg_expr::{error::Reason, Expression};
+    ///
+    /// let (expr, errors) = Expression::parse_with_recovery(
+    ///     r#"all(target_pointer_width = "x", any(unix windows), target_os = "linux""#,
+    /// );
+    ///
+    /// assert!(expr.is_none());
+    /// assert_eq!(
+    ///     errors.into_iter().map(|err| err.reason).collect::<Vec<_>>(),
+    ///     [
+    ///         Reason::InvalidInteger,
+    ///         Reason::Unexpected(&["=", ",", ")"]),
+    ///         Reason::UnclosedParens,
+    ///     ]
+    /// );
+    /// ```
+    pub fn parse_with_recovery(original: &str) -> (Option<Self>, Vec<ParseError>) {
+        let mut errors = Vec::new();
+        let expr = Self::parse_impl(original, &mut errors, true);
+        (expr.map(ExpressionRef::into_owned), errors)
+    }
+
+    /// Parses the expression, pushing errors as they are encountered. If
+    /// `recover` is false, parsing stops at the first error.
+    pub(super) fn parse_impl<'a>(
+        original: &'a str,
+        errors: &mut Vec<ParseError>,
+        recover: bool,
+    ) -> Option<ExpressionRef<'a>> {
         let lexer = Lexer::new(original);
 
         // The lexer automatically trims any cfg( ), so reacquire
-        // the string before we start walking tokens
+        // the string before we start walking tokens, but keep the full
+        // string for errors, so that they point to the correct location
+        let full = original;
+        let offset = lexer.start;
         let original = lexer.inner;
 
         #[derive(Debug)]
         struct FuncAndSpan {
             func: Func,
             parens_index: usize,
-            span: std::ops::Range<usize>,
-            predicates: SmallVec<[InnerPredicate; 5]>,
+            span: core::ops::Range<usize>,
+            num_predicates: usize,
             nest_level: u8,
+            // The number of errors when the function was opened
+            num_errors: usize,
+            // Whether this is the `target(...)` shorthand for an `all()` of
+            // target predicates, ie. `target(os = "linux")`
+            is_target: bool,
         }
 
         let mut func_stack = SmallVec::<[FuncAndSpan; 5]>::new();
         let mut expr_queue = SmallVec::<[ExprNode; 5]>::new();
+        let mut span_queue = SmallVec::<[core::ops::Range<usize>; 5]>::new();
 
         // Keep track of the last token to simplify validation of the token stream
         let mut last_token: Option<Token<'_>> = None;
 
-        let parse_predicate = |key: (&str, std::ops::Range<usize>),
-                               val: Option<(&str, std::ops::Range<usize>)>|
+        // The span of a whole predicate, from the start of the key to the
+        // closing quote of the value, if any, or the closing parenthesis of a
+        // call, ie. `version("1.47")`
+        let predicate_span = |key: &(&str, core::ops::Range<usize>),
+                              val: &Option<(&str, core::ops::Range<usize>)>,
+                              call_end: Option<usize>| {
+            key.1.start
+                ..call_end
+                    .unwrap_or_else(|| val.as_ref().map_or(key.1.end, |(_, vspan)| vspan.end + 1))
+        };
+
+        let unknown_key = |key: &str, span: core::ops::Range<usize>| ParseError {
+            original: original.to_owned(),
+            span,
+            reason: Reason::UnknownKey(closest(key, KNOWN_KEYS)),
+        };
+
+        let parse_predicate = |key: (&str, core::ops::Range<usize>),
+                               val: Option<(&str, core::ops::Range<usize>)>,
+                               is_call: bool,
+                               in_target: bool|
          -> Result<InnerPredicate, ParseError> {
             // Warning: It is possible for arbitrarily-set configuration
             // options to have the same value as compiler-set configuration
@@ -62,11 +330,50 @@ impl Expression {
             }
 
             let span = key.1;
-            let key = key.0;
+            // The keys in `target(...)` don't have the `target_` prefix
+            let prefixed;
+            let key = if in_target {
+                prefixed = format!("target_{}", key.0);
+                prefixed.as_str()
+            } else {
+                key.0
+            };
+
+            let unknown_target_key = |target_key: &str, span| {
+                let mut err = unknown_key(target_key, span);
+
+                // Suggest the key as it would be written in `target(...)`
+                if let (true, Reason::UnknownKey(Some(suggestion))) = (in_target, &mut err.reason) {
+                    *suggestion = suggestion.strip_prefix("target_").unwrap_or(suggestion);
+                }
+
+                err
+            };
+
+            // Paths are only valid as the argument of `accessible()`
+            if key.contains("::") {
+                return Err(ParseError {
+                    original: original.to_owned(),
+                    span,
+                    reason: Reason::InvalidCharacters,
+                });
+            }
 
             use super::{InnerTarget, Which};
 
             Ok(match key {
+                // version() and accessible() are the only predicates that are
+                // written as a call, which the parser ensures always has a value
+                "accessible" if is_call => InnerPredicate::Accessible(val.unwrap().1),
+                "version" if is_call => {
+                    let (val, vspan) = val.unwrap();
+
+                    InnerPredicate::Version(val.parse().map_err(|_err| ParseError {
+                        original: original.to_owned(),
+                        span: vspan,
+                        reason: Reason::InvalidVersion,
+                    })?)
+                }
                 // These are special cases in the cfg language that are
                 // semantically the same as `target_family = "<family>"`,
                 // so we just make them not special
@@ -76,10 +383,11 @@ impl Expression {
                 "unix" | "windows" => {
                     err_if_val!();
 
-                    InnerPredicate::Target(InnerTarget {
-                        which: Which::Family,
-                        span: Some(span),
-                    })
+                    InnerPredicate::Target(InnerTarget::new(Which::Family, Some(span), original))
+                }
+                "true" | "false" => {
+                    err_if_val!();
+                    InnerPredicate::Boolean(key == "true")
                 }
                 "test" => {
                     err_if_val!();
@@ -93,6 +401,34 @@ impl Expression {
                     err_if_val!();
                     InnerPredicate::ProcMacro
                 }
+                "target_thread_local" => {
+                    err_if_val!();
+                    InnerPredicate::TargetThreadLocal
+                }
+                "doc" => {
+                    err_if_val!();
+                    InnerPredicate::Doc
+                }
+                "doctest" => {
+                    err_if_val!();
+                    InnerPredicate::Doctest
+                }
+                "miri" => {
+                    err_if_val!();
+                    InnerPredicate::Miri
+                }
+                "fuzzing" => {
+                    err_if_val!();
+                    InnerPredicate::Fuzzing
+                }
+                "overflow_checks" => {
+                    err_if_val!();
+                    InnerPredicate::OverflowChecks
+                }
+                "ub_checks" => {
+                    err_if_val!();
+                    InnerPredicate::UbChecks
+                }
                 "feature" => {
                     // rustc allows bare feature without a value, but the only way
                     // such a predicate would ever evaluate to true would be if they
@@ -109,11 +445,22 @@ impl Expression {
                         }
                     }
                 }
+                "sanitize" => match val {
+                    Some((_, vspan)) => InnerPredicate::Sanitize(vspan),
+                    None => {
+                        return Err(ParseError {
+                            original: original.to_owned(),
+                            span,
+                            reason: Reason::Unexpected(&["= \"<sanitizer>\""]),
+                        });
+                    }
+                },
                 "panic" => match val {
-                    Some((_, vspan)) => InnerPredicate::Target(InnerTarget {
-                        which: Which::Panic,
-                        span: Some(vspan),
-                    }),
+                    Some((_, vspan)) => InnerPredicate::Target(InnerTarget::new(
+                        Which::Panic,
+                        Some(vspan),
+                        original,
+                    )),
                     None => {
                         return Err(ParseError {
                             original: original.to_owned(),
@@ -123,6 +470,11 @@ impl Expression {
                     }
                 },
                 target_key if key.starts_with("target_") => {
+                    // Only known keys are allowed in the `target_` namespace
+                    if !KNOWN_KEYS.contains(&target_key) {
+                        return Err(unknown_target_key(target_key, span));
+                    }
+
                     let (val, vspan) = match val {
                         None => {
                             return Err(ParseError {
@@ -136,10 +488,21 @@ impl Expression {
 
                     macro_rules! tp {
                         ($which:ident) => {
-                            InnerTarget {
-                                which: Which::$which,
-                                span: Some(vspan),
-                            }
+                            InnerTarget::new(Which::$which, Some(vspan), original)
+                        };
+                    }
+
+                    macro_rules! has_atomic {
+                        ($which:ident) => {
+                            InnerTarget::new(
+                                Which::$which(val.parse().map_err(|_err| ParseError {
+                                    original: original.to_owned(),
+                                    span: vspan,
+                                    reason: Reason::InvalidHasAtomic,
+                                })?),
+                                None,
+                                original,
+                            )
                         };
                     }
 
@@ -160,48 +523,29 @@ impl Expression {
                         "os" => tp!(Os),
                         "family" => tp!(Family),
                         "env" => tp!(Env),
-                        "endian" => InnerTarget {
-                            which: Which::Endian(val.parse().map_err(|_err| ParseError {
+                        "endian" => InnerTarget::new(
+                            Which::Endian(val.parse().map_err(|_err| ParseError {
                                 original: original.to_owned(),
                                 span: vspan,
                                 reason: Reason::InvalidInteger,
                             })?),
-                            span: None,
-                        },
-                        "has_atomic" => InnerTarget {
-                            which: Which::HasAtomic(val.parse().map_err(|_err| ParseError {
-                                original: original.to_owned(),
-                                span: vspan,
-                                reason: Reason::InvalidHasAtomic,
-                            })?),
-                            span: None,
-                        },
-                        "pointer_width" => InnerTarget {
-                            which: Which::PointerWidth(val.parse().map_err(|_err| ParseError {
+                            None,
+                            original,
+                        ),
+                        "has_atomic" => has_atomic!(HasAtomic),
+                        "has_atomic_load_store" => has_atomic!(HasAtomicLoadStore),
+                        "has_atomic_equal_alignment" => has_atomic!(HasAtomicEqualAlignment),
+                        "pointer_width" => InnerTarget::new(
+                            Which::PointerWidth(val.parse().map_err(|_err| ParseError {
                                 original: original.to_owned(),
                                 span: vspan,
                                 reason: Reason::InvalidInteger,
                             })?),
-                            span: None,
-                        },
+                            None,
+                            original,
+                        ),
                         "vendor" => tp!(Vendor),
-                        _ => {
-                            return Err(ParseError {
-                                original: original.to_owned(),
-                                span,
-                                reason: Reason::Unexpected(&[
-                                    "target_arch",
-                                    "target_feature",
-                                    "target_os",
-                                    "target_family",
-                                    "target_env",
-                                    "target_endian",
-                                    "target_has_atomic",
-                                    "target_pointer_width",
-                                    "target_vendor",
-                                ]),
-                            })
-                        }
+                        _ => return Err(unknown_target_key(target_key, span)),
                     };
 
                     InnerPredicate::Target(tp)
@@ -213,180 +557,308 @@ impl Expression {
             })
         };
 
-        macro_rules! token_err {
-            ($span:expr) => {{
-                let expected: &[&str] = match last_token {
-                    None => &["<key>", "all", "any", "not"],
-                    Some(Token::All | Token::Any | Token::Not) => &["("],
-                    Some(Token::CloseParen) => &[")", ","],
-                    Some(Token::Comma) => &[")", "<key>"],
-                    Some(Token::Equals) => &["\""],
-                    Some(Token::Key(_)) => &["=", ",", ")"],
-                    Some(Token::Value(_)) => &[",", ")"],
-                    Some(Token::OpenParen) => &["<key>", ")", "all", "any", "not"],
-                };
-
-                return Err(ParseError {
-                    original: original.to_owned(),
-                    span: $span,
-                    reason: Reason::Unexpected(&expected),
+        macro_rules! error {
+            ($err:expr) => {{
+                let err: ParseError = $err;
+                errors.push(ParseError {
+                    original: full.to_owned(),
+                    span: err.span.start + offset..err.span.end + offset,
+                    reason: err.reason,
                 });
+                if !recover {
+                    return None;
+                }
             }};
         }
 
         let mut pred_key: Option<(&str, _)> = None;
         let mut pred_val: Option<(&str, _)> = None;
+        // The end of the closing parenthesis if the current predicate is a
+        // call, ie. `version("1.47")` rather than `key = "value"`
+        let mut pred_call: Option<usize> = None;
+        // Whether we are currently inside the parentheses of a call
+        let mut in_call = false;
 
         let mut root_predicate_count = 0;
 
+        // When recovering from a syntax error, tokens are skipped until the
+        // next `,` or `)` at the same nesting level as the error
+        let mut skipping = false;
+        let mut skip_depth = 0usize;
+
         // Basic implementation of the https://en.wikipedia.org/wiki/Shunting-yard_algorithm
-        'outer: for lt in lexer {
-            let lt = lt?;
-            match &lt.token {
-                Token::Key(k) => {
-                    if matches!(last_token, None | Some(Token::OpenParen | Token::Comma)) {
-                        pred_key = Some((k, lt.span.clone()));
-                    } else {
-                        token_err!(lt.span)
-                    }
+        let mut lexer = lexer;
+        // A token that needs to be handled again after recovering from an error
+        let mut retry = None;
+
+        'outer: while let Some(lt) = retry.take().map(Ok).or_else(|| lexer.next()) {
+            let lt = match lt {
+                Ok(lt) => lt,
+                Err(err) => {
+                    error!(err);
+
+                    pred_key = None;
+                    pred_val = None;
+                    pred_call = None;
+                    skipping = true;
+                    // An unfinished call's parentheses still need to be skipped
+                    skip_depth = usize::from(core::mem::take(&mut in_call));
+                    continue;
                 }
-                Token::Value(v) => {
-                    if matches!(last_token, Some(Token::Equals)) {
-                        // We only record the span for keys and values
-                        // so that the expression doesn't need a lifetime
-                        // but in the value case we need to strip off
-                        // the quotes so that the proper raw string is
-                        // provided to callers when evaluating the expression
-                        pred_val = Some((v, lt.span.start + 1..lt.span.end - 1));
-                    } else {
-                        token_err!(lt.span)
+            };
+
+            if skipping {
+                match lt.token {
+                    Token::OpenParen => {
+                        skip_depth += 1;
+                        continue;
                     }
-                }
-                Token::Equals => {
-                    if !matches!(last_token, Some(Token::Key(_))) {
-                        token_err!(lt.span)
+                    Token::CloseParen if skip_depth > 0 => {
+                        skip_depth -= 1;
+                        continue;
+                    }
+                    Token::Comma if skip_depth == 0 => {
+                        skipping = false;
+                        last_token = Some(Token::Comma);
+                        continue;
                     }
+                    // The `)` closes the function the error was in, so it
+                    // needs to be handled like any other `)`
+                    Token::CloseParen if !func_stack.is_empty() => {
+                        skipping = false;
+                        last_token = Some(Token::Comma);
+                    }
+                    _ => continue,
                 }
-                Token::All | Token::Any | Token::Not => {
-                    if matches!(last_token, None | Some(Token::OpenParen | Token::Comma)) {
-                        let new_fn = match lt.token {
-                            // the 0 is a dummy value -- it will be substituted for the real
-                            // number of predicates in the `CloseParen` branch below.
-                            Token::All => Func::All(0),
-                            Token::Any => Func::Any(0),
-                            Token::Not => Func::Not,
-                            _ => unreachable!(),
-                        };
+            }
 
-                        if let Some(fs) = func_stack.last_mut() {
-                            fs.nest_level += 1;
+            // Only `key = "value"` pairs are allowed in `target(...)`
+            let in_target = func_stack.last().map_or(false, |fs| fs.is_target);
+
+            // Reports an unexpected token, and starts skipping tokens if recovering
+            macro_rules! token_err {
+                ($expected:expr) => {{
+                    error!(ParseError {
+                        original: original.to_owned(),
+                        span: lt.span.clone(),
+                        reason: Reason::Unexpected($expected),
+                    });
+
+                    pred_key = None;
+                    pred_val = None;
+                    pred_call = None;
+                    skipping = true;
+                    // An unfinished call's parentheses still need to be skipped
+                    let was_in_call = core::mem::take(&mut in_call);
+                    skip_depth = usize::from(was_in_call);
+
+                    match lt.token {
+                        // The `)` closes the call, which takes the place of
+                        // a predicate
+                        Token::CloseParen if was_in_call => {
+                            skipping = false;
+                            last_token = Some(Token::CloseParen);
                         }
+                        Token::OpenParen => skip_depth += 1,
+                        Token::Comma if !was_in_call => {
+                            skipping = false;
+                            last_token = Some(Token::Comma);
+                        }
+                        // The `)` still closes the current function
+                        Token::CloseParen if !func_stack.is_empty() => {
+                            skipping = false;
+                            last_token = Some(Token::Comma);
+                            retry = Some(lt);
+                        }
+                        _ => {}
+                    }
 
-                        func_stack.push(FuncAndSpan {
-                            func: new_fn,
-                            span: lt.span,
-                            parens_index: 0,
-                            predicates: SmallVec::new(),
-                            nest_level: 0,
-                        });
-                    } else {
-                        token_err!(lt.span)
+                    continue 'outer;
+                }};
+            }
+
+            let call = if in_call {
+                pred_key.as_ref().map(|(key, _)| Call::of(key))
+            } else {
+                None
+            };
+            if let Err(expected) = follows(
+                last_token.as_ref().map(Token::kind),
+                lt.token.kind(),
+                in_target,
+                call,
+            ) {
+                token_err!(expected)
+            }
+
+            // The only tokens allowed in a call are a single argument, a
+            // version string or a path, and the closing parenthesis
+            if in_call {
+                match &lt.token {
+                    Token::Value(v) => pred_val = Some((v, lt.span.start + 1..lt.span.end - 1)),
+                    Token::Key(path) => pred_val = Some((path, lt.span.clone())),
+                    _ => {
+                        in_call = false;
+                        pred_call = Some(lt.span.end);
+                    }
+                }
+
+                last_token = Some(lt.token);
+                continue;
+            }
+
+            match &lt.token {
+                Token::Key(k) => pred_key = Some((k, lt.span.clone())),
+                Token::Value(v) => {
+                    // We only record the span for keys and values
+                    // so that the expression doesn't need a lifetime
+                    // but in the value case we need to strip off
+                    // the quotes so that the proper raw string is
+                    // provided to callers when evaluating the expression
+                    pred_val = Some((v, lt.span.start + 1..lt.span.end - 1));
+                }
+                Token::Equals => {}
+                Token::All | Token::Any | Token::Not => {
+                    let new_fn = match lt.token {
+                        // the 0 is a dummy value -- it will be substituted for the real
+                        // number of predicates in the `CloseParen` branch below.
+                        Token::All => Func::All(0),
+                        Token::Any => Func::Any(0),
+                        Token::Not => Func::Not,
+                        _ => unreachable!(),
+                    };
+
+                    if let Some(fs) = func_stack.last_mut() {
+                        fs.nest_level += 1;
                     }
+
+                    func_stack.push(FuncAndSpan {
+                        func: new_fn,
+                        span: lt.span,
+                        parens_index: 0,
+                        num_predicates: 0,
+                        nest_level: 0,
+                        num_errors: errors.len(),
+                        is_target: false,
+                    });
                 }
                 Token::OpenParen => {
                     if matches!(last_token, Some(Token::All | Token::Any | Token::Not)) {
                         if let Some(ref mut fs) = func_stack.last_mut() {
                             fs.parens_index = lt.span.start;
                         }
+                    } else if matches!(last_token, Some(Token::Key("version" | "accessible"))) {
+                        in_call = true;
                     } else {
-                        token_err!(lt.span)
+                        // The only other key that can be followed by `(` is
+                        // `target`, and `target(...)` is the same as an
+                        // `all()` of target predicates, so it is treated as
+                        // a function
+                        let (_, span) = pred_key.take().unwrap();
+
+                        if let Some(fs) = func_stack.last_mut() {
+                            fs.nest_level += 1;
+                        }
+
+                        func_stack.push(FuncAndSpan {
+                            func: Func::All(0),
+                            span,
+                            parens_index: lt.span.start,
+                            num_predicates: 0,
+                            nest_level: 0,
+                            num_errors: errors.len(),
+                            is_target: true,
+                        });
                     }
                 }
                 Token::CloseParen => {
-                    if matches!(
-                        last_token,
-                        None | Some(Token::All | Token::Any | Token::Not | Token::Equals)
-                    ) {
-                        token_err!(lt.span)
-                    } else {
-                        if let Some(top) = func_stack.pop() {
-                            let key = pred_key.take();
-                            let val = pred_val.take();
-
-                            // In this context, the boolean to int conversion is confusing.
-                            #[allow(clippy::bool_to_int_with_if)]
-                            let num_predicates = top.predicates.len()
-                                + if key.is_some() { 1 } else { 0 }
-                                + top.nest_level as usize;
-
-                            let func = match top.func {
-                                Func::All(_) => Func::All(num_predicates),
-                                Func::Any(_) => Func::Any(num_predicates),
-                                Func::Not => {
-                                    // not() doesn't take a predicate list, but only a single predicate,
-                                    // so ensure we have exactly 1
-                                    if num_predicates != 1 {
-                                        return Err(ParseError {
-                                            original: original.to_owned(),
-                                            span: top.span.start..lt.span.end,
-                                            reason: Reason::InvalidNot(num_predicates),
-                                        });
-                                    }
-
-                                    Func::Not
+                    if let Some(top) = func_stack.pop() {
+                        let key = pred_key.take();
+                        let val = pred_val.take();
+                        let call_end = pred_call.take();
+
+                        // In this context, the boolean to int conversion is confusing.
+                        #[allow(clippy::bool_to_int_with_if)]
+                        let num_predicates = top.num_predicates
+                            + if key.is_some() { 1 } else { 0 }
+                            + top.nest_level as usize;
+
+                        let func = match top.func {
+                            Func::All(_) => Func::All(num_predicates),
+                            Func::Any(_) => Func::Any(num_predicates),
+                            Func::Not => {
+                                // not() doesn't take a predicate list, but only a single predicate,
+                                // so ensure we have exactly 1, unless the count is
+                                // off due to an error that was already reported
+                                if num_predicates != 1 && errors.len() == top.num_errors {
+                                    error!(ParseError {
+                                        original: original.to_owned(),
+                                        span: top.span.start..lt.span.end,
+                                        reason: Reason::InvalidNot(num_predicates),
+                                    });
                                 }
-                            };
 
-                            for pred in top.predicates {
-                                expr_queue.push(ExprNode::Predicate(pred));
+                                Func::Not
                             }
+                        };
 
-                            if let Some(key) = key {
-                                let inner_pred = parse_predicate(key, val)?;
-                                expr_queue.push(ExprNode::Predicate(inner_pred));
+                        if let Some(key) = key {
+                            span_queue.push(predicate_span(&key, &val, call_end));
+                            match parse_predicate(key, val, call_end.is_some(), top.is_target) {
+                                Ok(inner_pred) => {
+                                    expr_queue.push(ExprNode::Predicate(inner_pred));
+                                }
+                                Err(err) => error!(err),
                             }
-
-                            expr_queue.push(ExprNode::Fn(func));
-
-                            // This is the only place we go back to the top of the outer loop,
-                            // so make sure we correctly record this token
-                            last_token = Some(Token::CloseParen);
-                            continue 'outer;
                         }
 
-                        // We didn't have an opening parentheses if we get here
-                        return Err(ParseError {
-                            original: original.to_owned(),
-                            span: lt.span,
-                            reason: Reason::UnopenedParens,
-                        });
+                        expr_queue.push(ExprNode::Fn(func));
+                        span_queue.push(top.span.start..lt.span.end);
+
+                        // This is the only place we go back to the top of the outer loop,
+                        // so make sure we correctly record this token
+                        last_token = Some(Token::CloseParen);
+                        continue 'outer;
                     }
+
+                    // We didn't have an opening parentheses if we get here
+                    error!(ParseError {
+                        original: original.to_owned(),
+                        span: lt.span.clone(),
+                        reason: Reason::UnopenedParens,
+                    });
                 }
                 Token::Comma => {
-                    if matches!(
-                        last_token,
-                        None | Some(
-                            Token::OpenParen | Token::All | Token::Any | Token::Not | Token::Equals
-                        )
-                    ) {
-                        token_err!(lt.span)
-                    } else {
-                        let key = pred_key.take();
-                        let val = pred_val.take();
-
-                        let inner_pred = key.map(|key| parse_predicate(key, val)).transpose()?;
-
-                        match (inner_pred, func_stack.last_mut()) {
-                            (Some(pred), Some(func)) => {
-                                func.predicates.push(pred);
-                            }
-                            (Some(pred), None) => {
-                                root_predicate_count += 1;
+                    let key = pred_key.take();
+                    let val = pred_val.take();
+                    let call_end = pred_call.take();
+
+                    let span = key.as_ref().map(|key| predicate_span(key, &val, call_end));
+                    let inner_pred = match key
+                        .map(|key| parse_predicate(key, val, call_end.is_some(), in_target))
+                        .transpose()
+                    {
+                        Ok(inner_pred) => inner_pred,
+                        Err(err) => {
+                            error!(err);
+                            None
+                        }
+                    };
+                    span_queue.extend(span);
+
+                    match (inner_pred, func_stack.last_mut()) {
+                        (Some(pred), Some(func)) => {
+                            // Predicates are pushed immediately so that the
+                            // operands of each function keep their source order
+                            func.num_predicates += 1;
+                            expr_queue.push(ExprNode::Predicate(pred));
+                        }
+                        (Some(pred), None) => {
+                            root_predicate_count += 1;
 
-                                expr_queue.push(ExprNode::Predicate(pred));
-                            }
-                            _ => {}
+                            expr_queue.push(ExprNode::Predicate(pred));
                         }
+                        _ => {}
                     }
                 }
             }
@@ -394,8 +866,16 @@ impl Expression {
             last_token = Some(lt.token);
         }
 
-        if let Some(Token::Equals) = last_token {
-            return Err(ParseError {
+        if in_call {
+            error!(ParseError {
+                original: original.to_owned(),
+                span: pred_key.as_ref().map_or(0, |(_, span)| span.start)..original.len(),
+                reason: Reason::UnclosedParens,
+            });
+        }
+
+        if !skipping && last_token == Some(Token::Equals) {
+            error!(ParseError {
                 original: original.to_owned(),
                 span: original.len()..original.len(),
                 reason: Reason::Unexpected(&["\"<value>\""]),
@@ -405,45 +885,126 @@ impl Expression {
         // If we still have functions on the stack, it means we have an unclosed parens
         if let Some(top) = func_stack.pop() {
             if top.parens_index != 0 {
-                Err(ParseError {
+                error!(ParseError {
                     original: original.to_owned(),
                     span: top.parens_index..original.len(),
                     reason: Reason::UnclosedParens,
-                })
+                });
             } else {
-                Err(ParseError {
+                error!(ParseError {
                     original: original.to_owned(),
                     span: top.span,
                     reason: Reason::Unexpected(&["("]),
-                })
+                });
             }
         } else {
             let key = pred_key.take();
             let val = pred_val.take();
+            let call_end = pred_call.take();
 
             if let Some(key) = key {
                 root_predicate_count += 1;
-                expr_queue.push(ExprNode::Predicate(parse_predicate(key, val)?));
+                span_queue.push(predicate_span(&key, &val, call_end));
+                match parse_predicate(key, val, call_end.is_some(), false) {
+                    Ok(inner_pred) => expr_queue.push(ExprNode::Predicate(inner_pred)),
+                    Err(err) => error!(err),
+                }
             }
 
-            if expr_queue.is_empty() {
-                Err(ParseError {
-                    original: original.to_owned(),
-                    span: 0..original.len(),
-                    reason: Reason::Empty,
-                })
-            } else if root_predicate_count > 1 {
-                Err(ParseError {
+            if root_predicate_count > 1 {
+                error!(ParseError {
                     original: original.to_owned(),
                     span: 0..original.len(),
                     reason: Reason::MultipleRootPredicates,
-                })
-            } else {
-                Ok(Expression {
+                });
+            } else if expr_queue.is_empty() && errors.is_empty() {
+                error!(ParseError {
                     original: original.to_owned(),
-                    expr: expr_queue,
-                })
+                    span: 0..original.len(),
+                    reason: Reason::Empty,
+                });
+            }
+        }
+
+        if !errors.is_empty() {
+            return None;
+        }
+
+        Some(ExpressionRef {
+            original,
+            expr: expr_queue,
+            spans: span_queue,
+        })
+    }
+}
+
+/// The grammar of expressions, which returns whether a token can follow the
+/// last one, or the tokens that were expected instead
+///
+/// `in_target` is true inside `target()`, where only `key = "value"` pairs
+/// are allowed, and `call` is the key of the call the token is in, eg.
+/// `version`, if any. This is `const` so that the
+/// [`cfg_expr!`](crate::cfg_expr) macro can check expressions with the same
+/// rules as the parser.
+pub(crate) const fn follows(
+    last: Option<Kind>,
+    next: Kind,
+    in_target: bool,
+    call: Option<Call>,
+) -> Result<(), &'static [&'static str]> {
+    let accepted = if let Some(callee) = call {
+        // The only tokens allowed in a call are a single argument, a version
+        // string or a path, and the closing parenthesis
+        matches!(
+            (next, last, callee),
+            (Kind::Value, Some(Kind::OpenParen), Call::Version)
+                | (Kind::Key(_), Some(Kind::OpenParen), Call::Accessible)
+                | (Kind::CloseParen, Some(Kind::Key(_) | Kind::Value), _)
+        )
+    } else {
+        match next {
+            Kind::Key(_) => matches!(last, None | Some(Kind::OpenParen | Kind::Comma)),
+            Kind::Value => matches!(last, Some(Kind::Equals)),
+            Kind::Equals => matches!(last, Some(Kind::Key(_))),
+            Kind::All | Kind::Any | Kind::Not => {
+                !in_target && matches!(last, None | Some(Kind::OpenParen | Kind::Comma))
             }
+            Kind::OpenParen => {
+                matches!(last, Some(Kind::All | Kind::Any | Kind::Not))
+                    || !in_target
+                        && matches!(
+                            last,
+                            Some(Kind::Key(Call::Version | Call::Accessible | Call::Target))
+                        )
+            }
+            Kind::CloseParen => !matches!(
+                last,
+                None | Some(Kind::All | Kind::Any | Kind::Not | Kind::Equals)
+            ),
+            Kind::Comma => !matches!(
+                last,
+                None | Some(Kind::OpenParen | Kind::All | Kind::Any | Kind::Not | Kind::Equals)
+            ),
         }
+    };
+
+    if accepted {
+        return Ok(());
     }
+
+    Err(match (last, call) {
+        (Some(Kind::OpenParen), Some(Call::Accessible)) => &["<path>"],
+        (Some(Kind::OpenParen), Some(_)) => &["\"<version>\""],
+        (Some(Kind::Key(_) | Kind::Value), Some(_)) => &[")"],
+        (Some(Kind::OpenParen), None) if in_target => &["<key>", ")"],
+        (Some(Kind::Comma), _) if in_target => &[")", "<key>"],
+        (None, _) => &["<key>", "all", "any", "not"],
+        (Some(Kind::All | Kind::Any | Kind::Not), _) => &["("],
+        (Some(Kind::CloseParen), _) => &[")", ","],
+        (Some(Kind::Comma), _) => &[")", "<key>"],
+        (Some(Kind::Equals), _) => &["\""],
+        (Some(Kind::Key(_)), _) => &["=", ",", ")"],
+        (Some(Kind::Value), _) => &[",", ")"],
+        (Some(Kind::OpenParen), _) => &["<key>", ")", "all", "any", "not"],
+    })
 }
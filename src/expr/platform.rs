@@ -0,0 +1,120 @@
+use crate::{
+    error::{ParseError, Reason},
+    expr::{CfgSet, EvalOptions, Expression, FeatureSet},
+    targets::TargetInfo,
+};
+use alloc::{borrow::ToOwned, string::String};
+use core::fmt;
+
+/// The platform of a cargo
+/// [`[target.<platform>]`](https://doc.rust-lang.org/cargo/reference/specifying-dependencies.html#platform-specific-dependencies)
+/// table, which is either a target triple, or a `cfg()` expression
+///
+/// ```
+/// use cfg_expr::{expr::{FeatureSet, Platform}, targets::get_builtin_target_by_triple};
+///
+/// let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+/// let windows = get_builtin_target_by_triple("x86_64-pc-windows-msvc").unwrap();
+///
+/// let unix = Platform::parse("cfg(unix)").unwrap();
+/// assert!(unix.matches(linux, &FeatureSet::new()));
+/// assert!(!unix.matches(windows, &FeatureSet::new()));
+///
+/// let msvc = Platform::parse("x86_64-pc-windows-msvc").unwrap();
+/// assert!(!msvc.matches(linux, &FeatureSet::new()));
+/// assert!(msvc.matches(windows, &FeatureSet::new()));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[allow(clippy::large_enum_variant)]
+pub enum Platform {
+    /// A target triple, eg. `x86_64-unknown-linux-gnu`
+    Triple(String),
+    /// A `cfg()` expression, eg. `cfg(unix)`
+    Cfg(Expression),
+}
+
+impl Platform {
+    /// Parses a platform, which is a `cfg()` expression if it starts with
+    /// `cfg`, and otherwise a target triple, which may only contain ASCII
+    /// alphanumerics, `-`, `_` and `.`, the same as cargo allows
+    pub fn parse(platform: &str) -> Result<Self, ParseError> {
+        let trimmed = platform.trim();
+
+        if trimmed.starts_with("cfg(") || trimmed.starts_with("cfg ") {
+            return Expression::parse(platform).map(Self::Cfg);
+        }
+
+        if trimmed.is_empty() {
+            return Err(ParseError {
+                original: platform.to_owned(),
+                span: 0..platform.len(),
+                reason: Reason::Empty,
+            });
+        }
+
+        let start = platform.len() - platform.trim_start().len();
+        if let Some(invalid) = trimmed
+            .char_indices()
+            .find(|(_, c)| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+        {
+            let invalid_start = start + invalid.0;
+            return Err(ParseError {
+                original: platform.to_owned(),
+                span: invalid_start..invalid_start + invalid.1.len_utf8(),
+                reason: Reason::InvalidCharacters,
+            });
+        }
+
+        Ok(Self::Triple(trimmed.to_owned()))
+    }
+
+    /// Returns true if the platform applies to the target, ie. a triple is
+    /// the same as the target's triple, and an expression evaluates to true
+    /// with [`Expression::eval_target`], without any crate features, as
+    /// cargo doesn't allow `feature` predicates in platforms
+    pub fn matches(&self, target: &TargetInfo, target_features: &FeatureSet) -> bool {
+        match self {
+            Self::Triple(triple) => triple == target.triple.as_str(),
+            Self::Cfg(expr) => expr.eval_target(target, &FeatureSet::new(), target_features),
+        }
+    }
+
+    /// Returns true if the platform applies to the target, where an
+    /// expression is evaluated with [`Expression::eval_with`], eg. with
+    /// [`EvalOptions::cargo_compat`] to select the same dependencies as cargo
+    pub fn matches_with(&self, target: &TargetInfo, options: &EvalOptions) -> bool {
+        match self {
+            Self::Triple(triple) => triple == target.triple.as_str(),
+            Self::Cfg(expr) => expr.eval_with(target, options),
+        }
+    }
+
+    /// Returns true if the platform applies to the target with the specified
+    /// triple and cfgs, eg. from [`CfgSet::from_rustc_print_cfg`]
+    pub fn matches_cfg_set(&self, triple: &str, cfgs: &CfgSet) -> bool {
+        match self {
+            Self::Triple(t) => t == triple,
+            Self::Cfg(expr) => expr.eval_cfg_set(cfgs),
+        }
+    }
+}
+
+/// Writes the triple, or the expression wrapped in `cfg()`
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Triple(triple) => f.write_str(triple),
+            Self::Cfg(expr) => write!(f, "cfg({expr})"),
+        }
+    }
+}
+
+/// Parses the platform via [`Platform::parse`], so that it can be used with
+/// [`str::parse`]
+impl core::str::FromStr for Platform {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
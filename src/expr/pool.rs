@@ -0,0 +1,158 @@
+use crate::{error::ParseError, expr::Expression};
+use std::{
+    borrow::Borrow,
+    collections::{HashMap, HashSet},
+    hash::{Hash, Hasher},
+    ops::Deref,
+    sync::Arc,
+};
+
+/// A handle to an expression interned in an [`ExpressionPool`], which is
+/// cheap to clone, compare, and hash, as all handles to structurally
+/// identical expressions share the same [`Expression`]
+#[derive(Clone, Debug)]
+pub struct ExprId(Arc<Expression>);
+
+impl Deref for ExprId {
+    type Target = Expression;
+
+    fn deref(&self) -> &Expression {
+        &self.0
+    }
+}
+
+impl AsRef<Expression> for ExprId {
+    fn as_ref(&self) -> &Expression {
+        &self.0
+    }
+}
+
+/// Handles are equal if they are for the same interned expression, which,
+/// for handles from the same pool, is the same as the expressions being
+/// equal
+impl PartialEq for ExprId {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for ExprId {}
+
+impl Hash for ExprId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).hash(state);
+    }
+}
+
+/// An interned expression, compared by its structure, so that it can be
+/// looked up by an [`Expression`]
+struct Interned(ExprId);
+
+impl PartialEq for Interned {
+    fn eq(&self, other: &Self) -> bool {
+        *self.0 .0 == *other.0 .0
+    }
+}
+
+impl Eq for Interned {}
+
+impl Hash for Interned {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0 .0.hash(state);
+    }
+}
+
+impl Borrow<Expression> for Interned {
+    fn borrow(&self) -> &Expression {
+        &self.0 .0
+    }
+}
+
+/// An interner of expressions, which only parses each distinct string once,
+/// and de-duplicates structurally identical expressions, eg. `all(a, b)` and
+/// `cfg(all( a,b ))`, so that they share a single [`Expression`], for tools
+/// that parse the same handful of expressions many times over, eg. across
+/// every crate in a registry.
+///
+/// ```
+/// use cfg_expr::expr::ExpressionPool;
+///
+/// let mut pool = ExpressionPool::new();
+///
+/// let a = pool.intern(r#"cfg(all(unix, feature = "a"))"#).unwrap();
+/// let b = pool.intern(r#"all( unix,feature="a" )"#).unwrap();
+/// let c = pool.intern("windows").unwrap();
+///
+/// assert_eq!(a, b);
+/// assert_ne!(a, c);
+/// assert_eq!(pool.len(), 2);
+///
+/// // Handles dereference to the interned expression, which is the first one
+/// // that was interned
+/// assert_eq!(b.original(), r#"all(unix, feature = "a")"#);
+/// assert!(pool.intern("all(").is_err());
+/// ```
+#[derive(Default)]
+pub struct ExpressionPool {
+    /// The expression of each string that has been interned
+    texts: HashMap<Box<str>, ExprId>,
+    /// The distinct expressions, looked up by their structure
+    interned: HashSet<Interned>,
+    /// The distinct expressions, in the order they were interned
+    exprs: Vec<ExprId>,
+}
+
+impl ExpressionPool {
+    /// Creates an empty pool
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Interns the expression parsed from the string, only parsing it if the
+    /// same string hasn't been interned before. Strings that fail to parse
+    /// are not cached.
+    pub fn intern(&mut self, text: &str) -> Result<ExprId, ParseError> {
+        if let Some(id) = self.texts.get(text) {
+            return Ok(id.clone());
+        }
+
+        let id = self.insert(Expression::parse(text)?);
+        self.texts.insert(text.into(), id.clone());
+        Ok(id)
+    }
+
+    /// Interns an expression, returning the handle of the structurally
+    /// identical expression that was already interned, if there is one
+    pub fn insert(&mut self, expr: Expression) -> ExprId {
+        if let Some(interned) = self.interned.get(&expr) {
+            return interned.0.clone();
+        }
+
+        let id = ExprId(Arc::new(expr));
+        self.interned.insert(Interned(id.clone()));
+        self.exprs.push(id.clone());
+        id
+    }
+
+    /// Gets the handle of an expression that is structurally identical to
+    /// this one, if one has been interned
+    pub fn get(&self, expr: &Expression) -> Option<&ExprId> {
+        self.interned.get(expr).map(|interned| &interned.0)
+    }
+
+    /// The number of distinct expressions in the pool
+    pub fn len(&self) -> usize {
+        self.exprs.len()
+    }
+
+    /// Returns true if no expressions have been interned
+    pub fn is_empty(&self) -> bool {
+        self.exprs.is_empty()
+    }
+
+    /// The distinct expressions in the pool, in the order they were first
+    /// interned
+    pub fn iter(&self) -> impl Iterator<Item = &ExprId> {
+        self.exprs.iter()
+    }
+}
@@ -0,0 +1,93 @@
+use crate::expr::{tree::ExprTree, Expression, Predicate};
+use alloc::{boxed::Box, vec::Vec};
+
+/// What to do with a predicate in [`Expression::map_predicates`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Rewrite<'a> {
+    /// Keeps the predicate as is
+    Keep,
+    /// Replaces the predicate with another
+    Replace(Predicate<'a>),
+    /// Replaces the predicate with a constant, `all()` for `true` and `any()`
+    /// for `false`
+    Constant(bool),
+    /// Removes the predicate from the function it is an operand of. A `not()`
+    /// whose operand is removed, or an `all()` or `any()` whose operands are
+    /// all removed, is itself removed, and if the root of the expression is
+    /// removed the result is `all()`.
+    Drop,
+}
+
+fn rewrite<'a, 'r, 'x, F>(tree: ExprTree<'a>, f: &mut F) -> Option<ExprTree<'x>>
+where
+    'a: 'x,
+    'r: 'x,
+    F: FnMut(&Predicate<'a>) -> Rewrite<'r>,
+{
+    match tree {
+        ExprTree::Predicate(pred) => match f(&pred) {
+            Rewrite::Keep => Some(ExprTree::Predicate(pred)),
+            Rewrite::Replace(pred) => Some(ExprTree::Predicate(pred)),
+            Rewrite::Constant(true) => Some(ExprTree::All(Vec::new())),
+            Rewrite::Constant(false) => Some(ExprTree::Any(Vec::new())),
+            Rewrite::Drop => None,
+        },
+        ExprTree::Not(operand) => rewrite(*operand, f).map(|o| ExprTree::Not(Box::new(o))),
+        ExprTree::All(operands) => rewrite_operands(operands, f).map(ExprTree::All),
+        ExprTree::Any(operands) => rewrite_operands(operands, f).map(ExprTree::Any),
+    }
+}
+
+/// Rewrites the operands of an `all()` or `any()`, which is removed if every
+/// one of its operands is, rather than being left as a constant, eg.
+/// `any(feature = "a")` isn't turned into `any()`, which is always false
+fn rewrite_operands<'a, 'r, 'x, F>(
+    operands: Vec<ExprTree<'a>>,
+    f: &mut F,
+) -> Option<Vec<ExprTree<'x>>>
+where
+    'a: 'x,
+    'r: 'x,
+    F: FnMut(&Predicate<'a>) -> Rewrite<'r>,
+{
+    let had_operands = !operands.is_empty();
+    let operands: Vec<_> = operands.into_iter().filter_map(|o| rewrite(o, f)).collect();
+
+    if had_operands && operands.is_empty() {
+        None
+    } else {
+        Some(operands)
+    }
+}
+
+impl Expression {
+    /// Creates a new expression by keeping, replacing, or dropping each
+    /// predicate in this expression, as determined by the [`Rewrite`] returned
+    /// by the closure.
+    ///
+    /// The structure of the expression is otherwise left untouched, so use
+    /// [`Self::simplify`] on the result to fold any constants or functions that
+    /// were left with a single operand.
+    ///
+    /// ```
+    /// use cfg_expr::{expr::Rewrite, Expression, Predicate};
+    ///
+    /// let expr = Expression::parse(r#"all(unix, feature = "foo", any(feature = "bar", target_arch = "x86"))"#).unwrap();
+    ///
+    /// // Strip out the features, leaving only the target constraints
+    /// let target_only = expr.map_predicates(|pred| match pred {
+    ///     Predicate::Feature(_) => Rewrite::Drop,
+    ///     _ => Rewrite::Keep,
+    /// });
+    ///
+    /// assert_eq!(target_only.to_string(), r#"all(unix, any(target_arch = "x86"))"#);
+    /// assert_eq!(target_only.simplify().to_string(), r#"all(unix, target_arch = "x86")"#);
+    /// ```
+    pub fn map_predicates<'a, 'r, F>(&'a self, mut f: F) -> Expression
+    where
+        F: FnMut(&Predicate<'a>) -> Rewrite<'r>,
+    {
+        let tree = rewrite(self.root(), &mut f).unwrap_or(ExprTree::All(Vec::new()));
+        Expression::from_tree(&tree)
+    }
+}
@@ -0,0 +1,389 @@
+use crate::expr::{analysis::is_exclusive, ExprNode, Expression, Func, InnerPredicate, Predicate};
+use std::collections::HashMap;
+
+/// A variable, or its negation if `positive` is false
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Lit {
+    var: usize,
+    positive: bool,
+}
+
+impl Lit {
+    fn negate(self) -> Self {
+        Self {
+            var: self.var,
+            positive: !self.positive,
+        }
+    }
+}
+
+/// A formula in conjunctive normal form, ie. clauses of literals that must
+/// each have at least one true literal, with a variable for each distinct
+/// predicate, and one for each function, via the Tseitin transformation, so
+/// that the formula is linear in the size of the expressions
+#[derive(Default)]
+struct Cnf<'a> {
+    /// The predicate of each predicate variable
+    preds: Vec<(usize, Predicate<'a>)>,
+    /// The variable of each predicate, by its canonical `cfg()` form
+    pred_vars: HashMap<String, usize>,
+    vars: usize,
+    clauses: Vec<Vec<Lit>>,
+}
+
+impl<'a> Cnf<'a> {
+    fn var(&mut self) -> Lit {
+        self.vars += 1;
+        Lit {
+            var: self.vars - 1,
+            positive: true,
+        }
+    }
+
+    fn predicate(&mut self, pred: Predicate<'a>) -> Lit {
+        let mut cfg = String::new();
+        pred.write_cfg(&mut cfg).unwrap();
+
+        if let Some(var) = self.pred_vars.get(&cfg) {
+            return Lit {
+                var: *var,
+                positive: true,
+            };
+        }
+
+        let lit = self.var();
+        self.pred_vars.insert(cfg, lit.var);
+        self.preds.push((lit.var, pred));
+        lit
+    }
+
+    /// Adds the expression to the formula, returning the literal that is
+    /// true when the expression is
+    fn encode(&mut self, expr: &'a Expression) -> Lit {
+        let mut stack = Vec::<Lit>::new();
+
+        for node in expr.expr.iter() {
+            let lit = match node {
+                ExprNode::Predicate(InnerPredicate::Boolean(value)) => {
+                    let lit = self.var();
+                    self.clauses
+                        .push(vec![if *value { lit } else { lit.negate() }]);
+                    lit
+                }
+                ExprNode::Predicate(pred) => self.predicate(pred.to_pred(&expr.original)),
+                ExprNode::Fn(Func::Not) => stack.pop().unwrap().negate(),
+                ExprNode::Fn(Func::All(count)) => {
+                    let operands = stack.split_off(stack.len() - count);
+                    self.gate(&operands, true)
+                }
+                ExprNode::Fn(Func::Any(count)) => {
+                    let operands = stack.split_off(stack.len() - count);
+                    self.gate(&operands, false)
+                }
+            };
+
+            stack.push(lit);
+        }
+
+        stack.pop().unwrap()
+    }
+
+    /// Adds a variable that is equivalent to the `all()` of the operands, or
+    /// their `any()` if `is_all` is false
+    fn gate(&mut self, operands: &[Lit], is_all: bool) -> Lit {
+        let gate = self.var();
+
+        // An any() is the negation of the all() of the negated operands, so
+        // both are encoded the same way
+        let (gate_lit, flip) = if is_all {
+            (gate, false)
+        } else {
+            (gate.negate(), true)
+        };
+        let operand = |lit: Lit| if flip { lit.negate() } else { lit };
+
+        // The gate implies each operand
+        for op in operands {
+            self.clauses.push(vec![gate_lit.negate(), operand(*op)]);
+        }
+
+        // And all of the operands imply the gate
+        let mut clause = vec![gate_lit];
+        clause.extend(operands.iter().map(|op| operand(*op).negate()));
+        self.clauses.push(clause);
+
+        gate
+    }
+
+    /// Adds the clauses that prevent two values of a single-valued target
+    /// property from both being true
+    fn exclude(&mut self) {
+        for (i, (a_var, a)) in self.preds.iter().enumerate() {
+            for (b_var, b) in &self.preds[i + 1..] {
+                if is_exclusive(a, b) {
+                    self.clauses.push(vec![
+                        Lit {
+                            var: *a_var,
+                            positive: false,
+                        },
+                        Lit {
+                            var: *b_var,
+                            positive: false,
+                        },
+                    ]);
+                }
+            }
+        }
+    }
+
+    /// Solves the formula with the literals asserted, returning the value of
+    /// each predicate if it is satisfiable
+    fn solve(mut self, assertions: &[Lit]) -> Option<Vec<(Predicate<'a>, bool)>> {
+        self.exclude();
+        self.clauses.extend(assertions.iter().map(|lit| vec![*lit]));
+
+        let assignment = Solver::new(self.clauses, self.vars).solve()?;
+
+        Some(
+            self.preds
+                .into_iter()
+                .map(|(var, pred)| (pred, assignment[var] == Some(true)))
+                .collect(),
+        )
+    }
+}
+
+/// A conflict-driven clause learning solver, which assigns the literals of
+/// unit clauses, and otherwise decides the value of a variable, and when an
+/// assignment falsifies a clause, learns a clause that prevents the decisions
+/// that led to it, and backtracks to where the learned clause is unit
+struct Solver {
+    clauses: Vec<Vec<Lit>>,
+    assignment: Vec<Option<bool>>,
+    /// The decision level each variable was assigned at
+    levels: Vec<usize>,
+    /// The clause that forced the value of each variable, if it wasn't a
+    /// decision
+    reasons: Vec<Option<usize>>,
+    /// The assigned literals, in the order they were assigned
+    trail: Vec<Lit>,
+    /// The length of the trail at the start of each decision level
+    decisions: Vec<usize>,
+}
+
+impl Solver {
+    fn new(clauses: Vec<Vec<Lit>>, vars: usize) -> Self {
+        Self {
+            clauses,
+            assignment: vec![None; vars],
+            levels: vec![0; vars],
+            reasons: vec![None; vars],
+            trail: Vec::new(),
+            decisions: Vec::new(),
+        }
+    }
+
+    fn value(&self, lit: Lit) -> Option<bool> {
+        self.assignment[lit.var].map(|value| value == lit.positive)
+    }
+
+    fn assign(&mut self, lit: Lit, reason: Option<usize>) {
+        self.assignment[lit.var] = Some(lit.positive);
+        self.levels[lit.var] = self.decisions.len();
+        self.reasons[lit.var] = reason;
+        self.trail.push(lit);
+    }
+
+    /// Assigns the literals of unit clauses until there are none left,
+    /// returning the clause that is false if there is one
+    fn propagate(&mut self) -> Option<usize> {
+        loop {
+            let mut propagated = false;
+
+            for i in 0..self.clauses.len() {
+                let mut unassigned = None;
+                let mut unassigned_count = 0;
+                let mut satisfied = false;
+
+                for lit in &self.clauses[i] {
+                    match self.value(*lit) {
+                        Some(true) => {
+                            satisfied = true;
+                            break;
+                        }
+                        Some(false) => {}
+                        None => {
+                            unassigned = Some(*lit);
+                            unassigned_count += 1;
+                        }
+                    }
+                }
+
+                match (satisfied, unassigned_count, unassigned) {
+                    (false, 0, _) => return Some(i),
+                    (false, 1, Some(lit)) => {
+                        self.assign(lit, Some(i));
+                        propagated = true;
+                    }
+                    _ => {}
+                }
+            }
+
+            if !propagated {
+                return None;
+            }
+        }
+    }
+
+    /// Learns the clause that prevents the conflict, by resolving it with
+    /// the reasons of its literals from the current decision level until
+    /// only one is left, returning it along with the level to backtrack to
+    fn analyze(&self, conflict: usize) -> (Vec<Lit>, usize) {
+        let level = self.decisions.len();
+        let mut seen = vec![false; self.assignment.len()];
+        let mut learned = Vec::new();
+        let mut current = 0;
+        let mut clause = &self.clauses[conflict];
+        let mut index = self.trail.len();
+
+        let asserting = loop {
+            for lit in clause {
+                if seen[lit.var] || self.levels[lit.var] == 0 {
+                    continue;
+                }
+
+                seen[lit.var] = true;
+                if self.levels[lit.var] == level {
+                    current += 1;
+                } else {
+                    learned.push(*lit);
+                }
+            }
+
+            // The most recently assigned literal of the current level
+            loop {
+                index -= 1;
+                if seen[self.trail[index].var] {
+                    break;
+                }
+            }
+
+            // It stays seen, as it is in the reason it is resolved with
+            let lit = self.trail[index];
+            current -= 1;
+
+            if current == 0 {
+                break lit.negate();
+            }
+
+            clause = &self.clauses[self.reasons[lit.var].unwrap()];
+        };
+
+        let backtrack = learned
+            .iter()
+            .map(|lit| self.levels[lit.var])
+            .max()
+            .unwrap_or(0);
+        learned.push(asserting);
+
+        (learned, backtrack)
+    }
+
+    fn backtrack(&mut self, level: usize) {
+        let len = self.decisions[level];
+        for lit in self.trail.drain(len..) {
+            self.assignment[lit.var] = None;
+        }
+        self.decisions.truncate(level);
+    }
+
+    fn solve(mut self) -> Option<Vec<Option<bool>>> {
+        loop {
+            if let Some(conflict) = self.propagate() {
+                if self.decisions.is_empty() {
+                    return None;
+                }
+
+                let (learned, level) = self.analyze(conflict);
+                self.backtrack(level);
+                self.clauses.push(learned);
+                continue;
+            }
+
+            let var = match self.assignment.iter().position(Option::is_none) {
+                Some(var) => var,
+                None => return Some(self.assignment),
+            };
+
+            self.decisions.push(self.trail.len());
+            self.assign(
+                Lit {
+                    var,
+                    positive: false,
+                },
+                None,
+            );
+        }
+    }
+}
+
+impl Expression {
+    /// Finds a value for each predicate that makes the expression true, with
+    /// a SAT solver, if there is one.
+    ///
+    /// As with [`Self::implies`], predicates are independent of each other,
+    /// other than target properties that only have a single value per target,
+    /// but this remains fast for expressions that are too large to convert to
+    /// disjunctive normal form.
+    ///
+    /// ```
+    /// use cfg_expr::{Expression, Predicate};
+    ///
+    /// let expr = Expression::parse(r#"all(any(feature = "a", feature = "b"), not(feature = "a"))"#).unwrap();
+    /// let assignment = expr.satisfying_assignment().unwrap();
+    ///
+    /// assert_eq!(
+    ///     assignment,
+    ///     [(Predicate::Feature("a"), false), (Predicate::Feature("b"), true)]
+    /// );
+    ///
+    /// let expr = Expression::parse(r#"all(target_os = "linux", target_os = "macos")"#).unwrap();
+    /// assert!(expr.satisfying_assignment().is_none());
+    /// ```
+    pub fn satisfying_assignment(&self) -> Option<Vec<(Predicate<'_>, bool)>> {
+        let mut cnf = Cnf::default();
+        let lit = cnf.encode(self);
+        cnf.solve(&[lit])
+    }
+
+    /// Returns true if there is at least one value for each predicate that
+    /// makes the expression true, see [`Self::satisfying_assignment`]
+    pub fn is_satisfiable(&self) -> bool {
+        self.satisfying_assignment().is_some()
+    }
+
+    /// The same as [`Self::implies`], but with a SAT solver
+    pub fn implies_sat(&self, other: &Expression) -> bool {
+        let mut cnf = Cnf::default();
+        let a = cnf.encode(self);
+        let b = cnf.encode(other);
+
+        // A implies B if there is no assignment where A is true and B is false
+        cnf.solve(&[a, b.negate()]).is_none()
+    }
+
+    /// Returns true if the expressions are true for exactly the same values
+    /// of the predicates, ie. each implies the other
+    ///
+    /// ```
+    /// use cfg_expr::Expression;
+    ///
+    /// let a = Expression::parse(r#"not(any(unix, target_os = "linux"))"#).unwrap();
+    /// let b = Expression::parse(r#"all(not(target_family = "unix"), not(target_os = "linux"))"#).unwrap();
+    ///
+    /// assert!(a.is_equivalent(&b));
+    /// ```
+    pub fn is_equivalent(&self, other: &Expression) -> bool {
+        self.implies_sat(other) && other.implies_sat(self)
+    }
+}
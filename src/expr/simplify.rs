@@ -0,0 +1,248 @@
+use crate::expr::{tree::ExprTree, Expression, Predicate};
+use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
+
+impl<'a> ExprTree<'a> {
+    #[inline]
+    fn is_true(&self) -> bool {
+        matches!(self, Self::All(ops) if ops.is_empty())
+    }
+
+    #[inline]
+    fn is_false(&self) -> bool {
+        matches!(self, Self::Any(ops) if ops.is_empty())
+    }
+
+    /// Returns true if one of the trees is the negation of the other
+    fn is_complement(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Not(a), b) | (b, Self::Not(a)) => **a == *b,
+            _ => false,
+        }
+    }
+
+    /// Replaces every predicate for which `known` returns a value with the
+    /// equivalent constant, ie. `all()` for true and `any()` for false
+    pub(crate) fn substitute(
+        &self,
+        known: &mut impl FnMut(&Predicate<'a>) -> Option<bool>,
+    ) -> Self {
+        match self {
+            // Literals are always known
+            Self::Predicate(Predicate::Boolean(_)) => self.clone().simplify(),
+            Self::Predicate(pred) => match known(pred) {
+                Some(true) => Self::All(Vec::new()),
+                Some(false) => Self::Any(Vec::new()),
+                None => self.clone(),
+            },
+            Self::Not(operand) => Self::Not(Box::new(operand.substitute(known))),
+            Self::All(operands) => {
+                Self::All(operands.iter().map(|o| o.substitute(known)).collect())
+            }
+            Self::Any(operands) => {
+                Self::Any(operands.iter().map(|o| o.substitute(known)).collect())
+            }
+        }
+    }
+
+    pub(crate) fn simplify(self) -> Self {
+        match self {
+            Self::Predicate(Predicate::Boolean(true)) => Self::All(Vec::new()),
+            Self::Predicate(Predicate::Boolean(false)) => Self::Any(Vec::new()),
+            Self::Predicate(_) => self,
+            Self::Not(operand) => match operand.simplify() {
+                Self::Not(inner) => *inner,
+                t if t.is_true() => Self::Any(Vec::new()),
+                f if f.is_false() => Self::All(Vec::new()),
+                other => Self::Not(Box::new(other)),
+            },
+            Self::All(operands) => Self::simplify_list(operands, true),
+            Self::Any(operands) => Self::simplify_list(operands, false),
+        }
+    }
+
+    /// Simplifies the operands of an `all()`, or an `any()` if `is_all` is false
+    fn simplify_list(operands: Vec<Self>, is_all: bool) -> Self {
+        // The identity is the value that doesn't affect the result, eg `true`
+        // for all(), and the annihilator is the one that forces the result
+        let (identity, annihilator) = if is_all {
+            (Self::All(Vec::new()), Self::Any(Vec::new()))
+        } else {
+            (Self::Any(Vec::new()), Self::All(Vec::new()))
+        };
+
+        let mut simplified = Vec::with_capacity(operands.len());
+
+        for operand in operands {
+            let operand = operand.simplify();
+
+            // Flatten nested functions of the same kind into this one
+            let nested = match operand {
+                Self::All(ops) if is_all => ops,
+                Self::Any(ops) if !is_all => ops,
+                other => vec![other],
+            };
+
+            for operand in nested {
+                if operand == annihilator {
+                    return annihilator;
+                }
+
+                if operand == identity || simplified.contains(&operand) {
+                    continue;
+                }
+
+                // `x` and `not(x)` together are always false for all(), and
+                // always true for any()
+                if simplified.iter().any(|op| operand.is_complement(op)) {
+                    return annihilator;
+                }
+
+                simplified.push(operand);
+            }
+        }
+
+        if simplified.len() == 1 {
+            simplified.pop().unwrap()
+        } else if is_all {
+            Self::All(simplified)
+        } else {
+            Self::Any(simplified)
+        }
+    }
+
+    /// Sorts the operands of every `all()` and `any()` by their canonical
+    /// form, returning the sorted tree along with its canonical form
+    fn sort(self) -> (Self, String) {
+        let (name, operands) = match self {
+            Self::Predicate(pred) => {
+                let mut cfg = String::new();
+                let _ = pred.write_cfg(&mut cfg);
+                return (Self::Predicate(pred), cfg);
+            }
+            Self::Not(operand) => {
+                let (operand, cfg) = operand.sort();
+                return (Self::Not(Box::new(operand)), format!("not({cfg})"));
+            }
+            Self::All(operands) => ("all", operands),
+            Self::Any(operands) => ("any", operands),
+        };
+
+        let mut sorted: Vec<_> = operands.into_iter().map(Self::sort).collect();
+        sorted.sort_by(|(_, a), (_, b)| a.cmp(b));
+
+        let cfg = format!(
+            "{name}({})",
+            sorted
+                .iter()
+                .map(|(_, cfg)| cfg.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        let operands = sorted.into_iter().map(|(operand, _)| operand).collect();
+
+        let tree = if name == "all" {
+            Self::All(operands)
+        } else {
+            Self::Any(operands)
+        };
+        (tree, cfg)
+    }
+}
+
+impl Expression {
+    /// Returns a semantically equivalent expression with redundant structure
+    /// removed, namely
+    ///
+    /// * `not(not(x))` becomes `x`
+    /// * `all(x)` and `any(x)` become `x`
+    /// * nested functions of the same kind are flattened, eg. `all(a, all(b, c))`
+    ///   becomes `all(a, b, c)`
+    /// * duplicate operands are removed, eg. `any(a, b, a)` becomes `any(a, b)`
+    /// * constant subexpressions are folded, eg. `all(a, any())`, or
+    ///   `all(a, not(a))` become `any()`, and `any(a, not(all()))` becomes `a`
+    /// * `true` and `false` become `all()` and `any()` respectively
+    ///
+    /// The result is written in canonical form, see [`std::fmt::Display`].
+    ///
+    /// ```
+    /// use cfg_expr::Expression;
+    ///
+    /// let expr = Expression::parse("all(not(not(unix)), all(unix, any(test)), any(feature = \"a\", not(all())))").unwrap();
+    ///
+    /// assert_eq!(expr.simplify().to_string(), r#"all(unix, test, feature = "a")"#);
+    /// ```
+    pub fn simplify(&self) -> Expression {
+        Expression::from_tree(&self.root().simplify())
+    }
+
+    /// Returns the canonical form of the expression, which is the same for
+    /// all expressions that only differ in their layout, the order of the
+    /// operands of `all()` and `any()`, or the redundant structure removed by
+    /// [`Self::simplify`], eg. so that tools that independently generate the
+    /// same constraint write byte-identical strings that can be diffed or
+    /// hashed.
+    ///
+    /// The expression is simplified and the operands of each function are
+    /// sorted by their canonical string, repeatedly, as operands that only
+    /// differ in their order are only found to be redundant once they are
+    /// sorted. The canonical string is the [`Self::original`] of the returned
+    /// expression.
+    ///
+    /// Expressions that are only equivalent because of the values they are
+    /// true for, eg. `any(a, all(a, b))` and `a`, can still have different
+    /// canonical forms.
+    ///
+    /// ```
+    /// use cfg_expr::Expression;
+    ///
+    /// let a = Expression::parse(r#"any(target_os = "linux", all(feature = "b", unix, feature = "a"))"#).unwrap();
+    /// let b = Expression::parse(r#"cfg(any(all(all(target_family="unix", feature="a"), feature="b"), target_os="linux"))"#).unwrap();
+    ///
+    /// assert_eq!(a.canonicalize(), b.canonicalize());
+    /// assert_eq!(
+    ///     a.canonicalize().original(),
+    ///     r#"any(all(feature = "a", feature = "b", unix), target_os = "linux")"#
+    /// );
+    /// ```
+    pub fn canonicalize(&self) -> Expression {
+        let mut tree = self.root();
+        loop {
+            // Simplifying only removes structure, so this stops once sorting
+            // doesn't reveal anything more to remove
+            let (sorted, _) = tree.clone().simplify().sort();
+            if sorted == tree {
+                return Expression::from_tree(&tree);
+            }
+
+            tree = sorted;
+        }
+    }
+
+    /// Evaluates the predicates whose value is known, returning the simplified
+    /// residual expression that only contains the predicates whose value is
+    /// still unknown, ie. those for which `eval_predicate` returned `None`.
+    ///
+    /// If every predicate is known, the residual is either `all()` (true) or
+    /// `any()` (false).
+    ///
+    /// ```
+    /// use cfg_expr::{targets::*, Expression, Predicate};
+    ///
+    /// let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    ///
+    /// let expr = Expression::parse(r#"any(windows, all(unix, feature = "foo"), all(target_os = "macos", feature = "bar"))"#).unwrap();
+    ///
+    /// let residual = expr.partial_eval(|pred| match pred {
+    ///     Predicate::Target(tp) => Some(tp.matches(linux)),
+    ///     _ => None,
+    /// });
+    ///
+    /// assert_eq!(residual.to_string(), r#"feature = "foo""#);
+    /// ```
+    pub fn partial_eval<EP>(&self, mut eval_predicate: EP) -> Expression
+    where
+        EP: FnMut(&Predicate<'_>) -> Option<bool>,
+    {
+        Expression::from_tree(&self.root().substitute(&mut eval_predicate).simplify())
+    }
+}
@@ -0,0 +1,138 @@
+use crate::expr::{ExprNode, Expression, Func, InnerPredicate};
+use alloc::vec::Vec;
+
+/// The number of predicates of each kind in an expression
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PredicateCounts {
+    /// Target predicates, including the bare `unix` and `windows`
+    pub target: usize,
+    /// `feature = "<name>"`
+    pub feature: usize,
+    /// `target_feature = "<name>"`
+    pub target_feature: usize,
+    /// The other predicates that are set by rustc or other tools, eg. `test`,
+    /// `debug_assertions`, `miri`, or `sanitize = "<sanitizer>"`
+    pub builtin: usize,
+    /// `true` and `false`
+    pub boolean: usize,
+    /// `version("<version>")`
+    pub version: usize,
+    /// `accessible(<path>)`
+    pub accessible: usize,
+    /// Bare predicates that aren't known, eg. `tokio_unstable`
+    pub flag: usize,
+    /// Key-value predicates that aren't known, eg. `my_key = "value"`
+    pub key_value: usize,
+}
+
+impl PredicateCounts {
+    /// The total number of predicates
+    pub fn total(&self) -> usize {
+        self.target
+            + self.feature
+            + self.target_feature
+            + self.builtin
+            + self.boolean
+            + self.version
+            + self.accessible
+            + self.flag
+            + self.key_value
+    }
+}
+
+/// Metrics of how complex an expression is, see [`Expression::stats`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// The length of [`Expression::original`], in bytes
+    pub len: usize,
+    /// The deepest that functions are nested, eg. `unix` has a depth of 0,
+    /// and `all(not(unix))` has a depth of 2, as with
+    /// [`ParseOptions::max_depth`](crate::expr::ParseOptions::max_depth)
+    pub depth: usize,
+    /// The number of predicates of each kind
+    pub predicates: PredicateCounts,
+    /// The number of `all()` functions
+    pub all: usize,
+    /// The number of `any()` functions
+    pub any: usize,
+    /// The number of `not()` functions
+    pub not: usize,
+}
+
+impl Expression {
+    /// Gathers metrics of how complex the expression is, eg. to survey the
+    /// expressions used across a registry
+    ///
+    /// ```
+    /// use cfg_expr::Expression;
+    ///
+    /// let expr = Expression::parse(r#"all(unix, any(feature = "a", feature = "b"), not(test))"#).unwrap();
+    /// let stats = expr.stats();
+    ///
+    /// assert_eq!(stats.len, expr.original().len());
+    /// assert_eq!(stats.depth, 2);
+    /// assert_eq!((stats.all, stats.any, stats.not), (1, 1, 1));
+    /// assert_eq!(stats.predicates.feature, 2);
+    /// assert_eq!(stats.predicates.total(), 4);
+    /// ```
+    pub fn stats(&self) -> Stats {
+        let mut stats = Stats {
+            len: self.original.len(),
+            ..Stats::default()
+        };
+
+        // The depth of each operand that hasn't been consumed by a function
+        let mut depths = Vec::new();
+
+        for node in self.expr.iter() {
+            let count = match node {
+                ExprNode::Predicate(pred) => {
+                    let counts = &mut stats.predicates;
+                    let count = match pred {
+                        InnerPredicate::Target(_) => &mut counts.target,
+                        InnerPredicate::Feature(_) => &mut counts.feature,
+                        InnerPredicate::TargetFeature(_) => &mut counts.target_feature,
+                        InnerPredicate::Boolean(_) => &mut counts.boolean,
+                        InnerPredicate::Version(_) => &mut counts.version,
+                        InnerPredicate::Accessible(_) => &mut counts.accessible,
+                        InnerPredicate::Other { value: None, .. } => &mut counts.flag,
+                        InnerPredicate::Other { value: Some(_), .. } => &mut counts.key_value,
+                        InnerPredicate::Test
+                        | InnerPredicate::DebugAssertions
+                        | InnerPredicate::ProcMacro
+                        | InnerPredicate::TargetThreadLocal
+                        | InnerPredicate::Doc
+                        | InnerPredicate::Doctest
+                        | InnerPredicate::Miri
+                        | InnerPredicate::Fuzzing
+                        | InnerPredicate::OverflowChecks
+                        | InnerPredicate::UbChecks
+                        | InnerPredicate::Sanitize(_) => &mut counts.builtin,
+                    };
+                    *count += 1;
+
+                    depths.push(0);
+                    continue;
+                }
+                ExprNode::Fn(Func::All(count)) => {
+                    stats.all += 1;
+                    *count
+                }
+                ExprNode::Fn(Func::Any(count)) => {
+                    stats.any += 1;
+                    *count
+                }
+                ExprNode::Fn(Func::Not) => {
+                    stats.not += 1;
+                    1
+                }
+            };
+
+            let deepest = depths.drain(depths.len() - count..).max().unwrap_or(0);
+            depths.push(deepest + 1);
+        }
+
+        stats.depth = depths.pop().unwrap_or(0);
+        stats
+    }
+}
@@ -0,0 +1,74 @@
+use alloc::vec::Vec;
+
+/// Every predicate key that has a special meaning, used to suggest the
+/// intended key when an unknown one is very similar to one of these
+pub(crate) const KNOWN_KEYS: &[&str] = &[
+    "accessible",
+    "debug_assertions",
+    "doc",
+    "doctest",
+    "false",
+    "feature",
+    "fuzzing",
+    "miri",
+    "overflow_checks",
+    "panic",
+    "proc_macro",
+    "sanitize",
+    "target_abi",
+    "target_arch",
+    "target_endian",
+    "target_env",
+    "target_family",
+    "target_feature",
+    "target_has_atomic",
+    "target_has_atomic_equal_alignment",
+    "target_has_atomic_load_store",
+    "target_os",
+    "target_pointer_width",
+    "target_thread_local",
+    "target_vendor",
+    "test",
+    "true",
+    "ub_checks",
+    "unix",
+    "version",
+    "windows",
+];
+
+/// The [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+/// between two strings, ie. the number of single character insertions,
+/// deletions, or substitutions needed to turn one into the other
+pub(crate) fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ac) in a.chars().enumerate() {
+        let mut diag = row[0];
+        row[0] = i + 1;
+
+        for (j, bc) in b.iter().enumerate() {
+            let sub = diag + usize::from(ac != *bc);
+            diag = row[j + 1];
+            row[j + 1] = sub.min(diag + 1).min(row[j] + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Finds the candidate that `input` is most likely a misspelling of, if any.
+///
+/// To avoid suggesting something unrelated, a candidate must start with the
+/// same character, and only differ by 1 edit for every 4 characters.
+pub(crate) fn closest(input: &str, candidates: &[&'static str]) -> Option<&'static str> {
+    let first = input.chars().next()?;
+
+    candidates
+        .iter()
+        .filter(|cand| cand.starts_with(first) && **cand != input)
+        .map(|cand| (levenshtein(input, cand), *cand))
+        .filter(|(dist, cand)| *dist <= (cand.len() / 4).max(1))
+        .min_by_key(|(dist, _)| *dist)
+        .map(|(_, cand)| cand)
+}
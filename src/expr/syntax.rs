@@ -0,0 +1,191 @@
+//! A `const` check of the syntax of expressions, which lets the
+//! [`cfg_expr!`](crate::cfg_expr) macro fail the build on invalid expressions
+//! without a proc-macro.
+//!
+//! The tokens are lexed with [`scan`], and checked with [`follows`], the same
+//! `const` lexer and grammar that the parser uses, so only the state that the
+//! parser keeps on its stack is tracked here. The values themselves, eg. the
+//! version in `version()`, are checked when the expression is parsed.
+
+use super::{
+    lexer::{scan, skip_whitespace, unwrap_cfg, Call, Kind, Scan},
+    parser::follows,
+    Expression,
+};
+use std::sync::{Mutex, PoisonError};
+
+/// The deepest nesting of functions that can be checked, which is the same as
+/// the default depth limit of [`ParseOptions::untrusted`](super::ParseOptions::untrusted)
+const MAX_DEPTH: usize = 64;
+
+/// A function that is open, ie. `all(`, `any(`, `not(`, or `target(`
+#[derive(Clone, Copy)]
+struct Frame {
+    is_not: bool,
+    is_target: bool,
+    /// The number of operands so far, which is only needed for `not()`
+    operands: usize,
+}
+
+/// Panics if the expression, which can be wrapped in `cfg()`, isn't
+/// syntactically valid, which fails the build when it is called in a `const`
+#[doc(hidden)]
+pub const fn check_syntax(text: &str) {
+    let b = text.as_bytes();
+    let (mut i, end) = unwrap_cfg(b);
+
+    let mut stack = [Frame {
+        is_not: false,
+        is_target: false,
+        operands: 0,
+    }; MAX_DEPTH];
+    let mut depth = 0;
+
+    let mut last = None;
+    // The call the current token is in, eg. `version(`
+    let mut call = None;
+    // Whether there is a predicate that hasn't been ended by a `,` or `)` yet
+    let mut pending = false;
+    let mut root_predicates = 0;
+
+    loop {
+        i = skip_whitespace(b, i, end);
+        if i == end {
+            break;
+        }
+
+        let (kind, len) = match scan(b, i, end) {
+            Scan::Token(kind, len) => (kind, len),
+            Scan::UnclosedQuotes => panic!("invalid cfg expression: unclosed quotes"),
+            Scan::Invalid(_) => panic!("invalid cfg expression: invalid character"),
+        };
+
+        let in_target = depth > 0 && stack[depth - 1].is_target;
+        if follows(last, kind, in_target, call).is_err() {
+            panic!("invalid cfg expression: unexpected token");
+        }
+
+        if call.is_some() {
+            if let Kind::CloseParen = kind {
+                call = None;
+            }
+        } else {
+            match kind {
+                Kind::Key(_) => pending = true,
+                Kind::Value | Kind::Equals => {}
+                Kind::All | Kind::Any | Kind::Not => {
+                    (stack, depth) = push(stack, depth, matches!(kind, Kind::Not), false);
+                }
+                Kind::OpenParen => match last {
+                    Some(Kind::Key(Call::Target)) => {
+                        pending = false;
+                        (stack, depth) = push(stack, depth, false, true);
+                    }
+                    Some(Kind::Key(callee)) => call = Some(callee),
+                    _ => {}
+                },
+                Kind::CloseParen => {
+                    if depth == 0 {
+                        panic!("invalid cfg expression: unopened parentheses");
+                    }
+
+                    depth -= 1;
+                    let frame = stack[depth];
+                    if frame.is_not && frame.operands + pending as usize != 1 {
+                        panic!("invalid cfg expression: not() takes 1 predicate");
+                    }
+                    pending = false;
+                }
+                Kind::Comma => {
+                    if pending {
+                        if depth > 0 {
+                            stack[depth - 1].operands += 1;
+                        } else {
+                            root_predicates += 1;
+                        }
+                    }
+                    pending = false;
+                }
+            }
+        }
+
+        last = Some(kind);
+        i += len;
+    }
+
+    if call.is_some() || depth > 0 {
+        panic!("invalid cfg expression: unclosed parentheses");
+    }
+    if let Some(Kind::Equals) = last {
+        panic!("invalid cfg expression: expected a quoted value");
+    }
+    if last.is_none() {
+        panic!("invalid cfg expression: empty expression");
+    }
+    if root_predicates + pending as usize > 1 {
+        panic!("invalid cfg expression: multiple root predicates");
+    }
+}
+
+/// Opens a function, which is an operand of the function it is in
+///
+/// The stack is passed by value as `const fn`s can't take `&mut` on the
+/// minimum supported Rust version
+const fn push(
+    mut stack: [Frame; MAX_DEPTH],
+    depth: usize,
+    is_not: bool,
+    is_target: bool,
+) -> ([Frame; MAX_DEPTH], usize) {
+    if depth == MAX_DEPTH {
+        panic!("invalid cfg expression: functions are nested too deeply");
+    }
+
+    if depth > 0 {
+        stack[depth - 1].operands += 1;
+    }
+    stack[depth] = Frame {
+        is_not,
+        is_target,
+        operands: 0,
+    };
+    (stack, depth + 1)
+}
+
+/// An expression that is parsed the first time it is used, which the
+/// [`cfg_expr!`](crate::cfg_expr) macro stores in a `static`
+#[doc(hidden)]
+pub struct LazyExpression {
+    text: &'static str,
+    parsed: Mutex<Option<&'static Expression>>,
+}
+
+impl LazyExpression {
+    // `Mutex::new` is `const` since 1.63, which is older than the
+    // `rust-version` of the crate, but not the MSRV in clippy.toml
+    #[allow(clippy::incompatible_msrv)]
+    pub const fn new(text: &'static str) -> Self {
+        Self {
+            text,
+            parsed: Mutex::new(None),
+        }
+    }
+
+    /// Gets the parsed expression, parsing it if this is the first use
+    ///
+    /// # Panics
+    ///
+    /// Panics if the expression fails to parse, which can only be due to its
+    /// values, as its syntax was checked when it was compiled
+    pub fn get(&self) -> &'static Expression {
+        let mut parsed = self.parsed.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(expr) = *parsed {
+            return expr;
+        }
+
+        let expr = Expression::parse(self.text).unwrap_or_else(|err| panic!("{err}"));
+        let expr = &*Box::leak(Box::new(expr));
+        *parsed = Some(expr);
+        expr
+    }
+}
@@ -0,0 +1,202 @@
+use crate::expr::{
+    ExprNode, Expression, Func, InnerPredicate, InnerTarget, Predicate, TargetPredicate, Which,
+};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use smallvec::SmallVec;
+
+/// A recursive view of an [`Expression`], as opposed to the flat postfix
+/// representation used for evaluation, which allows walking the nesting
+/// structure of the expression.
+///
+/// The operands of each function are in the same order as they appear in
+/// the original string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExprTree<'a> {
+    /// A single predicate
+    Predicate(Predicate<'a>),
+    /// `all()` with its operands. It is true if all of its operands are true,
+    /// or if there are no operands.
+    All(Vec<ExprTree<'a>>),
+    /// `any()` with its operands. It is true if any of its operands are true,
+    /// and false if there are no operands.
+    Any(Vec<ExprTree<'a>>),
+    /// `not()` with its single operand
+    Not(Box<ExprTree<'a>>),
+}
+
+impl Expression {
+    /// Retrieves the root of the tree view of the expression
+    ///
+    /// ```
+    /// use cfg_expr::{expr::ExprTree, Expression, Predicate};
+    ///
+    /// let expr = Expression::parse(r#"all(test, not(feature = "foo"))"#).unwrap();
+    ///
+    /// assert_eq!(
+    ///     expr.root(),
+    ///     ExprTree::All(vec![
+    ///         ExprTree::Predicate(Predicate::Test),
+    ///         ExprTree::Not(Box::new(ExprTree::Predicate(Predicate::Feature("foo")))),
+    ///     ])
+    /// );
+    /// ```
+    pub fn root(&self) -> ExprTree<'_> {
+        let mut stack = Vec::new();
+
+        for node in self.expr.iter() {
+            match node {
+                ExprNode::Predicate(pred) => {
+                    stack.push(ExprTree::Predicate(pred.to_pred(&self.original)));
+                }
+                ExprNode::Fn(Func::All(count)) => {
+                    let operands = stack.split_off(stack.len() - count);
+                    stack.push(ExprTree::All(operands));
+                }
+                ExprNode::Fn(Func::Any(count)) => {
+                    let operands = stack.split_off(stack.len() - count);
+                    stack.push(ExprTree::Any(operands));
+                }
+                ExprNode::Fn(Func::Not) => {
+                    let operand = stack.pop().unwrap();
+                    stack.push(ExprTree::Not(Box::new(operand)));
+                }
+            }
+        }
+
+        stack.pop().unwrap()
+    }
+
+    /// Creates an expression from a tree, writing the tree in its canonical
+    /// string form and pointing the spans of each predicate into it
+    pub(crate) fn from_tree(tree: &ExprTree<'_>) -> Self {
+        fn write(tree: &ExprTree<'_>, original: &mut String, expression: &mut Expression) {
+            let start = original.len();
+
+            let (name, operands) = match tree {
+                ExprTree::Predicate(pred) => {
+                    let pred = InnerPredicate::write(pred, original);
+                    expression.expr.push(ExprNode::Predicate(pred));
+                    expression.spans.push(start..original.len());
+                    return;
+                }
+                ExprTree::Not(operand) => ("not(", core::slice::from_ref(&**operand)),
+                ExprTree::All(operands) => ("all(", operands.as_slice()),
+                ExprTree::Any(operands) => ("any(", operands.as_slice()),
+            };
+
+            original.push_str(name);
+            for (i, operand) in operands.iter().enumerate() {
+                if i > 0 {
+                    original.push_str(", ");
+                }
+
+                write(operand, original, expression);
+            }
+            original.push(')');
+
+            expression.expr.push(ExprNode::Fn(match tree {
+                ExprTree::All(_) => Func::All(operands.len()),
+                ExprTree::Any(_) => Func::Any(operands.len()),
+                _ => Func::Not,
+            }));
+            expression.spans.push(start..original.len());
+        }
+
+        let mut original = String::new();
+        let mut expression = Self {
+            expr: SmallVec::new(),
+            spans: SmallVec::new(),
+            original: String::new(),
+        };
+        write(tree, &mut original, &mut expression);
+        expression.original = original;
+
+        expression
+    }
+}
+
+impl InnerPredicate {
+    /// Appends the canonical form of the predicate to the string, returning
+    /// the inner predicate with spans pointing to the written key and value
+    fn write(pred: &Predicate<'_>, original: &mut String) -> Self {
+        match pred {
+            Predicate::Version(version) => {
+                let _ = pred.write_cfg(original);
+                return Self::Version(*version);
+            }
+            Predicate::Accessible(path) => {
+                original.push_str("accessible(");
+                let start = original.len();
+                original.push_str(path);
+                let span = start..original.len();
+                original.push(')');
+                return Self::Accessible(span);
+            }
+            _ => {}
+        }
+
+        let (key, val) = pred.as_key_value();
+
+        let key_span = original.len()..original.len() + key.len();
+        original.push_str(key);
+
+        let val_span = val.map(|val| {
+            original.push_str(" = \"");
+            let start = original.len();
+            original.push_str(&val);
+            let span = start..original.len();
+            original.push('"');
+            span
+        });
+
+        // String values point to the value, with the exception of the bare
+        // `unix` and `windows` families, which point to the key
+        let span = Some(val_span.clone().unwrap_or_else(|| key_span.clone()));
+
+        match pred {
+            Predicate::Target(tp) => {
+                let (which, span) = match tp {
+                    TargetPredicate::Abi(_) => (Which::Abi, span),
+                    TargetPredicate::Arch(_) => (Which::Arch, span),
+                    TargetPredicate::Endian(end) => (Which::Endian(*end), None),
+                    TargetPredicate::Env(_) => (Which::Env, span),
+                    TargetPredicate::Family(_) => (Which::Family, span),
+                    TargetPredicate::HasAtomic(ha) => (Which::HasAtomic(*ha), None),
+                    TargetPredicate::HasAtomicLoadStore(ha) => {
+                        (Which::HasAtomicLoadStore(*ha), None)
+                    }
+                    TargetPredicate::HasAtomicEqualAlignment(ha) => {
+                        (Which::HasAtomicEqualAlignment(*ha), None)
+                    }
+                    TargetPredicate::Os(_) => (Which::Os, span),
+                    TargetPredicate::Panic(_) => (Which::Panic, span),
+                    TargetPredicate::PointerWidth(pw) => (Which::PointerWidth(*pw), None),
+                    TargetPredicate::Vendor(_) => (Which::Vendor, span),
+                };
+
+                Self::Target(InnerTarget::new(which, span, original))
+            }
+            Predicate::Test => Self::Test,
+            Predicate::DebugAssertions => Self::DebugAssertions,
+            Predicate::ProcMacro => Self::ProcMacro,
+            Predicate::TargetThreadLocal => Self::TargetThreadLocal,
+            Predicate::Doc => Self::Doc,
+            Predicate::Doctest => Self::Doctest,
+            Predicate::Miri => Self::Miri,
+            Predicate::Fuzzing => Self::Fuzzing,
+            Predicate::OverflowChecks => Self::OverflowChecks,
+            Predicate::UbChecks => Self::UbChecks,
+            Predicate::Feature(_) => Self::Feature(val_span.unwrap_or(key_span)),
+            Predicate::TargetFeature(_) => Self::TargetFeature(val_span.unwrap_or(key_span)),
+            Predicate::Sanitize(_) => Self::Sanitize(val_span.unwrap_or(key_span)),
+            Predicate::Boolean(value) => Self::Boolean(*value),
+            Predicate::Flag(_) | Predicate::KeyValue { .. } => Self::Other {
+                identifier: key_span,
+                value: val_span,
+            },
+            Predicate::Version(_) | Predicate::Accessible(_) => {
+                unreachable!("calls are written above")
+            }
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{
+    error::{ParseError, Reason},
+    expr::{
+        suggest::{closest, KNOWN_KEYS},
+        ExprNode, Expression, InnerPredicate, InnerTarget, Which,
+    },
+    targets as targ,
+};
+use alloc::vec::Vec;
+
+/// The sorted [known](crate::targets::Arch::ALL_KNOWN) values of a target
+/// predicate, see [`known_values`]
+pub(crate) trait KnownValues {
+    /// Retrieves the known value that is the same as the specified value, so
+    /// that it can be borrowed
+    fn find(&self, value: &str) -> Option<&'static str>;
+    /// Retrieves the known value that is closest to the specified value, for
+    /// suggesting a fix for a typo
+    fn closest(&self, value: &str) -> Option<&'static str>;
+}
+
+macro_rules! known_values {
+    ($($typ:ident: $missing:expr),+ $(,)?) => {
+        $(
+            impl KnownValues for &'static [targ::$typ] {
+                fn find(&self, value: &str) -> Option<&'static str> {
+                    let known: &'static [targ::$typ] = self;
+                    let missing: Option<&'static str> = $missing;
+
+                    if missing == Some(value) {
+                        return missing;
+                    }
+
+                    known
+                        .binary_search_by(|val| val.as_str().cmp(value))
+                        .ok()
+                        .map(|i| known[i].as_str())
+                }
+
+                fn closest(&self, value: &str) -> Option<&'static str> {
+                    let known: &'static [targ::$typ] = self;
+                    let known: Vec<_> = known.iter().map(|val| val.as_str()).collect();
+                    closest(value, &known)
+                }
+            }
+        )+
+    };
+}
+
+// The empty string is valid for the ABI and environment of targets that don't
+// have one, see `TargetMatcher`, the OS and vendor of such targets, `none`
+// and `unknown`, are already known values
+known_values!(
+    Abi: Some(""),
+    Arch: None,
+    Env: Some(""),
+    Family: None,
+    Os: None,
+    Panic: None,
+    Vendor: None,
+);
+
+/// Retrieves the known values of the target predicate, or `None` if the
+/// predicate isn't validated
+pub(crate) fn known_values(which: &Which) -> Option<&'static dyn KnownValues> {
+    Some(match which {
+        Which::Abi => &targ::Abi::ALL_KNOWN,
+        Which::Arch => &targ::Arch::ALL_KNOWN,
+        Which::Env => &targ::Env::ALL_KNOWN,
+        Which::Family => &targ::Family::ALL_KNOWN,
+        Which::Os => &targ::Os::ALL_KNOWN,
+        Which::Panic => &targ::Panic::ALL_KNOWN,
+        Which::Vendor => &targ::Vendor::ALL_KNOWN,
+        // These are already validated when parsing
+        Which::Endian(_)
+        | Which::HasAtomic(_)
+        | Which::HasAtomicLoadStore(_)
+        | Which::HasAtomicEqualAlignment(_)
+        | Which::PointerWidth(_) => return None,
+    })
+}
+
+impl Expression {
+    /// Checks that the value of every target predicate is a
+    /// [known](crate::targets::Arch::ALL_KNOWN) one, ie. one that is used by
+    /// at least one of the [builtin](crate::targets::ALL_BUILTINS) targets, or
+    /// is documented in the reference,
+    /// and that no custom key is almost the same as a known key, eg.
+    /// `featurre` or `tests`, returning an error for each one that is.
+    ///
+    /// Such values and keys are usually typos, which would otherwise silently
+    /// evaluate to false for every target. Note however that targets that
+    /// aren't builtin, or newer than the builtin list, may use values that
+    /// aren't known yet, and that similar custom keys can be intentional, so
+    /// these errors can also be treated as warnings.
+    ///
+    /// ```
+    /// use cfg_expr::{error::Reason, Expression};
+    ///
+    /// let expr = Expression::parse(r#"any(target_os = "linuxx", target_arch = "x86-64", target_env = "gnu", featurre = "a")"#).unwrap();
+    ///
+    /// let errors = expr.validate().unwrap_err();
+    /// assert_eq!(errors[0].reason, Reason::UnknownValue(Some("linux")));
+    /// assert_eq!(errors[1].reason, Reason::UnknownValue(Some("x86_64")));
+    /// assert_eq!(errors[2].reason, Reason::UnknownKey(Some("feature")));
+    /// assert_eq!(errors.len(), 3);
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<ParseError>> {
+        let errors: Vec<_> = self
+            .expr
+            .iter()
+            .filter_map(|node| {
+                let (which, span) = match node {
+                    ExprNode::Predicate(InnerPredicate::Target(InnerTarget {
+                        which,
+                        span: Some(span),
+                        ..
+                    })) => (which, span),
+                    // Custom keys are allowed, but ones that are almost the
+                    // same as a known key are far more likely to be a typo
+                    ExprNode::Predicate(InnerPredicate::Other { identifier, .. }) => {
+                        let suggestion = closest(&self.original[identifier.clone()], KNOWN_KEYS)?;
+
+                        return Some(ParseError {
+                            original: self.original.clone(),
+                            span: identifier.clone(),
+                            reason: Reason::UnknownKey(Some(suggestion)),
+                        });
+                    }
+                    _ => return None,
+                };
+
+                let known = known_values(which)?;
+                let value = &self.original[span.clone()];
+
+                if known.find(value).is_some() {
+                    return None;
+                }
+
+                Some(ParseError {
+                    original: self.original.clone(),
+                    span: span.clone(),
+                    reason: Reason::UnknownValue(known.closest(value)),
+                })
+            })
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
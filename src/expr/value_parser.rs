@@ -0,0 +1,102 @@
+use crate::{
+    error::ParseError,
+    expr::{Expression, Platform},
+};
+use clap::{
+    builder::{TypedValueParser, ValueParserFactory},
+    error::ErrorKind,
+    Arg, Command, Error,
+};
+use std::ffi::OsStr;
+
+/// Parses the value of an argument, with the error pointing at the part of
+/// the value that isn't valid
+fn parse_value<T>(
+    cmd: &Command,
+    arg: Option<&Arg>,
+    value: &OsStr,
+    parse: impl FnOnce(&str) -> Result<T, ParseError>,
+) -> Result<T, Error> {
+    let arg = arg.map_or_else(|| "...".to_owned(), ToString::to_string);
+
+    let value = value.to_str().ok_or_else(|| {
+        Error::raw(
+            ErrorKind::InvalidUtf8,
+            format!("invalid UTF-8 was detected in the value for '{arg}'\n"),
+        )
+        .with_cmd(cmd)
+    })?;
+
+    parse(value).map_err(|err| {
+        Error::raw(
+            ErrorKind::ValueValidation,
+            format!("invalid value for '{arg}':\n{err}\n"),
+        )
+        .with_cmd(cmd)
+    })
+}
+
+/// A [`TypedValueParser`] for `cfg()` expressions, which is used by clap's
+/// `value_parser!(Expression)`
+///
+/// ```
+/// use cfg_expr::Expression;
+/// use clap::{value_parser, Arg, Command};
+///
+/// let cmd = Command::new("tool").arg(Arg::new("filter").long("filter").value_parser(value_parser!(Expression)));
+///
+/// let matches = cmd.clone().try_get_matches_from(["tool", "--filter", "cfg(unix)"]).unwrap();
+/// assert_eq!(matches.get_one::<Expression>("filter").unwrap().original(), "unix");
+///
+/// assert!(cmd.try_get_matches_from(["tool", "--filter", "all(unix"]).is_err());
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ExpressionValueParser;
+
+impl TypedValueParser for ExpressionValueParser {
+    type Value = Expression;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        parse_value(cmd, arg, value, Expression::parse)
+    }
+}
+
+impl ValueParserFactory for Expression {
+    type Parser = ExpressionValueParser;
+
+    fn value_parser() -> Self::Parser {
+        ExpressionValueParser
+    }
+}
+
+/// A [`TypedValueParser`] for target triples and `cfg()` expressions, which
+/// is used by clap's `value_parser!(Platform)`, eg. for a
+/// `--filter-platform` argument
+#[derive(Copy, Clone, Debug, Default)]
+pub struct PlatformValueParser;
+
+impl TypedValueParser for PlatformValueParser {
+    type Value = Platform;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        parse_value(cmd, arg, value, Platform::parse)
+    }
+}
+
+impl ValueParserFactory for Platform {
+    type Parser = PlatformValueParser;
+
+    fn value_parser() -> Self::Parser {
+        PlatformValueParser
+    }
+}
@@ -0,0 +1,102 @@
+use crate::expr::{tree::ExprTree, Expression, Predicate};
+
+/// Callbacks for traversing an [`Expression`] with [`Expression::visit`].
+///
+/// The expression is traversed depth first, in the same order as it is
+/// written. Each function is first entered via its `visit_*` method, then each
+/// of its operands is visited, and finally the function is exited via its
+/// `exit_*` method. Every method has a default implementation that does
+/// nothing, so implementors only need to handle the events they care about.
+///
+/// ```
+/// use cfg_expr::{expr::Visitor, Expression, Predicate};
+///
+/// /// Collects every feature that is enabled via a `not()`
+/// #[derive(Default)]
+/// struct NegatedFeatures<'a> {
+///     depth: usize,
+///     features: Vec<&'a str>,
+/// }
+///
+/// impl<'a> Visitor<'a> for NegatedFeatures<'a> {
+///     fn visit_not(&mut self) {
+///         self.depth += 1;
+///     }
+///
+///     fn exit_not(&mut self) {
+///         self.depth -= 1;
+///     }
+///
+///     fn visit_predicate(&mut self, pred: &Predicate<'a>) {
+///         if let Predicate::Feature(feat) = pred {
+///             if self.depth % 2 == 1 {
+///                 self.features.push(feat);
+///             }
+///         }
+///     }
+/// }
+///
+/// let expr = Expression::parse(r#"all(feature = "a", not(any(feature = "b", not(feature = "c"))))"#).unwrap();
+///
+/// let mut visitor = NegatedFeatures::default();
+/// expr.visit(&mut visitor);
+///
+/// assert_eq!(visitor.features, ["b"]);
+/// ```
+pub trait Visitor<'a> {
+    /// Called for each predicate
+    fn visit_predicate(&mut self, _pred: &Predicate<'a>) {}
+
+    /// Called when entering an `all()` with the specified number of operands
+    fn visit_all(&mut self, _num_operands: usize) {}
+
+    /// Called when exiting an `all()`, after all of its operands were visited
+    fn exit_all(&mut self, _num_operands: usize) {}
+
+    /// Called when entering an `any()` with the specified number of operands
+    fn visit_any(&mut self, _num_operands: usize) {}
+
+    /// Called when exiting an `any()`, after all of its operands were visited
+    fn exit_any(&mut self, _num_operands: usize) {}
+
+    /// Called when entering a `not()`
+    fn visit_not(&mut self) {}
+
+    /// Called when exiting a `not()`, after its operand was visited
+    fn exit_not(&mut self) {}
+}
+
+impl<'a> ExprTree<'a> {
+    fn visit(&self, visitor: &mut impl Visitor<'a>) {
+        match self {
+            Self::Predicate(pred) => visitor.visit_predicate(pred),
+            Self::All(operands) => {
+                visitor.visit_all(operands.len());
+                for operand in operands {
+                    operand.visit(visitor);
+                }
+                visitor.exit_all(operands.len());
+            }
+            Self::Any(operands) => {
+                visitor.visit_any(operands.len());
+                for operand in operands {
+                    operand.visit(visitor);
+                }
+                visitor.exit_any(operands.len());
+            }
+            Self::Not(operand) => {
+                visitor.visit_not();
+                operand.visit(visitor);
+                visitor.exit_not();
+            }
+        }
+    }
+}
+
+impl Expression {
+    /// Traverses the expression, calling the [`Visitor`] methods for each
+    /// function and predicate
+    pub fn visit<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        self.root().visit(visitor);
+    }
+}
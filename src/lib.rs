@@ -1,14 +1,82 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
+extern crate alloc;
+
+/// A C ABI for parsing expressions and evaluating them against targets, for
+/// build systems that aren't written in Rust to link the evaluator directly.
+///
+/// The functions are exported when the crate is built as a static or dynamic
+/// library with the `capi` feature, eg. with
+/// `cargo rustc --release --features capi --crate-type staticlib`, and are
+/// declared in C as
+///
+/// ```c
+/// typedef struct CfgExpr CfgExpr;
+///
+/// CfgExpr *cfg_expr_parse(const char *expr);
+/// int cfg_expr_eval_triple(const CfgExpr *expr, const char *triple, const char *const *features, size_t features_len);
+/// void cfg_expr_free(CfgExpr *expr);
+/// const char *cfg_expr_last_error(void);
+/// ```
+#[cfg(feature = "capi")]
+pub mod capi;
 /// Types related to parse errors
 pub mod error;
 /// Types related to cfg expressions
 pub mod expr;
+/// Types for reading the dependencies of packages from the output of
+/// `cargo metadata`, and filtering them by the target they're built for
+#[cfg(feature = "json")]
+pub mod metadata;
 /// Types related to rustc targets
 pub mod targets;
+/// The functions exported to JavaScript by [`wasm-bindgen`](wasm_bindgen),
+/// for evaluating expressions in the browser with the same engine as native
+/// tooling.
+///
+/// Expressions are passed as strings, and parsed on each call. Parse errors
+/// and unknown triples are thrown as `Error`s, with the same message as the
+/// [`Display`](core::fmt::Display) implementation of [`ParseError`].
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 pub use error::ParseError;
 pub use expr::{Expression, Predicate, TargetPredicate};
 
-#[cfg(feature = "targets")]
+#[cfg(feature = "target-lexicon")]
 pub use target_lexicon;
+
+/// Parses a `cfg()` expression, checking its syntax at compile time, so that
+/// invalid expressions fail the build rather than a runtime `unwrap()`.
+///
+/// The syntax is checked with the same lexer and grammar as
+/// [`Expression::parse`], which are `const`. The expression is then parsed
+/// the first time it is used, and cached in a `static`, so the macro
+/// evaluates to a `&'static Expression`. The values in the expression, eg.
+/// the version in `version()`, are only checked when it is parsed, and an
+/// invalid one panics.
+///
+/// ```
+/// use cfg_expr::{cfg_expr, Expression};
+///
+/// let expr: &'static Expression = cfg_expr!(r#"all(unix, feature = "foo")"#);
+/// assert_eq!(*expr, Expression::parse(r#"all(unix, feature = "foo")"#).unwrap());
+/// ```
+///
+/// ```compile_fail
+/// let expr = cfg_expr::cfg_expr!(r#"all(unix, feature = "foo""#);
+/// ```
+///
+/// ```compile_fail
+/// let expr = cfg_expr::cfg_expr!("not(unix, windows)");
+/// ```
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! cfg_expr {
+    ($expr:literal) => {{
+        const _: () = $crate::expr::check_syntax($expr);
+        static EXPR: $crate::expr::LazyExpression = $crate::expr::LazyExpression::new($expr);
+        EXPR.get()
+    }};
+}
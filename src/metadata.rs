@@ -0,0 +1,168 @@
+use crate::{
+    expr::{EvalOptions, Platform},
+    targets::TargetInfo,
+};
+use serde::{de::Error as _, Deserialize, Deserializer};
+
+pub use crate::expr::DependencyKind;
+
+/// The subset of the output of `cargo metadata` that describes the packages
+/// and their dependencies, see
+/// <https://doc.rust-lang.org/cargo/commands/cargo-metadata.html#json-format>
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct Metadata {
+    /// Every package in the dependency graph, including the workspace members
+    pub packages: Vec<Package>,
+    /// The ids of the packages in the workspace
+    #[serde(default)]
+    pub workspace_members: Vec<String>,
+}
+
+/// A package in [`Metadata`]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct Package {
+    /// The name of the package
+    pub name: String,
+    /// The version of the package
+    pub version: String,
+    /// The opaque id of the package, which is unique in the graph
+    pub id: String,
+    /// The dependencies declared in the package's manifest, for every target
+    pub dependencies: Vec<Dependency>,
+}
+
+/// A dependency declared in the manifest of a [`Package`]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct Dependency {
+    /// The name of the package that is depended on
+    pub name: String,
+    /// The name the dependency is renamed to, eg. `foo` for
+    /// `foo = { package = "bar" }`
+    #[serde(default)]
+    pub rename: Option<String>,
+    /// The version requirement of the dependency
+    pub req: String,
+    /// Which of the `[dependencies]` tables the dependency is declared in
+    #[serde(default)]
+    pub kind: DependencyKind,
+    /// True if the dependency is only enabled by a feature
+    #[serde(default)]
+    pub optional: bool,
+    /// True if the default features of the dependency are enabled
+    #[serde(default = "default_true")]
+    pub uses_default_features: bool,
+    /// The features of the dependency that are enabled
+    #[serde(default)]
+    pub features: Vec<String>,
+    /// The platform the dependency is declared for, ie. the `<platform>` of
+    /// a `[target.<platform>.dependencies]` table, or `None` if it applies
+    /// to every target
+    #[serde(default, deserialize_with = "platform")]
+    pub target: Option<Platform>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn platform<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Platform>, D::Error> {
+    Option::<String>::deserialize(deserializer)?
+        .map(|platform| Platform::parse(&platform).map_err(D::Error::custom))
+        .transpose()
+}
+
+/// The kind is `null` for normal dependencies
+impl<'de> Deserialize<'de> for DependencyKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match Option::<String>::deserialize(deserializer)?.as_deref() {
+            None | Some("normal") => Ok(Self::Normal),
+            Some("dev") => Ok(Self::Development),
+            Some("build") => Ok(Self::Build),
+            Some(kind) => Err(D::Error::unknown_variant(kind, &["dev", "build"])),
+        }
+    }
+}
+
+impl Metadata {
+    /// Reads the JSON written by `cargo metadata --format-version 1`.
+    ///
+    /// The `target` of each dependency is parsed with [`Platform::parse`],
+    /// failing if it isn't a valid triple or `cfg()` expression.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Returns the metadata with only the dependencies that apply to the
+    /// target, see [`Dependency::applies_to`], eg. to build the dependency
+    /// graph of a workspace when it is built for the target.
+    ///
+    /// ```
+    /// use cfg_expr::{metadata::Metadata, targets::get_builtin_target_by_triple};
+    ///
+    /// let metadata = Metadata::from_json(r#"{
+    ///     "packages": [{
+    ///         "name": "app",
+    ///         "version": "0.1.0",
+    ///         "id": "app 0.1.0 (path+file:///app)",
+    ///         "dependencies": [
+    ///             { "name": "libc", "req": "^0.2", "kind": null, "target": "cfg(unix)" },
+    ///             { "name": "windows-sys", "req": "^0.52", "kind": null, "target": "cfg(windows)" },
+    ///             { "name": "cc", "req": "^1.0", "kind": "build", "target": null }
+    ///         ]
+    ///     }]
+    /// }"#).unwrap();
+    ///
+    /// let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    /// let filtered = metadata.for_target(linux);
+    /// let deps: Vec<_> = filtered.packages[0]
+    ///     .dependencies
+    ///     .iter()
+    ///     .map(|dep| dep.name.as_str())
+    ///     .collect();
+    ///
+    /// assert_eq!(deps, ["libc", "cc"]);
+    /// ```
+    pub fn for_target(&self, target: &TargetInfo) -> Self {
+        Self {
+            packages: self
+                .packages
+                .iter()
+                .map(|package| Package {
+                    dependencies: package.dependencies_for(target).cloned().collect(),
+                    ..package.clone()
+                })
+                .collect(),
+            workspace_members: self.workspace_members.clone(),
+        }
+    }
+
+    /// Finds the package with the id
+    pub fn package(&self, id: &str) -> Option<&Package> {
+        self.packages.iter().find(|package| package.id == id)
+    }
+}
+
+impl Package {
+    /// Iterates over the dependencies that apply to the target, see
+    /// [`Dependency::applies_to`]
+    pub fn dependencies_for<'a>(
+        &'a self,
+        target: &'a TargetInfo,
+    ) -> impl Iterator<Item = &'a Dependency> + 'a {
+        self.dependencies
+            .iter()
+            .filter(move |dep| dep.applies_to(target))
+    }
+}
+
+impl Dependency {
+    /// Returns true if the dependency is used when building for the target,
+    /// ie. it isn't declared for a platform, or its platform matches the
+    /// target the same way as cargo matches it, see
+    /// [`EvalOptions::cargo_compat`]
+    pub fn applies_to(&self, target: &TargetInfo) -> bool {
+        self.target.as_ref().map_or(true, |platform| {
+            platform.matches_with(target, &EvalOptions::cargo_compat())
+        })
+    }
+}
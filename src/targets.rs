@@ -1,46 +1,117 @@
 use crate::error::{HasAtomicParseError, Reason};
-use std::{borrow::Cow, ops::Deref};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    string::String,
+    vec::Vec,
+};
+use core::ops::Deref;
 
+mod aliases;
+mod arch;
+mod builder;
 mod builtins;
+mod db;
+mod features;
+#[cfg(feature = "targets")]
+mod guess;
+mod lookup;
+mod manifest;
+pub(crate) mod os_version;
+mod registry;
+#[cfg(feature = "rustc")]
+pub mod rustc;
+mod search;
+mod set;
+#[cfg(feature = "json")]
+mod spec;
+mod support;
 
+pub use builder::TargetInfoBuilder;
 /// A list of all of the [builtin](https://doc.rust-lang.org/nightly/nightly-rustc/rustc_target/spec/index.html#modules)
 /// targets known to rustc, as of 1.54.0
-pub use builtins::ALL_BUILTINS;
+pub use builtins::{ALL_BUILTINS, OS_FAMILIES};
+pub use db::TargetDb;
+pub use lookup::{lookup_builtin_target, TargetLookup};
+pub use manifest::parse_cargo_target_key;
+pub use os_version::OsVersion;
+pub use registry::TargetRegistry;
+pub use search::{search, TargetSearch};
+pub use set::TargetSet;
 
 /// The unique identifier for a target.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Triple(pub Cow<'static, str>);
 
 /// The "abi" field
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Abi(pub Cow<'static, str>);
 
 /// The "architecture" field
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Arch(pub Cow<'static, str>);
 
 /// The "vendor" field, which in practice is little more than an arbitrary modifier.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Vendor(pub Cow<'static, str>);
 
 /// The "operating system" field, which sometimes implies an environment, and
 /// sometimes isn't an actual operating system.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Os(pub Cow<'static, str>);
 
 /// Individual target families, which describe a set of targets grouped in some logical manner,
 /// typically by operating system. This includes values like `unix` and `windows`.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Family(pub Cow<'static, str>);
 
 /// The "environment" field, which specifies an ABI environment on top of the
 /// operating system. In many configurations, this field is omitted, and the
 /// environment is implied by the operating system.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Env(pub Cow<'static, str>);
 
 /// The panic strategy used on this target by default.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Panic(pub Cow<'static, str>);
 
 macro_rules! field_impls {
@@ -74,8 +145,8 @@ macro_rules! field_impls {
             }
         }
 
-        impl std::fmt::Display for $kind {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        impl core::fmt::Display for $kind {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 f.write_str(self.as_str())
             }
         }
@@ -103,7 +174,7 @@ pub enum HasAtomic {
     Pointer,
 }
 
-impl std::str::FromStr for HasAtomic {
+impl core::str::FromStr for HasAtomic {
     type Err = HasAtomicParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -119,8 +190,8 @@ impl std::str::FromStr for HasAtomic {
     }
 }
 
-impl std::fmt::Display for HasAtomic {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for HasAtomic {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::IntegerSize(size) => write!(f, "{size}"),
             Self::Pointer => write!(f, "ptr"),
@@ -128,6 +199,28 @@ impl std::fmt::Display for HasAtomic {
     }
 }
 
+/// Serializes as the value used in `target_has_atomic`, eg. `"64"` or `"ptr"`
+#[cfg(feature = "serde")]
+impl serde::Serialize for HasAtomic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HasAtomic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 /// A set of families for a target.
 ///
 /// Each target can be part of one or more families. This struct represents them.
@@ -162,6 +255,52 @@ impl Families {
     pub fn contains(&self, val: &Family) -> bool {
         self.0.contains(val)
     }
+
+    /// The families of a target with the operating system, eg. `linux`, or
+    /// `none` for bare metal targets, and architecture, eg. `wasm32`, looked
+    /// up in [`OS_FAMILIES`], with the `wasm` family for the WebAssembly
+    /// architectures.
+    ///
+    /// ```
+    /// use cfg_expr::targets::{Arch, Families, Family};
+    ///
+    /// assert_eq!(Families::for_target("linux", "x86_64"), Families::unix);
+    /// assert_eq!(Families::for_target("emscripten", "wasm32"), Families::unix_wasm);
+    /// assert_eq!(Families::for_target("wasi", "wasm32"), Families::wasm);
+    /// assert!(Families::for_target("none", "arm").is_empty());
+    /// ```
+    pub fn for_target(os: &str, arch: &str) -> Self {
+        let families = OS_FAMILIES
+            .binary_search_by(|(o, _)| o.as_str().cmp(os))
+            .map_or(Families::new_const(&[]), |i| OS_FAMILIES[i].1.clone());
+
+        if arch == Arch::wasm32.as_str() || arch == Arch::wasm64.as_str() {
+            Self::new(families.iter().cloned().chain(Some(Family::wasm)))
+        } else {
+            families
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Families {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.0.iter())
+    }
+}
+
+/// Deserializes from a sequence of families, which don't need to be sorted
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Families {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(Self::new(Vec::<Family>::deserialize(deserializer)?))
+    }
 }
 
 impl Deref for Families {
@@ -178,8 +317,8 @@ impl AsRef<[Family]> for Families {
     }
 }
 
-impl std::fmt::Display for Families {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for Families {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{{")?;
         let len = self.0.len();
         for (idx, family) in self.0.iter().enumerate() {
@@ -224,6 +363,27 @@ impl HasAtomics {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for HasAtomics {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.0.iter())
+    }
+}
+
+/// Deserializes from a sequence of atomics, which don't need to be sorted
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HasAtomics {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(Self::new(Vec::<HasAtomic>::deserialize(deserializer)?))
+    }
+}
+
 impl Deref for HasAtomics {
     type Target = [HasAtomic];
     fn deref(&self) -> &Self::Target {
@@ -238,8 +398,8 @@ impl AsRef<[HasAtomic]> for HasAtomics {
     }
 }
 
-impl std::fmt::Display for HasAtomics {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for HasAtomics {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{{")?;
         let len = self.0.len();
         for (idx, has_atomic) in self.0.iter().enumerate() {
@@ -291,7 +451,7 @@ macro_rules! impl_from_str {
             )+
         }
     ) => {
-        impl std::str::FromStr for $kind {
+        impl core::str::FromStr for $kind {
             type Err = Reason;
             fn from_str(s: &str) -> Result<Self, Self::Err> {
                 match s {
@@ -306,14 +466,33 @@ macro_rules! impl_from_str {
 target_enum! {
     /// The endian types known to rustc
     #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum Endian {
         big,
         little,
     }
 }
 
+/// How well rustc supports a target, see
+/// <https://doc.rust-lang.org/nightly/rustc/platform-support.html>
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlatformSupport {
+    /// The tier of the target, where tier 1 targets are guaranteed to work,
+    /// tier 2 targets are guaranteed to build, and tier 3 targets have no
+    /// guarantees, and aren't distributed by the Rust project
+    pub tier: u8,
+    /// Whether rustc and cargo are distributed for the target, so that it can
+    /// be used as a host, or `None` if it isn't documented
+    pub host_tools: Option<bool>,
+    /// Whether the target supports `std`, rather than only `core` and maybe
+    /// `alloc`, or `None` if it isn't documented
+    pub std: Option<bool>,
+}
+
 /// Contains information regarding a particular target known to rustc
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TargetInfo {
     /// The target's unique identifier
     pub triple: Triple,
@@ -348,34 +527,412 @@ pub struct TargetInfo {
     /// [target_endian](https://doc.rust-lang.org/reference/conditional-compilation.html#target_endian)
     /// predicate.
     pub endian: Endian,
-    /// The target's support for atomics. Used by the has_target_atomics predicate.
+    /// The target's support for atomics. Used by the `has_target_atomics` predicate.
     pub has_atomics: HasAtomics,
+    /// The sizes for which the target supports atomic loads and stores,
+    /// which can be a superset of [`Self::has_atomics`] on targets without
+    /// compare-and-swap. Used by the
+    /// [target_has_atomic_load_store](https://github.com/rust-lang/rust/issues/94039) predicate.
+    pub has_atomics_load_store: HasAtomics,
+    /// The sizes for which the target's atomic types have the same alignment
+    /// as the corresponding integer types. Used by the
+    /// [target_has_atomic_equal_alignment](https://github.com/rust-lang/rust/issues/93822) predicate.
+    pub has_atomics_equal_alignment: HasAtomics,
     /// The panic strategy used on this target by default. Used by the
     /// [panic](https://doc.rust-lang.org/beta/reference/conditional-compilation.html#panic) predicate.
     pub panic: Panic,
 }
 
-/// Attempts to find the `TargetInfo` for the specified target triple
+impl TargetInfo {
+    /// The target features that rustc enables by default for the target, eg.
+    /// `sse2` for `x86_64-unknown-linux-gnu`, which are used by the
+    /// [target_feature](https://doc.rust-lang.org/reference/conditional-compilation.html#target_feature)
+    /// predicate when no `-C target-feature` or `-C target-cpu` flags are
+    /// passed.
+    ///
+    /// The features are looked up by triple, so this is empty for targets
+    /// that aren't builtin.
+    ///
+    /// ```
+    /// let ti = cfg_expr::targets::get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    /// assert!(ti.default_features().contains(&"fxsr"));
+    /// ```
+    pub fn default_features(&self) -> &'static [&'static str] {
+        features::DEFAULT_FEATURES
+            .binary_search_by(|(triple, _)| (*triple).cmp(self.triple.as_str()))
+            .map_or(&[], |i| features::DEFAULT_FEATURES[i].1)
+    }
+
+    /// Returns true if rustc enables the target feature by default for the
+    /// target, see [`Self::default_features`]
+    ///
+    /// ```
+    /// use cfg_expr::targets::get_builtin_target_by_triple;
+    ///
+    /// assert!(get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap().has_default_feature("sse2"));
+    /// assert!(get_builtin_target_by_triple("aarch64-unknown-linux-gnu").unwrap().has_default_feature("neon"));
+    /// assert!(!get_builtin_target_by_triple("i586-unknown-linux-gnu").unwrap().has_default_feature("sse2"));
+    /// ```
+    pub fn has_default_feature(&self, feature: &str) -> bool {
+        self.default_features().contains(&feature)
+    }
+
+    /// How well rustc supports the target, eg. to only consider the targets
+    /// that the Rust project distributes the standard library for.
+    ///
+    /// The support is looked up by triple, so this is `None` for targets that
+    /// aren't builtin, or that rustc doesn't document the tier of.
+    ///
+    /// ```
+    /// use cfg_expr::targets::{get_builtin_target_by_triple, search};
+    ///
+    /// let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    /// let support = linux.support().unwrap();
+    /// assert_eq!(support.tier, 1);
+    /// assert_eq!(support.host_tools, Some(true));
+    /// assert_eq!(support.std, Some(true));
+    ///
+    /// let shippable: Vec<_> = search()
+    ///     .family("wasm")
+    ///     .filter(|ti| ti.support().map_or(false, |support| support.tier <= 2))
+    ///     .map(|ti| ti.triple.as_str())
+    ///     .collect();
+    ///
+    /// assert!(shippable.contains(&"wasm32-unknown-unknown"));
+    /// ```
+    pub fn support(&self) -> Option<PlatformSupport> {
+        support::SUPPORT
+            .binary_search_by(|(triple, _)| (*triple).cmp(self.triple.as_str()))
+            .ok()
+            .map(|i| support::SUPPORT[i].1)
+    }
+}
+
+/// An owned version of [`TargetInfo`] that uses plain `String`s, for targets
+/// that are created from runtime data, eg. a target spec, and which don't
+/// need to be compared with the builtin targets.
+///
+/// It can be matched against target predicates the same as a [`TargetInfo`].
+///
+/// ```
+/// use cfg_expr::{targets::{get_builtin_target_by_triple, TargetInfoOwned}, Expression, Predicate};
+///
+/// let mut owned = TargetInfoOwned::from(get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap());
+/// owned.os = Some("my-kernel".to_owned());
+///
+/// let expr = Expression::parse(r#"all(target_arch = "x86_64", target_os = "my-kernel")"#).unwrap();
+/// assert!(expr.eval(|pred| match pred {
+///     Predicate::Target(tp) => tp.matches(&owned),
+///     _ => false,
+/// }));
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TargetInfoOwned {
+    /// See [`TargetInfo::triple`]
+    pub triple: String,
+    /// See [`TargetInfo::os`]
+    pub os: Option<String>,
+    /// See [`TargetInfo::abi`]
+    pub abi: Option<String>,
+    /// See [`TargetInfo::arch`]
+    pub arch: String,
+    /// See [`TargetInfo::env`]
+    pub env: Option<String>,
+    /// See [`TargetInfo::vendor`]
+    pub vendor: Option<String>,
+    /// See [`TargetInfo::families`]
+    pub families: Vec<String>,
+    /// See [`TargetInfo::pointer_width`]
+    pub pointer_width: u8,
+    /// See [`TargetInfo::endian`]
+    pub endian: Endian,
+    /// See [`TargetInfo::has_atomics`]
+    pub has_atomics: Vec<HasAtomic>,
+    /// See [`TargetInfo::has_atomics_load_store`]
+    pub has_atomics_load_store: Vec<HasAtomic>,
+    /// See [`TargetInfo::has_atomics_equal_alignment`]
+    pub has_atomics_equal_alignment: Vec<HasAtomic>,
+    /// See [`TargetInfo::panic`]
+    pub panic: String,
+}
+
+impl TargetInfo {
+    /// Copies the target into a [`TargetInfoOwned`]
+    pub fn to_owned_info(&self) -> TargetInfoOwned {
+        TargetInfoOwned::from(self)
+    }
+}
+
+impl<'a> From<&'a TargetInfo> for TargetInfoOwned {
+    fn from(ti: &'a TargetInfo) -> Self {
+        fn owned(val: Option<&impl AsRef<str>>) -> Option<String> {
+            val.map(|val| val.as_ref().to_owned())
+        }
+
+        Self {
+            triple: ti.triple.as_str().to_owned(),
+            os: owned(ti.os.as_ref()),
+            abi: owned(ti.abi.as_ref()),
+            arch: ti.arch.as_str().to_owned(),
+            env: owned(ti.env.as_ref()),
+            vendor: owned(ti.vendor.as_ref()),
+            families: ti
+                .families
+                .iter()
+                .map(|fam| fam.as_str().to_owned())
+                .collect(),
+            pointer_width: ti.pointer_width,
+            endian: ti.endian,
+            has_atomics: ti.has_atomics.to_vec(),
+            has_atomics_load_store: ti.has_atomics_load_store.to_vec(),
+            has_atomics_equal_alignment: ti.has_atomics_equal_alignment.to_vec(),
+            panic: ti.panic.as_str().to_owned(),
+        }
+    }
+}
+
+impl From<TargetInfo> for TargetInfoOwned {
+    fn from(ti: TargetInfo) -> Self {
+        Self::from(&ti)
+    }
+}
+
+/// The strings are moved into the target without being copied
+impl From<TargetInfoOwned> for TargetInfo {
+    fn from(ti: TargetInfoOwned) -> Self {
+        Self {
+            triple: Triple::new(ti.triple),
+            os: ti.os.map(Os::new),
+            abi: ti.abi.map(Abi::new),
+            arch: Arch::new(ti.arch),
+            env: ti.env.map(Env::new),
+            vendor: ti.vendor.map(Vendor::new),
+            families: Families::new(ti.families.into_iter().map(Family::new)),
+            pointer_width: ti.pointer_width,
+            endian: ti.endian,
+            has_atomics: HasAtomics::new(ti.has_atomics),
+            has_atomics_load_store: HasAtomics::new(ti.has_atomics_load_store),
+            has_atomics_equal_alignment: HasAtomics::new(ti.has_atomics_equal_alignment),
+            panic: Panic::new(ti.panic),
+        }
+    }
+}
+
+impl PartialEq<TargetInfo> for TargetInfoOwned {
+    fn eq(&self, other: &TargetInfo) -> bool {
+        fn opt_eq(a: &Option<String>, b: Option<&impl AsRef<str>>) -> bool {
+            a.as_deref() == b.map(|b| b.as_ref())
+        }
+
+        self.triple == other.triple.as_str()
+            && opt_eq(&self.os, other.os.as_ref())
+            && opt_eq(&self.abi, other.abi.as_ref())
+            && self.arch == other.arch.as_str()
+            && opt_eq(&self.env, other.env.as_ref())
+            && opt_eq(&self.vendor, other.vendor.as_ref())
+            && self
+                .families
+                .iter()
+                .map(String::as_str)
+                .eq(other.families.iter().map(Family::as_str))
+            && self.pointer_width == other.pointer_width
+            && self.endian == other.endian
+            && self.has_atomics == *other.has_atomics
+            && self.has_atomics_load_store == *other.has_atomics_load_store
+            && self.has_atomics_equal_alignment == *other.has_atomics_equal_alignment
+            && self.panic == other.panic.as_str()
+    }
+}
+
+impl PartialEq<TargetInfoOwned> for TargetInfo {
+    fn eq(&self, other: &TargetInfoOwned) -> bool {
+        other == self
+    }
+}
+
+#[cfg(feature = "target-lexicon")]
+impl TargetInfo {
+    /// Converts the target to a [`target_lexicon::Triple`], eg. to pass it to
+    /// an object file writer or linker that uses target-lexicon.
+    ///
+    /// The triple is parsed by target-lexicon, which fails for the builtin
+    /// targets that it doesn't know the components of yet.
+    ///
+    /// ```
+    /// use cfg_expr::targets::get_builtin_target_by_triple;
+    /// use target_lexicon::{Architecture, OperatingSystem};
+    ///
+    /// let ti = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    /// let triple = ti.to_triple().unwrap();
+    ///
+    /// assert_eq!(triple.architecture, Architecture::X86_64);
+    /// assert_eq!(triple.operating_system, OperatingSystem::Linux);
+    /// ```
+    pub fn to_triple(&self) -> Result<target_lexicon::Triple, target_lexicon::ParseError> {
+        self.triple.as_str().parse()
+    }
+}
+
+#[cfg(feature = "target-lexicon")]
+impl TryFrom<&TargetInfo> for target_lexicon::Triple {
+    type Error = target_lexicon::ParseError;
+
+    fn try_from(ti: &TargetInfo) -> Result<Self, Self::Error> {
+        ti.to_triple()
+    }
+}
+
+/// Attempts to find the `TargetInfo` for the specified target triple, or, if
+/// it isn't builtin, for the current name of a triple that rustc has renamed,
+/// eg. `wasm32-wasi`, which is now `wasm32-wasip1`, see [`canonical_triple`]
 ///
 /// ```
 /// assert!(cfg_expr::targets::get_builtin_target_by_triple("x86_64-unknown-linux-musl").is_some());
 /// ```
 pub fn get_builtin_target_by_triple(triple: &str) -> Option<&'static TargetInfo> {
+    find_builtin(triple).or_else(|| aliases::renamed(triple).and_then(find_builtin))
+}
+
+fn find_builtin(triple: &str) -> Option<&'static TargetInfo> {
     ALL_BUILTINS
         .binary_search_by(|ti| ti.triple.as_ref().cmp(triple))
         .map(|i| &ALL_BUILTINS[i])
         .ok()
 }
 
+/// Retrieves the current name of the builtin target with the specified
+/// triple, which is the triple itself, unless rustc has renamed it, and the
+/// new name is also builtin, eg. `x86_64-sun-solaris` is now
+/// `x86_64-pc-solaris`.
+///
+/// Returns `None` if neither the triple nor its new name is builtin.
+///
+/// ```
+/// use cfg_expr::targets::canonical_triple;
+///
+/// assert_eq!(canonical_triple("x86_64-sun-solaris"), Some("x86_64-pc-solaris"));
+/// assert_eq!(canonical_triple("x86_64-pc-solaris"), Some("x86_64-pc-solaris"));
+/// assert_eq!(canonical_triple("x86_64-unknown-nope"), None);
+/// ```
+pub fn canonical_triple(triple: &str) -> Option<&'static str> {
+    aliases::renamed(triple)
+        .and_then(find_builtin)
+        .or_else(|| find_builtin(triple))
+        .map(|ti| ti.triple.as_str())
+}
+
+/// The version of rustc for which the built-in targets were retrieved from.
+/// Targets may be added and removed between different rustc versions.
+pub const RUSTC_VERSION: &str = builtins::RUSTC_VERSION;
+
 /// Retrieves the version of rustc for which the built-in targets were
-/// retrieved from. Targets may be added and removed between different rustc
-/// versions.
+/// retrieved from, see [`RUSTC_VERSION`].
 ///
 /// ```
-/// assert_eq!("1.72.0", cfg_expr::targets::rustc_version());
+/// assert_eq!("1.95.0", cfg_expr::targets::rustc_version());
+/// assert_eq!(cfg_expr::targets::RUSTC_VERSION, cfg_expr::targets::rustc_version());
 /// ```
 pub fn rustc_version() -> &'static str {
-    builtins::RUSTC_VERSION
+    RUSTC_VERSION
+}
+
+/// Compares the [builtin](ALL_BUILTINS) targets with the targets of the
+/// rustc at the path, which is looked up in `PATH` if it is only a file name,
+/// eg. in CI to catch when the builtin targets are out of date with the
+/// compiler that is used, see [`rustc::Rustc::verify`].
+///
+/// ```no_run
+/// let report = cfg_expr::targets::verify_against_rustc("rustc").unwrap();
+///
+/// for triple in &report.missing {
+///     println!("{triple} isn't a builtin target of cfg-expr");
+/// }
+/// for mismatch in &report.mismatched {
+///     println!(
+///         "{}: {} is {:?}, but rustc {} sets {:?}",
+///         mismatch.triple, mismatch.key, mismatch.expected, report.rustc_version, mismatch.actual
+///     );
+/// }
+/// ```
+#[cfg(feature = "rustc")]
+pub fn verify_against_rustc(
+    path: impl Into<std::path::PathBuf>,
+) -> Result<rustc::VerifyReport, crate::error::RustcError> {
+    rustc::Rustc::new(path).verify(ALL_BUILTINS)
+}
+
+/// Expands the specified target features for an arch with the features that
+/// they imply, eg. `avx2` implies `avx`, which implies `sse4.2`, and so on.
+/// The result is the sorted, deduplicated transitive closure, including the
+/// specified features.
+///
+/// The implications are a snapshot from the same version of rustc as the
+/// builtin targets, features that aren't known for the arch only expand to
+/// themselves.
+///
+/// ```
+/// use cfg_expr::targets::{expand_features, Arch};
+///
+/// let features = expand_features(&Arch::x86_64, &["avx2"]);
+/// assert!(features.contains(&"avx2"));
+/// assert!(features.contains(&"avx"));
+/// assert!(features.contains(&"sse4.2"));
+///
+/// assert_eq!(expand_features(&Arch::x86_64, &["not-a-feature"]), ["not-a-feature"]);
+/// ```
+pub fn expand_features<'a>(arch: &Arch, features: &[&'a str]) -> Vec<&'a str> {
+    let implied = features::IMPLIED_FEATURES
+        .binary_search_by(|(a, _)| (*a).cmp(arch.as_str()))
+        .map_or(&[][..], |i| features::IMPLIED_FEATURES[i].1);
+
+    let mut expanded = features.to_vec();
+    for feature in features {
+        if let Ok(i) = implied.binary_search_by(|(f, _)| f.cmp(feature)) {
+            expanded.extend_from_slice(implied[i].1);
+        }
+    }
+
+    expanded.sort_unstable();
+    expanded.dedup();
+    expanded
+}
+
+/// Queries the rustc at the specified path for the information of every
+/// target it supports, as opposed to the builtin targets, which are a
+/// snapshot of a specific rustc version.
+///
+/// The targets are returned as [`TargetInfoOwned`]s, since they are only
+/// known at runtime, which can be matched against directly, or converted
+/// into [`TargetInfo`]s without copying their values, eg. to
+/// [register](TargetRegistry::register) them.
+///
+/// Targets that rustc can't print the cfgs for in the current environment,
+/// eg. Apple targets when the SDK isn't installed, are skipped.
+///
+/// ```no_run
+/// let targets = cfg_expr::targets::from_rustc("rustc").unwrap();
+/// assert!(targets.iter().any(|ti| ti.triple == "x86_64-unknown-linux-gnu"));
+/// ```
+#[cfg(feature = "rustc")]
+pub fn from_rustc(
+    rustc: impl Into<std::path::PathBuf>,
+) -> Result<Vec<TargetInfoOwned>, crate::error::RustcError> {
+    let rustc = rustc::Rustc::new(rustc);
+
+    let mut targets = Vec::new();
+    for triple in rustc.target_list()? {
+        match rustc.target_info(&triple) {
+            Ok(ti) => targets.push(TargetInfoOwned::from(ti)),
+            Err(crate::error::RustcError::Failed { .. }) => {}
+            Err(err) => return Err(err),
+        }
+    }
+
+    // Keep the same order as the builtins so that they can be searched
+    targets.sort_unstable_by(|a, b| a.triple.cmp(&b.triple));
+
+    Ok(targets)
 }
 
 #[cfg(test)]
@@ -425,4 +982,293 @@ mod test {
 
         assert_eq!(a, b);
     }
+
+    #[test]
+    fn registry_overrides_builtins() {
+        use super::{Arch, TargetRegistry, Triple, ALL_BUILTINS};
+
+        let mut registry = TargetRegistry::new();
+        assert_eq!(registry.len(), ALL_BUILTINS.len());
+
+        let mut linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu")
+            .unwrap()
+            .clone();
+        linux.arch = Arch::new("x86_64_custom");
+        assert!(registry.register(linux.clone()).is_none());
+
+        let mut first = linux.clone();
+        first.triple = Triple::new("0-first");
+        let mut last = linux.clone();
+        last.triple = Triple::new("zzz-last");
+        registry.extend([first, last]);
+
+        assert!(registry.is_custom("x86_64-unknown-linux-gnu"));
+        assert_eq!(
+            registry
+                .get_target_by_triple("x86_64-unknown-linux-gnu")
+                .unwrap()
+                .arch,
+            linux.arch
+        );
+        assert_eq!(registry.len(), ALL_BUILTINS.len() + 2);
+
+        let triples: Vec<_> = registry.iter().map(|ti| ti.triple.as_str()).collect();
+        assert_eq!(triples.len(), registry.len());
+        assert_eq!(triples[0], "0-first");
+        assert_eq!(triples[triples.len() - 1], "zzz-last");
+        assert!(triples.windows(2).all(|w| w[0] < w[1]));
+
+        assert!(registry.unregister("x86_64-unknown-linux-gnu").is_some());
+        assert_eq!(
+            registry.get_target_by_triple("x86_64-unknown-linux-gnu"),
+            get_builtin_target_by_triple("x86_64-unknown-linux-gnu")
+        );
+
+        let registry = TargetRegistry::empty();
+        assert!(registry.is_empty());
+        assert!(registry
+            .get_target_by_triple("x86_64-unknown-linux-gnu")
+            .is_none());
+    }
+
+    #[test]
+    fn builder_matches_builtins() {
+        use super::{TargetInfo, ALL_BUILTINS};
+        use crate::error::TargetInfoError;
+        use alloc::borrow::Cow;
+
+        for builtin in ALL_BUILTINS {
+            let mut builder = TargetInfo::builder(builtin.triple.as_str())
+                .arch(builtin.arch.as_str())
+                .os(builtin.os.as_ref().map(|os| os.as_str()))
+                .abi(builtin.abi.as_ref().map(|abi| abi.as_str()))
+                .env(builtin.env.as_ref().map(|env| env.as_str()))
+                .vendor(builtin.vendor.as_ref().map(|ven| ven.as_str()))
+                .pointer_width(builtin.pointer_width)
+                .endian(builtin.endian)
+                .has_atomics(builtin.has_atomics.iter().copied())
+                .has_atomics_load_store(builtin.has_atomics_load_store.iter().copied())
+                .has_atomics_equal_alignment(builtin.has_atomics_equal_alignment.iter().copied())
+                .panic(builtin.panic.as_str());
+
+            for family in builtin.families.iter() {
+                builder = builder.family(family.as_str());
+            }
+
+            let ti = builder.build().unwrap();
+            assert_eq!(&ti, builtin);
+
+            // Every value is known, and so is borrowed from the builtins
+            assert!(matches!(ti.triple.0, Cow::Borrowed(_)));
+            assert!(matches!(ti.arch.0, Cow::Borrowed(_)));
+        }
+
+        assert_eq!(
+            TargetInfo::builder("x").pointer_width(64).build(),
+            Err(TargetInfoError::MissingField("arch"))
+        );
+        assert_eq!(
+            TargetInfo::builder("x")
+                .arch("x86_64")
+                .pointer_width(128)
+                .build(),
+            Err(TargetInfoError::InvalidPointerWidth(128))
+        );
+        assert_eq!(
+            TargetInfo::builder("x")
+                .arch("x86_64")
+                .os(Some("linuxx"))
+                .pointer_width(64)
+                .build(),
+            Err(TargetInfoError::UnknownValue {
+                field: "os",
+                value: "linuxx".to_owned(),
+                closest: Some("linux"),
+            })
+        );
+
+        let ti = TargetInfo::builder("x86_64-unknown-linuxx")
+            .arch("x86_64")
+            .os(Some("linuxx"))
+            .pointer_width(64)
+            .validate(false)
+            .build()
+            .unwrap();
+        assert!(matches!(ti.os.unwrap().0, Cow::Owned(_)));
+    }
+
+    #[test]
+    fn owned_round_trips() {
+        use super::{TargetInfo, ALL_BUILTINS};
+
+        for builtin in ALL_BUILTINS {
+            let owned = builtin.to_owned_info();
+            assert_eq!(&owned, builtin);
+            assert_eq!(&TargetInfo::from(owned), builtin);
+        }
+    }
+
+    #[test]
+    fn default_features_are_sorted() {
+        use super::features::{DEFAULT_FEATURES, IMPLIED_FEATURES};
+
+        for window in DEFAULT_FEATURES.windows(2) {
+            assert!(window[0].0 < window[1].0);
+        }
+
+        for (triple, features) in DEFAULT_FEATURES {
+            assert!(get_builtin_target_by_triple(triple).is_some(), "{triple}");
+            assert!(features.windows(2).all(|w| w[0] < w[1]), "{triple}");
+        }
+
+        // The implications are searched by arch and feature
+        for window in IMPLIED_FEATURES.windows(2) {
+            assert!(window[0].0 < window[1].0);
+        }
+
+        for (arch, implied) in IMPLIED_FEATURES {
+            assert!(implied.windows(2).all(|w| w[0].0 < w[1].0), "{arch}");
+        }
+    }
+
+    #[test]
+    fn renames_are_sorted() {
+        use super::aliases::{renamed, RENAMED};
+
+        for window in RENAMED.windows(2) {
+            assert!(window[0].0 < window[1].0);
+        }
+
+        for (old, new) in RENAMED {
+            assert_eq!(renamed(old), Some(*new));
+            assert_eq!(renamed(new), None, "{new} was renamed again");
+        }
+
+        // The old names resolve to the current target once they are removed
+        for (old, new) in [
+            ("aarch64-fuchsia", "aarch64-unknown-fuchsia"),
+            ("asmjs-unknown-emscripten", "wasm32-unknown-emscripten"),
+            ("avr-unknown-gnu-atmega328", "avr-none"),
+            ("i586-pc-windows-msvc", "i686-pc-windows-msvc"),
+            ("x86_64-sun-solaris", "x86_64-pc-solaris"),
+        ] {
+            assert_eq!(super::canonical_triple(old), Some(new));
+            assert_eq!(super::canonical_triple(new), Some(new));
+        }
+    }
+
+    #[cfg(feature = "targets")]
+    #[test]
+    fn guesses_builtins() {
+        // The builtin targets whose components don't follow rustc's naming
+        // conventions, so they can't be guessed from their triple
+        const EXCEPTIONS: &[&str] = &[
+            // The os is `horizon`
+            "aarch64-nintendo-switch-freestanding",
+            "armv6k-nintendo-3ds",
+            // The os is `vexos`
+            "armv7a-vex-v5",
+            // The vendor is `unknown`
+            "wasm32-wali-linux-musl",
+            "x86_64-lynx-lynxos178",
+        ];
+
+        let wrong: Vec<_> = super::ALL_BUILTINS
+            .iter()
+            .filter(|ti| {
+                let guess = super::guess::guess(ti.triple.as_str()).unwrap();
+
+                guess.arch != ti.arch
+                    || guess.os != ti.os
+                    || guess.vendor != ti.vendor
+                    || guess.pointer_width != ti.pointer_width
+                    || guess.endian != ti.endian
+                    || guess.families != ti.families
+            })
+            .map(|ti| ti.triple.as_str())
+            .collect();
+
+        assert_eq!(wrong, EXCEPTIONS);
+    }
+
+    #[test]
+    fn os_families_match_builtins() {
+        use super::{Families, OS_FAMILIES};
+
+        for window in OS_FAMILIES.windows(2) {
+            assert!(window[0].0 < window[1].0);
+        }
+
+        for ti in super::ALL_BUILTINS {
+            let os = ti.os.as_ref().map_or("none", |os| os.as_str());
+            let families = Families::for_target(os, ti.arch.as_str());
+
+            // The freestanding Switch target isn't unix, unlike the other
+            // horizon target
+            if ti.triple.as_str() != "aarch64-nintendo-switch-freestanding" {
+                assert_eq!(families, ti.families, "{}", ti.triple);
+            }
+        }
+    }
+
+    #[test]
+    fn support_is_sorted() {
+        use super::support::SUPPORT;
+
+        for window in SUPPORT.windows(2) {
+            assert!(window[0].0 < window[1].0);
+        }
+
+        for (triple, support) in SUPPORT {
+            assert!(get_builtin_target_by_triple(triple).is_some(), "{triple}");
+            assert!((1..=3).contains(&support.tier), "{triple}");
+        }
+    }
+
+    #[test]
+    fn known_values_cover_builtins() {
+        use super::*;
+
+        fn check<T: Ord + core::fmt::Debug>(known: &[T], used: impl Iterator<Item = T>) {
+            assert!(known.windows(2).all(|w| w[0] < w[1]));
+
+            for val in used {
+                assert!(known.binary_search(&val).is_ok(), "{val:?}");
+            }
+        }
+
+        check(
+            Abi::ALL_KNOWN,
+            ALL_BUILTINS.iter().filter_map(|ti| ti.abi.clone()),
+        );
+        check(
+            Arch::ALL_KNOWN,
+            ALL_BUILTINS.iter().map(|ti| ti.arch.clone()),
+        );
+        check(
+            Vendor::ALL_KNOWN,
+            ALL_BUILTINS.iter().filter_map(|ti| ti.vendor.clone()),
+        );
+        check(
+            Os::ALL_KNOWN,
+            ALL_BUILTINS.iter().filter_map(|ti| ti.os.clone()),
+        );
+        check(
+            Family::ALL_KNOWN,
+            ALL_BUILTINS
+                .iter()
+                .flat_map(|ti| ti.families.iter().cloned()),
+        );
+        check(
+            Env::ALL_KNOWN,
+            ALL_BUILTINS.iter().filter_map(|ti| ti.env.clone()),
+        );
+        check(
+            Panic::ALL_KNOWN,
+            ALL_BUILTINS.iter().map(|ti| ti.panic.clone()),
+        );
+
+        // Bare metal targets don't have an os, which is matched by `target_os = "none"`
+        assert!(Os::ALL_KNOWN.contains(&Os::none));
+    }
 }
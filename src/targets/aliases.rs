@@ -0,0 +1,22 @@
+/// The triples that rustc has renamed, or replaced with another target, and
+/// their current names, sorted by the old triple. Targets that were removed
+/// without a replacement, eg. `armv7-apple-ios`, aren't included.
+pub(crate) const RENAMED: &[(&str, &str)] = &[
+    ("aarch64-fuchsia", "aarch64-unknown-fuchsia"),
+    ("asmjs-unknown-emscripten", "wasm32-unknown-emscripten"),
+    ("avr-unknown-gnu-atmega328", "avr-none"),
+    ("i586-pc-nto-qnx700", "i686-pc-nto-qnx700"),
+    ("i586-pc-windows-msvc", "i686-pc-windows-msvc"),
+    ("wasm32-wasi", "wasm32-wasip1"),
+    ("wasm32-wasi-preview1-threads", "wasm32-wasip1-threads"),
+    ("x86_64-fuchsia", "x86_64-unknown-fuchsia"),
+    ("x86_64-sun-solaris", "x86_64-pc-solaris"),
+];
+
+/// Returns the current name of a triple that rustc has renamed
+pub(crate) fn renamed(triple: &str) -> Option<&'static str> {
+    RENAMED
+        .binary_search_by(|(old, _)| (*old).cmp(triple))
+        .ok()
+        .map(|i| RENAMED[i].1)
+}
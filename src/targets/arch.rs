@@ -0,0 +1,79 @@
+use super::*;
+
+/// The prefixes of the architecture component of triples, ie. the
+/// sub-architectures, and the architecture rustc uses for `target_arch`. The
+/// longest prefix that matches is used, eg. `arm64ec` before `arm64`
+const SUB_ARCHES: &[(&str, Arch)] = &[
+    ("aarch64", Arch::aarch64),
+    ("arm", Arch::arm),
+    ("arm64", Arch::aarch64),
+    ("arm64ec", Arch::new_const("arm64ec")),
+    ("asmjs", Arch::wasm32),
+    ("bpf", Arch::bpf),
+    ("i386", Arch::x86),
+    ("i486", Arch::x86),
+    ("i586", Arch::x86),
+    ("i686", Arch::x86),
+    ("mips", Arch::mips),
+    ("mips64", Arch::mips64),
+    ("mipsisa32r6", Arch::mips32r6),
+    ("mipsisa64r6", Arch::mips64r6),
+    ("powerpc", Arch::powerpc),
+    ("powerpc64", Arch::powerpc64),
+    ("riscv32", Arch::riscv32),
+    ("riscv64", Arch::riscv64),
+    ("sparc", Arch::sparc),
+    ("sparc64", Arch::sparc64),
+    ("sparcv9", Arch::sparc64),
+    ("thumb", Arch::arm),
+    ("wasm32", Arch::wasm32),
+    ("wasm64", Arch::wasm64),
+    ("x86_64", Arch::x86_64),
+];
+
+impl Arch {
+    /// Maps the architecture component of a triple, eg. `thumbv7em`,
+    /// `armebv7r`, or `riscv32imac`, to the architecture that rustc uses for
+    /// `target_arch`, eg. `arm` or `riscv32`.
+    ///
+    /// Components that aren't a known sub-architecture are returned if they
+    /// are a [known](Arch::ALL_KNOWN) architecture, or the architecture of the
+    /// builtin targets with the same component, and `None` otherwise.
+    ///
+    /// ```
+    /// use cfg_expr::targets::Arch;
+    ///
+    /// assert_eq!(Arch::from_sub_arch("thumbv7em"), Some(Arch::arm));
+    /// assert_eq!(Arch::from_sub_arch("armebv7r"), Some(Arch::arm));
+    /// assert_eq!(Arch::from_sub_arch("riscv64gc"), Some(Arch::riscv64));
+    /// assert_eq!(Arch::from_sub_arch("i686"), Some(Arch::x86));
+    /// assert_eq!(Arch::from_sub_arch("arm64e"), Some(Arch::aarch64));
+    /// assert_eq!(Arch::from_sub_arch("m68k"), Some(Arch::m68k));
+    /// assert_eq!(Arch::from_sub_arch("z80"), None);
+    /// ```
+    pub fn from_sub_arch(sub_arch: &str) -> Option<Arch> {
+        if let Some((_, arch)) = SUB_ARCHES
+            .iter()
+            .filter(|(prefix, _)| sub_arch.starts_with(prefix))
+            .max_by_key(|(prefix, _)| prefix.len())
+        {
+            return Some(arch.clone());
+        }
+
+        Arch::ALL_KNOWN
+            .iter()
+            .find(|arch| arch.as_str() == sub_arch)
+            .or_else(|| {
+                ALL_BUILTINS
+                    .iter()
+                    .find(|ti| {
+                        ti.triple
+                            .as_str()
+                            .strip_prefix(sub_arch)
+                            .map_or(false, |rest| rest.starts_with('-'))
+                    })
+                    .map(|ti| &ti.arch)
+            })
+            .cloned()
+    }
+}
@@ -0,0 +1,259 @@
+use super::*;
+use crate::{
+    error::TargetInfoError,
+    expr::{known_values, Which},
+};
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+
+/// Builds a [`TargetInfo`], validating that the values of its fields are
+/// [known](Arch::ALL_KNOWN), ie. used by at least one of the
+/// [builtin](ALL_BUILTINS) targets or documented in the reference, which can
+/// be disabled with [`Self::validate`] for targets that use new values.
+///
+/// Values that are known are borrowed from the known values, so only
+/// unknown values are allocated.
+///
+/// ```
+/// use cfg_expr::targets::{Arch, Endian, HasAtomic, TargetInfo};
+///
+/// let ti = TargetInfo::builder("riscv32imc-acme-none")
+///     .arch("riscv32")
+///     .os(None)
+///     .vendor(Some("acme"))
+///     .pointer_width(32)
+///     .has_atomics([HasAtomic::IntegerSize(8), HasAtomic::IntegerSize(16)])
+///     .panic("abort")
+///     .validate(false)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(ti.arch, Arch::riscv32);
+/// assert_eq!(ti.endian, Endian::little);
+///
+/// let err = TargetInfo::builder("x86_64-unknown-linux-gnu")
+///     .arch("x86-64")
+///     .pointer_width(64)
+///     .build()
+///     .unwrap_err();
+///
+/// assert_eq!(err.to_string(), "unknown arch \"x86-64\", did you mean `x86_64`?");
+/// ```
+#[derive(Clone, Debug)]
+pub struct TargetInfoBuilder {
+    triple: String,
+    arch: Option<String>,
+    os: Option<String>,
+    abi: Option<String>,
+    env: Option<String>,
+    vendor: Option<String>,
+    families: Vec<String>,
+    pointer_width: Option<u8>,
+    endian: Endian,
+    has_atomics: Vec<HasAtomic>,
+    has_atomics_load_store: Option<Vec<HasAtomic>>,
+    has_atomics_equal_alignment: Option<Vec<HasAtomic>>,
+    panic: String,
+    validate: bool,
+}
+
+impl TargetInfo {
+    /// Creates a [`TargetInfoBuilder`] for a target with the specified triple
+    pub fn builder(triple: &str) -> TargetInfoBuilder {
+        TargetInfoBuilder::new(triple)
+    }
+}
+
+impl TargetInfoBuilder {
+    /// Creates a builder for a target with the specified triple. The
+    /// architecture and pointer width must be set, the other fields default
+    /// to those of a little endian target without an OS, environment or
+    /// atomics, which unwinds on panic.
+    pub fn new(triple: &str) -> Self {
+        Self {
+            triple: triple.to_owned(),
+            arch: None,
+            os: None,
+            abi: None,
+            env: None,
+            vendor: None,
+            families: Vec::new(),
+            pointer_width: None,
+            endian: Endian::little,
+            has_atomics: Vec::new(),
+            has_atomics_load_store: None,
+            has_atomics_equal_alignment: None,
+            panic: "unwind".to_owned(),
+            validate: true,
+        }
+    }
+
+    /// Sets the CPU architecture, eg. `x86_64`
+    pub fn arch(mut self, arch: &str) -> Self {
+        self.arch = Some(arch.to_owned());
+        self
+    }
+
+    /// Sets the operating system, eg. `linux`. `none` is the same as `None`.
+    pub fn os(mut self, os: Option<&str>) -> Self {
+        self.os = os.filter(|os| *os != "none").map(str::to_owned);
+        self
+    }
+
+    /// Sets the ABI, eg. `eabihf`. An empty string is the same as `None`.
+    pub fn abi(mut self, abi: Option<&str>) -> Self {
+        self.abi = abi.filter(|abi| !abi.is_empty()).map(str::to_owned);
+        self
+    }
+
+    /// Sets the environment, eg. `gnu`. An empty string is the same as `None`.
+    pub fn env(mut self, env: Option<&str>) -> Self {
+        self.env = env.filter(|env| !env.is_empty()).map(str::to_owned);
+        self
+    }
+
+    /// Sets the vendor, eg. `apple`
+    pub fn vendor(mut self, vendor: Option<&str>) -> Self {
+        self.vendor = vendor.map(str::to_owned);
+        self
+    }
+
+    /// Adds a family, eg. `unix`
+    pub fn family(mut self, family: &str) -> Self {
+        self.families.push(family.to_owned());
+        self
+    }
+
+    /// Sets the size of pointers, in bits
+    pub fn pointer_width(mut self, pointer_width: u8) -> Self {
+        self.pointer_width = Some(pointer_width);
+        self
+    }
+
+    /// Sets the endianness
+    pub fn endian(mut self, endian: Endian) -> Self {
+        self.endian = endian;
+        self
+    }
+
+    /// Sets the sizes that support atomic operations, which are also used for
+    /// the sizes that support atomic loads and stores, and that have equal
+    /// alignment, unless they are set separately
+    pub fn has_atomics(mut self, has_atomics: impl IntoIterator<Item = HasAtomic>) -> Self {
+        self.has_atomics = has_atomics.into_iter().collect();
+        self
+    }
+
+    /// Sets the sizes that support atomic loads and stores
+    pub fn has_atomics_load_store(
+        mut self,
+        has_atomics: impl IntoIterator<Item = HasAtomic>,
+    ) -> Self {
+        self.has_atomics_load_store = Some(has_atomics.into_iter().collect());
+        self
+    }
+
+    /// Sets the sizes whose atomic types have the same alignment as the
+    /// corresponding integer types
+    pub fn has_atomics_equal_alignment(
+        mut self,
+        has_atomics: impl IntoIterator<Item = HasAtomic>,
+    ) -> Self {
+        self.has_atomics_equal_alignment = Some(has_atomics.into_iter().collect());
+        self
+    }
+
+    /// Sets the default panic strategy, eg. `abort`
+    pub fn panic(mut self, panic: &str) -> Self {
+        self.panic = panic.to_owned();
+        self
+    }
+
+    /// Sets whether the values of the fields are validated against the known
+    /// values, which defaults to true
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Retrieves the known value equal to the specified value, so that it
+    /// can be borrowed, or an error if validation is enabled
+    fn value(
+        &self,
+        field: &'static str,
+        which: Which,
+        value: String,
+    ) -> Result<Cow<'static, str>, TargetInfoError> {
+        let known = match known_values(&which) {
+            Some(known) => known,
+            None => return Ok(Cow::Owned(value)),
+        };
+
+        match known.find(&value) {
+            Some(known) => Ok(Cow::Borrowed(known)),
+            None if self.validate => Err(TargetInfoError::UnknownValue {
+                field,
+                closest: known.closest(&value),
+                value,
+            }),
+            None => Ok(Cow::Owned(value)),
+        }
+    }
+
+    /// Builds the target, failing if a required field wasn't set, or if a
+    /// value isn't known and validation is enabled
+    pub fn build(self) -> Result<TargetInfo, TargetInfoError> {
+        let arch = self
+            .arch
+            .clone()
+            .ok_or(TargetInfoError::MissingField("arch"))?;
+        let pointer_width = self
+            .pointer_width
+            .ok_or(TargetInfoError::MissingField("pointer width"))?;
+
+        if self.validate && ![16, 32, 64].contains(&pointer_width) {
+            return Err(TargetInfoError::InvalidPointerWidth(pointer_width));
+        }
+
+        let triple = match get_builtin_target_by_triple(&self.triple) {
+            Some(builtin) => builtin.triple.clone(),
+            None => Triple::new(self.triple.clone()),
+        };
+
+        let optional = |field, which, value: Option<String>| {
+            value
+                .map(|value| self.value(field, which, value))
+                .transpose()
+        };
+
+        let families = self
+            .families
+            .iter()
+            .map(|fam| self.value("family", Which::Family, fam.clone()).map(Family))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let has_atomics_load_store = self
+            .has_atomics_load_store
+            .clone()
+            .unwrap_or_else(|| self.has_atomics.clone());
+        let has_atomics_equal_alignment = self
+            .has_atomics_equal_alignment
+            .clone()
+            .unwrap_or_else(|| self.has_atomics.clone());
+
+        Ok(TargetInfo {
+            triple,
+            os: optional("os", Which::Os, self.os.clone())?.map(Os),
+            abi: optional("abi", Which::Abi, self.abi.clone())?.map(Abi),
+            arch: Arch(self.value("arch", Which::Arch, arch)?),
+            env: optional("env", Which::Env, self.env.clone())?.map(Env),
+            vendor: optional("vendor", Which::Vendor, self.vendor.clone())?.map(Vendor),
+            families: Families::new(families),
+            pointer_width,
+            endian: self.endian,
+            has_atomics: HasAtomics::new(self.has_atomics.iter().copied()),
+            has_atomics_load_store: HasAtomics::new(has_atomics_load_store),
+            has_atomics_equal_alignment: HasAtomics::new(has_atomics_equal_alignment),
+            panic: Panic(self.value("panic", Which::Panic, self.panic.clone())?),
+        })
+    }
+}
@@ -10,7 +10,7 @@
 
 use super::*;
 
-pub(crate) const RUSTC_VERSION: &str = "1.72.0";
+pub(crate) const RUSTC_VERSION: &str = "1.95.0";
 
 pub const ALL_BUILTINS: &[TargetInfo] = &[
     TargetInfo {
@@ -24,6 +24,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -37,32 +39,38 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-apple-ios-macabi"),
         os: Some(Os::ios),
-        abi: None,
+        abi: Some(Abi::macabi),
         arch: Arch::aarch64,
-        env: None,
+        env: Some(Env::macabi),
         vendor: Some(Vendor::apple),
         families: Families::unix,
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-apple-ios-sim"),
         os: Some(Os::ios),
-        abi: None,
+        abi: Some(Abi::sim),
         arch: Arch::aarch64,
-        env: None,
+        env: Some(Env::sim),
         vendor: Some(Vendor::apple),
         families: Families::unix,
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -76,11 +84,28 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
-        triple: Triple::new_const("aarch64-apple-watchos-sim"),
-        os: Some(Os::watchos),
+        triple: Triple::new_const("aarch64-apple-tvos-sim"),
+        os: Some(Os::tvos),
+        abi: Some(Abi::sim),
+        arch: Arch::aarch64,
+        env: Some(Env::sim),
+        vendor: Some(Vendor::apple),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-apple-visionos"),
+        os: Some(Os::visionos),
         abi: None,
         arch: Arch::aarch64,
         env: None,
@@ -89,19 +114,53 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
-        triple: Triple::new_const("aarch64-fuchsia"),
-        os: Some(Os::fuchsia),
+        triple: Triple::new_const("aarch64-apple-visionos-sim"),
+        os: Some(Os::visionos),
+        abi: Some(Abi::sim),
+        arch: Arch::aarch64,
+        env: Some(Env::sim),
+        vendor: Some(Vendor::apple),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-apple-watchos"),
+        os: Some(Os::watchos),
         abi: None,
         arch: Arch::aarch64,
         env: None,
-        vendor: Some(Vendor::unknown),
+        vendor: Some(Vendor::apple),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-apple-watchos-sim"),
+        os: Some(Os::watchos),
+        abi: Some(Abi::sim),
+        arch: Arch::aarch64,
+        env: Some(Env::sim),
+        vendor: Some(Vendor::apple),
         families: Families::unix,
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -115,6 +174,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -128,6 +189,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -136,17 +199,19 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         abi: None,
         arch: Arch::aarch64,
         env: None,
-        vendor: Some(Vendor::unknown),
+        vendor: Some(Vendor::nintendo),
         families: Families::new_const(&[]),
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-pc-windows-gnullvm"),
         os: Some(Os::windows),
-        abi: None,
+        abi: Some(Abi::llvm),
         arch: Arch::aarch64,
         env: Some(Env::gnu),
         vendor: Some(Vendor::pc),
@@ -154,6 +219,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -167,6 +234,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -180,6 +249,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -193,8 +264,25 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-helenos"),
+        os: Some(Os::helenos),
+        abi: None,
+        arch: Arch::aarch64,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::abort,
+    },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-hermit"),
         os: Some(Os::hermit),
@@ -206,8 +294,25 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::abort,
     },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-illumos"),
+        os: Some(Os::illumos),
+        abi: None,
+        arch: Arch::aarch64,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+    },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-linux-gnu"),
         os: Some(Os::linux),
@@ -219,12 +324,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-linux-gnu_ilp32"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::ilp32),
         arch: Arch::aarch64,
         env: Some(Env::gnu),
         vendor: Some(Vendor::unknown),
@@ -232,6 +339,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -245,6 +354,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -258,6 +369,23 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-managarm-mlibc"),
+        os: Some(Os::managarm),
+        abi: None,
+        arch: Arch::aarch64,
+        env: Some(Env::mlibc),
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -271,6 +399,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -284,12 +414,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-none-softfloat"),
         os: None,
-        abi: None,
+        abi: Some(Abi::softfloat),
         arch: Arch::aarch64,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -297,8 +429,25 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::abort,
     },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-nto-qnx700"),
+        os: Some(Os::nto),
+        abi: None,
+        arch: Arch::aarch64,
+        env: Some(Env::nto70),
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+    },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-nto-qnx710"),
         os: Some(Os::nto),
@@ -310,8 +459,55 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-nto-qnx710_iosock"),
+        os: Some(Os::nto),
+        abi: None,
+        arch: Arch::aarch64,
+        env: Some(Env::nto71_iosock),
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-nto-qnx800"),
+        os: Some(Os::nto),
+        abi: None,
+        arch: Arch::aarch64,
+        env: Some(Env::nto80),
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-nuttx"),
+        os: Some(Os::nuttx),
+        abi: None,
+        arch: Arch::aarch64,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::abort,
+    },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-openbsd"),
         os: Some(Os::openbsd),
@@ -323,6 +519,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -336,8 +534,40 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-teeos"),
+        os: Some(Os::teeos),
+        abi: None,
+        arch: Arch::aarch64,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-trusty"),
+        os: Some(Os::trusty),
+        abi: None,
+        arch: Arch::aarch64,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::abort,
+    },
     TargetInfo {
         triple: Triple::new_const("aarch64-unknown-uefi"),
         os: Some(Os::uefi),
@@ -349,12 +579,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
         triple: Triple::new_const("aarch64-uwp-windows-msvc"),
         os: Some(Os::windows),
-        abi: None,
+        abi: Some(Abi::uwp),
         arch: Arch::aarch64,
         env: Some(Env::msvc),
         vendor: Some(Vendor::uwp),
@@ -362,6 +594,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -375,8 +609,25 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
+    TargetInfo {
+        triple: Triple::new_const("aarch64_be-unknown-hermit"),
+        os: Some(Os::hermit),
+        abi: None,
+        arch: Arch::aarch64,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::abort,
+    },
     TargetInfo {
         triple: Triple::new_const("aarch64_be-unknown-linux-gnu"),
         os: Some(Os::linux),
@@ -388,12 +639,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("aarch64_be-unknown-linux-gnu_ilp32"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::ilp32),
         arch: Arch::aarch64,
         env: Some(Env::gnu),
         vendor: Some(Vendor::unknown),
@@ -401,6 +654,23 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64_be-unknown-linux-musl"),
+        os: Some(Os::linux),
+        abi: None,
+        arch: Arch::aarch64,
+        env: Some(Env::musl),
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -414,12 +684,74 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
+    TargetInfo {
+        triple: Triple::new_const("aarch64_be-unknown-none-softfloat"),
+        os: None,
+        abi: Some(Abi::softfloat),
+        arch: Arch::aarch64,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64v8r-unknown-none"),
+        os: None,
+        abi: None,
+        arch: Arch::aarch64,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64v8r-unknown-none-softfloat"),
+        os: None,
+        abi: Some(Abi::softfloat),
+        arch: Arch::aarch64,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("amdgcn-amd-amdhsa"),
+        os: Some(Os::amdhsa),
+        abi: None,
+        arch: Arch::amdgpu,
+        env: None,
+        vendor: Some(Vendor::amd),
+        families: Families::new_const(&[]),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
     TargetInfo {
         triple: Triple::new_const("arm-linux-androideabi"),
         os: Some(Os::android),
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -427,12 +759,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("arm-unknown-linux-gnueabi"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: Some(Env::gnu),
         vendor: Some(Vendor::unknown),
@@ -440,12 +774,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("arm-unknown-linux-gnueabihf"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::eabihf),
         arch: Arch::arm,
         env: Some(Env::gnu),
         vendor: Some(Vendor::unknown),
@@ -453,12 +789,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("arm-unknown-linux-musleabi"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: Some(Env::musl),
         vendor: Some(Vendor::unknown),
@@ -466,12 +804,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("arm-unknown-linux-musleabihf"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::eabihf),
         arch: Arch::arm,
         env: Some(Env::musl),
         vendor: Some(Vendor::unknown),
@@ -479,6 +819,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -492,51 +834,119 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
-        triple: Triple::new_const("armeb-unknown-linux-gnueabi"),
-        os: Some(Os::linux),
+        triple: Triple::new_const("arm64e-apple-darwin"),
+        os: Some(Os::macos),
         abi: None,
-        arch: Arch::arm,
-        env: Some(Env::gnu),
-        vendor: Some(Vendor::unknown),
+        arch: Arch::aarch64,
+        env: None,
+        vendor: Some(Vendor::apple),
         families: Families::unix,
-        pointer_width: 32,
-        endian: Endian::big,
-        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
-        triple: Triple::new_const("armebv7r-none-eabi"),
-        os: None,
+        triple: Triple::new_const("arm64e-apple-ios"),
+        os: Some(Os::ios),
         abi: None,
-        arch: Arch::arm,
+        arch: Arch::aarch64,
         env: None,
-        vendor: Some(Vendor::unknown),
-        families: Families::new_const(&[]),
-        pointer_width: 32,
-        endian: Endian::big,
-        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
-        panic: Panic::abort,
+        vendor: Some(Vendor::apple),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
     },
     TargetInfo {
-        triple: Triple::new_const("armebv7r-none-eabihf"),
-        os: None,
+        triple: Triple::new_const("arm64e-apple-tvos"),
+        os: Some(Os::tvos),
         abi: None,
-        arch: Arch::arm,
+        arch: Arch::aarch64,
+        env: None,
+        vendor: Some(Vendor::apple),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("arm64ec-pc-windows-msvc"),
+        os: Some(Os::windows),
+        abi: None,
+        arch: Arch::arm64ec,
+        env: Some(Env::msvc),
+        vendor: Some(Vendor::pc),
+        families: Families::windows,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("armeb-unknown-linux-gnueabi"),
+        os: Some(Os::linux),
+        abi: Some(Abi::eabi),
+        arch: Arch::arm,
+        env: Some(Env::gnu),
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("armebv7r-none-eabi"),
+        os: None,
+        abi: Some(Abi::eabi),
+        arch: Arch::arm,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("armebv7r-none-eabihf"),
+        os: None,
+        abi: Some(Abi::eabihf),
+        arch: Arch::arm,
         env: None,
         vendor: Some(Vendor::unknown),
         families: Families::new_const(&[]),
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
         triple: Triple::new_const("armv4t-none-eabi"),
         os: None,
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -544,12 +954,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::new_const(&[]),
+        has_atomics_equal_alignment: HasAtomics::new_const(&[]),
         panic: Panic::abort,
     },
     TargetInfo {
         triple: Triple::new_const("armv4t-unknown-linux-gnueabi"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: Some(Env::gnu),
         vendor: Some(Vendor::unknown),
@@ -557,12 +969,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("armv5te-none-eabi"),
         os: None,
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -570,12 +984,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::new_const(&[]),
+        has_atomics_equal_alignment: HasAtomics::new_const(&[]),
         panic: Panic::abort,
     },
     TargetInfo {
         triple: Triple::new_const("armv5te-unknown-linux-gnueabi"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: Some(Env::gnu),
         vendor: Some(Vendor::unknown),
@@ -583,12 +999,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("armv5te-unknown-linux-musleabi"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: Some(Env::musl),
         vendor: Some(Vendor::unknown),
@@ -596,12 +1014,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("armv5te-unknown-linux-uclibceabi"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: Some(Env::uclibc),
         vendor: Some(Vendor::unknown),
@@ -609,38 +1029,74 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
+    TargetInfo {
+        triple: Triple::new_const("armv6-none-eabi"),
+        os: None,
+        abi: Some(Abi::eabi),
+        arch: Arch::arm,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv6-none-eabihf"),
+        os: None,
+        abi: Some(Abi::eabihf),
+        arch: Arch::arm,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
     TargetInfo {
         triple: Triple::new_const("armv6-unknown-freebsd"),
         os: Some(Os::freebsd),
-        abi: None,
+        abi: Some(Abi::eabihf),
         arch: Arch::arm,
-        env: Some(Env::gnueabihf),
+        env: None,
         vendor: Some(Vendor::unknown),
         families: Families::unix,
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("armv6-unknown-netbsd-eabihf"),
         os: Some(Os::netbsd),
-        abi: None,
+        abi: Some(Abi::eabihf),
         arch: Arch::arm,
-        env: Some(Env::eabihf),
+        env: None,
         vendor: Some(Vendor::unknown),
         families: Families::unix,
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("armv6k-nintendo-3ds"),
         os: Some(Os::horizon),
-        abi: None,
+        abi: Some(Abi::eabihf),
         arch: Arch::arm,
         env: Some(Env::newlib),
         vendor: Some(Vendor::nintendo),
@@ -648,38 +1104,44 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
-        triple: Triple::new_const("armv7-apple-ios"),
-        os: Some(Os::ios),
-        abi: None,
+        triple: Triple::new_const("armv7-linux-androideabi"),
+        os: Some(Os::android),
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: None,
-        vendor: Some(Vendor::apple),
+        vendor: Some(Vendor::unknown),
         families: Families::unix,
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
-        triple: Triple::new_const("armv7-linux-androideabi"),
-        os: Some(Os::android),
-        abi: None,
+        triple: Triple::new_const("armv7-rtems-eabihf"),
+        os: Some(Os::rtems),
+        abi: Some(Abi::eabihf),
         arch: Arch::arm,
-        env: None,
+        env: Some(Env::newlib),
         vendor: Some(Vendor::unknown),
         families: Families::unix,
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("armv7-sony-vita-newlibeabihf"),
         os: Some(Os::vita),
-        abi: None,
+        abi: Some(Abi::eabihf),
         arch: Arch::arm,
         env: Some(Env::newlib),
         vendor: Some(Vendor::sony),
@@ -687,25 +1149,29 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("armv7-unknown-freebsd"),
         os: Some(Os::freebsd),
-        abi: None,
+        abi: Some(Abi::eabihf),
         arch: Arch::arm,
-        env: Some(Env::gnueabihf),
+        env: None,
         vendor: Some(Vendor::unknown),
         families: Families::unix,
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("armv7-unknown-linux-gnueabi"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: Some(Env::gnu),
         vendor: Some(Vendor::unknown),
@@ -713,12 +1179,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("armv7-unknown-linux-gnueabihf"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::eabihf),
         arch: Arch::arm,
         env: Some(Env::gnu),
         vendor: Some(Vendor::unknown),
@@ -726,12 +1194,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("armv7-unknown-linux-musleabi"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: Some(Env::musl),
         vendor: Some(Vendor::unknown),
@@ -739,12 +1209,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("armv7-unknown-linux-musleabihf"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::eabihf),
         arch: Arch::arm,
         env: Some(Env::musl),
         vendor: Some(Vendor::unknown),
@@ -752,12 +1224,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("armv7-unknown-linux-ohos"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: Some(Env::ohos),
         vendor: Some(Vendor::unknown),
@@ -765,12 +1239,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("armv7-unknown-linux-uclibceabi"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: Some(Env::uclibc),
         vendor: Some(Vendor::unknown),
@@ -778,12 +1254,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("armv7-unknown-linux-uclibceabihf"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::eabihf),
         arch: Arch::arm,
         env: Some(Env::uclibc),
         vendor: Some(Vendor::unknown),
@@ -791,25 +1269,44 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("armv7-unknown-netbsd-eabihf"),
         os: Some(Os::netbsd),
-        abi: None,
+        abi: Some(Abi::eabihf),
         arch: Arch::arm,
-        env: Some(Env::eabihf),
+        env: None,
         vendor: Some(Vendor::unknown),
         families: Families::unix,
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
+    TargetInfo {
+        triple: Triple::new_const("armv7-unknown-trusty"),
+        os: Some(Os::trusty),
+        abi: Some(Abi::eabi),
+        arch: Arch::arm,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
     TargetInfo {
         triple: Triple::new_const("armv7-wrs-vxworks-eabihf"),
         os: Some(Os::vxworks),
-        abi: None,
+        abi: Some(Abi::eabihf),
         arch: Arch::arm,
         env: Some(Env::gnu),
         vendor: Some(Vendor::wrs),
@@ -817,12 +1314,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("armv7a-kmc-solid_asp3-eabi"),
         os: Some(Os::solid_asp3),
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: None,
         vendor: Some(Vendor::kmc),
@@ -830,12 +1329,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("armv7a-kmc-solid_asp3-eabihf"),
         os: Some(Os::solid_asp3),
-        abi: None,
+        abi: Some(Abi::eabihf),
         arch: Arch::arm,
         env: None,
         vendor: Some(Vendor::kmc),
@@ -843,12 +1344,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("armv7a-none-eabi"),
         os: None,
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -856,19 +1359,68 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
         triple: Triple::new_const("armv7a-none-eabihf"),
         os: None,
-        abi: None,
+        abi: Some(Abi::eabihf),
+        arch: Arch::arm,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7a-nuttx-eabi"),
+        os: Some(Os::nuttx),
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: None,
         vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7a-nuttx-eabihf"),
+        os: Some(Os::nuttx),
+        abi: Some(Abi::eabihf),
+        arch: Arch::arm,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7a-vex-v5"),
+        os: Some(Os::vexos),
+        abi: Some(Abi::eabihf),
+        arch: Arch::arm,
+        env: Some(Env::v5),
+        vendor: Some(Vendor::vex),
         families: Families::new_const(&[]),
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
@@ -882,12 +1434,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("armv7r-none-eabi"),
         os: None,
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -895,12 +1449,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
         triple: Triple::new_const("armv7r-none-eabihf"),
         os: None,
-        abi: None,
+        abi: Some(Abi::eabihf),
         arch: Arch::arm,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -908,6 +1464,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
@@ -921,23 +1479,27 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
-        triple: Triple::new_const("asmjs-unknown-emscripten"),
-        os: Some(Os::emscripten),
-        abi: None,
-        arch: Arch::wasm32,
+        triple: Triple::new_const("armv8r-none-eabihf"),
+        os: None,
+        abi: Some(Abi::eabihf),
+        arch: Arch::arm,
         env: None,
         vendor: Some(Vendor::unknown),
-        families: Families::unix_wasm,
+        families: Families::new_const(&[]),
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
     },
     TargetInfo {
-        triple: Triple::new_const("avr-unknown-gnu-atmega328"),
+        triple: Triple::new_const("avr-none"),
         os: None,
         abi: None,
         arch: Arch::avr,
@@ -947,6 +1509,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 16,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_8_16_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -960,6 +1524,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
@@ -973,8 +1539,40 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_64_ptr,
         panic: Panic::abort,
     },
+    TargetInfo {
+        triple: Triple::new_const("csky-unknown-linux-gnuabiv2"),
+        os: Some(Os::linux),
+        abi: Some(Abi::abiv2),
+        arch: Arch::csky,
+        env: Some(Env::gnu),
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("csky-unknown-linux-gnuabiv2hf"),
+        os: Some(Os::linux),
+        abi: Some(Abi::abiv2hf),
+        arch: Arch::csky,
+        env: Some(Env::gnu),
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+    },
     TargetInfo {
         triple: Triple::new_const("hexagon-unknown-linux-musl"),
         os: Some(Os::linux),
@@ -986,71 +1584,113 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("hexagon-unknown-none-elf"),
+        os: None,
+        abi: None,
+        arch: Arch::hexagon,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("hexagon-unknown-qurt"),
+        os: Some(Os::qurt),
+        abi: None,
+        arch: Arch::hexagon,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("i386-apple-ios"),
         os: Some(Os::ios),
-        abi: None,
+        abi: Some(Abi::sim),
         arch: Arch::x86,
-        env: None,
+        env: Some(Env::sim),
         vendor: Some(Vendor::apple),
         families: Families::unix,
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
-        triple: Triple::new_const("i586-pc-nto-qnx700"),
-        os: Some(Os::nto),
+        triple: Triple::new_const("i586-unknown-linux-gnu"),
+        os: Some(Os::linux),
         abi: None,
         arch: Arch::x86,
-        env: Some(Env::nto70),
+        env: Some(Env::gnu),
         vendor: Some(Vendor::unknown),
         families: Families::unix,
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
-        triple: Triple::new_const("i586-pc-windows-msvc"),
-        os: Some(Os::windows),
+        triple: Triple::new_const("i586-unknown-linux-musl"),
+        os: Some(Os::linux),
         abi: None,
         arch: Arch::x86,
-        env: Some(Env::msvc),
-        vendor: Some(Vendor::pc),
-        families: Families::windows,
+        env: Some(Env::musl),
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
-        triple: Triple::new_const("i586-unknown-linux-gnu"),
-        os: Some(Os::linux),
+        triple: Triple::new_const("i586-unknown-netbsd"),
+        os: Some(Os::netbsd),
         abi: None,
         arch: Arch::x86,
-        env: Some(Env::gnu),
+        env: None,
         vendor: Some(Vendor::unknown),
         families: Families::unix,
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
-        triple: Triple::new_const("i586-unknown-linux-musl"),
-        os: Some(Os::linux),
+        triple: Triple::new_const("i586-unknown-redox"),
+        os: Some(Os::redox),
         abi: None,
         arch: Arch::x86,
-        env: Some(Env::musl),
+        env: Some(Env::relibc),
         vendor: Some(Vendor::unknown),
         families: Families::unix,
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1064,6 +1704,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1077,6 +1719,23 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("i686-pc-nto-qnx700"),
+        os: Some(Os::nto),
+        abi: None,
+        arch: Arch::x86,
+        env: Some(Env::nto70),
+        vendor: Some(Vendor::pc),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1090,6 +1749,23 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("i686-pc-windows-gnullvm"),
+        os: Some(Os::windows),
+        abi: Some(Abi::llvm),
+        arch: Arch::x86,
+        env: Some(Env::gnu),
+        vendor: Some(Vendor::pc),
+        families: Families::windows,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1103,6 +1779,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1116,6 +1794,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1129,20 +1809,54 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
-        triple: Triple::new_const("i686-unknown-linux-gnu"),
-        os: Some(Os::linux),
+        triple: Triple::new_const("i686-unknown-helenos"),
+        os: Some(Os::helenos),
         abi: None,
         arch: Arch::x86,
-        env: Some(Env::gnu),
+        env: None,
         vendor: Some(Vendor::unknown),
-        families: Families::unix,
+        families: Families::new_const(&[]),
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("i686-unknown-hurd-gnu"),
+        os: Some(Os::hurd),
+        abi: None,
+        arch: Arch::x86,
+        env: Some(Env::gnu),
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("i686-unknown-linux-gnu"),
+        os: Some(Os::linux),
+        abi: None,
+        arch: Arch::x86,
+        env: Some(Env::gnu),
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("i686-unknown-linux-musl"),
@@ -1155,6 +1869,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1168,6 +1884,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1181,6 +1899,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1194,12 +1914,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
         triple: Triple::new_const("i686-uwp-windows-gnu"),
         os: Some(Os::windows),
-        abi: None,
+        abi: Some(Abi::uwp),
         arch: Arch::x86,
         env: Some(Env::gnu),
         vendor: Some(Vendor::uwp),
@@ -1207,12 +1929,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("i686-uwp-windows-msvc"),
         os: Some(Os::windows),
-        abi: None,
+        abi: Some(Abi::uwp),
         arch: Arch::x86,
         env: Some(Env::msvc),
         vendor: Some(Vendor::uwp),
@@ -1220,6 +1944,38 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("i686-win7-windows-gnu"),
+        os: Some(Os::windows),
+        abi: None,
+        arch: Arch::x86,
+        env: Some(Env::gnu),
+        vendor: Some(Vendor::win7),
+        families: Families::windows,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("i686-win7-windows-msvc"),
+        os: Some(Os::windows),
+        abi: None,
+        arch: Arch::x86,
+        env: Some(Env::msvc),
+        vendor: Some(Vendor::win7),
+        families: Families::windows,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1233,8 +1989,40 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
+    TargetInfo {
+        triple: Triple::new_const("loongarch32-unknown-none"),
+        os: None,
+        abi: None,
+        arch: Arch::loongarch32,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("loongarch32-unknown-none-softfloat"),
+        os: None,
+        abi: Some(Abi::softfloat),
+        arch: Arch::loongarch32,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
     TargetInfo {
         triple: Triple::new_const("loongarch64-unknown-linux-gnu"),
         os: Some(Os::linux),
@@ -1246,6 +2034,38 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("loongarch64-unknown-linux-musl"),
+        os: Some(Os::linux),
+        abi: None,
+        arch: Arch::loongarch64,
+        env: Some(Env::musl),
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("loongarch64-unknown-linux-ohos"),
+        os: Some(Os::linux),
+        abi: None,
+        arch: Arch::loongarch64,
+        env: Some(Env::ohos),
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1259,12 +2079,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
         triple: Triple::new_const("loongarch64-unknown-none-softfloat"),
         os: None,
-        abi: None,
+        abi: Some(Abi::softfloat),
         arch: Arch::loongarch64,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -1272,6 +2094,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
@@ -1285,8 +2109,40 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16,
         panic: Panic::unwind,
     },
+    TargetInfo {
+        triple: Triple::new_const("m68k-unknown-none-elf"),
+        os: None,
+        abi: None,
+        arch: Arch::m68k,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("mips-mti-none-elf"),
+        os: None,
+        abi: None,
+        arch: Arch::mips,
+        env: None,
+        vendor: Some(Vendor::mti),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
     TargetInfo {
         triple: Triple::new_const("mips-unknown-linux-gnu"),
         os: Some(Os::linux),
@@ -1298,6 +2154,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1311,6 +2169,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1324,25 +2184,29 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("mips64-openwrt-linux-musl"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::abi64),
         arch: Arch::mips64,
         env: Some(Env::musl),
-        vendor: Some(Vendor::unknown),
+        vendor: Some(Vendor::openwrt),
         families: Families::unix,
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("mips64-unknown-linux-gnuabi64"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::abi64),
         arch: Arch::mips64,
         env: Some(Env::gnu),
         vendor: Some(Vendor::unknown),
@@ -1350,12 +2214,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("mips64-unknown-linux-muslabi64"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::abi64),
         arch: Arch::mips64,
         env: Some(Env::musl),
         vendor: Some(Vendor::unknown),
@@ -1363,12 +2229,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("mips64el-unknown-linux-gnuabi64"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::abi64),
         arch: Arch::mips64,
         env: Some(Env::gnu),
         vendor: Some(Vendor::unknown),
@@ -1376,12 +2244,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("mips64el-unknown-linux-muslabi64"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::abi64),
         arch: Arch::mips64,
         env: Some(Env::musl),
         vendor: Some(Vendor::unknown),
@@ -1389,8 +2259,25 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
+    TargetInfo {
+        triple: Triple::new_const("mipsel-mti-none-elf"),
+        os: None,
+        abi: None,
+        arch: Arch::mips,
+        env: None,
+        vendor: Some(Vendor::mti),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
     TargetInfo {
         triple: Triple::new_const("mipsel-sony-psp"),
         os: Some(Os::psp),
@@ -1402,19 +2289,23 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("mipsel-sony-psx"),
-        os: None,
+        os: Some(Os::psx),
         abi: None,
         arch: Arch::mips,
-        env: Some(Env::psx),
+        env: None,
         vendor: Some(Vendor::sony),
         families: Families::new_const(&[]),
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::new_const(&[]),
+        has_atomics_equal_alignment: HasAtomics::new_const(&[]),
         panic: Panic::abort,
     },
     TargetInfo {
@@ -1428,6 +2319,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1441,6 +2334,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1454,6 +2349,23 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("mipsel-unknown-netbsd"),
+        os: Some(Os::netbsd),
+        abi: None,
+        arch: Arch::mips,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1467,58 +2379,68 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
         triple: Triple::new_const("mipsisa32r6-unknown-linux-gnu"),
         os: Some(Os::linux),
         abi: None,
-        arch: Arch::mips,
+        arch: Arch::mips32r6,
         env: Some(Env::gnu),
         vendor: Some(Vendor::unknown),
         families: Families::unix,
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("mipsisa32r6el-unknown-linux-gnu"),
         os: Some(Os::linux),
         abi: None,
-        arch: Arch::mips,
+        arch: Arch::mips32r6,
         env: Some(Env::gnu),
         vendor: Some(Vendor::unknown),
         families: Families::unix,
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("mipsisa64r6-unknown-linux-gnuabi64"),
         os: Some(Os::linux),
-        abi: None,
-        arch: Arch::mips64,
+        abi: Some(Abi::abi64),
+        arch: Arch::mips64r6,
         env: Some(Env::gnu),
         vendor: Some(Vendor::unknown),
         families: Families::unix,
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("mipsisa64r6el-unknown-linux-gnuabi64"),
         os: Some(Os::linux),
-        abi: None,
-        arch: Arch::mips64,
+        abi: Some(Abi::abi64),
+        arch: Arch::mips64r6,
         env: Some(Env::gnu),
         vendor: Some(Vendor::unknown),
         families: Families::unix,
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1532,6 +2454,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 16,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::new_const(&[]),
+        has_atomics_equal_alignment: HasAtomics::new_const(&[]),
         panic: Panic::abort,
     },
     TargetInfo {
@@ -1545,6 +2469,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
@@ -1558,8 +2484,25 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
+    TargetInfo {
+        triple: Triple::new_const("powerpc-unknown-helenos"),
+        os: Some(Os::helenos),
+        abi: None,
+        arch: Arch::powerpc,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
     TargetInfo {
         triple: Triple::new_const("powerpc-unknown-linux-gnu"),
         os: Some(Os::linux),
@@ -1571,12 +2514,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("powerpc-unknown-linux-gnuspe"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::spe),
         arch: Arch::powerpc,
         env: Some(Env::gnu),
         vendor: Some(Vendor::unknown),
@@ -1584,6 +2529,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1597,6 +2544,23 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("powerpc-unknown-linux-muslspe"),
+        os: Some(Os::linux),
+        abi: Some(Abi::spe),
+        arch: Arch::powerpc,
+        env: Some(Env::musl),
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1610,6 +2574,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1623,6 +2589,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1636,12 +2604,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("powerpc-wrs-vxworks-spe"),
         os: Some(Os::vxworks),
-        abi: None,
+        abi: Some(Abi::spe),
         arch: Arch::powerpc,
         env: Some(Env::gnu),
         vendor: Some(Vendor::wrs),
@@ -1649,12 +2619,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("powerpc64-ibm-aix"),
         os: Some(Os::aix),
-        abi: None,
+        abi: Some(Abi::vec_extabi),
         arch: Arch::powerpc64,
         env: None,
         vendor: Some(Vendor::ibm),
@@ -1662,12 +2634,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("powerpc64-unknown-freebsd"),
         os: Some(Os::freebsd),
-        abi: None,
+        abi: Some(Abi::elfv2),
         arch: Arch::powerpc64,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -1675,12 +2649,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("powerpc64-unknown-linux-gnu"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::elfv1),
         arch: Arch::powerpc64,
         env: Some(Env::gnu),
         vendor: Some(Vendor::unknown),
@@ -1688,12 +2664,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("powerpc64-unknown-linux-musl"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::elfv2),
         arch: Arch::powerpc64,
         env: Some(Env::musl),
         vendor: Some(Vendor::unknown),
@@ -1701,12 +2679,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("powerpc64-unknown-openbsd"),
         os: Some(Os::openbsd),
-        abi: None,
+        abi: Some(Abi::elfv2),
         arch: Arch::powerpc64,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -1714,12 +2694,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("powerpc64-wrs-vxworks"),
         os: Some(Os::vxworks),
-        abi: None,
+        abi: Some(Abi::elfv1),
         arch: Arch::powerpc64,
         env: Some(Env::gnu),
         vendor: Some(Vendor::wrs),
@@ -1727,12 +2709,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("powerpc64le-unknown-freebsd"),
         os: Some(Os::freebsd),
-        abi: None,
+        abi: Some(Abi::elfv2),
         arch: Arch::powerpc64,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -1740,12 +2724,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("powerpc64le-unknown-linux-gnu"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::elfv2),
         arch: Arch::powerpc64,
         env: Some(Env::gnu),
         vendor: Some(Vendor::unknown),
@@ -1753,12 +2739,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("powerpc64le-unknown-linux-musl"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::elfv2),
         arch: Arch::powerpc64,
         env: Some(Env::musl),
         vendor: Some(Vendor::unknown),
@@ -1766,38 +2754,44 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
-        triple: Triple::new_const("riscv32gc-unknown-linux-gnu"),
-        os: Some(Os::linux),
+        triple: Triple::new_const("riscv32-wrs-vxworks"),
+        os: Some(Os::vxworks),
         abi: None,
         arch: Arch::riscv32,
         env: Some(Env::gnu),
-        vendor: Some(Vendor::unknown),
+        vendor: Some(Vendor::wrs),
         families: Families::unix,
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
-        triple: Triple::new_const("riscv32gc-unknown-linux-musl"),
-        os: Some(Os::linux),
-        abi: None,
+        triple: Triple::new_const("riscv32e-unknown-none-elf"),
+        os: None,
+        abi: Some(Abi::ilp32e),
         arch: Arch::riscv32,
-        env: Some(Env::musl),
+        env: None,
         vendor: Some(Vendor::unknown),
-        families: Families::unix,
+        families: Families::new_const(&[]),
         pointer_width: 32,
         endian: Endian::little,
-        has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::unwind,
+        has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
     },
     TargetInfo {
-        triple: Triple::new_const("riscv32i-unknown-none-elf"),
+        triple: Triple::new_const("riscv32em-unknown-none-elf"),
         os: None,
-        abi: None,
+        abi: Some(Abi::ilp32e),
         arch: Arch::riscv32,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -1805,12 +2799,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
-        triple: Triple::new_const("riscv32im-unknown-none-elf"),
+        triple: Triple::new_const("riscv32emc-unknown-none-elf"),
         os: None,
-        abi: None,
+        abi: Some(Abi::ilp32e),
         arch: Arch::riscv32,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -1818,37 +2814,43 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
-        triple: Triple::new_const("riscv32imac-esp-espidf"),
-        os: Some(Os::espidf),
+        triple: Triple::new_const("riscv32gc-unknown-linux-gnu"),
+        os: Some(Os::linux),
         abi: None,
         arch: Arch::riscv32,
-        env: Some(Env::newlib),
-        vendor: Some(Vendor::espressif),
+        env: Some(Env::gnu),
+        vendor: Some(Vendor::unknown),
         families: Families::unix,
         pointer_width: 32,
         endian: Endian::little,
-        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
-        panic: Panic::abort,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
     },
     TargetInfo {
-        triple: Triple::new_const("riscv32imac-unknown-none-elf"),
-        os: None,
+        triple: Triple::new_const("riscv32gc-unknown-linux-musl"),
+        os: Some(Os::linux),
         abi: None,
         arch: Arch::riscv32,
-        env: None,
+        env: Some(Env::musl),
         vendor: Some(Vendor::unknown),
-        families: Families::new_const(&[]),
+        families: Families::unix,
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
-        panic: Panic::abort,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
     },
     TargetInfo {
-        triple: Triple::new_const("riscv32imac-unknown-xous-elf"),
-        os: Some(Os::xous),
+        triple: Triple::new_const("riscv32i-unknown-none-elf"),
+        os: None,
         abi: None,
         arch: Arch::riscv32,
         env: None,
@@ -1856,24 +2858,28 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         families: Families::new_const(&[]),
         pointer_width: 32,
         endian: Endian::little,
-        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
-        triple: Triple::new_const("riscv32imc-esp-espidf"),
-        os: Some(Os::espidf),
+        triple: Triple::new_const("riscv32im-risc0-zkvm-elf"),
+        os: Some(Os::zkvm),
         abi: None,
         arch: Arch::riscv32,
-        env: Some(Env::newlib),
-        vendor: Some(Vendor::espressif),
-        families: Families::unix,
+        env: None,
+        vendor: Some(Vendor::risc0),
+        families: Families::new_const(&[]),
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
-        triple: Triple::new_const("riscv32imc-unknown-none-elf"),
+        triple: Triple::new_const("riscv32im-unknown-none-elf"),
         os: None,
         abi: None,
         arch: Arch::riscv32,
@@ -1883,19 +2889,233 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
-        triple: Triple::new_const("riscv64gc-unknown-freebsd"),
-        os: Some(Os::freebsd),
+        triple: Triple::new_const("riscv32ima-unknown-none-elf"),
+        os: None,
         abi: None,
-        arch: Arch::riscv64,
+        arch: Arch::riscv32,
         env: None,
         vendor: Some(Vendor::unknown),
-        families: Families::unix,
-        pointer_width: 64,
+        families: Families::new_const(&[]),
+        pointer_width: 32,
         endian: Endian::little,
-        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv32imac-esp-espidf"),
+        os: Some(Os::espidf),
+        abi: None,
+        arch: Arch::riscv32,
+        env: Some(Env::newlib),
+        vendor: Some(Vendor::espressif),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv32imac-unknown-none-elf"),
+        os: None,
+        abi: None,
+        arch: Arch::riscv32,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv32imac-unknown-nuttx-elf"),
+        os: Some(Os::nuttx),
+        abi: None,
+        arch: Arch::riscv32,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv32imac-unknown-xous-elf"),
+        os: Some(Os::xous),
+        abi: None,
+        arch: Arch::riscv32,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv32imafc-esp-espidf"),
+        os: Some(Os::espidf),
+        abi: None,
+        arch: Arch::riscv32,
+        env: Some(Env::newlib),
+        vendor: Some(Vendor::espressif),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv32imafc-unknown-none-elf"),
+        os: None,
+        abi: None,
+        arch: Arch::riscv32,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv32imafc-unknown-nuttx-elf"),
+        os: Some(Os::nuttx),
+        abi: None,
+        arch: Arch::riscv32,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv32imc-esp-espidf"),
+        os: Some(Os::espidf),
+        abi: None,
+        arch: Arch::riscv32,
+        env: Some(Env::newlib),
+        vendor: Some(Vendor::espressif),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv32imc-unknown-none-elf"),
+        os: None,
+        abi: None,
+        arch: Arch::riscv32,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv32imc-unknown-nuttx-elf"),
+        os: Some(Os::nuttx),
+        abi: None,
+        arch: Arch::riscv32,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv64-linux-android"),
+        os: Some(Os::android),
+        abi: None,
+        arch: Arch::riscv64,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv64-wrs-vxworks"),
+        os: Some(Os::vxworks),
+        abi: None,
+        arch: Arch::riscv64,
+        env: Some(Env::gnu),
+        vendor: Some(Vendor::wrs),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv64a23-unknown-linux-gnu"),
+        os: Some(Os::linux),
+        abi: None,
+        arch: Arch::riscv64,
+        env: Some(Env::gnu),
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv64gc-unknown-freebsd"),
+        os: Some(Os::freebsd),
+        abi: None,
+        arch: Arch::riscv64,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1909,8 +3129,25 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
+    TargetInfo {
+        triple: Triple::new_const("riscv64gc-unknown-hermit"),
+        os: Some(Os::hermit),
+        abi: None,
+        arch: Arch::riscv64,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
     TargetInfo {
         triple: Triple::new_const("riscv64gc-unknown-linux-gnu"),
         os: Some(Os::linux),
@@ -1922,6 +3159,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1935,6 +3174,23 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv64gc-unknown-managarm-mlibc"),
+        os: Some(Os::managarm),
+        abi: None,
+        arch: Arch::riscv64,
+        env: Some(Env::mlibc),
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1948,6 +3204,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -1961,6 +3219,23 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv64gc-unknown-nuttx-elf"),
+        os: Some(Os::nuttx),
+        abi: None,
+        arch: Arch::riscv64,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
@@ -1974,8 +3249,40 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv64gc-unknown-redox"),
+        os: Some(Os::redox),
+        abi: None,
+        arch: Arch::riscv64,
+        env: Some(Env::relibc),
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
+    TargetInfo {
+        triple: Triple::new_const("riscv64im-unknown-none-elf"),
+        os: None,
+        abi: None,
+        arch: Arch::riscv64,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
     TargetInfo {
         triple: Triple::new_const("riscv64imac-unknown-none-elf"),
         os: None,
@@ -1987,6 +3294,23 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv64imac-unknown-nuttx-elf"),
+        os: Some(Os::nuttx),
+        abi: None,
+        arch: Arch::riscv64,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
@@ -1999,7 +3323,9 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         families: Families::unix,
         pointer_width: 64,
         endian: Endian::big,
-        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -2012,9 +3338,26 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         families: Families::unix,
         pointer_width: 64,
         endian: Endian::big,
-        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
+    TargetInfo {
+        triple: Triple::new_const("s390x-unknown-none-softfloat"),
+        os: None,
+        abi: Some(Abi::softfloat),
+        arch: Arch::s390x,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
     TargetInfo {
         triple: Triple::new_const("sparc-unknown-linux-gnu"),
         os: Some(Os::linux),
@@ -2025,9 +3368,41 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         families: Families::unix,
         pointer_width: 32,
         endian: Endian::big,
-        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::unwind,
     },
+    TargetInfo {
+        triple: Triple::new_const("sparc-unknown-none-elf"),
+        os: None,
+        abi: None,
+        arch: Arch::sparc,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("sparc64-unknown-helenos"),
+        os: Some(Os::helenos),
+        abi: None,
+        arch: Arch::sparc64,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
     TargetInfo {
         triple: Triple::new_const("sparc64-unknown-linux-gnu"),
         os: Some(Os::linux),
@@ -2039,6 +3414,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -2052,6 +3429,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -2065,6 +3444,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -2078,12 +3459,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::big,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("thumbv4t-none-eabi"),
         os: None,
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -2091,12 +3474,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::new_const(&[]),
+        has_atomics_equal_alignment: HasAtomics::new_const(&[]),
         panic: Panic::abort,
     },
     TargetInfo {
         triple: Triple::new_const("thumbv5te-none-eabi"),
         os: None,
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -2104,12 +3489,29 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::new_const(&[]),
+        has_atomics_equal_alignment: HasAtomics::new_const(&[]),
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv6-none-eabi"),
+        os: None,
+        abi: Some(Abi::eabi),
+        arch: Arch::arm,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
         triple: Triple::new_const("thumbv6m-none-eabi"),
         os: None,
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -2117,6 +3519,83 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv6m-nuttx-eabi"),
+        os: Some(Os::nuttx),
+        abi: Some(Abi::eabi),
+        arch: Arch::arm,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv7a-none-eabi"),
+        os: None,
+        abi: Some(Abi::eabi),
+        arch: Arch::arm,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv7a-none-eabihf"),
+        os: None,
+        abi: Some(Abi::eabihf),
+        arch: Arch::arm,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv7a-nuttx-eabi"),
+        os: Some(Os::nuttx),
+        abi: Some(Abi::eabi),
+        arch: Arch::arm,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv7a-nuttx-eabihf"),
+        os: Some(Os::nuttx),
+        abi: Some(Abi::eabihf),
+        arch: Arch::arm,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
@@ -2130,12 +3609,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7a-uwp-windows-msvc"),
         os: Some(Os::windows),
-        abi: None,
+        abi: Some(Abi::uwp),
         arch: Arch::arm,
         env: Some(Env::msvc),
         vendor: Some(Vendor::uwp),
@@ -2143,12 +3624,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7em-none-eabi"),
         os: None,
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -2156,12 +3639,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7em-none-eabihf"),
         os: None,
-        abi: None,
+        abi: Some(Abi::eabihf),
         arch: Arch::arm,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -2169,12 +3654,44 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv7em-nuttx-eabi"),
+        os: Some(Os::nuttx),
+        abi: Some(Abi::eabi),
+        arch: Arch::arm,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv7em-nuttx-eabihf"),
+        os: Some(Os::nuttx),
+        abi: Some(Abi::eabihf),
+        arch: Arch::arm,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7m-none-eabi"),
         os: None,
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -2182,12 +3699,29 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv7m-nuttx-eabi"),
+        os: Some(Os::nuttx),
+        abi: Some(Abi::eabi),
+        arch: Arch::arm,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7neon-linux-androideabi"),
         os: Some(Os::android),
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -2195,12 +3729,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7neon-unknown-linux-gnueabihf"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::eabihf),
         arch: Arch::arm,
         env: Some(Env::gnu),
         vendor: Some(Vendor::unknown),
@@ -2208,12 +3744,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("thumbv7neon-unknown-linux-musleabihf"),
         os: Some(Os::linux),
-        abi: None,
+        abi: Some(Abi::eabihf),
         arch: Arch::arm,
         env: Some(Env::musl),
         vendor: Some(Vendor::unknown),
@@ -2221,12 +3759,44 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
+    TargetInfo {
+        triple: Triple::new_const("thumbv7r-none-eabi"),
+        os: None,
+        abi: Some(Abi::eabi),
+        arch: Arch::arm,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv7r-none-eabihf"),
+        os: None,
+        abi: Some(Abi::eabihf),
+        arch: Arch::arm,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
     TargetInfo {
         triple: Triple::new_const("thumbv8m.base-none-eabi"),
         os: None,
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -2234,12 +3804,29 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv8m.base-nuttx-eabi"),
+        os: Some(Os::nuttx),
+        abi: Some(Abi::eabi),
+        arch: Arch::arm,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
         triple: Triple::new_const("thumbv8m.main-none-eabi"),
         os: None,
-        abi: None,
+        abi: Some(Abi::eabi),
         arch: Arch::arm,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -2247,12 +3834,14 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
         triple: Triple::new_const("thumbv8m.main-none-eabihf"),
         os: None,
-        abi: None,
+        abi: Some(Abi::eabihf),
         arch: Arch::arm,
         env: None,
         vendor: Some(Vendor::unknown),
@@ -2260,6 +3849,53 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv8m.main-nuttx-eabi"),
+        os: Some(Os::nuttx),
+        abi: Some(Abi::eabi),
+        arch: Arch::arm,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv8m.main-nuttx-eabihf"),
+        os: Some(Os::nuttx),
+        abi: Some(Abi::eabihf),
+        arch: Arch::arm,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv8r-none-eabihf"),
+        os: None,
+        abi: Some(Abi::eabihf),
+        arch: Arch::arm,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
@@ -2273,6 +3909,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -2286,19 +3924,98 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("wasm32-wali-linux-musl"),
+        os: Some(Os::linux),
+        abi: None,
+        arch: Arch::wasm32,
+        env: Some(Env::musl),
+        vendor: Some(Vendor::unknown),
+        families: Families::unix_wasm,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("wasm32-wasip1"),
+        os: Some(Os::wasi),
+        abi: None,
+        arch: Arch::wasm32,
+        env: Some(Env::p1),
+        vendor: Some(Vendor::unknown),
+        families: Families::wasm,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
-        triple: Triple::new_const("wasm32-wasi"),
+        triple: Triple::new_const("wasm32-wasip1-threads"),
         os: Some(Os::wasi),
         abi: None,
         arch: Arch::wasm32,
+        env: Some(Env::p1),
+        vendor: Some(Vendor::unknown),
+        families: Families::wasm,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("wasm32-wasip2"),
+        os: Some(Os::wasi),
+        abi: None,
+        arch: Arch::wasm32,
+        env: Some(Env::p2),
+        vendor: Some(Vendor::unknown),
+        families: Families::wasm,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("wasm32-wasip3"),
+        os: Some(Os::wasi),
+        abi: None,
+        arch: Arch::wasm32,
+        env: Some(Env::p3),
+        vendor: Some(Vendor::unknown),
+        families: Families::wasm,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("wasm32v1-none"),
+        os: None,
+        abi: None,
+        arch: Arch::wasm32,
         env: None,
         vendor: Some(Vendor::unknown),
         families: Families::wasm,
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
@@ -2312,6 +4029,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
@@ -2325,64 +4044,74 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-apple-ios"),
         os: Some(Os::ios),
-        abi: None,
+        abi: Some(Abi::sim),
         arch: Arch::x86_64,
-        env: None,
+        env: Some(Env::sim),
         vendor: Some(Vendor::apple),
         families: Families::unix,
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-apple-ios-macabi"),
         os: Some(Os::ios),
-        abi: None,
+        abi: Some(Abi::macabi),
         arch: Arch::x86_64,
-        env: None,
+        env: Some(Env::macabi),
         vendor: Some(Vendor::apple),
         families: Families::unix,
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-apple-tvos"),
         os: Some(Os::tvos),
-        abi: None,
+        abi: Some(Abi::sim),
         arch: Arch::x86_64,
-        env: None,
+        env: Some(Env::sim),
         vendor: Some(Vendor::apple),
         families: Families::unix,
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-apple-watchos-sim"),
         os: Some(Os::watchos),
-        abi: None,
+        abi: Some(Abi::sim),
         arch: Arch::x86_64,
-        env: None,
+        env: Some(Env::sim),
         vendor: Some(Vendor::apple),
         families: Families::unix,
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-fortanix-unknown-sgx"),
         os: Some(Os::unknown),
-        abi: None,
+        abi: Some(Abi::fortanix),
         arch: Arch::x86_64,
         env: Some(Env::sgx),
         vendor: Some(Vendor::fortanix),
@@ -2390,11 +4119,13 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
-        triple: Triple::new_const("x86_64-fuchsia"),
-        os: Some(Os::fuchsia),
+        triple: Triple::new_const("x86_64-linux-android"),
+        os: Some(Os::android),
         abi: None,
         arch: Arch::x86_64,
         env: None,
@@ -2403,11 +4134,13 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
-        triple: Triple::new_const("x86_64-linux-android"),
-        os: Some(Os::android),
+        triple: Triple::new_const("x86_64-lynx-lynxos178"),
+        os: Some(Os::lynxos178),
         abi: None,
         arch: Arch::x86_64,
         env: None,
@@ -2416,6 +4149,23 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-pc-cygwin"),
+        os: Some(Os::cygwin),
+        abi: None,
+        arch: Arch::x86_64,
+        env: None,
+        vendor: Some(Vendor::pc),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -2424,11 +4174,43 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         abi: None,
         arch: Arch::x86_64,
         env: Some(Env::nto71),
-        vendor: Some(Vendor::unknown),
+        vendor: Some(Vendor::pc),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-pc-nto-qnx710_iosock"),
+        os: Some(Os::nto),
+        abi: None,
+        arch: Arch::x86_64,
+        env: Some(Env::nto71_iosock),
+        vendor: Some(Vendor::pc),
         families: Families::unix,
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-pc-nto-qnx800"),
+        os: Some(Os::nto),
+        abi: None,
+        arch: Arch::x86_64,
+        env: Some(Env::nto80),
+        vendor: Some(Vendor::pc),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -2442,6 +4224,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -2454,20 +4238,24 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         families: Families::windows,
         pointer_width: 64,
         endian: Endian::little,
-        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-pc-windows-gnullvm"),
         os: Some(Os::windows),
-        abi: None,
+        abi: Some(Abi::llvm),
         arch: Arch::x86_64,
         env: Some(Env::gnu),
         vendor: Some(Vendor::pc),
         families: Families::windows,
         pointer_width: 64,
         endian: Endian::little,
-        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -2480,21 +4268,25 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         families: Families::windows,
         pointer_width: 64,
         endian: Endian::little,
-        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
-        triple: Triple::new_const("x86_64-sun-solaris"),
-        os: Some(Os::solaris),
+        triple: Triple::new_const("x86_64-unikraft-linux-musl"),
+        os: Some(Os::linux),
         abi: None,
         arch: Arch::x86_64,
-        env: None,
-        vendor: Some(Vendor::sun),
+        env: Some(Env::musl),
+        vendor: Some(Vendor::unikraft),
         families: Families::unix,
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
-        panic: Panic::unwind,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-dragonfly"),
@@ -2507,6 +4299,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -2520,6 +4314,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -2532,7 +4328,9 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         families: Families::unix,
         pointer_width: 64,
         endian: Endian::little,
-        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -2546,8 +4344,25 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-helenos"),
+        os: Some(Os::helenos),
+        abi: None,
+        arch: Arch::x86_64,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-hermit"),
         os: Some(Os::hermit),
@@ -2559,8 +4374,25 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-hurd-gnu"),
+        os: Some(Os::hurd),
+        abi: None,
+        arch: Arch::x86_64,
+        env: Some(Env::gnu),
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+    },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-illumos"),
         os: Some(Os::illumos),
@@ -2572,6 +4404,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -2585,6 +4419,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
@@ -2598,19 +4434,38 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
-        triple: Triple::new_const("x86_64-unknown-linux-gnux32"),
+        triple: Triple::new_const("x86_64-unknown-linux-gnuasan"),
         os: Some(Os::linux),
         abi: None,
         arch: Arch::x86_64,
         env: Some(Env::gnu),
         vendor: Some(Vendor::unknown),
         families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-linux-gnux32"),
+        os: Some(Os::linux),
+        abi: Some(Abi::x32),
+        arch: Arch::x86_64,
+        env: Some(Env::gnu),
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
         pointer_width: 32,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -2624,8 +4479,70 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-linux-none"),
+        os: Some(Os::linux),
+        abi: None,
+        arch: Arch::x86_64,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-linux-ohos"),
+        os: Some(Os::linux),
+        abi: None,
+        arch: Arch::x86_64,
+        env: Some(Env::ohos),
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-managarm-mlibc"),
+        os: Some(Os::managarm),
+        abi: None,
+        arch: Arch::x86_64,
+        env: Some(Env::mlibc),
+        vendor: Some(Vendor::unknown),
+        families: Families::unix,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-motor"),
+        os: Some(Os::motor),
+        abi: None,
+        arch: Arch::x86_64,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-netbsd"),
         os: Some(Os::netbsd),
@@ -2637,6 +4554,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -2650,6 +4569,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
@@ -2663,6 +4584,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -2676,8 +4599,25 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-trusty"),
+        os: Some(Os::trusty),
+        abi: None,
+        arch: Arch::x86_64,
+        env: None,
+        vendor: Some(Vendor::unknown),
+        families: Families::new_const(&[]),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::abort,
+    },
     TargetInfo {
         triple: Triple::new_const("x86_64-unknown-uefi"),
         os: Some(Os::uefi),
@@ -2689,32 +4629,68 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::abort,
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-uwp-windows-gnu"),
         os: Some(Os::windows),
-        abi: None,
+        abi: Some(Abi::uwp),
         arch: Arch::x86_64,
         env: Some(Env::gnu),
         vendor: Some(Vendor::uwp),
         families: Families::windows,
         pointer_width: 64,
         endian: Endian::little,
-        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
         triple: Triple::new_const("x86_64-uwp-windows-msvc"),
         os: Some(Os::windows),
-        abi: None,
+        abi: Some(Abi::uwp),
         arch: Arch::x86_64,
         env: Some(Env::msvc),
         vendor: Some(Vendor::uwp),
         families: Families::windows,
         pointer_width: 64,
         endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-win7-windows-gnu"),
+        os: Some(Os::windows),
+        abi: None,
+        arch: Arch::x86_64,
+        env: Some(Env::gnu),
+        vendor: Some(Vendor::win7),
+        families: Families::windows,
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
+        panic: Panic::unwind,
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-win7-windows-msvc"),
+        os: Some(Os::windows),
+        abi: None,
+        arch: Arch::x86_64,
+        env: Some(Env::msvc),
+        vendor: Some(Vendor::win7),
+        families: Families::windows,
+        pointer_width: 64,
+        endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -2728,6 +4704,8 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_ptr,
         panic: Panic::unwind,
     },
     TargetInfo {
@@ -2741,22 +4719,218 @@ pub const ALL_BUILTINS: &[TargetInfo] = &[
         pointer_width: 64,
         endian: Endian::little,
         has_atomics: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: Panic::unwind,
     },
+    TargetInfo {
+        triple: Triple::new_const("xtensa-esp32-espidf"),
+        os: Some(Os::espidf),
+        abi: None,
+        arch: Arch::xtensa,
+        env: Some(Env::newlib),
+        vendor: Some(Vendor::espressif),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("xtensa-esp32-none-elf"),
+        os: None,
+        abi: None,
+        arch: Arch::xtensa,
+        env: None,
+        vendor: Some(Vendor::espressif),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("xtensa-esp32s2-espidf"),
+        os: Some(Os::espidf),
+        abi: None,
+        arch: Arch::xtensa,
+        env: Some(Env::newlib),
+        vendor: Some(Vendor::espressif),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("xtensa-esp32s2-none-elf"),
+        os: None,
+        abi: None,
+        arch: Arch::xtensa,
+        env: None,
+        vendor: Some(Vendor::espressif),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(&[]),
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("xtensa-esp32s3-espidf"),
+        os: Some(Os::espidf),
+        abi: None,
+        arch: Arch::xtensa,
+        env: Some(Env::newlib),
+        vendor: Some(Vendor::espressif),
+        families: Families::unix,
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+    TargetInfo {
+        triple: Triple::new_const("xtensa-esp32s3-none-elf"),
+        os: None,
+        abi: None,
+        arch: Arch::xtensa,
+        env: None,
+        vendor: Some(Vendor::espressif),
+        families: Families::new_const(&[]),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_load_store: HasAtomics::atomic_8_16_32_ptr,
+        has_atomics_equal_alignment: HasAtomics::atomic_8_16_32_ptr,
+        panic: Panic::abort,
+    },
+];
+
+/// The families of the builtin targets of each operating system, sorted by
+/// the operating system, where `none` is for the targets without one. The
+/// `wasm` family isn't included as it depends on the architecture, see
+/// [`Families::for_target`]
+pub const OS_FAMILIES: &[(Os, Families)] = &[
+    (Os::aix, Families::unix),
+    (Os::amdhsa, Families::new_const(&[])),
+    (Os::android, Families::unix),
+    (Os::cuda, Families::new_const(&[])),
+    (Os::cygwin, Families::unix),
+    (Os::dragonfly, Families::unix),
+    (Os::emscripten, Families::unix),
+    (Os::espidf, Families::unix),
+    (Os::freebsd, Families::unix),
+    (Os::fuchsia, Families::unix),
+    (Os::haiku, Families::unix),
+    (Os::helenos, Families::new_const(&[])),
+    (Os::hermit, Families::new_const(&[])),
+    (Os::horizon, Families::unix),
+    (Os::hurd, Families::unix),
+    (Os::illumos, Families::unix),
+    (Os::ios, Families::unix),
+    (Os::l4re, Families::unix),
+    (Os::linux, Families::unix),
+    (Os::lynxos178, Families::unix),
+    (Os::macos, Families::unix),
+    (Os::managarm, Families::unix),
+    (Os::motor, Families::new_const(&[])),
+    (Os::netbsd, Families::unix),
+    (Os::none, Families::new_const(&[])),
+    (Os::nto, Families::unix),
+    (Os::nuttx, Families::unix),
+    (Os::openbsd, Families::unix),
+    (Os::psp, Families::new_const(&[])),
+    (Os::psx, Families::new_const(&[])),
+    (Os::qurt, Families::unix),
+    (Os::redox, Families::unix),
+    (Os::rtems, Families::unix),
+    (Os::solaris, Families::unix),
+    (Os::solid_asp3, Families::new_const(&[])),
+    (Os::teeos, Families::new_const(&[])),
+    (Os::trusty, Families::new_const(&[])),
+    (Os::tvos, Families::unix),
+    (Os::uefi, Families::new_const(&[])),
+    (Os::unknown, Families::new_const(&[])),
+    (Os::vexos, Families::new_const(&[])),
+    (Os::visionos, Families::unix),
+    (Os::vita, Families::unix),
+    (Os::vxworks, Families::unix),
+    (Os::wasi, Families::new_const(&[])),
+    (Os::watchos, Families::unix),
+    (Os::windows, Families::windows),
+    (Os::xous, Families::new_const(&[])),
+    (Os::zkvm, Families::new_const(&[])),
 ];
 
-impl super::Abi {}
+impl super::Abi {
+    pub const abi64: Abi = Abi::new_const("abi64");
+    pub const abiv2: Abi = Abi::new_const("abiv2");
+    pub const abiv2hf: Abi = Abi::new_const("abiv2hf");
+    pub const eabi: Abi = Abi::new_const("eabi");
+    pub const eabihf: Abi = Abi::new_const("eabihf");
+    pub const elfv1: Abi = Abi::new_const("elfv1");
+    pub const elfv2: Abi = Abi::new_const("elfv2");
+    pub const fortanix: Abi = Abi::new_const("fortanix");
+    pub const ilp32: Abi = Abi::new_const("ilp32");
+    pub const ilp32e: Abi = Abi::new_const("ilp32e");
+    pub const llvm: Abi = Abi::new_const("llvm");
+    pub const macabi: Abi = Abi::new_const("macabi");
+    pub const sim: Abi = Abi::new_const("sim");
+    pub const softfloat: Abi = Abi::new_const("softfloat");
+    pub const spe: Abi = Abi::new_const("spe");
+    pub const uwp: Abi = Abi::new_const("uwp");
+    pub const vec_extabi: Abi = Abi::new_const("vec-extabi");
+    pub const x32: Abi = Abi::new_const("x32");
+
+    /// Every known value, ie. the values of the builtin targets, and the
+    /// values documented in the reference, sorted
+    pub const ALL_KNOWN: &[Abi] = &[
+        Abi::abi64,
+        Abi::abiv2,
+        Abi::abiv2hf,
+        Abi::eabi,
+        Abi::eabihf,
+        Abi::elfv1,
+        Abi::elfv2,
+        Abi::fortanix,
+        Abi::ilp32,
+        Abi::ilp32e,
+        Abi::llvm,
+        Abi::macabi,
+        Abi::sim,
+        Abi::softfloat,
+        Abi::spe,
+        Abi::uwp,
+        Abi::vec_extabi,
+        Abi::x32,
+    ];
+}
 
 impl super::Arch {
     pub const aarch64: Arch = Arch::new_const("aarch64");
+    pub const amdgpu: Arch = Arch::new_const("amdgpu");
     pub const arm: Arch = Arch::new_const("arm");
+    pub const arm64ec: Arch = Arch::new_const("arm64ec");
     pub const avr: Arch = Arch::new_const("avr");
     pub const bpf: Arch = Arch::new_const("bpf");
+    pub const csky: Arch = Arch::new_const("csky");
     pub const hexagon: Arch = Arch::new_const("hexagon");
+    pub const loongarch32: Arch = Arch::new_const("loongarch32");
     pub const loongarch64: Arch = Arch::new_const("loongarch64");
     pub const m68k: Arch = Arch::new_const("m68k");
     pub const mips: Arch = Arch::new_const("mips");
+    pub const mips32r6: Arch = Arch::new_const("mips32r6");
     pub const mips64: Arch = Arch::new_const("mips64");
+    pub const mips64r6: Arch = Arch::new_const("mips64r6");
     pub const msp430: Arch = Arch::new_const("msp430");
     pub const nvptx64: Arch = Arch::new_const("nvptx64");
     pub const powerpc: Arch = Arch::new_const("powerpc");
@@ -2770,63 +4944,205 @@ impl super::Arch {
     pub const wasm64: Arch = Arch::new_const("wasm64");
     pub const x86: Arch = Arch::new_const("x86");
     pub const x86_64: Arch = Arch::new_const("x86_64");
+    pub const xtensa: Arch = Arch::new_const("xtensa");
+
+    /// Every known value, ie. the values of the builtin targets, and the
+    /// values documented in the reference, sorted
+    pub const ALL_KNOWN: &[Arch] = &[
+        Arch::aarch64,
+        Arch::amdgpu,
+        Arch::arm,
+        Arch::arm64ec,
+        Arch::avr,
+        Arch::bpf,
+        Arch::csky,
+        Arch::hexagon,
+        Arch::loongarch32,
+        Arch::loongarch64,
+        Arch::m68k,
+        Arch::mips,
+        Arch::mips32r6,
+        Arch::mips64,
+        Arch::mips64r6,
+        Arch::msp430,
+        Arch::nvptx64,
+        Arch::powerpc,
+        Arch::powerpc64,
+        Arch::riscv32,
+        Arch::riscv64,
+        Arch::s390x,
+        Arch::sparc,
+        Arch::sparc64,
+        Arch::wasm32,
+        Arch::wasm64,
+        Arch::x86,
+        Arch::x86_64,
+        Arch::xtensa,
+    ];
 }
 
 impl super::Vendor {
+    pub const amd: Vendor = Vendor::new_const("amd");
     pub const apple: Vendor = Vendor::new_const("apple");
     pub const espressif: Vendor = Vendor::new_const("espressif");
     pub const fortanix: Vendor = Vendor::new_const("fortanix");
     pub const ibm: Vendor = Vendor::new_const("ibm");
     pub const kmc: Vendor = Vendor::new_const("kmc");
+    pub const mti: Vendor = Vendor::new_const("mti");
     pub const nintendo: Vendor = Vendor::new_const("nintendo");
     pub const nvidia: Vendor = Vendor::new_const("nvidia");
+    pub const openwrt: Vendor = Vendor::new_const("openwrt");
     pub const pc: Vendor = Vendor::new_const("pc");
+    pub const risc0: Vendor = Vendor::new_const("risc0");
     pub const sony: Vendor = Vendor::new_const("sony");
     pub const sun: Vendor = Vendor::new_const("sun");
+    pub const unikraft: Vendor = Vendor::new_const("unikraft");
     pub const unknown: Vendor = Vendor::new_const("unknown");
     pub const uwp: Vendor = Vendor::new_const("uwp");
+    pub const vex: Vendor = Vendor::new_const("vex");
+    pub const win7: Vendor = Vendor::new_const("win7");
     pub const wrs: Vendor = Vendor::new_const("wrs");
+
+    /// Every known value, ie. the values of the builtin targets, and the
+    /// values documented in the reference, sorted
+    pub const ALL_KNOWN: &[Vendor] = &[
+        Vendor::amd,
+        Vendor::apple,
+        Vendor::espressif,
+        Vendor::fortanix,
+        Vendor::ibm,
+        Vendor::kmc,
+        Vendor::mti,
+        Vendor::nintendo,
+        Vendor::nvidia,
+        Vendor::openwrt,
+        Vendor::pc,
+        Vendor::risc0,
+        Vendor::sony,
+        Vendor::sun,
+        Vendor::unikraft,
+        Vendor::unknown,
+        Vendor::uwp,
+        Vendor::vex,
+        Vendor::win7,
+        Vendor::wrs,
+    ];
 }
 
 impl super::Os {
     pub const aix: Os = Os::new_const("aix");
+    pub const amdhsa: Os = Os::new_const("amdhsa");
     pub const android: Os = Os::new_const("android");
     pub const cuda: Os = Os::new_const("cuda");
+    pub const cygwin: Os = Os::new_const("cygwin");
     pub const dragonfly: Os = Os::new_const("dragonfly");
     pub const emscripten: Os = Os::new_const("emscripten");
     pub const espidf: Os = Os::new_const("espidf");
     pub const freebsd: Os = Os::new_const("freebsd");
     pub const fuchsia: Os = Os::new_const("fuchsia");
     pub const haiku: Os = Os::new_const("haiku");
+    pub const helenos: Os = Os::new_const("helenos");
     pub const hermit: Os = Os::new_const("hermit");
     pub const horizon: Os = Os::new_const("horizon");
+    pub const hurd: Os = Os::new_const("hurd");
     pub const illumos: Os = Os::new_const("illumos");
     pub const ios: Os = Os::new_const("ios");
     pub const l4re: Os = Os::new_const("l4re");
     pub const linux: Os = Os::new_const("linux");
+    pub const lynxos178: Os = Os::new_const("lynxos178");
     pub const macos: Os = Os::new_const("macos");
+    pub const managarm: Os = Os::new_const("managarm");
+    pub const motor: Os = Os::new_const("motor");
     pub const netbsd: Os = Os::new_const("netbsd");
+    pub const none: Os = Os::new_const("none");
     pub const nto: Os = Os::new_const("nto");
+    pub const nuttx: Os = Os::new_const("nuttx");
     pub const openbsd: Os = Os::new_const("openbsd");
     pub const psp: Os = Os::new_const("psp");
+    pub const psx: Os = Os::new_const("psx");
+    pub const qurt: Os = Os::new_const("qurt");
     pub const redox: Os = Os::new_const("redox");
+    pub const rtems: Os = Os::new_const("rtems");
     pub const solaris: Os = Os::new_const("solaris");
     pub const solid_asp3: Os = Os::new_const("solid_asp3");
+    pub const teeos: Os = Os::new_const("teeos");
+    pub const trusty: Os = Os::new_const("trusty");
     pub const tvos: Os = Os::new_const("tvos");
     pub const uefi: Os = Os::new_const("uefi");
     pub const unknown: Os = Os::new_const("unknown");
+    pub const vexos: Os = Os::new_const("vexos");
+    pub const visionos: Os = Os::new_const("visionos");
     pub const vita: Os = Os::new_const("vita");
     pub const vxworks: Os = Os::new_const("vxworks");
     pub const wasi: Os = Os::new_const("wasi");
     pub const watchos: Os = Os::new_const("watchos");
     pub const windows: Os = Os::new_const("windows");
     pub const xous: Os = Os::new_const("xous");
+    pub const zkvm: Os = Os::new_const("zkvm");
+
+    /// Every known value, ie. the values of the builtin targets, and the
+    /// values documented in the reference, sorted
+    pub const ALL_KNOWN: &[Os] = &[
+        Os::aix,
+        Os::amdhsa,
+        Os::android,
+        Os::cuda,
+        Os::cygwin,
+        Os::dragonfly,
+        Os::emscripten,
+        Os::espidf,
+        Os::freebsd,
+        Os::fuchsia,
+        Os::haiku,
+        Os::helenos,
+        Os::hermit,
+        Os::horizon,
+        Os::hurd,
+        Os::illumos,
+        Os::ios,
+        Os::l4re,
+        Os::linux,
+        Os::lynxos178,
+        Os::macos,
+        Os::managarm,
+        Os::motor,
+        Os::netbsd,
+        Os::none,
+        Os::nto,
+        Os::nuttx,
+        Os::openbsd,
+        Os::psp,
+        Os::psx,
+        Os::qurt,
+        Os::redox,
+        Os::rtems,
+        Os::solaris,
+        Os::solid_asp3,
+        Os::teeos,
+        Os::trusty,
+        Os::tvos,
+        Os::uefi,
+        Os::unknown,
+        Os::vexos,
+        Os::visionos,
+        Os::vita,
+        Os::vxworks,
+        Os::wasi,
+        Os::watchos,
+        Os::windows,
+        Os::xous,
+        Os::zkvm,
+    ];
 }
 
 impl super::Family {
     pub const unix: Family = Family::new_const("unix");
     pub const wasm: Family = Family::new_const("wasm");
     pub const windows: Family = Family::new_const("windows");
+
+    /// Every known value, ie. the values of the builtin targets, and the
+    /// values documented in the reference, sorted
+    pub const ALL_KNOWN: &[Family] = &[Family::unix, Family::wasm, Family::windows];
 }
 
 const __families_unix: &[Family] = &[Family::unix];
@@ -2842,21 +5158,53 @@ impl super::Families {
 }
 
 impl super::Env {
-    pub const eabihf: Env = Env::new_const("eabihf");
     pub const gnu: Env = Env::new_const("gnu");
-    pub const gnueabihf: Env = Env::new_const("gnueabihf");
+    pub const macabi: Env = Env::new_const("macabi");
+    pub const mlibc: Env = Env::new_const("mlibc");
     pub const msvc: Env = Env::new_const("msvc");
     pub const musl: Env = Env::new_const("musl");
     pub const newlib: Env = Env::new_const("newlib");
     pub const nto70: Env = Env::new_const("nto70");
     pub const nto71: Env = Env::new_const("nto71");
+    pub const nto71_iosock: Env = Env::new_const("nto71_iosock");
+    pub const nto80: Env = Env::new_const("nto80");
     pub const ohos: Env = Env::new_const("ohos");
-    pub const psx: Env = Env::new_const("psx");
+    pub const p1: Env = Env::new_const("p1");
+    pub const p2: Env = Env::new_const("p2");
+    pub const p3: Env = Env::new_const("p3");
     pub const relibc: Env = Env::new_const("relibc");
     pub const sgx: Env = Env::new_const("sgx");
+    pub const sim: Env = Env::new_const("sim");
     pub const uclibc: Env = Env::new_const("uclibc");
+    pub const v5: Env = Env::new_const("v5");
+
+    /// Every known value, ie. the values of the builtin targets, and the
+    /// values documented in the reference, sorted
+    pub const ALL_KNOWN: &[Env] = &[
+        Env::gnu,
+        Env::macabi,
+        Env::mlibc,
+        Env::msvc,
+        Env::musl,
+        Env::newlib,
+        Env::nto70,
+        Env::nto71,
+        Env::nto71_iosock,
+        Env::nto80,
+        Env::ohos,
+        Env::p1,
+        Env::p2,
+        Env::p3,
+        Env::relibc,
+        Env::sgx,
+        Env::sim,
+        Env::uclibc,
+        Env::v5,
+    ];
 }
 
+const __has_atomics_8: &[HasAtomic] = &[HasAtomic::IntegerSize(8)];
+const __has_atomics_8_16: &[HasAtomic] = &[HasAtomic::IntegerSize(8), HasAtomic::IntegerSize(16)];
 const __has_atomics_8_16_32_64_128_ptr: &[HasAtomic] = &[
     HasAtomic::IntegerSize(8),
     HasAtomic::IntegerSize(16),
@@ -2878,16 +5226,30 @@ const __has_atomics_8_16_32_ptr: &[HasAtomic] = &[
     HasAtomic::IntegerSize(32),
     HasAtomic::Pointer,
 ];
+const __has_atomics_8_16_ptr: &[HasAtomic] = &[
+    HasAtomic::IntegerSize(8),
+    HasAtomic::IntegerSize(16),
+    HasAtomic::Pointer,
+];
+const __has_atomics_64_ptr: &[HasAtomic] = &[HasAtomic::IntegerSize(64), HasAtomic::Pointer];
 
 impl super::HasAtomics {
+    pub const atomic_8: HasAtomics = HasAtomics::new_const(__has_atomics_8);
+    pub const atomic_8_16: HasAtomics = HasAtomics::new_const(__has_atomics_8_16);
     pub const atomic_8_16_32_64_128_ptr: HasAtomics =
         HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr);
     pub const atomic_8_16_32_64_ptr: HasAtomics =
         HasAtomics::new_const(__has_atomics_8_16_32_64_ptr);
     pub const atomic_8_16_32_ptr: HasAtomics = HasAtomics::new_const(__has_atomics_8_16_32_ptr);
+    pub const atomic_8_16_ptr: HasAtomics = HasAtomics::new_const(__has_atomics_8_16_ptr);
+    pub const atomic_64_ptr: HasAtomics = HasAtomics::new_const(__has_atomics_64_ptr);
 }
 
 impl super::Panic {
     pub const abort: Panic = Panic::new_const("abort");
     pub const unwind: Panic = Panic::new_const("unwind");
+
+    /// Every known value, ie. the values of the builtin targets, and the
+    /// values documented in the reference, sorted
+    pub const ALL_KNOWN: &[Panic] = &[Panic::abort, Panic::unwind];
 }
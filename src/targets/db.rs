@@ -0,0 +1,133 @@
+use super::{builtins, TargetInfo, TargetRegistry, TargetSearch, ALL_BUILTINS};
+use crate::expr::RustVersion;
+
+#[cfg(feature = "rustc-1-70")]
+mod rustc_1_70;
+#[cfg(feature = "rustc-1-72")]
+mod rustc_1_72;
+#[cfg(feature = "rustc-1-75")]
+mod rustc_1_75;
+#[cfg(feature = "rustc-1-80")]
+mod rustc_1_80;
+
+/// The targets of a specific version of rustc, eg. for auditing a crate
+/// against the targets and cfg values of its minimum supported Rust version
+/// instead of the latest ones.
+///
+/// The [builtin](ALL_BUILTINS) targets are always available, and the targets
+/// of rustc 1.70, 1.72, 1.75, and 1.80 are each enabled by a feature, eg.
+/// `rustc-1-75`.
+///
+/// ```
+/// use cfg_expr::targets::TargetDb;
+///
+/// let latest = TargetDb::latest();
+/// assert_eq!(latest.rustc_version(), cfg_expr::targets::rustc_version());
+/// assert!(latest.get_target_by_triple("wasm32-wasip2").is_some());
+///
+/// // Versions newer than the builtin targets use them
+/// assert_eq!(TargetDb::for_rustc_version("2.0"), Some(latest));
+/// assert!(TargetDb::for_rustc_version("not a version").is_none());
+///
+/// // Versions older than every database use the oldest one
+/// let oldest = TargetDb::for_rustc_version("1.0").unwrap();
+/// assert_eq!(oldest, TargetDb::all()[0]);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TargetDb {
+    rustc_version: &'static str,
+    targets: &'static [TargetInfo],
+}
+
+/// Every available database, from the oldest to the newest version of rustc
+const ALL_DBS: &[TargetDb] = &[
+    #[cfg(feature = "rustc-1-70")]
+    TargetDb {
+        rustc_version: rustc_1_70::RUSTC_VERSION,
+        targets: rustc_1_70::TARGETS,
+    },
+    #[cfg(feature = "rustc-1-72")]
+    TargetDb {
+        rustc_version: rustc_1_72::RUSTC_VERSION,
+        targets: rustc_1_72::TARGETS,
+    },
+    #[cfg(feature = "rustc-1-75")]
+    TargetDb {
+        rustc_version: rustc_1_75::RUSTC_VERSION,
+        targets: rustc_1_75::TARGETS,
+    },
+    #[cfg(feature = "rustc-1-80")]
+    TargetDb {
+        rustc_version: rustc_1_80::RUSTC_VERSION,
+        targets: rustc_1_80::TARGETS,
+    },
+    TargetDb {
+        rustc_version: builtins::RUSTC_VERSION,
+        targets: ALL_BUILTINS,
+    },
+];
+
+impl TargetDb {
+    /// The database of the builtin targets
+    pub fn latest() -> Self {
+        ALL_DBS[ALL_DBS.len() - 1]
+    }
+
+    /// Every available database, from the oldest to the newest version of
+    /// rustc
+    pub fn all() -> &'static [TargetDb] {
+        ALL_DBS
+    }
+
+    /// The database with the targets as they were in the version of rustc,
+    /// eg. `1.75` or `1.75.0`, ie. the database of the newest version of
+    /// rustc that isn't newer than the version.
+    ///
+    /// If the version is older than every available database, eg. `1.75`
+    /// when the `rustc-1-*` features aren't enabled, the oldest database is
+    /// used instead, as it is the nearest to the version. Check
+    /// [`rustc_version`](Self::rustc_version) to see which one was used.
+    ///
+    /// Returns `None` if the version can't be parsed.
+    pub fn for_rustc_version(version: &str) -> Option<Self> {
+        let version: RustVersion = version.trim().parse().ok()?;
+
+        let db = ALL_DBS.iter().rev().copied().find(|db| {
+            db.rustc_version
+                .parse::<RustVersion>()
+                .map_or(false, |db_version| db_version.matches(&version))
+        });
+        Some(db.unwrap_or(ALL_DBS[0]))
+    }
+
+    /// The version of rustc the targets were retrieved from
+    pub fn rustc_version(&self) -> &'static str {
+        self.rustc_version
+    }
+
+    /// The targets, sorted by their triple
+    pub fn targets(&self) -> &'static [TargetInfo] {
+        self.targets
+    }
+
+    /// Retrieves the target with the triple, see
+    /// [`get_builtin_target_by_triple`](super::get_builtin_target_by_triple),
+    /// except that renamed triples aren't resolved
+    pub fn get_target_by_triple(&self, triple: &str) -> Option<&'static TargetInfo> {
+        self.targets
+            .binary_search_by(|ti| ti.triple.as_str().cmp(triple))
+            .ok()
+            .map(|i| &self.targets[i])
+    }
+
+    /// Searches the targets, see [`search`](super::search)
+    pub fn search(&self) -> TargetSearch<'static> {
+        TargetSearch::new(self.targets)
+    }
+
+    /// A registry with the targets as its builtin targets, so that custom
+    /// targets can be added to them
+    pub fn registry(&self) -> TargetRegistry {
+        TargetRegistry::with_builtins(self.targets)
+    }
+}
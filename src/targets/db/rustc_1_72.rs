@@ -0,0 +1,3202 @@
+/*
+ *
+ * AUTO-GENERATED BY ./update
+ * DO NOT MODIFY
+ *
+ * cargo run --manifest-path update/Cargo.toml -- --snapshot
+ */
+
+#![allow(non_upper_case_globals)]
+
+use crate::targets::*;
+
+pub(crate) const RUSTC_VERSION: &str = "1.72.0";
+
+pub(crate) const TARGETS: &[TargetInfo] = &[
+    TargetInfo {
+        triple: Triple::new_const("aarch64-apple-darwin"),
+        os: Some(Os::new_const("macos")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: None,
+        vendor: Some(Vendor::new_const("apple")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-apple-ios"),
+        os: Some(Os::new_const("ios")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: None,
+        vendor: Some(Vendor::new_const("apple")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-apple-ios-macabi"),
+        os: Some(Os::new_const("ios")),
+        abi: Some(Abi::new_const("macabi")),
+        arch: Arch::new_const("aarch64"),
+        env: None,
+        vendor: Some(Vendor::new_const("apple")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-apple-ios-sim"),
+        os: Some(Os::new_const("ios")),
+        abi: Some(Abi::new_const("sim")),
+        arch: Arch::new_const("aarch64"),
+        env: None,
+        vendor: Some(Vendor::new_const("apple")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-apple-tvos"),
+        os: Some(Os::new_const("tvos")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: None,
+        vendor: Some(Vendor::new_const("apple")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-apple-watchos-sim"),
+        os: Some(Os::new_const("watchos")),
+        abi: Some(Abi::new_const("sim")),
+        arch: Arch::new_const("aarch64"),
+        env: None,
+        vendor: Some(Vendor::new_const("apple")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-fuchsia"),
+        os: Some(Os::new_const("fuchsia")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-kmc-solid_asp3"),
+        os: Some(Os::new_const("solid_asp3")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: None,
+        vendor: Some(Vendor::new_const("kmc")),
+        families: Families::new_const(__families_none),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-linux-android"),
+        os: Some(Os::new_const("android")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-nintendo-switch-freestanding"),
+        os: Some(Os::new_const("horizon")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-pc-windows-gnullvm"),
+        os: Some(Os::new_const("windows")),
+        abi: Some(Abi::new_const("llvm")),
+        arch: Arch::new_const("aarch64"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("pc")),
+        families: Families::new_const(__families_windows),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-pc-windows-msvc"),
+        os: Some(Os::new_const("windows")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: Some(Env::new_const("msvc")),
+        vendor: Some(Vendor::new_const("pc")),
+        families: Families::new_const(__families_windows),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-freebsd"),
+        os: Some(Os::new_const("freebsd")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-fuchsia"),
+        os: Some(Os::new_const("fuchsia")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-hermit"),
+        os: Some(Os::new_const("hermit")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-linux-gnu"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-linux-gnu_ilp32"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("ilp32")),
+        arch: Arch::new_const("aarch64"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-linux-musl"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: Some(Env::new_const("musl")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-linux-ohos"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: Some(Env::new_const("ohos")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-netbsd"),
+        os: Some(Os::new_const("netbsd")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-none"),
+        os: None,
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-none-softfloat"),
+        os: None,
+        abi: Some(Abi::new_const("softfloat")),
+        arch: Arch::new_const("aarch64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-nto-qnx710"),
+        os: Some(Os::new_const("nto")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: Some(Env::new_const("nto71")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-openbsd"),
+        os: Some(Os::new_const("openbsd")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-redox"),
+        os: Some(Os::new_const("redox")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: Some(Env::new_const("relibc")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-unknown-uefi"),
+        os: Some(Os::new_const("uefi")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-uwp-windows-msvc"),
+        os: Some(Os::new_const("windows")),
+        abi: Some(Abi::new_const("uwp")),
+        arch: Arch::new_const("aarch64"),
+        env: Some(Env::new_const("msvc")),
+        vendor: Some(Vendor::new_const("uwp")),
+        families: Families::new_const(__families_windows),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64-wrs-vxworks"),
+        os: Some(Os::new_const("vxworks")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("wrs")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64_be-unknown-linux-gnu"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64_be-unknown-linux-gnu_ilp32"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("ilp32")),
+        arch: Arch::new_const("aarch64"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("aarch64_be-unknown-netbsd"),
+        os: Some(Os::new_const("netbsd")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("arm-linux-androideabi"),
+        os: Some(Os::new_const("android")),
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("arm-unknown-linux-gnueabi"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("arm-unknown-linux-gnueabihf"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("eabihf")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("arm-unknown-linux-musleabi"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("musl")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("arm-unknown-linux-musleabihf"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("eabihf")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("musl")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("arm64_32-apple-watchos"),
+        os: Some(Os::new_const("watchos")),
+        abi: None,
+        arch: Arch::new_const("aarch64"),
+        env: None,
+        vendor: Some(Vendor::new_const("apple")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armeb-unknown-linux-gnueabi"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armebv7r-none-eabi"),
+        os: None,
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armebv7r-none-eabihf"),
+        os: None,
+        abi: Some(Abi::new_const("eabihf")),
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv4t-none-eabi"),
+        os: None,
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_none),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_none),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_none),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv4t-unknown-linux-gnueabi"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv5te-none-eabi"),
+        os: None,
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_none),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_none),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_none),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv5te-unknown-linux-gnueabi"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv5te-unknown-linux-musleabi"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("musl")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv5te-unknown-linux-uclibceabi"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("uclibc")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv6-unknown-freebsd"),
+        os: Some(Os::new_const("freebsd")),
+        abi: Some(Abi::new_const("eabihf")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("gnueabihf")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv6-unknown-netbsd-eabihf"),
+        os: Some(Os::new_const("netbsd")),
+        abi: Some(Abi::new_const("eabihf")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("eabihf")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv6k-nintendo-3ds"),
+        os: Some(Os::new_const("horizon")),
+        abi: Some(Abi::new_const("eabihf")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("newlib")),
+        vendor: Some(Vendor::new_const("nintendo")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7-apple-ios"),
+        os: Some(Os::new_const("ios")),
+        abi: None,
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("apple")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7-linux-androideabi"),
+        os: Some(Os::new_const("android")),
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7-sony-vita-newlibeabihf"),
+        os: Some(Os::new_const("vita")),
+        abi: Some(Abi::new_const("eabihf")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("newlib")),
+        vendor: Some(Vendor::new_const("sony")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7-unknown-freebsd"),
+        os: Some(Os::new_const("freebsd")),
+        abi: Some(Abi::new_const("eabihf")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("gnueabihf")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7-unknown-linux-gnueabi"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7-unknown-linux-gnueabihf"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("eabihf")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7-unknown-linux-musleabi"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("musl")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7-unknown-linux-musleabihf"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("eabihf")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("musl")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7-unknown-linux-ohos"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("ohos")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7-unknown-linux-uclibceabi"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("uclibc")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7-unknown-linux-uclibceabihf"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("eabihf")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("uclibc")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7-unknown-netbsd-eabihf"),
+        os: Some(Os::new_const("netbsd")),
+        abi: Some(Abi::new_const("eabihf")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("eabihf")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7-wrs-vxworks-eabihf"),
+        os: Some(Os::new_const("vxworks")),
+        abi: Some(Abi::new_const("eabihf")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("wrs")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7a-kmc-solid_asp3-eabi"),
+        os: Some(Os::new_const("solid_asp3")),
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("kmc")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7a-kmc-solid_asp3-eabihf"),
+        os: Some(Os::new_const("solid_asp3")),
+        abi: Some(Abi::new_const("eabihf")),
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("kmc")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7a-none-eabi"),
+        os: None,
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7a-none-eabihf"),
+        os: None,
+        abi: Some(Abi::new_const("eabihf")),
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7k-apple-watchos"),
+        os: Some(Os::new_const("watchos")),
+        abi: None,
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("apple")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7r-none-eabi"),
+        os: None,
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7r-none-eabihf"),
+        os: None,
+        abi: Some(Abi::new_const("eabihf")),
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("armv7s-apple-ios"),
+        os: Some(Os::new_const("ios")),
+        abi: None,
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("apple")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("asmjs-unknown-emscripten"),
+        os: Some(Os::new_const("emscripten")),
+        abi: None,
+        arch: Arch::new_const("wasm32"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix_wasm),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("avr-unknown-gnu-atmega328"),
+        os: None,
+        abi: None,
+        arch: Arch::new_const("avr"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 16,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_none),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("bpfeb-unknown-none"),
+        os: None,
+        abi: None,
+        arch: Arch::new_const("bpf"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_none),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("bpfel-unknown-none"),
+        os: None,
+        abi: None,
+        arch: Arch::new_const("bpf"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_none),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("hexagon-unknown-linux-musl"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("hexagon"),
+        env: Some(Env::new_const("musl")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("i386-apple-ios"),
+        os: Some(Os::new_const("ios")),
+        abi: Some(Abi::new_const("sim")),
+        arch: Arch::new_const("x86"),
+        env: None,
+        vendor: Some(Vendor::new_const("apple")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("i586-pc-nto-qnx700"),
+        os: Some(Os::new_const("nto")),
+        abi: None,
+        arch: Arch::new_const("x86"),
+        env: Some(Env::new_const("nto70")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("i586-pc-windows-msvc"),
+        os: Some(Os::new_const("windows")),
+        abi: None,
+        arch: Arch::new_const("x86"),
+        env: Some(Env::new_const("msvc")),
+        vendor: Some(Vendor::new_const("pc")),
+        families: Families::new_const(__families_windows),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("i586-unknown-linux-gnu"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("x86"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("i586-unknown-linux-musl"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("x86"),
+        env: Some(Env::new_const("musl")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("i686-apple-darwin"),
+        os: Some(Os::new_const("macos")),
+        abi: None,
+        arch: Arch::new_const("x86"),
+        env: None,
+        vendor: Some(Vendor::new_const("apple")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("i686-linux-android"),
+        os: Some(Os::new_const("android")),
+        abi: None,
+        arch: Arch::new_const("x86"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("i686-pc-windows-gnu"),
+        os: Some(Os::new_const("windows")),
+        abi: None,
+        arch: Arch::new_const("x86"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("pc")),
+        families: Families::new_const(__families_windows),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("i686-pc-windows-msvc"),
+        os: Some(Os::new_const("windows")),
+        abi: None,
+        arch: Arch::new_const("x86"),
+        env: Some(Env::new_const("msvc")),
+        vendor: Some(Vendor::new_const("pc")),
+        families: Families::new_const(__families_windows),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("i686-unknown-freebsd"),
+        os: Some(Os::new_const("freebsd")),
+        abi: None,
+        arch: Arch::new_const("x86"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("i686-unknown-haiku"),
+        os: Some(Os::new_const("haiku")),
+        abi: None,
+        arch: Arch::new_const("x86"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("i686-unknown-linux-gnu"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("x86"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("i686-unknown-linux-musl"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("x86"),
+        env: Some(Env::new_const("musl")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("i686-unknown-netbsd"),
+        os: Some(Os::new_const("netbsd")),
+        abi: None,
+        arch: Arch::new_const("x86"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("i686-unknown-openbsd"),
+        os: Some(Os::new_const("openbsd")),
+        abi: None,
+        arch: Arch::new_const("x86"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("i686-unknown-uefi"),
+        os: Some(Os::new_const("uefi")),
+        abi: None,
+        arch: Arch::new_const("x86"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("i686-uwp-windows-gnu"),
+        os: Some(Os::new_const("windows")),
+        abi: Some(Abi::new_const("uwp")),
+        arch: Arch::new_const("x86"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("uwp")),
+        families: Families::new_const(__families_windows),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("i686-uwp-windows-msvc"),
+        os: Some(Os::new_const("windows")),
+        abi: Some(Abi::new_const("uwp")),
+        arch: Arch::new_const("x86"),
+        env: Some(Env::new_const("msvc")),
+        vendor: Some(Vendor::new_const("uwp")),
+        families: Families::new_const(__families_windows),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("i686-wrs-vxworks"),
+        os: Some(Os::new_const("vxworks")),
+        abi: None,
+        arch: Arch::new_const("x86"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("wrs")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("loongarch64-unknown-linux-gnu"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("loongarch64"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("loongarch64-unknown-none"),
+        os: None,
+        abi: None,
+        arch: Arch::new_const("loongarch64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("loongarch64-unknown-none-softfloat"),
+        os: None,
+        abi: Some(Abi::new_const("softfloat")),
+        arch: Arch::new_const("loongarch64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("m68k-unknown-linux-gnu"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("m68k"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("mips-unknown-linux-gnu"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("mips"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("mips-unknown-linux-musl"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("mips"),
+        env: Some(Env::new_const("musl")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("mips-unknown-linux-uclibc"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("mips"),
+        env: Some(Env::new_const("uclibc")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("mips64-openwrt-linux-musl"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("abi64")),
+        arch: Arch::new_const("mips64"),
+        env: Some(Env::new_const("musl")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("mips64-unknown-linux-gnuabi64"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("abi64")),
+        arch: Arch::new_const("mips64"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("mips64-unknown-linux-muslabi64"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("abi64")),
+        arch: Arch::new_const("mips64"),
+        env: Some(Env::new_const("musl")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("mips64el-unknown-linux-gnuabi64"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("abi64")),
+        arch: Arch::new_const("mips64"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("mips64el-unknown-linux-muslabi64"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("abi64")),
+        arch: Arch::new_const("mips64"),
+        env: Some(Env::new_const("musl")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("mipsel-sony-psp"),
+        os: Some(Os::new_const("psp")),
+        abi: None,
+        arch: Arch::new_const("mips"),
+        env: None,
+        vendor: Some(Vendor::new_const("sony")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("mipsel-sony-psx"),
+        os: None,
+        abi: None,
+        arch: Arch::new_const("mips"),
+        env: Some(Env::new_const("psx")),
+        vendor: Some(Vendor::new_const("sony")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_none),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_none),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_none),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("mipsel-unknown-linux-gnu"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("mips"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("mipsel-unknown-linux-musl"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("mips"),
+        env: Some(Env::new_const("musl")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("mipsel-unknown-linux-uclibc"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("mips"),
+        env: Some(Env::new_const("uclibc")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("mipsel-unknown-none"),
+        os: None,
+        abi: None,
+        arch: Arch::new_const("mips"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("mipsisa32r6-unknown-linux-gnu"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("mips"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("mipsisa32r6el-unknown-linux-gnu"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("mips"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("mipsisa64r6-unknown-linux-gnuabi64"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("abi64")),
+        arch: Arch::new_const("mips64"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("mipsisa64r6el-unknown-linux-gnuabi64"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("abi64")),
+        arch: Arch::new_const("mips64"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("msp430-none-elf"),
+        os: None,
+        abi: None,
+        arch: Arch::new_const("msp430"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 16,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_none),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_none),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_none),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("nvptx64-nvidia-cuda"),
+        os: Some(Os::new_const("cuda")),
+        abi: None,
+        arch: Arch::new_const("nvptx64"),
+        env: None,
+        vendor: Some(Vendor::new_const("nvidia")),
+        families: Families::new_const(__families_none),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("powerpc-unknown-freebsd"),
+        os: Some(Os::new_const("freebsd")),
+        abi: None,
+        arch: Arch::new_const("powerpc"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("powerpc-unknown-linux-gnu"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("powerpc"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("powerpc-unknown-linux-gnuspe"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("spe")),
+        arch: Arch::new_const("powerpc"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("powerpc-unknown-linux-musl"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("powerpc"),
+        env: Some(Env::new_const("musl")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("powerpc-unknown-netbsd"),
+        os: Some(Os::new_const("netbsd")),
+        abi: None,
+        arch: Arch::new_const("powerpc"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("powerpc-unknown-openbsd"),
+        os: Some(Os::new_const("openbsd")),
+        abi: None,
+        arch: Arch::new_const("powerpc"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("powerpc-wrs-vxworks"),
+        os: Some(Os::new_const("vxworks")),
+        abi: None,
+        arch: Arch::new_const("powerpc"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("wrs")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("powerpc-wrs-vxworks-spe"),
+        os: Some(Os::new_const("vxworks")),
+        abi: Some(Abi::new_const("spe")),
+        arch: Arch::new_const("powerpc"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("wrs")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("powerpc64-ibm-aix"),
+        os: Some(Os::new_const("aix")),
+        abi: Some(Abi::new_const("vec-extabi")),
+        arch: Arch::new_const("powerpc64"),
+        env: None,
+        vendor: Some(Vendor::new_const("ibm")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("powerpc64-unknown-freebsd"),
+        os: Some(Os::new_const("freebsd")),
+        abi: Some(Abi::new_const("elfv2")),
+        arch: Arch::new_const("powerpc64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("powerpc64-unknown-linux-gnu"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("elfv1")),
+        arch: Arch::new_const("powerpc64"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("powerpc64-unknown-linux-musl"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("elfv2")),
+        arch: Arch::new_const("powerpc64"),
+        env: Some(Env::new_const("musl")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("powerpc64-unknown-openbsd"),
+        os: Some(Os::new_const("openbsd")),
+        abi: Some(Abi::new_const("elfv2")),
+        arch: Arch::new_const("powerpc64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("powerpc64-wrs-vxworks"),
+        os: Some(Os::new_const("vxworks")),
+        abi: Some(Abi::new_const("elfv1")),
+        arch: Arch::new_const("powerpc64"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("wrs")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("powerpc64le-unknown-freebsd"),
+        os: Some(Os::new_const("freebsd")),
+        abi: Some(Abi::new_const("elfv2")),
+        arch: Arch::new_const("powerpc64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("powerpc64le-unknown-linux-gnu"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("elfv2")),
+        arch: Arch::new_const("powerpc64"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("powerpc64le-unknown-linux-musl"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("elfv2")),
+        arch: Arch::new_const("powerpc64"),
+        env: Some(Env::new_const("musl")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv32gc-unknown-linux-gnu"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("riscv32"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv32gc-unknown-linux-musl"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("riscv32"),
+        env: Some(Env::new_const("musl")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv32i-unknown-none-elf"),
+        os: None,
+        abi: None,
+        arch: Arch::new_const("riscv32"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_none),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv32im-unknown-none-elf"),
+        os: None,
+        abi: None,
+        arch: Arch::new_const("riscv32"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_none),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv32imac-esp-espidf"),
+        os: Some(Os::new_const("espidf")),
+        abi: None,
+        arch: Arch::new_const("riscv32"),
+        env: Some(Env::new_const("newlib")),
+        vendor: Some(Vendor::new_const("espressif")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv32imac-unknown-none-elf"),
+        os: None,
+        abi: None,
+        arch: Arch::new_const("riscv32"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv32imac-unknown-xous-elf"),
+        os: Some(Os::new_const("xous")),
+        abi: None,
+        arch: Arch::new_const("riscv32"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv32imc-esp-espidf"),
+        os: Some(Os::new_const("espidf")),
+        abi: None,
+        arch: Arch::new_const("riscv32"),
+        env: Some(Env::new_const("newlib")),
+        vendor: Some(Vendor::new_const("espressif")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv32imc-unknown-none-elf"),
+        os: None,
+        abi: None,
+        arch: Arch::new_const("riscv32"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_none),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv64gc-unknown-freebsd"),
+        os: Some(Os::new_const("freebsd")),
+        abi: None,
+        arch: Arch::new_const("riscv64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv64gc-unknown-fuchsia"),
+        os: Some(Os::new_const("fuchsia")),
+        abi: None,
+        arch: Arch::new_const("riscv64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv64gc-unknown-linux-gnu"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("riscv64"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv64gc-unknown-linux-musl"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("riscv64"),
+        env: Some(Env::new_const("musl")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv64gc-unknown-netbsd"),
+        os: Some(Os::new_const("netbsd")),
+        abi: None,
+        arch: Arch::new_const("riscv64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv64gc-unknown-none-elf"),
+        os: None,
+        abi: None,
+        arch: Arch::new_const("riscv64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv64gc-unknown-openbsd"),
+        os: Some(Os::new_const("openbsd")),
+        abi: None,
+        arch: Arch::new_const("riscv64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("riscv64imac-unknown-none-elf"),
+        os: None,
+        abi: None,
+        arch: Arch::new_const("riscv64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("s390x-unknown-linux-gnu"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("s390x"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("s390x-unknown-linux-musl"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("s390x"),
+        env: Some(Env::new_const("musl")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("sparc-unknown-linux-gnu"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("sparc"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("sparc64-unknown-linux-gnu"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("sparc64"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("sparc64-unknown-netbsd"),
+        os: Some(Os::new_const("netbsd")),
+        abi: None,
+        arch: Arch::new_const("sparc64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("sparc64-unknown-openbsd"),
+        os: Some(Os::new_const("openbsd")),
+        abi: None,
+        arch: Arch::new_const("sparc64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("sparcv9-sun-solaris"),
+        os: Some(Os::new_const("solaris")),
+        abi: None,
+        arch: Arch::new_const("sparc64"),
+        env: None,
+        vendor: Some(Vendor::new_const("sun")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::big,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv4t-none-eabi"),
+        os: None,
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_none),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_none),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_none),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv5te-none-eabi"),
+        os: None,
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_none),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_none),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_none),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv6m-none-eabi"),
+        os: None,
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_none),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv7a-pc-windows-msvc"),
+        os: Some(Os::new_const("windows")),
+        abi: None,
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("msvc")),
+        vendor: Some(Vendor::new_const("pc")),
+        families: Families::new_const(__families_windows),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv7a-uwp-windows-msvc"),
+        os: Some(Os::new_const("windows")),
+        abi: Some(Abi::new_const("uwp")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("msvc")),
+        vendor: Some(Vendor::new_const("uwp")),
+        families: Families::new_const(__families_windows),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv7em-none-eabi"),
+        os: None,
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv7em-none-eabihf"),
+        os: None,
+        abi: Some(Abi::new_const("eabihf")),
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv7m-none-eabi"),
+        os: None,
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv7neon-linux-androideabi"),
+        os: Some(Os::new_const("android")),
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv7neon-unknown-linux-gnueabihf"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("eabihf")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv7neon-unknown-linux-musleabihf"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("eabihf")),
+        arch: Arch::new_const("arm"),
+        env: Some(Env::new_const("musl")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv8m.base-none-eabi"),
+        os: None,
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv8m.main-none-eabi"),
+        os: None,
+        abi: Some(Abi::new_const("eabi")),
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("thumbv8m.main-none-eabihf"),
+        os: None,
+        abi: Some(Abi::new_const("eabihf")),
+        arch: Arch::new_const("arm"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("wasm32-unknown-emscripten"),
+        os: Some(Os::new_const("emscripten")),
+        abi: None,
+        arch: Arch::new_const("wasm32"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix_wasm),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("wasm32-unknown-unknown"),
+        os: Some(Os::new_const("unknown")),
+        abi: None,
+        arch: Arch::new_const("wasm32"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_wasm),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("wasm32-wasi"),
+        os: Some(Os::new_const("wasi")),
+        abi: None,
+        arch: Arch::new_const("wasm32"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_wasm),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("wasm64-unknown-unknown"),
+        os: Some(Os::new_const("unknown")),
+        abi: None,
+        arch: Arch::new_const("wasm64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_wasm),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-apple-darwin"),
+        os: Some(Os::new_const("macos")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: None,
+        vendor: Some(Vendor::new_const("apple")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-apple-ios"),
+        os: Some(Os::new_const("ios")),
+        abi: Some(Abi::new_const("sim")),
+        arch: Arch::new_const("x86_64"),
+        env: None,
+        vendor: Some(Vendor::new_const("apple")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-apple-ios-macabi"),
+        os: Some(Os::new_const("ios")),
+        abi: Some(Abi::new_const("macabi")),
+        arch: Arch::new_const("x86_64"),
+        env: None,
+        vendor: Some(Vendor::new_const("apple")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-apple-tvos"),
+        os: Some(Os::new_const("tvos")),
+        abi: Some(Abi::new_const("sim")),
+        arch: Arch::new_const("x86_64"),
+        env: None,
+        vendor: Some(Vendor::new_const("apple")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-apple-watchos-sim"),
+        os: Some(Os::new_const("watchos")),
+        abi: Some(Abi::new_const("sim")),
+        arch: Arch::new_const("x86_64"),
+        env: None,
+        vendor: Some(Vendor::new_const("apple")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-fortanix-unknown-sgx"),
+        os: Some(Os::new_const("unknown")),
+        abi: Some(Abi::new_const("fortanix")),
+        arch: Arch::new_const("x86_64"),
+        env: Some(Env::new_const("sgx")),
+        vendor: Some(Vendor::new_const("fortanix")),
+        families: Families::new_const(__families_none),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-fuchsia"),
+        os: Some(Os::new_const("fuchsia")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-linux-android"),
+        os: Some(Os::new_const("android")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-pc-nto-qnx710"),
+        os: Some(Os::new_const("nto")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: Some(Env::new_const("nto71")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-pc-solaris"),
+        os: Some(Os::new_const("solaris")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: None,
+        vendor: Some(Vendor::new_const("pc")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-pc-windows-gnu"),
+        os: Some(Os::new_const("windows")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("pc")),
+        families: Families::new_const(__families_windows),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-pc-windows-gnullvm"),
+        os: Some(Os::new_const("windows")),
+        abi: Some(Abi::new_const("llvm")),
+        arch: Arch::new_const("x86_64"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("pc")),
+        families: Families::new_const(__families_windows),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-pc-windows-msvc"),
+        os: Some(Os::new_const("windows")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: Some(Env::new_const("msvc")),
+        vendor: Some(Vendor::new_const("pc")),
+        families: Families::new_const(__families_windows),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-sun-solaris"),
+        os: Some(Os::new_const("solaris")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: None,
+        vendor: Some(Vendor::new_const("sun")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-dragonfly"),
+        os: Some(Os::new_const("dragonfly")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-freebsd"),
+        os: Some(Os::new_const("freebsd")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-fuchsia"),
+        os: Some(Os::new_const("fuchsia")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-haiku"),
+        os: Some(Os::new_const("haiku")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-hermit"),
+        os: Some(Os::new_const("hermit")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-illumos"),
+        os: Some(Os::new_const("illumos")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-l4re-uclibc"),
+        os: Some(Os::new_const("l4re")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: Some(Env::new_const("uclibc")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-linux-gnu"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-linux-gnux32"),
+        os: Some(Os::new_const("linux")),
+        abi: Some(Abi::new_const("x32")),
+        arch: Arch::new_const("x86_64"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 32,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-linux-musl"),
+        os: Some(Os::new_const("linux")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: Some(Env::new_const("musl")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-netbsd"),
+        os: Some(Os::new_const("netbsd")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-none"),
+        os: None,
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-openbsd"),
+        os: Some(Os::new_const("openbsd")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-redox"),
+        os: Some(Os::new_const("redox")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: Some(Env::new_const("relibc")),
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-unknown-uefi"),
+        os: Some(Os::new_const("uefi")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: None,
+        vendor: Some(Vendor::new_const("unknown")),
+        families: Families::new_const(__families_none),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("abort"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-uwp-windows-gnu"),
+        os: Some(Os::new_const("windows")),
+        abi: Some(Abi::new_const("uwp")),
+        arch: Arch::new_const("x86_64"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("uwp")),
+        families: Families::new_const(__families_windows),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-uwp-windows-msvc"),
+        os: Some(Os::new_const("windows")),
+        abi: Some(Abi::new_const("uwp")),
+        arch: Arch::new_const("x86_64"),
+        env: Some(Env::new_const("msvc")),
+        vendor: Some(Vendor::new_const("uwp")),
+        families: Families::new_const(__families_windows),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64-wrs-vxworks"),
+        os: Some(Os::new_const("vxworks")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: Some(Env::new_const("gnu")),
+        vendor: Some(Vendor::new_const("wrs")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+    TargetInfo {
+        triple: Triple::new_const("x86_64h-apple-darwin"),
+        os: Some(Os::new_const("macos")),
+        abi: None,
+        arch: Arch::new_const("x86_64"),
+        env: None,
+        vendor: Some(Vendor::new_const("apple")),
+        families: Families::new_const(__families_unix),
+        pointer_width: 64,
+        endian: Endian::little,
+        has_atomics: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_load_store: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        has_atomics_equal_alignment: HasAtomics::new_const(__has_atomics_8_16_32_64_128_ptr),
+        panic: Panic::new_const("unwind"),
+    },
+];
+
+const __families_none: &[Family] = &[];
+const __families_unix: &[Family] = &[Family::new_const("unix")];
+const __families_unix_wasm: &[Family] = &[Family::new_const("unix"), Family::new_const("wasm")];
+const __families_wasm: &[Family] = &[Family::new_const("wasm")];
+const __families_windows: &[Family] = &[Family::new_const("windows")];
+const __has_atomics_none: &[HasAtomic] = &[];
+const __has_atomics_8: &[HasAtomic] = &[HasAtomic::IntegerSize(8)];
+const __has_atomics_8_16: &[HasAtomic] = &[HasAtomic::IntegerSize(8), HasAtomic::IntegerSize(16)];
+const __has_atomics_8_16_32_64_128_ptr: &[HasAtomic] = &[
+    HasAtomic::IntegerSize(8),
+    HasAtomic::IntegerSize(16),
+    HasAtomic::IntegerSize(32),
+    HasAtomic::IntegerSize(64),
+    HasAtomic::IntegerSize(128),
+    HasAtomic::Pointer,
+];
+const __has_atomics_8_16_32_64_ptr: &[HasAtomic] = &[
+    HasAtomic::IntegerSize(8),
+    HasAtomic::IntegerSize(16),
+    HasAtomic::IntegerSize(32),
+    HasAtomic::IntegerSize(64),
+    HasAtomic::Pointer,
+];
+const __has_atomics_8_16_32_ptr: &[HasAtomic] = &[
+    HasAtomic::IntegerSize(8),
+    HasAtomic::IntegerSize(16),
+    HasAtomic::IntegerSize(32),
+    HasAtomic::Pointer,
+];
+const __has_atomics_8_16_ptr: &[HasAtomic] = &[
+    HasAtomic::IntegerSize(8),
+    HasAtomic::IntegerSize(16),
+    HasAtomic::Pointer,
+];
+const __has_atomics_64_ptr: &[HasAtomic] = &[HasAtomic::IntegerSize(64), HasAtomic::Pointer];
@@ -0,0 +1,3743 @@
+/*
+ *
+ * AUTO-GENERATED BY ./update
+ * DO NOT MODIFY
+ *
+ * cargo run --manifest-path update/Cargo.toml
+ */
+
+pub(crate) const DEFAULT_FEATURES: &[(&str, &[&str])] = &[
+    (
+        "aarch64-apple-darwin",
+        &[
+            "aes", "crc", "dit", "dotprod", "dpb", "dpb2", "fcma", "fhm", "flagm", "flagm2",
+            "fp16", "frintts", "jsconv", "lor", "lse", "lse2", "neon", "paca", "pacg", "pan",
+            "pmuv3", "ras", "rcpc", "rcpc2", "rdm", "sb", "sha2", "sha3", "ssbs", "v8.1a", "v8.2a",
+            "v8.3a", "v8.4a", "vh",
+        ],
+    ),
+    ("aarch64-apple-ios", &["aes", "neon", "pmuv3", "sha2"]),
+    (
+        "aarch64-apple-ios-macabi",
+        &[
+            "aes", "crc", "dpb", "fcma", "fp16", "jsconv", "lor", "lse", "neon", "paca", "pacg",
+            "pan", "pmuv3", "ras", "rcpc", "rdm", "sha2", "v8.1a", "v8.2a", "v8.3a", "vh",
+        ],
+    ),
+    (
+        "aarch64-apple-ios-sim",
+        &[
+            "aes", "crc", "dpb", "fcma", "fp16", "jsconv", "lor", "lse", "neon", "paca", "pacg",
+            "pan", "pmuv3", "ras", "rcpc", "rdm", "sha2", "v8.1a", "v8.2a", "v8.3a", "vh",
+        ],
+    ),
+    ("aarch64-apple-tvos", &["aes", "neon", "pmuv3", "sha2"]),
+    (
+        "aarch64-apple-tvos-sim",
+        &[
+            "aes", "crc", "dpb", "fcma", "fp16", "jsconv", "lor", "lse", "neon", "paca", "pacg",
+            "pan", "pmuv3", "ras", "rcpc", "rdm", "sha2", "v8.1a", "v8.2a", "v8.3a", "vh",
+        ],
+    ),
+    ("aarch64-apple-visionos", &["aes", "neon", "pmuv3", "sha2"]),
+    (
+        "aarch64-apple-visionos-sim",
+        &[
+            "aes", "crc", "dpb", "fcma", "fp16", "jsconv", "lor", "lse", "neon", "paca", "pacg",
+            "pan", "pmuv3", "ras", "rcpc", "rdm", "sha2", "v8.1a", "v8.2a", "v8.3a", "vh",
+        ],
+    ),
+    ("aarch64-apple-watchos", &["aes", "neon", "pmuv3", "sha2"]),
+    (
+        "aarch64-apple-watchos-sim",
+        &[
+            "aes", "crc", "dpb", "fcma", "fp16", "jsconv", "lor", "lse", "neon", "paca", "pacg",
+            "pan", "pmuv3", "ras", "rcpc", "rdm", "sha2", "v8.1a", "v8.2a", "v8.3a", "vh",
+        ],
+    ),
+    ("aarch64-kmc-solid_asp3", &["neon"]),
+    ("aarch64-linux-android", &["neon"]),
+    (
+        "aarch64-nintendo-switch-freestanding",
+        &["aes", "crc", "neon", "sha2"],
+    ),
+    ("aarch64-pc-windows-gnullvm", &["neon"]),
+    ("aarch64-pc-windows-msvc", &["neon"]),
+    ("aarch64-unknown-freebsd", &["neon"]),
+    ("aarch64-unknown-fuchsia", &["aes", "crc", "neon", "sha2"]),
+    ("aarch64-unknown-helenos", &["neon"]),
+    ("aarch64-unknown-hermit", &["neon"]),
+    ("aarch64-unknown-illumos", &["neon"]),
+    ("aarch64-unknown-linux-gnu", &["neon", "outline-atomics"]),
+    (
+        "aarch64-unknown-linux-gnu_ilp32",
+        &["neon", "outline-atomics"],
+    ),
+    (
+        "aarch64-unknown-linux-musl",
+        &["crt-static", "neon", "outline-atomics"],
+    ),
+    ("aarch64-unknown-linux-ohos", &["neon"]),
+    ("aarch64-unknown-managarm-mlibc", &["neon"]),
+    ("aarch64-unknown-netbsd", &["neon"]),
+    ("aarch64-unknown-none", &["neon"]),
+    ("aarch64-unknown-none-softfloat", &[]),
+    ("aarch64-unknown-nto-qnx700", &["neon"]),
+    ("aarch64-unknown-nto-qnx710", &["neon"]),
+    ("aarch64-unknown-nto-qnx710_iosock", &["neon"]),
+    ("aarch64-unknown-nto-qnx800", &["neon"]),
+    ("aarch64-unknown-nuttx", &["neon"]),
+    ("aarch64-unknown-openbsd", &["neon"]),
+    ("aarch64-unknown-redox", &["crt-static", "neon"]),
+    ("aarch64-unknown-teeos", &["neon"]),
+    ("aarch64-unknown-trusty", &["crt-static", "neon"]),
+    ("aarch64-unknown-uefi", &["neon"]),
+    ("aarch64-uwp-windows-msvc", &["neon"]),
+    ("aarch64-wrs-vxworks", &["crt-static", "neon"]),
+    ("aarch64_be-unknown-hermit", &["neon"]),
+    ("aarch64_be-unknown-linux-gnu", &["neon", "outline-atomics"]),
+    (
+        "aarch64_be-unknown-linux-gnu_ilp32",
+        &["neon", "outline-atomics"],
+    ),
+    (
+        "aarch64_be-unknown-linux-musl",
+        &["neon", "outline-atomics"],
+    ),
+    ("aarch64_be-unknown-netbsd", &["neon"]),
+    ("aarch64_be-unknown-none-softfloat", &[]),
+    (
+        "aarch64v8r-unknown-none",
+        &[
+            "crc", "dit", "dpb", "flagm", "lse", "neon", "paca", "pacg", "pan", "ras", "rcpc",
+            "rcpc2",
+        ],
+    ),
+    (
+        "aarch64v8r-unknown-none-softfloat",
+        &[
+            "crc", "dit", "dpb", "flagm", "lse", "paca", "pacg", "pan", "ras", "rcpc", "rcpc2",
+        ],
+    ),
+    ("amdgcn-amd-amdhsa", &[]),
+    ("arm-linux-androideabi", &["v5te"]),
+    ("arm-unknown-linux-gnueabi", &["v5te", "v6"]),
+    (
+        "arm-unknown-linux-gnueabihf",
+        &["fpregs", "v5te", "v6", "vfp2"],
+    ),
+    ("arm-unknown-linux-musleabi", &["crt-static", "v5te", "v6"]),
+    (
+        "arm-unknown-linux-musleabihf",
+        &["crt-static", "fpregs", "v5te", "v6", "vfp2"],
+    ),
+    (
+        "arm64_32-apple-watchos",
+        &[
+            "aes", "crc", "dpb", "fcma", "fp16", "jsconv", "lor", "lse", "neon", "paca", "pacg",
+            "pan", "pmuv3", "ras", "rcpc", "rdm", "sha2", "v8.1a", "v8.2a", "v8.3a", "vh",
+        ],
+    ),
+    (
+        "arm64e-apple-darwin",
+        &[
+            "aes", "crc", "dit", "dotprod", "dpb", "dpb2", "fcma", "fhm", "flagm", "flagm2",
+            "fp16", "frintts", "jsconv", "lor", "lse", "lse2", "neon", "paca", "pacg", "pan",
+            "pmuv3", "ras", "rcpc", "rcpc2", "rdm", "sb", "sha2", "sha3", "ssbs", "v8.1a", "v8.2a",
+            "v8.3a", "v8.4a", "vh",
+        ],
+    ),
+    (
+        "arm64e-apple-ios",
+        &[
+            "aes", "crc", "dpb", "fcma", "fp16", "jsconv", "lor", "lse", "neon", "paca", "pacg",
+            "pan", "pmuv3", "ras", "rcpc", "rdm", "sha2", "v8.1a", "v8.2a", "v8.3a", "vh",
+        ],
+    ),
+    (
+        "arm64e-apple-tvos",
+        &[
+            "aes", "crc", "dpb", "fcma", "fp16", "jsconv", "lor", "lse", "neon", "paca", "pacg",
+            "pan", "pmuv3", "ras", "rcpc", "rdm", "sha2", "v8.1a", "v8.2a", "v8.3a", "vh",
+        ],
+    ),
+    ("arm64ec-pc-windows-msvc", &["neon"]),
+    (
+        "armeb-unknown-linux-gnueabi",
+        &["crc", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7", "v8"],
+    ),
+    (
+        "armebv7r-none-eabi",
+        &["dsp", "rclass", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7"],
+    ),
+    (
+        "armebv7r-none-eabihf",
+        &[
+            "dsp", "fpregs", "rclass", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7", "vfp2",
+        ],
+    ),
+    ("armv4t-none-eabi", &["soft-float"]),
+    ("armv4t-unknown-linux-gnueabi", &["soft-float"]),
+    ("armv5te-none-eabi", &["soft-float", "v5te"]),
+    ("armv5te-unknown-linux-gnueabi", &["soft-float", "v5te"]),
+    (
+        "armv5te-unknown-linux-musleabi",
+        &["crt-static", "soft-float", "v5te"],
+    ),
+    ("armv5te-unknown-linux-uclibceabi", &["soft-float", "v5te"]),
+    (
+        "armv6-none-eabi",
+        &["dsp", "soft-float", "v5te", "v6", "v6k"],
+    ),
+    (
+        "armv6-none-eabihf",
+        &["dsp", "fpregs", "v5te", "v6", "v6k", "vfp2"],
+    ),
+    (
+        "armv6-unknown-freebsd",
+        &["dsp", "fpregs", "v5te", "v6", "vfp2"],
+    ),
+    (
+        "armv6-unknown-netbsd-eabihf",
+        &["dsp", "fpregs", "v5te", "v6", "vfp2"],
+    ),
+    (
+        "armv6k-nintendo-3ds",
+        &["fpregs", "v5te", "v6", "v6k", "vfp2"],
+    ),
+    (
+        "armv7-linux-androideabi",
+        &[
+            "aclass",
+            "d32",
+            "dsp",
+            "fpregs",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "armv7-rtems-eabihf",
+        &[
+            "aclass", "d32", "dsp", "fpregs", "neon", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7",
+            "vfp2", "vfp3",
+        ],
+    ),
+    (
+        "armv7-sony-vita-newlibeabihf",
+        &[
+            "aclass",
+            "d32",
+            "dsp",
+            "fpregs",
+            "neon",
+            "thumb-mode",
+            "thumb2",
+            "trustzone",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "armv7-unknown-freebsd",
+        &[
+            "aclass", "d32", "dsp", "fpregs", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7", "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "armv7-unknown-linux-gnueabi",
+        &[
+            "aclass",
+            "d32",
+            "dsp",
+            "fpregs",
+            "soft-float",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "armv7-unknown-linux-gnueabihf",
+        &[
+            "aclass", "d32", "dsp", "fpregs", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7", "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "armv7-unknown-linux-musleabi",
+        &[
+            "aclass",
+            "crt-static",
+            "d32",
+            "dsp",
+            "fpregs",
+            "soft-float",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "armv7-unknown-linux-musleabihf",
+        &[
+            "aclass",
+            "crt-static",
+            "d32",
+            "dsp",
+            "fpregs",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "armv7-unknown-linux-ohos",
+        &[
+            "aclass",
+            "d32",
+            "dsp",
+            "fpregs",
+            "soft-float",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "armv7-unknown-linux-uclibceabi",
+        &[
+            "aclass",
+            "d32",
+            "dsp",
+            "fpregs",
+            "soft-float",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "armv7-unknown-linux-uclibceabihf",
+        &[
+            "aclass", "d32", "dsp", "fpregs", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7", "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "armv7-unknown-netbsd-eabihf",
+        &[
+            "aclass", "d32", "dsp", "fpregs", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7", "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "armv7-unknown-trusty",
+        &[
+            "aclass",
+            "crt-static",
+            "d32",
+            "dsp",
+            "fpregs",
+            "soft-float",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "armv7-wrs-vxworks-eabihf",
+        &[
+            "aclass",
+            "crt-static",
+            "d32",
+            "dsp",
+            "fpregs",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "armv7a-kmc-solid_asp3-eabi",
+        &[
+            "aclass",
+            "d32",
+            "dsp",
+            "fpregs",
+            "soft-float",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "armv7a-kmc-solid_asp3-eabihf",
+        &[
+            "aclass", "d32", "dsp", "fpregs", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7", "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "armv7a-none-eabi",
+        &[
+            "aclass",
+            "d32",
+            "dsp",
+            "fpregs",
+            "soft-float",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "armv7a-none-eabihf",
+        &[
+            "aclass", "d32", "dsp", "fpregs", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7", "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "armv7a-nuttx-eabi",
+        &[
+            "aclass",
+            "d32",
+            "dsp",
+            "fpregs",
+            "soft-float",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "armv7a-nuttx-eabihf",
+        &[
+            "aclass", "d32", "dsp", "fpregs", "neon", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7",
+            "vfp2", "vfp3",
+        ],
+    ),
+    (
+        "armv7a-vex-v5",
+        &[
+            "aclass",
+            "d32",
+            "dsp",
+            "fpregs",
+            "neon",
+            "thumb2",
+            "trustzone",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "armv7k-apple-watchos",
+        &[
+            "aclass",
+            "d32",
+            "dsp",
+            "fpregs",
+            "neon",
+            "thumb2",
+            "trustzone",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+            "vfp4",
+        ],
+    ),
+    (
+        "armv7r-none-eabi",
+        &["dsp", "rclass", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7"],
+    ),
+    (
+        "armv7r-none-eabihf",
+        &[
+            "dsp", "fpregs", "rclass", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7", "vfp2",
+        ],
+    ),
+    (
+        "armv7s-apple-ios",
+        &[
+            "aclass", "d32", "dsp", "fpregs", "neon", "thumb2", "v5te", "v6", "v6k", "v6t2", "v7",
+            "vfp2", "vfp3", "vfp4",
+        ],
+    ),
+    (
+        "armv8r-none-eabihf",
+        &[
+            "crc",
+            "dsp",
+            "fpregs",
+            "rclass",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "v8",
+            "virtualization",
+        ],
+    ),
+    ("avr-none", &["addsubiw", "ijmpcall", "lpm"]),
+    ("bpfeb-unknown-none", &[]),
+    ("bpfel-unknown-none", &[]),
+    (
+        "csky-unknown-linux-gnuabiv2",
+        &[
+            "2e3",
+            "3e7",
+            "7e10",
+            "cache",
+            "dsp1e2",
+            "dspe60",
+            "e1",
+            "e2",
+            "edsp",
+            "elrw",
+            "hard-tp",
+            "high-registers",
+            "hwdiv",
+            "mp",
+            "mp1e2",
+            "nvic",
+            "trust",
+        ],
+    ),
+    (
+        "csky-unknown-linux-gnuabiv2hf",
+        &[
+            "10e60",
+            "2e3",
+            "3e3r1",
+            "3e3r2",
+            "3e3r3",
+            "3e7",
+            "7e10",
+            "cache",
+            "doloop",
+            "dsp1e2",
+            "dspe60",
+            "e1",
+            "e2",
+            "edsp",
+            "elrw",
+            "float7e60",
+            "fpuv3_df",
+            "fpuv3_hf",
+            "fpuv3_hi",
+            "fpuv3_sf",
+            "hard-float",
+            "hard-float-abi",
+            "hard-tp",
+            "high-registers",
+            "hwdiv",
+            "mp",
+            "mp1e2",
+            "nvic",
+            "trust",
+            "vdsp2e60f",
+            "vdspv2",
+        ],
+    ),
+    (
+        "hexagon-unknown-linux-musl",
+        &["hvx", "hvx-length128b", "hvxv60"],
+    ),
+    (
+        "hexagon-unknown-none-elf",
+        &["hvx", "hvx-length128b", "hvxv60"],
+    ),
+    (
+        "hexagon-unknown-qurt",
+        &[
+            "hvx",
+            "hvx-length128b",
+            "hvxv60",
+            "hvxv62",
+            "hvxv65",
+            "hvxv66",
+            "hvxv67",
+            "hvxv68",
+            "hvxv69",
+            "zreg",
+        ],
+    ),
+    (
+        "i386-apple-ios",
+        &[
+            "cmpxchg16b",
+            "fxsr",
+            "lahfsahf",
+            "sse",
+            "sse2",
+            "sse3",
+            "sse4.1",
+            "ssse3",
+            "x87",
+        ],
+    ),
+    ("i586-unknown-linux-gnu", &["x87"]),
+    ("i586-unknown-linux-musl", &["crt-static", "x87"]),
+    ("i586-unknown-netbsd", &["x87"]),
+    ("i586-unknown-redox", &["crt-static", "x87"]),
+    (
+        "i686-apple-darwin",
+        &[
+            "cmpxchg16b",
+            "fxsr",
+            "lahfsahf",
+            "sse",
+            "sse2",
+            "sse3",
+            "sse4.1",
+            "ssse3",
+            "x87",
+        ],
+    ),
+    (
+        "i686-linux-android",
+        &["fxsr", "sse", "sse2", "sse3", "ssse3", "x87"],
+    ),
+    ("i686-pc-nto-qnx700", &["fxsr", "sse", "sse2", "x87"]),
+    ("i686-pc-windows-gnu", &["fxsr", "sse", "sse2", "x87"]),
+    ("i686-pc-windows-gnullvm", &["fxsr", "sse", "sse2", "x87"]),
+    ("i686-pc-windows-msvc", &["fxsr", "sse", "sse2", "x87"]),
+    ("i686-unknown-freebsd", &["fxsr", "sse", "sse2", "x87"]),
+    ("i686-unknown-haiku", &["fxsr", "sse", "sse2", "x87"]),
+    ("i686-unknown-helenos", &["fxsr", "sse", "sse2", "x87"]),
+    ("i686-unknown-hurd-gnu", &["fxsr", "sse", "sse2", "x87"]),
+    ("i686-unknown-linux-gnu", &["fxsr", "sse", "sse2", "x87"]),
+    (
+        "i686-unknown-linux-musl",
+        &["crt-static", "fxsr", "sse", "sse2", "x87"],
+    ),
+    ("i686-unknown-netbsd", &["fxsr", "sse", "sse2", "x87"]),
+    ("i686-unknown-openbsd", &["fxsr", "sse", "sse2", "x87"]),
+    ("i686-unknown-uefi", &["fxsr", "x87"]),
+    ("i686-uwp-windows-gnu", &["fxsr", "sse", "sse2", "x87"]),
+    ("i686-uwp-windows-msvc", &["fxsr", "sse", "sse2", "x87"]),
+    ("i686-win7-windows-gnu", &["fxsr", "sse", "sse2", "x87"]),
+    ("i686-win7-windows-msvc", &["fxsr", "sse", "sse2", "x87"]),
+    (
+        "i686-wrs-vxworks",
+        &["crt-static", "fxsr", "sse", "sse2", "x87"],
+    ),
+    ("loongarch32-unknown-none", &["d", "f"]),
+    ("loongarch32-unknown-none-softfloat", &[]),
+    (
+        "loongarch64-unknown-linux-gnu",
+        &["32s", "d", "f", "lsx", "ual"],
+    ),
+    (
+        "loongarch64-unknown-linux-musl",
+        &["32s", "d", "f", "lsx", "ual"],
+    ),
+    (
+        "loongarch64-unknown-linux-ohos",
+        &["32s", "d", "f", "lsx", "ual"],
+    ),
+    ("loongarch64-unknown-none", &["32s", "d", "f", "ual"]),
+    ("loongarch64-unknown-none-softfloat", &["32s", "ual"]),
+    (
+        "m68k-unknown-linux-gnu",
+        &["isa-68000", "isa-68010", "isa-68020"],
+    ),
+    ("m68k-unknown-none-elf", &["isa-68000", "isa-68010"]),
+    ("mips-mti-none-elf", &[]),
+    ("mips-unknown-linux-gnu", &[]),
+    ("mips-unknown-linux-musl", &[]),
+    ("mips-unknown-linux-uclibc", &[]),
+    ("mips64-openwrt-linux-musl", &["fp64"]),
+    ("mips64-unknown-linux-gnuabi64", &["fp64"]),
+    ("mips64-unknown-linux-muslabi64", &["fp64"]),
+    ("mips64el-unknown-linux-gnuabi64", &["fp64"]),
+    ("mips64el-unknown-linux-muslabi64", &["fp64"]),
+    ("mipsel-mti-none-elf", &[]),
+    ("mipsel-sony-psp", &[]),
+    ("mipsel-sony-psx", &[]),
+    ("mipsel-unknown-linux-gnu", &[]),
+    ("mipsel-unknown-linux-musl", &[]),
+    ("mipsel-unknown-linux-uclibc", &[]),
+    ("mipsel-unknown-netbsd", &[]),
+    ("mipsel-unknown-none", &[]),
+    ("mipsisa32r6-unknown-linux-gnu", &["fp64"]),
+    ("mipsisa32r6el-unknown-linux-gnu", &["fp64"]),
+    ("mipsisa64r6-unknown-linux-gnuabi64", &["fp64"]),
+    ("mipsisa64r6el-unknown-linux-gnuabi64", &["fp64"]),
+    ("msp430-none-elf", &[]),
+    ("nvptx64-nvidia-cuda", &["sm_20", "sm_21", "sm_30"]),
+    ("powerpc-unknown-freebsd", &[]),
+    ("powerpc-unknown-helenos", &[]),
+    ("powerpc-unknown-linux-gnu", &[]),
+    ("powerpc-unknown-linux-gnuspe", &["msync"]),
+    ("powerpc-unknown-linux-musl", &[]),
+    ("powerpc-unknown-linux-muslspe", &["msync"]),
+    ("powerpc-unknown-netbsd", &[]),
+    ("powerpc-unknown-openbsd", &[]),
+    ("powerpc-wrs-vxworks", &["crt-static"]),
+    ("powerpc-wrs-vxworks-spe", &["crt-static", "msync"]),
+    ("powerpc64-ibm-aix", &["altivec", "vsx"]),
+    ("powerpc64-unknown-freebsd", &["altivec"]),
+    ("powerpc64-unknown-linux-gnu", &["altivec"]),
+    ("powerpc64-unknown-linux-musl", &["altivec"]),
+    ("powerpc64-unknown-openbsd", &["altivec"]),
+    ("powerpc64-wrs-vxworks", &["altivec", "crt-static"]),
+    (
+        "powerpc64le-unknown-freebsd",
+        &[
+            "altivec",
+            "partword-atomics",
+            "power8-altivec",
+            "power8-crypto",
+            "power8-vector",
+            "quadword-atomics",
+            "vsx",
+        ],
+    ),
+    (
+        "powerpc64le-unknown-linux-gnu",
+        &[
+            "altivec",
+            "partword-atomics",
+            "power8-altivec",
+            "power8-crypto",
+            "power8-vector",
+            "quadword-atomics",
+            "vsx",
+        ],
+    ),
+    (
+        "powerpc64le-unknown-linux-musl",
+        &[
+            "altivec",
+            "crt-static",
+            "partword-atomics",
+            "power8-altivec",
+            "power8-crypto",
+            "power8-vector",
+            "quadword-atomics",
+            "vsx",
+        ],
+    ),
+    (
+        "riscv32-wrs-vxworks",
+        &[
+            "a",
+            "c",
+            "crt-static",
+            "d",
+            "f",
+            "m",
+            "zaamo",
+            "zalrsc",
+            "zca",
+            "zicsr",
+            "zifencei",
+        ],
+    ),
+    ("riscv32e-unknown-none-elf", &["e"]),
+    ("riscv32em-unknown-none-elf", &["e", "m"]),
+    ("riscv32emc-unknown-none-elf", &["c", "e", "m", "zca"]),
+    (
+        "riscv32gc-unknown-linux-gnu",
+        &[
+            "a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr", "zifencei",
+        ],
+    ),
+    (
+        "riscv32gc-unknown-linux-musl",
+        &[
+            "a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr", "zifencei",
+        ],
+    ),
+    ("riscv32i-unknown-none-elf", &[]),
+    ("riscv32im-risc0-zkvm-elf", &["m"]),
+    ("riscv32im-unknown-none-elf", &["m"]),
+    (
+        "riscv32ima-unknown-none-elf",
+        &["a", "m", "zaamo", "zalrsc"],
+    ),
+    (
+        "riscv32imac-esp-espidf",
+        &["a", "c", "m", "zaamo", "zalrsc", "zca"],
+    ),
+    (
+        "riscv32imac-unknown-none-elf",
+        &["a", "c", "m", "zaamo", "zalrsc", "zca"],
+    ),
+    (
+        "riscv32imac-unknown-nuttx-elf",
+        &["a", "c", "m", "zaamo", "zalrsc", "zca"],
+    ),
+    (
+        "riscv32imac-unknown-xous-elf",
+        &["a", "c", "m", "zaamo", "zalrsc", "zca"],
+    ),
+    (
+        "riscv32imafc-esp-espidf",
+        &["a", "c", "f", "m", "zaamo", "zalrsc", "zca", "zicsr"],
+    ),
+    (
+        "riscv32imafc-unknown-none-elf",
+        &["a", "c", "f", "m", "zaamo", "zalrsc", "zca", "zicsr"],
+    ),
+    (
+        "riscv32imafc-unknown-nuttx-elf",
+        &["a", "c", "f", "m", "zaamo", "zalrsc", "zca", "zicsr"],
+    ),
+    ("riscv32imc-esp-espidf", &["c", "m", "zca"]),
+    ("riscv32imc-unknown-none-elf", &["c", "m", "zca"]),
+    ("riscv32imc-unknown-nuttx-elf", &["c", "m", "zca"]),
+    (
+        "riscv64-linux-android",
+        &[
+            "a", "b", "c", "d", "f", "m", "v", "zaamo", "zalrsc", "zba", "zbb", "zbs", "zca",
+            "zicsr", "zifencei", "zve32f", "zve32x", "zve64d", "zve64f", "zve64x", "zvl128b",
+            "zvl32b", "zvl64b",
+        ],
+    ),
+    (
+        "riscv64-wrs-vxworks",
+        &[
+            "a",
+            "c",
+            "crt-static",
+            "d",
+            "f",
+            "m",
+            "zaamo",
+            "zalrsc",
+            "zca",
+            "zicsr",
+            "zifencei",
+        ],
+    ),
+    (
+        "riscv64a23-unknown-linux-gnu",
+        &[
+            "a",
+            "b",
+            "c",
+            "d",
+            "f",
+            "m",
+            "rva23u64",
+            "supm",
+            "v",
+            "za128rs",
+            "za64rs",
+            "zaamo",
+            "zalrsc",
+            "zawrs",
+            "zba",
+            "zbb",
+            "zbs",
+            "zca",
+            "zcb",
+            "zcmop",
+            "zfa",
+            "zfhmin",
+            "zic64b",
+            "zicbom",
+            "zicbop",
+            "zicboz",
+            "ziccamoa",
+            "ziccif",
+            "zicclsm",
+            "ziccrse",
+            "zicntr",
+            "zicond",
+            "zicsr",
+            "zihintntl",
+            "zihintpause",
+            "zihpm",
+            "zimop",
+            "zkt",
+            "zvbb",
+            "zve32f",
+            "zve32x",
+            "zve64d",
+            "zve64f",
+            "zve64x",
+            "zvfhmin",
+            "zvkb",
+            "zvkt",
+            "zvl128b",
+            "zvl32b",
+            "zvl64b",
+        ],
+    ),
+    (
+        "riscv64gc-unknown-freebsd",
+        &[
+            "a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr", "zifencei",
+        ],
+    ),
+    (
+        "riscv64gc-unknown-fuchsia",
+        &[
+            "a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr", "zifencei",
+        ],
+    ),
+    (
+        "riscv64gc-unknown-hermit",
+        &[
+            "a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr", "zifencei",
+        ],
+    ),
+    (
+        "riscv64gc-unknown-linux-gnu",
+        &[
+            "a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr", "zifencei",
+        ],
+    ),
+    (
+        "riscv64gc-unknown-linux-musl",
+        &[
+            "a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr", "zifencei",
+        ],
+    ),
+    (
+        "riscv64gc-unknown-managarm-mlibc",
+        &["a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr"],
+    ),
+    (
+        "riscv64gc-unknown-netbsd",
+        &[
+            "a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr", "zifencei",
+        ],
+    ),
+    (
+        "riscv64gc-unknown-none-elf",
+        &[
+            "a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr", "zifencei",
+        ],
+    ),
+    (
+        "riscv64gc-unknown-nuttx-elf",
+        &[
+            "a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr", "zifencei",
+        ],
+    ),
+    (
+        "riscv64gc-unknown-openbsd",
+        &[
+            "a", "c", "d", "f", "m", "zaamo", "zalrsc", "zca", "zicsr", "zifencei",
+        ],
+    ),
+    (
+        "riscv64gc-unknown-redox",
+        &[
+            "a",
+            "c",
+            "crt-static",
+            "d",
+            "f",
+            "m",
+            "zaamo",
+            "zalrsc",
+            "zca",
+            "zicsr",
+        ],
+    ),
+    ("riscv64im-unknown-none-elf", &["m"]),
+    (
+        "riscv64imac-unknown-none-elf",
+        &["a", "c", "m", "zaamo", "zalrsc", "zca"],
+    ),
+    (
+        "riscv64imac-unknown-nuttx-elf",
+        &["a", "c", "m", "zaamo", "zalrsc", "zca"],
+    ),
+    ("s390x-unknown-linux-gnu", &[]),
+    ("s390x-unknown-linux-musl", &[]),
+    ("s390x-unknown-none-softfloat", &[]),
+    ("sparc-unknown-linux-gnu", &["v8plus", "v9"]),
+    ("sparc-unknown-none-elf", &[]),
+    ("sparc64-unknown-helenos", &["v9"]),
+    ("sparc64-unknown-linux-gnu", &["v9"]),
+    ("sparc64-unknown-netbsd", &["v9"]),
+    ("sparc64-unknown-openbsd", &["v9"]),
+    ("sparcv9-sun-solaris", &["v9"]),
+    ("thumbv4t-none-eabi", &["soft-float", "thumb-mode"]),
+    ("thumbv5te-none-eabi", &["soft-float", "thumb-mode", "v5te"]),
+    (
+        "thumbv6-none-eabi",
+        &["dsp", "soft-float", "thumb-mode", "v5te", "v6", "v6k"],
+    ),
+    (
+        "thumbv6m-none-eabi",
+        &["mclass", "thumb-mode", "v5te", "v6"],
+    ),
+    (
+        "thumbv6m-nuttx-eabi",
+        &["mclass", "thumb-mode", "v5te", "v6"],
+    ),
+    (
+        "thumbv7a-none-eabi",
+        &[
+            "aclass",
+            "d32",
+            "dsp",
+            "fpregs",
+            "soft-float",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "thumbv7a-none-eabihf",
+        &[
+            "aclass",
+            "d32",
+            "dsp",
+            "fpregs",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "thumbv7a-nuttx-eabi",
+        &[
+            "aclass",
+            "d32",
+            "dsp",
+            "fpregs",
+            "soft-float",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "thumbv7a-nuttx-eabihf",
+        &[
+            "aclass",
+            "d32",
+            "dsp",
+            "fpregs",
+            "neon",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "thumbv7a-pc-windows-msvc",
+        &[
+            "aclass",
+            "d32",
+            "dsp",
+            "fpregs",
+            "neon",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "thumbv7a-uwp-windows-msvc",
+        &[
+            "aclass",
+            "d32",
+            "dsp",
+            "fpregs",
+            "neon",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "thumbv7em-none-eabi",
+        &[
+            "dsp",
+            "mclass",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+        ],
+    ),
+    (
+        "thumbv7em-none-eabihf",
+        &[
+            "dsp",
+            "fpregs",
+            "mclass",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+        ],
+    ),
+    (
+        "thumbv7em-nuttx-eabi",
+        &[
+            "dsp",
+            "mclass",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+        ],
+    ),
+    (
+        "thumbv7em-nuttx-eabihf",
+        &[
+            "dsp",
+            "fpregs",
+            "mclass",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+        ],
+    ),
+    (
+        "thumbv7m-none-eabi",
+        &[
+            "mclass",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+        ],
+    ),
+    (
+        "thumbv7m-nuttx-eabi",
+        &[
+            "mclass",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+        ],
+    ),
+    (
+        "thumbv7neon-linux-androideabi",
+        &[
+            "aclass",
+            "d32",
+            "dsp",
+            "fpregs",
+            "neon",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "thumbv7neon-unknown-linux-gnueabihf",
+        &[
+            "aclass",
+            "d32",
+            "dsp",
+            "fpregs",
+            "neon",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "thumbv7neon-unknown-linux-musleabihf",
+        &[
+            "aclass",
+            "d32",
+            "dsp",
+            "fpregs",
+            "neon",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+            "vfp3",
+        ],
+    ),
+    (
+        "thumbv7r-none-eabi",
+        &[
+            "dsp",
+            "rclass",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+        ],
+    ),
+    (
+        "thumbv7r-none-eabihf",
+        &[
+            "dsp",
+            "fpregs",
+            "rclass",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+        ],
+    ),
+    (
+        "thumbv8m.base-none-eabi",
+        &["mclass", "thumb-mode", "v5te", "v6"],
+    ),
+    (
+        "thumbv8m.base-nuttx-eabi",
+        &["mclass", "thumb-mode", "v5te", "v6"],
+    ),
+    (
+        "thumbv8m.main-none-eabi",
+        &[
+            "mclass",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+        ],
+    ),
+    (
+        "thumbv8m.main-none-eabihf",
+        &[
+            "fpregs",
+            "mclass",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+        ],
+    ),
+    (
+        "thumbv8m.main-nuttx-eabi",
+        &[
+            "mclass",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+        ],
+    ),
+    (
+        "thumbv8m.main-nuttx-eabihf",
+        &[
+            "fpregs",
+            "mclass",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+        ],
+    ),
+    (
+        "thumbv8r-none-eabihf",
+        &[
+            "crc",
+            "dsp",
+            "fpregs",
+            "rclass",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "v8",
+            "virtualization",
+        ],
+    ),
+    (
+        "wasm32-unknown-emscripten",
+        &[
+            "bulk-memory",
+            "crt-static",
+            "exception-handling",
+            "multivalue",
+            "mutable-globals",
+            "nontrapping-fptoint",
+            "reference-types",
+            "sign-ext",
+        ],
+    ),
+    (
+        "wasm32-unknown-unknown",
+        &[
+            "bulk-memory",
+            "multivalue",
+            "mutable-globals",
+            "nontrapping-fptoint",
+            "reference-types",
+            "sign-ext",
+        ],
+    ),
+    (
+        "wasm32-wali-linux-musl",
+        &[
+            "atomics",
+            "bulk-memory",
+            "crt-static",
+            "multivalue",
+            "mutable-globals",
+            "nontrapping-fptoint",
+            "reference-types",
+            "sign-ext",
+        ],
+    ),
+    (
+        "wasm32-wasip1",
+        &[
+            "bulk-memory",
+            "crt-static",
+            "multivalue",
+            "mutable-globals",
+            "nontrapping-fptoint",
+            "reference-types",
+            "sign-ext",
+        ],
+    ),
+    (
+        "wasm32-wasip1-threads",
+        &[
+            "atomics",
+            "bulk-memory",
+            "crt-static",
+            "multivalue",
+            "mutable-globals",
+            "nontrapping-fptoint",
+            "reference-types",
+            "sign-ext",
+        ],
+    ),
+    (
+        "wasm32-wasip2",
+        &[
+            "bulk-memory",
+            "crt-static",
+            "multivalue",
+            "mutable-globals",
+            "nontrapping-fptoint",
+            "reference-types",
+            "sign-ext",
+        ],
+    ),
+    (
+        "wasm32-wasip3",
+        &[
+            "bulk-memory",
+            "crt-static",
+            "multivalue",
+            "mutable-globals",
+            "nontrapping-fptoint",
+            "reference-types",
+            "sign-ext",
+        ],
+    ),
+    ("wasm32v1-none", &["mutable-globals"]),
+    (
+        "wasm64-unknown-unknown",
+        &[
+            "bulk-memory",
+            "multivalue",
+            "mutable-globals",
+            "nontrapping-fptoint",
+            "reference-types",
+            "sign-ext",
+        ],
+    ),
+    (
+        "x86_64-apple-darwin",
+        &[
+            "cmpxchg16b",
+            "fxsr",
+            "lahfsahf",
+            "sse",
+            "sse2",
+            "sse3",
+            "sse4.1",
+            "ssse3",
+            "x87",
+        ],
+    ),
+    (
+        "x86_64-apple-ios",
+        &[
+            "cmpxchg16b",
+            "fxsr",
+            "lahfsahf",
+            "sse",
+            "sse2",
+            "sse3",
+            "sse4.1",
+            "ssse3",
+            "x87",
+        ],
+    ),
+    (
+        "x86_64-apple-ios-macabi",
+        &[
+            "cmpxchg16b",
+            "fxsr",
+            "lahfsahf",
+            "sse",
+            "sse2",
+            "sse3",
+            "sse4.1",
+            "ssse3",
+            "x87",
+        ],
+    ),
+    (
+        "x86_64-apple-tvos",
+        &[
+            "cmpxchg16b",
+            "fxsr",
+            "lahfsahf",
+            "sse",
+            "sse2",
+            "sse3",
+            "sse4.1",
+            "ssse3",
+            "x87",
+        ],
+    ),
+    (
+        "x86_64-apple-watchos-sim",
+        &[
+            "cmpxchg16b",
+            "fxsr",
+            "lahfsahf",
+            "sse",
+            "sse2",
+            "sse3",
+            "sse4.1",
+            "ssse3",
+            "x87",
+        ],
+    ),
+    (
+        "x86_64-fortanix-unknown-sgx",
+        &["fxsr", "rdrand", "rdseed", "sse", "sse2", "x87"],
+    ),
+    (
+        "x86_64-linux-android",
+        &[
+            "fxsr", "popcnt", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3", "x87",
+        ],
+    ),
+    ("x86_64-lynx-lynxos178", &["fxsr", "sse", "sse2", "x87"]),
+    ("x86_64-pc-cygwin", &["fxsr", "sse", "sse2", "x87"]),
+    ("x86_64-pc-nto-qnx710", &["fxsr", "sse", "sse2", "x87"]),
+    (
+        "x86_64-pc-nto-qnx710_iosock",
+        &["fxsr", "sse", "sse2", "x87"],
+    ),
+    ("x86_64-pc-nto-qnx800", &["fxsr", "sse", "sse2", "x87"]),
+    ("x86_64-pc-solaris", &["fxsr", "sse", "sse2", "x87"]),
+    (
+        "x86_64-pc-windows-gnu",
+        &[
+            "cmpxchg16b",
+            "fxsr",
+            "lahfsahf",
+            "sse",
+            "sse2",
+            "sse3",
+            "x87",
+        ],
+    ),
+    (
+        "x86_64-pc-windows-gnullvm",
+        &[
+            "cmpxchg16b",
+            "fxsr",
+            "lahfsahf",
+            "sse",
+            "sse2",
+            "sse3",
+            "x87",
+        ],
+    ),
+    (
+        "x86_64-pc-windows-msvc",
+        &[
+            "cmpxchg16b",
+            "fxsr",
+            "lahfsahf",
+            "sse",
+            "sse2",
+            "sse3",
+            "x87",
+        ],
+    ),
+    (
+        "x86_64-unikraft-linux-musl",
+        &["fxsr", "sse", "sse2", "x87"],
+    ),
+    ("x86_64-unknown-dragonfly", &["fxsr", "sse", "sse2", "x87"]),
+    ("x86_64-unknown-freebsd", &["fxsr", "sse", "sse2", "x87"]),
+    (
+        "x86_64-unknown-fuchsia",
+        &[
+            "cmpxchg16b",
+            "fxsr",
+            "lahfsahf",
+            "popcnt",
+            "sse",
+            "sse2",
+            "sse3",
+            "sse4.1",
+            "sse4.2",
+            "ssse3",
+            "x87",
+        ],
+    ),
+    ("x86_64-unknown-haiku", &["fxsr", "sse", "sse2", "x87"]),
+    ("x86_64-unknown-helenos", &["fxsr", "sse", "sse2", "x87"]),
+    (
+        "x86_64-unknown-hermit",
+        &["fxsr", "rdrand", "rdseed", "sse", "sse2", "x87"],
+    ),
+    ("x86_64-unknown-hurd-gnu", &["fxsr", "sse", "sse2", "x87"]),
+    ("x86_64-unknown-illumos", &["fxsr", "sse", "sse2", "x87"]),
+    (
+        "x86_64-unknown-l4re-uclibc",
+        &["fxsr", "sse", "sse2", "x87"],
+    ),
+    ("x86_64-unknown-linux-gnu", &["fxsr", "sse", "sse2", "x87"]),
+    (
+        "x86_64-unknown-linux-gnuasan",
+        &["fxsr", "sse", "sse2", "x87"],
+    ),
+    (
+        "x86_64-unknown-linux-gnux32",
+        &["fxsr", "sse", "sse2", "x87"],
+    ),
+    (
+        "x86_64-unknown-linux-musl",
+        &["crt-static", "fxsr", "sse", "sse2", "x87"],
+    ),
+    ("x86_64-unknown-linux-none", &["fxsr", "sse", "sse2", "x87"]),
+    ("x86_64-unknown-linux-ohos", &["fxsr", "sse", "sse2", "x87"]),
+    (
+        "x86_64-unknown-managarm-mlibc",
+        &["fxsr", "sse", "sse2", "x87"],
+    ),
+    (
+        "x86_64-unknown-motor",
+        &["crt-static", "fxsr", "sse", "sse2", "x87"],
+    ),
+    ("x86_64-unknown-netbsd", &["fxsr", "sse", "sse2", "x87"]),
+    ("x86_64-unknown-none", &["fxsr", "x87"]),
+    ("x86_64-unknown-openbsd", &["fxsr", "sse", "sse2", "x87"]),
+    (
+        "x86_64-unknown-redox",
+        &["crt-static", "fxsr", "sse", "sse2", "x87"],
+    ),
+    (
+        "x86_64-unknown-trusty",
+        &["crt-static", "sse", "sse2", "x87"],
+    ),
+    ("x86_64-unknown-uefi", &["fxsr", "x87"]),
+    (
+        "x86_64-uwp-windows-gnu",
+        &[
+            "cmpxchg16b",
+            "fxsr",
+            "lahfsahf",
+            "sse",
+            "sse2",
+            "sse3",
+            "x87",
+        ],
+    ),
+    (
+        "x86_64-uwp-windows-msvc",
+        &[
+            "cmpxchg16b",
+            "fxsr",
+            "lahfsahf",
+            "sse",
+            "sse2",
+            "sse3",
+            "x87",
+        ],
+    ),
+    ("x86_64-win7-windows-gnu", &["fxsr", "sse", "sse2", "x87"]),
+    ("x86_64-win7-windows-msvc", &["fxsr", "sse", "sse2", "x87"]),
+    (
+        "x86_64-wrs-vxworks",
+        &["crt-static", "fxsr", "sse", "sse2", "x87"],
+    ),
+    (
+        "x86_64h-apple-darwin",
+        &[
+            "avx",
+            "avx2",
+            "bmi1",
+            "bmi2",
+            "cmpxchg16b",
+            "ermsb",
+            "f16c",
+            "fma",
+            "fxsr",
+            "lahfsahf",
+            "lzcnt",
+            "movbe",
+            "popcnt",
+            "sse",
+            "sse2",
+            "sse3",
+            "sse4.1",
+            "sse4.2",
+            "ssse3",
+            "x87",
+            "xsave",
+            "xsaveopt",
+        ],
+    ),
+    ("xtensa-esp32-espidf", &[]),
+    ("xtensa-esp32-none-elf", &[]),
+    ("xtensa-esp32s2-espidf", &[]),
+    ("xtensa-esp32s2-none-elf", &[]),
+    ("xtensa-esp32s3-espidf", &[]),
+    ("xtensa-esp32s3-none-elf", &[]),
+];
+
+/// The features implied by each feature, sorted by feature
+pub(crate) type Implications = &'static [(&'static str, &'static [&'static str])];
+
+pub(crate) const IMPLIED_FEATURES: &[(&str, Implications)] = &[
+    (
+        "aarch64",
+        &[
+            ("aes", &["neon"]),
+            ("dotprod", &["neon"]),
+            ("dpb2", &["dpb"]),
+            ("f32mm", &["neon", "sve"]),
+            ("f64mm", &["neon", "sve"]),
+            ("fcma", &["neon"]),
+            ("fhm", &["fp16", "neon"]),
+            ("fp16", &["neon"]),
+            ("fp8", &["bf16", "faminmax", "lut"]),
+            (
+                "fp8dot2",
+                &["bf16", "faminmax", "fp8", "fp8dot4", "fp8fma", "lut"],
+            ),
+            ("fp8dot4", &["bf16", "faminmax", "fp8", "fp8fma", "lut"]),
+            ("fp8fma", &["bf16", "faminmax", "fp8", "lut"]),
+            ("jsconv", &["neon"]),
+            ("lse128", &["lse"]),
+            ("rcpc2", &["rcpc"]),
+            ("rcpc3", &["rcpc", "rcpc2"]),
+            ("rdm", &["neon"]),
+            ("sha2", &["neon"]),
+            ("sha3", &["neon", "sha2"]),
+            ("sm4", &["neon"]),
+            ("sme", &["bf16"]),
+            ("sme-b16b16", &["bf16", "sme", "sme2", "sve-b16b16"]),
+            ("sme-f16f16", &["bf16", "sme", "sme2"]),
+            ("sme-f64f64", &["bf16", "sme"]),
+            (
+                "sme-f8f16",
+                &["bf16", "faminmax", "fp8", "lut", "sme", "sme-f8f32", "sme2"],
+            ),
+            (
+                "sme-f8f32",
+                &["bf16", "faminmax", "fp8", "lut", "sme", "sme2"],
+            ),
+            ("sme-fa64", &["bf16", "neon", "sme", "sve", "sve2"]),
+            ("sme-i16i64", &["bf16", "sme"]),
+            ("sme2", &["bf16", "sme"]),
+            ("sme2p1", &["bf16", "sme", "sme2"]),
+            (
+                "ssve-fp8dot2",
+                &[
+                    "bf16",
+                    "faminmax",
+                    "fp8",
+                    "lut",
+                    "sme",
+                    "sme2",
+                    "ssve-fp8dot4",
+                    "ssve-fp8fma",
+                ],
+            ),
+            (
+                "ssve-fp8dot4",
+                &[
+                    "bf16",
+                    "faminmax",
+                    "fp8",
+                    "lut",
+                    "sme",
+                    "sme2",
+                    "ssve-fp8fma",
+                ],
+            ),
+            (
+                "ssve-fp8fma",
+                &["bf16", "faminmax", "fp8", "lut", "sme", "sme2"],
+            ),
+            ("sve", &["neon"]),
+            ("sve-b16b16", &["bf16"]),
+            ("sve2", &["neon", "sve"]),
+            ("sve2-aes", &["aes", "neon", "sve", "sve2"]),
+            ("sve2-bitperm", &["neon", "sve", "sve2"]),
+            ("sve2-sha3", &["neon", "sha2", "sha3", "sve", "sve2"]),
+            ("sve2-sm4", &["neon", "sm4", "sve", "sve2"]),
+            ("sve2p1", &["neon", "sve", "sve2"]),
+            ("v8.1a", &["crc", "lor", "lse", "neon", "pan", "rdm", "vh"]),
+            (
+                "v8.2a",
+                &[
+                    "crc", "dpb", "lor", "lse", "neon", "pan", "ras", "rdm", "v8.1a", "vh",
+                ],
+            ),
+            (
+                "v8.3a",
+                &[
+                    "crc", "dpb", "jsconv", "lor", "lse", "neon", "paca", "pacg", "pan", "ras",
+                    "rcpc", "rdm", "v8.1a", "v8.2a", "vh",
+                ],
+            ),
+            (
+                "v8.4a",
+                &[
+                    "crc", "dit", "dotprod", "dpb", "flagm", "jsconv", "lor", "lse", "neon",
+                    "paca", "pacg", "pan", "ras", "rcpc", "rdm", "v8.1a", "v8.2a", "v8.3a", "vh",
+                ],
+            ),
+            (
+                "v8.5a",
+                &[
+                    "bti", "crc", "dit", "dotprod", "dpb", "dpb2", "flagm", "jsconv", "lor", "lse",
+                    "neon", "paca", "pacg", "pan", "ras", "rcpc", "rdm", "sb", "ssbs", "v8.1a",
+                    "v8.2a", "v8.3a", "v8.4a", "vh",
+                ],
+            ),
+            (
+                "v8.6a",
+                &[
+                    "bf16", "bti", "crc", "dit", "dotprod", "dpb", "dpb2", "flagm", "i8mm",
+                    "jsconv", "lor", "lse", "neon", "paca", "pacg", "pan", "ras", "rcpc", "rdm",
+                    "sb", "ssbs", "v8.1a", "v8.2a", "v8.3a", "v8.4a", "v8.5a", "vh",
+                ],
+            ),
+            (
+                "v8.7a",
+                &[
+                    "bf16", "bti", "crc", "dit", "dotprod", "dpb", "dpb2", "flagm", "i8mm",
+                    "jsconv", "lor", "lse", "neon", "paca", "pacg", "pan", "ras", "rcpc", "rdm",
+                    "sb", "ssbs", "v8.1a", "v8.2a", "v8.3a", "v8.4a", "v8.5a", "v8.6a", "vh",
+                    "wfxt",
+                ],
+            ),
+            (
+                "v8.8a",
+                &[
+                    "bf16", "bti", "crc", "dit", "dotprod", "dpb", "dpb2", "flagm", "hbc", "i8mm",
+                    "jsconv", "lor", "lse", "mops", "neon", "paca", "pacg", "pan", "ras", "rcpc",
+                    "rdm", "sb", "ssbs", "v8.1a", "v8.2a", "v8.3a", "v8.4a", "v8.5a", "v8.6a",
+                    "v8.7a", "vh", "wfxt",
+                ],
+            ),
+            (
+                "v8.9a",
+                &[
+                    "bf16", "bti", "crc", "cssc", "dit", "dotprod", "dpb", "dpb2", "flagm", "hbc",
+                    "i8mm", "jsconv", "lor", "lse", "mops", "neon", "paca", "pacg", "pan", "ras",
+                    "rcpc", "rdm", "sb", "ssbs", "v8.1a", "v8.2a", "v8.3a", "v8.4a", "v8.5a",
+                    "v8.6a", "v8.7a", "v8.8a", "vh", "wfxt",
+                ],
+            ),
+            (
+                "v9.1a",
+                &[
+                    "bf16", "bti", "crc", "dit", "dotprod", "dpb", "dpb2", "flagm", "i8mm",
+                    "jsconv", "lor", "lse", "neon", "paca", "pacg", "pan", "ras", "rcpc", "rdm",
+                    "sb", "ssbs", "sve", "sve2", "v8.1a", "v8.2a", "v8.3a", "v8.4a", "v8.5a",
+                    "v8.6a", "v9a", "vh",
+                ],
+            ),
+            (
+                "v9.2a",
+                &[
+                    "bf16", "bti", "crc", "dit", "dotprod", "dpb", "dpb2", "flagm", "i8mm",
+                    "jsconv", "lor", "lse", "neon", "paca", "pacg", "pan", "ras", "rcpc", "rdm",
+                    "sb", "ssbs", "sve", "sve2", "v8.1a", "v8.2a", "v8.3a", "v8.4a", "v8.5a",
+                    "v8.6a", "v8.7a", "v9.1a", "v9a", "vh", "wfxt",
+                ],
+            ),
+            (
+                "v9.3a",
+                &[
+                    "bf16", "bti", "crc", "dit", "dotprod", "dpb", "dpb2", "flagm", "hbc", "i8mm",
+                    "jsconv", "lor", "lse", "mops", "neon", "paca", "pacg", "pan", "ras", "rcpc",
+                    "rdm", "sb", "ssbs", "sve", "sve2", "v8.1a", "v8.2a", "v8.3a", "v8.4a",
+                    "v8.5a", "v8.6a", "v8.7a", "v8.8a", "v9.1a", "v9.2a", "v9a", "vh", "wfxt",
+                ],
+            ),
+            (
+                "v9.4a",
+                &[
+                    "bf16", "bti", "crc", "cssc", "dit", "dotprod", "dpb", "dpb2", "flagm", "hbc",
+                    "i8mm", "jsconv", "lor", "lse", "mops", "neon", "paca", "pacg", "pan", "ras",
+                    "rcpc", "rdm", "sb", "ssbs", "sve", "sve2", "v8.1a", "v8.2a", "v8.3a", "v8.4a",
+                    "v8.5a", "v8.6a", "v8.7a", "v8.8a", "v8.9a", "v9.1a", "v9.2a", "v9.3a", "v9a",
+                    "vh", "wfxt",
+                ],
+            ),
+            (
+                "v9.5a",
+                &[
+                    "bf16", "bti", "crc", "cssc", "dit", "dotprod", "dpb", "dpb2", "flagm", "hbc",
+                    "i8mm", "jsconv", "lor", "lse", "mops", "neon", "paca", "pacg", "pan", "ras",
+                    "rcpc", "rdm", "sb", "ssbs", "sve", "sve2", "v8.1a", "v8.2a", "v8.3a", "v8.4a",
+                    "v8.5a", "v8.6a", "v8.7a", "v8.8a", "v8.9a", "v9.1a", "v9.2a", "v9.3a",
+                    "v9.4a", "v9a", "vh", "wfxt",
+                ],
+            ),
+            (
+                "v9a",
+                &[
+                    "bti", "crc", "dit", "dotprod", "dpb", "dpb2", "flagm", "jsconv", "lor", "lse",
+                    "neon", "paca", "pacg", "pan", "ras", "rcpc", "rdm", "sb", "ssbs", "sve",
+                    "sve2", "v8.1a", "v8.2a", "v8.3a", "v8.4a", "v8.5a", "vh",
+                ],
+            ),
+        ],
+    ),
+    (
+        "arm",
+        &[
+            ("aes", &["d32", "neon", "vfp2", "vfp3"]),
+            ("dotprod", &["d32", "neon", "vfp2", "vfp3"]),
+            ("fp-armv8", &["d32", "vfp2", "vfp3", "vfp4"]),
+            ("fp16", &["d32", "neon", "vfp2", "vfp3"]),
+            ("i8mm", &["d32", "neon", "vfp2", "vfp3"]),
+            ("neon", &["d32", "vfp2", "vfp3"]),
+            ("sha2", &["d32", "neon", "vfp2", "vfp3"]),
+            ("v6k", &["v6"]),
+            ("v6t2", &["thumb2", "v6", "v6k"]),
+            ("v7", &["thumb2", "v6", "v6k", "v6t2"]),
+            ("v8", &["thumb2", "v6", "v6k", "v6t2", "v7"]),
+            ("vfp3", &["d32", "vfp2"]),
+            ("vfp4", &["d32", "vfp2", "vfp3"]),
+        ],
+    ),
+    (
+        "arm64ec",
+        &[
+            ("dpb2", &["dpb"]),
+            ("f32mm", &["sve"]),
+            ("f64mm", &["sve"]),
+            ("fhm", &["fp16"]),
+            ("fp8", &["bf16", "faminmax", "lut"]),
+            (
+                "fp8dot2",
+                &["bf16", "faminmax", "fp8", "fp8dot4", "fp8fma", "lut"],
+            ),
+            ("fp8dot4", &["bf16", "faminmax", "fp8", "fp8fma", "lut"]),
+            ("fp8fma", &["bf16", "faminmax", "fp8", "lut"]),
+            ("lse128", &["lse"]),
+            ("rcpc2", &["rcpc"]),
+            ("rcpc3", &["rcpc", "rcpc2"]),
+            ("sha3", &["sha2"]),
+            ("sme", &["bf16"]),
+            ("sme-b16b16", &["bf16", "sme", "sme2", "sve-b16b16"]),
+            ("sme-f16f16", &["bf16", "sme", "sme2"]),
+            ("sme-f64f64", &["bf16", "sme"]),
+            (
+                "sme-f8f16",
+                &["bf16", "faminmax", "fp8", "lut", "sme", "sme-f8f32", "sme2"],
+            ),
+            (
+                "sme-f8f32",
+                &["bf16", "faminmax", "fp8", "lut", "sme", "sme2"],
+            ),
+            ("sme-fa64", &["bf16", "sme", "sve", "sve2"]),
+            ("sme-i16i64", &["bf16", "sme"]),
+            ("sme2", &["bf16", "sme"]),
+            ("sme2p1", &["bf16", "sme", "sme2"]),
+            (
+                "ssve-fp8dot2",
+                &[
+                    "bf16",
+                    "faminmax",
+                    "fp8",
+                    "lut",
+                    "sme",
+                    "sme2",
+                    "ssve-fp8dot4",
+                    "ssve-fp8fma",
+                ],
+            ),
+            (
+                "ssve-fp8dot4",
+                &[
+                    "bf16",
+                    "faminmax",
+                    "fp8",
+                    "lut",
+                    "sme",
+                    "sme2",
+                    "ssve-fp8fma",
+                ],
+            ),
+            (
+                "ssve-fp8fma",
+                &["bf16", "faminmax", "fp8", "lut", "sme", "sme2"],
+            ),
+            ("sve-b16b16", &["bf16"]),
+            ("sve2", &["sve"]),
+            ("sve2-aes", &["aes", "sve", "sve2"]),
+            ("sve2-bitperm", &["sve", "sve2"]),
+            ("sve2-sha3", &["sha2", "sha3", "sve", "sve2"]),
+            ("sve2-sm4", &["sm4", "sve", "sve2"]),
+            ("sve2p1", &["sve", "sve2"]),
+            ("v8.1a", &["crc", "lor", "lse", "pan", "rdm", "vh"]),
+            (
+                "v8.2a",
+                &[
+                    "crc", "dpb", "lor", "lse", "pan", "ras", "rdm", "v8.1a", "vh",
+                ],
+            ),
+            (
+                "v8.3a",
+                &[
+                    "crc", "dpb", "jsconv", "lor", "lse", "paca", "pacg", "pan", "ras", "rcpc",
+                    "rdm", "v8.1a", "v8.2a", "vh",
+                ],
+            ),
+            (
+                "v8.4a",
+                &[
+                    "crc", "dit", "dotprod", "dpb", "flagm", "jsconv", "lor", "lse", "paca",
+                    "pacg", "pan", "ras", "rcpc", "rdm", "v8.1a", "v8.2a", "v8.3a", "vh",
+                ],
+            ),
+            (
+                "v8.5a",
+                &[
+                    "bti", "crc", "dit", "dotprod", "dpb", "dpb2", "flagm", "jsconv", "lor", "lse",
+                    "paca", "pacg", "pan", "ras", "rcpc", "rdm", "sb", "ssbs", "v8.1a", "v8.2a",
+                    "v8.3a", "v8.4a", "vh",
+                ],
+            ),
+            (
+                "v8.6a",
+                &[
+                    "bf16", "bti", "crc", "dit", "dotprod", "dpb", "dpb2", "flagm", "i8mm",
+                    "jsconv", "lor", "lse", "paca", "pacg", "pan", "ras", "rcpc", "rdm", "sb",
+                    "ssbs", "v8.1a", "v8.2a", "v8.3a", "v8.4a", "v8.5a", "vh",
+                ],
+            ),
+            (
+                "v8.7a",
+                &[
+                    "bf16", "bti", "crc", "dit", "dotprod", "dpb", "dpb2", "flagm", "i8mm",
+                    "jsconv", "lor", "lse", "paca", "pacg", "pan", "ras", "rcpc", "rdm", "sb",
+                    "ssbs", "v8.1a", "v8.2a", "v8.3a", "v8.4a", "v8.5a", "v8.6a", "vh", "wfxt",
+                ],
+            ),
+            (
+                "v8.8a",
+                &[
+                    "bf16", "bti", "crc", "dit", "dotprod", "dpb", "dpb2", "flagm", "hbc", "i8mm",
+                    "jsconv", "lor", "lse", "mops", "paca", "pacg", "pan", "ras", "rcpc", "rdm",
+                    "sb", "ssbs", "v8.1a", "v8.2a", "v8.3a", "v8.4a", "v8.5a", "v8.6a", "v8.7a",
+                    "vh", "wfxt",
+                ],
+            ),
+            (
+                "v8.9a",
+                &[
+                    "bf16", "bti", "crc", "cssc", "dit", "dotprod", "dpb", "dpb2", "flagm", "hbc",
+                    "i8mm", "jsconv", "lor", "lse", "mops", "paca", "pacg", "pan", "ras", "rcpc",
+                    "rdm", "sb", "ssbs", "v8.1a", "v8.2a", "v8.3a", "v8.4a", "v8.5a", "v8.6a",
+                    "v8.7a", "v8.8a", "vh", "wfxt",
+                ],
+            ),
+            (
+                "v9.1a",
+                &[
+                    "bf16", "bti", "crc", "dit", "dotprod", "dpb", "dpb2", "flagm", "i8mm",
+                    "jsconv", "lor", "lse", "paca", "pacg", "pan", "ras", "rcpc", "rdm", "sb",
+                    "ssbs", "sve", "sve2", "v8.1a", "v8.2a", "v8.3a", "v8.4a", "v8.5a", "v8.6a",
+                    "v9a", "vh",
+                ],
+            ),
+            (
+                "v9.2a",
+                &[
+                    "bf16", "bti", "crc", "dit", "dotprod", "dpb", "dpb2", "flagm", "i8mm",
+                    "jsconv", "lor", "lse", "paca", "pacg", "pan", "ras", "rcpc", "rdm", "sb",
+                    "ssbs", "sve", "sve2", "v8.1a", "v8.2a", "v8.3a", "v8.4a", "v8.5a", "v8.6a",
+                    "v8.7a", "v9.1a", "v9a", "vh", "wfxt",
+                ],
+            ),
+            (
+                "v9.3a",
+                &[
+                    "bf16", "bti", "crc", "dit", "dotprod", "dpb", "dpb2", "flagm", "hbc", "i8mm",
+                    "jsconv", "lor", "lse", "mops", "paca", "pacg", "pan", "ras", "rcpc", "rdm",
+                    "sb", "ssbs", "sve", "sve2", "v8.1a", "v8.2a", "v8.3a", "v8.4a", "v8.5a",
+                    "v8.6a", "v8.7a", "v8.8a", "v9.1a", "v9.2a", "v9a", "vh", "wfxt",
+                ],
+            ),
+            (
+                "v9.4a",
+                &[
+                    "bf16", "bti", "crc", "cssc", "dit", "dotprod", "dpb", "dpb2", "flagm", "hbc",
+                    "i8mm", "jsconv", "lor", "lse", "mops", "paca", "pacg", "pan", "ras", "rcpc",
+                    "rdm", "sb", "ssbs", "sve", "sve2", "v8.1a", "v8.2a", "v8.3a", "v8.4a",
+                    "v8.5a", "v8.6a", "v8.7a", "v8.8a", "v8.9a", "v9.1a", "v9.2a", "v9.3a", "v9a",
+                    "vh", "wfxt",
+                ],
+            ),
+            (
+                "v9.5a",
+                &[
+                    "bf16", "bti", "crc", "cssc", "dit", "dotprod", "dpb", "dpb2", "flagm", "hbc",
+                    "i8mm", "jsconv", "lor", "lse", "mops", "paca", "pacg", "pan", "ras", "rcpc",
+                    "rdm", "sb", "ssbs", "sve", "sve2", "v8.1a", "v8.2a", "v8.3a", "v8.4a",
+                    "v8.5a", "v8.6a", "v8.7a", "v8.8a", "v8.9a", "v9.1a", "v9.2a", "v9.3a",
+                    "v9.4a", "v9a", "vh", "wfxt",
+                ],
+            ),
+            (
+                "v9a",
+                &[
+                    "bti", "crc", "dit", "dotprod", "dpb", "dpb2", "flagm", "jsconv", "lor", "lse",
+                    "paca", "pacg", "pan", "ras", "rcpc", "rdm", "sb", "ssbs", "sve", "sve2",
+                    "v8.1a", "v8.2a", "v8.3a", "v8.4a", "v8.5a", "vh",
+                ],
+            ),
+        ],
+    ),
+    (
+        "csky",
+        &[("3e3r2", &["3e3r1", "doloop"]), ("3e3r3", &["doloop"])],
+    ),
+    (
+        "hexagon",
+        &[
+            ("hvxv65", &["hvxv62"]),
+            ("hvxv66", &["hvxv62", "hvxv65", "zreg"]),
+            ("hvxv67", &["hvxv62", "hvxv65", "hvxv66", "zreg"]),
+            ("hvxv68", &["hvxv62", "hvxv65", "hvxv66", "hvxv67", "zreg"]),
+            (
+                "hvxv69",
+                &["hvxv62", "hvxv65", "hvxv66", "hvxv67", "hvxv68", "zreg"],
+            ),
+            (
+                "hvxv71",
+                &[
+                    "hvxv62", "hvxv65", "hvxv66", "hvxv67", "hvxv68", "hvxv69", "zreg",
+                ],
+            ),
+            (
+                "hvxv73",
+                &[
+                    "hvxv62", "hvxv65", "hvxv66", "hvxv67", "hvxv68", "hvxv69", "hvxv71", "zreg",
+                ],
+            ),
+            (
+                "hvxv75",
+                &[
+                    "hvxv62", "hvxv65", "hvxv66", "hvxv67", "hvxv68", "hvxv69", "hvxv71", "hvxv73",
+                    "zreg",
+                ],
+            ),
+            (
+                "hvxv79",
+                &[
+                    "hvxv62", "hvxv65", "hvxv66", "hvxv67", "hvxv68", "hvxv69", "hvxv71", "hvxv73",
+                    "hvxv75", "zreg",
+                ],
+            ),
+        ],
+    ),
+    (
+        "loongarch32",
+        &[
+            ("d", &["f"]),
+            ("lasx", &["d", "f", "lsx"]),
+            ("lsx", &["d", "f"]),
+        ],
+    ),
+    (
+        "loongarch64",
+        &[
+            ("d", &["f"]),
+            ("lasx", &["d", "f", "lsx"]),
+            ("lsx", &["d", "f"]),
+        ],
+    ),
+    (
+        "m68k",
+        &[
+            ("isa-68030", &["isa-68020"]),
+            (
+                "isa-68040",
+                &["isa-68020", "isa-68030", "isa-68881", "isa-68882"],
+            ),
+            (
+                "isa-68060",
+                &[
+                    "isa-68020",
+                    "isa-68030",
+                    "isa-68040",
+                    "isa-68881",
+                    "isa-68882",
+                ],
+            ),
+            ("isa-68882", &["isa-68881"]),
+        ],
+    ),
+    (
+        "nvptx64",
+        &[
+            ("ptx40", &["ptx32"]),
+            ("ptx41", &["ptx32", "ptx40"]),
+            ("ptx42", &["ptx32", "ptx40", "ptx41"]),
+            ("ptx43", &["ptx32", "ptx40", "ptx41", "ptx42"]),
+            ("ptx50", &["ptx32", "ptx40", "ptx41", "ptx42", "ptx43"]),
+            (
+                "ptx60",
+                &["ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50"],
+            ),
+            (
+                "ptx61",
+                &[
+                    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60",
+                ],
+            ),
+            (
+                "ptx62",
+                &[
+                    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60", "ptx61",
+                ],
+            ),
+            (
+                "ptx63",
+                &[
+                    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60", "ptx61", "ptx62",
+                ],
+            ),
+            (
+                "ptx64",
+                &[
+                    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60", "ptx61",
+                    "ptx62", "ptx63",
+                ],
+            ),
+            (
+                "ptx65",
+                &[
+                    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60", "ptx61",
+                    "ptx62", "ptx63", "ptx64",
+                ],
+            ),
+            (
+                "ptx70",
+                &[
+                    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60", "ptx61",
+                    "ptx62", "ptx63", "ptx64", "ptx65",
+                ],
+            ),
+            (
+                "ptx71",
+                &[
+                    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60", "ptx61",
+                    "ptx62", "ptx63", "ptx64", "ptx65", "ptx70",
+                ],
+            ),
+            (
+                "ptx72",
+                &[
+                    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60", "ptx61",
+                    "ptx62", "ptx63", "ptx64", "ptx65", "ptx70", "ptx71",
+                ],
+            ),
+            (
+                "ptx73",
+                &[
+                    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60", "ptx61",
+                    "ptx62", "ptx63", "ptx64", "ptx65", "ptx70", "ptx71", "ptx72",
+                ],
+            ),
+            (
+                "ptx74",
+                &[
+                    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60", "ptx61",
+                    "ptx62", "ptx63", "ptx64", "ptx65", "ptx70", "ptx71", "ptx72", "ptx73",
+                ],
+            ),
+            (
+                "ptx75",
+                &[
+                    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60", "ptx61",
+                    "ptx62", "ptx63", "ptx64", "ptx65", "ptx70", "ptx71", "ptx72", "ptx73",
+                    "ptx74",
+                ],
+            ),
+            (
+                "ptx76",
+                &[
+                    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60", "ptx61",
+                    "ptx62", "ptx63", "ptx64", "ptx65", "ptx70", "ptx71", "ptx72", "ptx73",
+                    "ptx74", "ptx75",
+                ],
+            ),
+            (
+                "ptx77",
+                &[
+                    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60", "ptx61",
+                    "ptx62", "ptx63", "ptx64", "ptx65", "ptx70", "ptx71", "ptx72", "ptx73",
+                    "ptx74", "ptx75", "ptx76",
+                ],
+            ),
+            (
+                "ptx78",
+                &[
+                    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60", "ptx61",
+                    "ptx62", "ptx63", "ptx64", "ptx65", "ptx70", "ptx71", "ptx72", "ptx73",
+                    "ptx74", "ptx75", "ptx76", "ptx77",
+                ],
+            ),
+            (
+                "ptx80",
+                &[
+                    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60", "ptx61",
+                    "ptx62", "ptx63", "ptx64", "ptx65", "ptx70", "ptx71", "ptx72", "ptx73",
+                    "ptx74", "ptx75", "ptx76", "ptx77", "ptx78",
+                ],
+            ),
+            (
+                "ptx81",
+                &[
+                    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60", "ptx61",
+                    "ptx62", "ptx63", "ptx64", "ptx65", "ptx70", "ptx71", "ptx72", "ptx73",
+                    "ptx74", "ptx75", "ptx76", "ptx77", "ptx78", "ptx80",
+                ],
+            ),
+            (
+                "ptx82",
+                &[
+                    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60", "ptx61",
+                    "ptx62", "ptx63", "ptx64", "ptx65", "ptx70", "ptx71", "ptx72", "ptx73",
+                    "ptx74", "ptx75", "ptx76", "ptx77", "ptx78", "ptx80", "ptx81",
+                ],
+            ),
+            (
+                "ptx83",
+                &[
+                    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60", "ptx61",
+                    "ptx62", "ptx63", "ptx64", "ptx65", "ptx70", "ptx71", "ptx72", "ptx73",
+                    "ptx74", "ptx75", "ptx76", "ptx77", "ptx78", "ptx80", "ptx81", "ptx82",
+                ],
+            ),
+            (
+                "ptx84",
+                &[
+                    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60", "ptx61",
+                    "ptx62", "ptx63", "ptx64", "ptx65", "ptx70", "ptx71", "ptx72", "ptx73",
+                    "ptx74", "ptx75", "ptx76", "ptx77", "ptx78", "ptx80", "ptx81", "ptx82",
+                    "ptx83",
+                ],
+            ),
+            (
+                "ptx85",
+                &[
+                    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60", "ptx61",
+                    "ptx62", "ptx63", "ptx64", "ptx65", "ptx70", "ptx71", "ptx72", "ptx73",
+                    "ptx74", "ptx75", "ptx76", "ptx77", "ptx78", "ptx80", "ptx81", "ptx82",
+                    "ptx83", "ptx84",
+                ],
+            ),
+            (
+                "ptx86",
+                &[
+                    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60", "ptx61",
+                    "ptx62", "ptx63", "ptx64", "ptx65", "ptx70", "ptx71", "ptx72", "ptx73",
+                    "ptx74", "ptx75", "ptx76", "ptx77", "ptx78", "ptx80", "ptx81", "ptx82",
+                    "ptx83", "ptx84", "ptx85",
+                ],
+            ),
+            (
+                "ptx87",
+                &[
+                    "ptx32", "ptx40", "ptx41", "ptx42", "ptx43", "ptx50", "ptx60", "ptx61",
+                    "ptx62", "ptx63", "ptx64", "ptx65", "ptx70", "ptx71", "ptx72", "ptx73",
+                    "ptx74", "ptx75", "ptx76", "ptx77", "ptx78", "ptx80", "ptx81", "ptx82",
+                    "ptx83", "ptx84", "ptx85", "ptx86",
+                ],
+            ),
+            (
+                "sm_100",
+                &[
+                    "sm_32", "sm_35", "sm_37", "sm_50", "sm_52", "sm_53", "sm_60", "sm_61",
+                    "sm_62", "sm_70", "sm_72", "sm_75", "sm_80", "sm_86", "sm_87", "sm_89",
+                    "sm_90",
+                ],
+            ),
+            (
+                "sm_100a",
+                &[
+                    "sm_100", "sm_32", "sm_35", "sm_37", "sm_50", "sm_52", "sm_53", "sm_60",
+                    "sm_61", "sm_62", "sm_70", "sm_72", "sm_75", "sm_80", "sm_86", "sm_87",
+                    "sm_89", "sm_90",
+                ],
+            ),
+            (
+                "sm_101",
+                &[
+                    "sm_100", "sm_32", "sm_35", "sm_37", "sm_50", "sm_52", "sm_53", "sm_60",
+                    "sm_61", "sm_62", "sm_70", "sm_72", "sm_75", "sm_80", "sm_86", "sm_87",
+                    "sm_89", "sm_90",
+                ],
+            ),
+            (
+                "sm_101a",
+                &[
+                    "sm_100", "sm_101", "sm_32", "sm_35", "sm_37", "sm_50", "sm_52", "sm_53",
+                    "sm_60", "sm_61", "sm_62", "sm_70", "sm_72", "sm_75", "sm_80", "sm_86",
+                    "sm_87", "sm_89", "sm_90",
+                ],
+            ),
+            (
+                "sm_120",
+                &[
+                    "sm_100", "sm_101", "sm_32", "sm_35", "sm_37", "sm_50", "sm_52", "sm_53",
+                    "sm_60", "sm_61", "sm_62", "sm_70", "sm_72", "sm_75", "sm_80", "sm_86",
+                    "sm_87", "sm_89", "sm_90",
+                ],
+            ),
+            (
+                "sm_120a",
+                &[
+                    "sm_100", "sm_101", "sm_120", "sm_32", "sm_35", "sm_37", "sm_50", "sm_52",
+                    "sm_53", "sm_60", "sm_61", "sm_62", "sm_70", "sm_72", "sm_75", "sm_80",
+                    "sm_86", "sm_87", "sm_89", "sm_90",
+                ],
+            ),
+            ("sm_35", &["sm_32"]),
+            ("sm_37", &["sm_32", "sm_35"]),
+            ("sm_50", &["sm_32", "sm_35", "sm_37"]),
+            ("sm_52", &["sm_32", "sm_35", "sm_37", "sm_50"]),
+            ("sm_53", &["sm_32", "sm_35", "sm_37", "sm_50", "sm_52"]),
+            (
+                "sm_60",
+                &["sm_32", "sm_35", "sm_37", "sm_50", "sm_52", "sm_53"],
+            ),
+            (
+                "sm_61",
+                &[
+                    "sm_32", "sm_35", "sm_37", "sm_50", "sm_52", "sm_53", "sm_60",
+                ],
+            ),
+            (
+                "sm_62",
+                &[
+                    "sm_32", "sm_35", "sm_37", "sm_50", "sm_52", "sm_53", "sm_60", "sm_61",
+                ],
+            ),
+            (
+                "sm_70",
+                &[
+                    "sm_32", "sm_35", "sm_37", "sm_50", "sm_52", "sm_53", "sm_60", "sm_61", "sm_62",
+                ],
+            ),
+            (
+                "sm_72",
+                &[
+                    "sm_32", "sm_35", "sm_37", "sm_50", "sm_52", "sm_53", "sm_60", "sm_61",
+                    "sm_62", "sm_70",
+                ],
+            ),
+            (
+                "sm_75",
+                &[
+                    "sm_32", "sm_35", "sm_37", "sm_50", "sm_52", "sm_53", "sm_60", "sm_61",
+                    "sm_62", "sm_70", "sm_72",
+                ],
+            ),
+            (
+                "sm_80",
+                &[
+                    "sm_32", "sm_35", "sm_37", "sm_50", "sm_52", "sm_53", "sm_60", "sm_61",
+                    "sm_62", "sm_70", "sm_72", "sm_75",
+                ],
+            ),
+            (
+                "sm_86",
+                &[
+                    "sm_32", "sm_35", "sm_37", "sm_50", "sm_52", "sm_53", "sm_60", "sm_61",
+                    "sm_62", "sm_70", "sm_72", "sm_75", "sm_80",
+                ],
+            ),
+            (
+                "sm_87",
+                &[
+                    "sm_32", "sm_35", "sm_37", "sm_50", "sm_52", "sm_53", "sm_60", "sm_61",
+                    "sm_62", "sm_70", "sm_72", "sm_75", "sm_80", "sm_86",
+                ],
+            ),
+            (
+                "sm_89",
+                &[
+                    "sm_32", "sm_35", "sm_37", "sm_50", "sm_52", "sm_53", "sm_60", "sm_61",
+                    "sm_62", "sm_70", "sm_72", "sm_75", "sm_80", "sm_86", "sm_87",
+                ],
+            ),
+            (
+                "sm_90",
+                &[
+                    "sm_32", "sm_35", "sm_37", "sm_50", "sm_52", "sm_53", "sm_60", "sm_61",
+                    "sm_62", "sm_70", "sm_72", "sm_75", "sm_80", "sm_86", "sm_87", "sm_89",
+                ],
+            ),
+            (
+                "sm_90a",
+                &[
+                    "sm_32", "sm_35", "sm_37", "sm_50", "sm_52", "sm_53", "sm_60", "sm_61",
+                    "sm_62", "sm_70", "sm_72", "sm_75", "sm_80", "sm_86", "sm_87", "sm_89",
+                    "sm_90",
+                ],
+            ),
+        ],
+    ),
+    (
+        "powerpc",
+        &[
+            (
+                "power10-vector",
+                &[
+                    "altivec",
+                    "power8-altivec",
+                    "power8-vector",
+                    "power9-altivec",
+                    "power9-vector",
+                    "vsx",
+                ],
+            ),
+            ("power8-altivec", &["altivec"]),
+            ("power8-crypto", &["altivec", "power8-altivec"]),
+            ("power8-vector", &["altivec", "power8-altivec", "vsx"]),
+            ("power9-altivec", &["altivec", "power8-altivec"]),
+            (
+                "power9-vector",
+                &[
+                    "altivec",
+                    "power8-altivec",
+                    "power8-vector",
+                    "power9-altivec",
+                    "vsx",
+                ],
+            ),
+            ("vsx", &["altivec"]),
+        ],
+    ),
+    (
+        "powerpc64",
+        &[
+            (
+                "power10-vector",
+                &[
+                    "power8-altivec",
+                    "power8-vector",
+                    "power9-altivec",
+                    "power9-vector",
+                    "vsx",
+                ],
+            ),
+            ("power8-crypto", &["power8-altivec"]),
+            ("power8-vector", &["power8-altivec", "vsx"]),
+            ("power9-altivec", &["power8-altivec"]),
+            (
+                "power9-vector",
+                &["power8-altivec", "power8-vector", "power9-altivec", "vsx"],
+            ),
+        ],
+    ),
+    (
+        "riscv32",
+        &[
+            ("a", &["zaamo", "zalrsc"]),
+            ("b", &["zba", "zbb", "zbs"]),
+            ("c", &["zca"]),
+            ("d", &["f", "zicsr"]),
+            ("f", &["zicsr"]),
+            (
+                "rva23u64",
+                &[
+                    "a",
+                    "b",
+                    "c",
+                    "d",
+                    "f",
+                    "m",
+                    "supm",
+                    "v",
+                    "za128rs",
+                    "za64rs",
+                    "zaamo",
+                    "zalrsc",
+                    "zawrs",
+                    "zba",
+                    "zbb",
+                    "zbs",
+                    "zca",
+                    "zcb",
+                    "zcmop",
+                    "zfa",
+                    "zfhmin",
+                    "zic64b",
+                    "zicbom",
+                    "zicbop",
+                    "zicboz",
+                    "ziccamoa",
+                    "ziccif",
+                    "zicclsm",
+                    "ziccrse",
+                    "zicntr",
+                    "zicond",
+                    "zicsr",
+                    "zihintntl",
+                    "zihintpause",
+                    "zihpm",
+                    "zimop",
+                    "zkt",
+                    "zvbb",
+                    "zve32f",
+                    "zve32x",
+                    "zve64d",
+                    "zve64f",
+                    "zve64x",
+                    "zvfhmin",
+                    "zvkb",
+                    "zvkt",
+                    "zvl128b",
+                    "zvl32b",
+                    "zvl64b",
+                ],
+            ),
+            (
+                "v",
+                &[
+                    "d", "f", "zicsr", "zve32f", "zve32x", "zve64d", "zve64f", "zve64x", "zvl128b",
+                    "zvl32b", "zvl64b",
+                ],
+            ),
+            ("za64rs", &["za128rs"]),
+            ("zabha", &["zaamo"]),
+            ("zacas", &["zaamo"]),
+            ("zbc", &["zbkc"]),
+            ("zcb", &["zca"]),
+            ("zcmop", &["zca"]),
+            ("zdinx", &["zfinx", "zicsr"]),
+            ("zfa", &["f", "zicsr"]),
+            ("zfbfmin", &["f", "zicsr"]),
+            ("zfh", &["f", "zfhmin", "zicsr"]),
+            ("zfhmin", &["f", "zicsr"]),
+            ("zfinx", &["zicsr"]),
+            ("zhinx", &["zfinx", "zhinxmin", "zicsr"]),
+            ("zhinxmin", &["zfinx", "zicsr"]),
+            ("zicntr", &["zicsr"]),
+            ("zihpm", &["zicsr"]),
+            (
+                "zk",
+                &[
+                    "zbkb",
+                    "zbkc",
+                    "zbkx",
+                    "zkn",
+                    "zknd",
+                    "zkne",
+                    "zkne_or_zknd",
+                    "zknh",
+                    "zkr",
+                    "zkt",
+                ],
+            ),
+            (
+                "zkn",
+                &[
+                    "zbkb",
+                    "zbkc",
+                    "zbkx",
+                    "zknd",
+                    "zkne",
+                    "zkne_or_zknd",
+                    "zknh",
+                ],
+            ),
+            ("zknd", &["zkne_or_zknd"]),
+            ("zkne", &["zkne_or_zknd"]),
+            ("zks", &["zbkb", "zbkc", "zbkx", "zksed", "zksh"]),
+            ("zvbb", &["zicsr", "zve32x", "zvkb", "zvl32b"]),
+            ("zvbc", &["zicsr", "zve32x", "zve64x", "zvl32b", "zvl64b"]),
+            ("zve32f", &["f", "zicsr", "zve32x", "zvl32b"]),
+            ("zve32x", &["zicsr", "zvl32b"]),
+            (
+                "zve64d",
+                &[
+                    "d", "f", "zicsr", "zve32f", "zve32x", "zve64f", "zve64x", "zvl32b", "zvl64b",
+                ],
+            ),
+            (
+                "zve64f",
+                &[
+                    "f", "zicsr", "zve32f", "zve32x", "zve64x", "zvl32b", "zvl64b",
+                ],
+            ),
+            ("zve64x", &["zicsr", "zve32x", "zvl32b", "zvl64b"]),
+            ("zvfbfmin", &["f", "zicsr", "zve32f", "zve32x", "zvl32b"]),
+            (
+                "zvfbfwma",
+                &[
+                    "f", "zfbfmin", "zicsr", "zve32f", "zve32x", "zvfbfmin", "zvl32b",
+                ],
+            ),
+            (
+                "zvfh",
+                &[
+                    "f", "zfhmin", "zicsr", "zve32f", "zve32x", "zvfhmin", "zvl32b",
+                ],
+            ),
+            ("zvfhmin", &["f", "zicsr", "zve32f", "zve32x", "zvl32b"]),
+            ("zvkb", &["zicsr", "zve32x", "zvl32b"]),
+            ("zvkg", &["zicsr", "zve32x", "zvl32b"]),
+            (
+                "zvkn",
+                &[
+                    "zicsr", "zve32x", "zve64x", "zvkb", "zvkned", "zvknha", "zvknhb", "zvkt",
+                    "zvl32b", "zvl64b",
+                ],
+            ),
+            (
+                "zvknc",
+                &[
+                    "zicsr", "zvbc", "zve32x", "zve64x", "zvkb", "zvkn", "zvkned", "zvknha",
+                    "zvknhb", "zvkt", "zvl32b", "zvl64b",
+                ],
+            ),
+            ("zvkned", &["zicsr", "zve32x", "zvl32b"]),
+            (
+                "zvkng",
+                &[
+                    "zicsr", "zve32x", "zve64x", "zvkb", "zvkg", "zvkn", "zvkned", "zvknha",
+                    "zvknhb", "zvkt", "zvl32b", "zvl64b",
+                ],
+            ),
+            ("zvknha", &["zicsr", "zve32x", "zvl32b"]),
+            (
+                "zvknhb",
+                &["zicsr", "zve32x", "zve64x", "zvknha", "zvl32b", "zvl64b"],
+            ),
+            (
+                "zvks",
+                &[
+                    "zicsr", "zve32x", "zvkb", "zvksed", "zvksh", "zvkt", "zvl32b",
+                ],
+            ),
+            (
+                "zvksc",
+                &[
+                    "zicsr", "zvbc", "zve32x", "zve64x", "zvkb", "zvks", "zvksed", "zvksh", "zvkt",
+                    "zvl32b", "zvl64b",
+                ],
+            ),
+            ("zvksed", &["zicsr", "zve32x", "zvl32b"]),
+            (
+                "zvksg",
+                &[
+                    "zicsr", "zve32x", "zvkb", "zvkg", "zvks", "zvksed", "zvksh", "zvkt", "zvl32b",
+                ],
+            ),
+            ("zvksh", &["zicsr", "zve32x", "zvl32b"]),
+            (
+                "zvl1024b",
+                &["zvl128b", "zvl256b", "zvl32b", "zvl512b", "zvl64b"],
+            ),
+            ("zvl128b", &["zvl32b", "zvl64b"]),
+            (
+                "zvl16384b",
+                &[
+                    "zvl1024b", "zvl128b", "zvl2048b", "zvl256b", "zvl32b", "zvl4096b", "zvl512b",
+                    "zvl64b", "zvl8192b",
+                ],
+            ),
+            (
+                "zvl2048b",
+                &[
+                    "zvl1024b", "zvl128b", "zvl256b", "zvl32b", "zvl512b", "zvl64b",
+                ],
+            ),
+            ("zvl256b", &["zvl128b", "zvl32b", "zvl64b"]),
+            (
+                "zvl32768b",
+                &[
+                    "zvl1024b",
+                    "zvl128b",
+                    "zvl16384b",
+                    "zvl2048b",
+                    "zvl256b",
+                    "zvl32b",
+                    "zvl4096b",
+                    "zvl512b",
+                    "zvl64b",
+                    "zvl8192b",
+                ],
+            ),
+            (
+                "zvl4096b",
+                &[
+                    "zvl1024b", "zvl128b", "zvl2048b", "zvl256b", "zvl32b", "zvl512b", "zvl64b",
+                ],
+            ),
+            ("zvl512b", &["zvl128b", "zvl256b", "zvl32b", "zvl64b"]),
+            ("zvl64b", &["zvl32b"]),
+            (
+                "zvl65536b",
+                &[
+                    "zvl1024b",
+                    "zvl128b",
+                    "zvl16384b",
+                    "zvl2048b",
+                    "zvl256b",
+                    "zvl32768b",
+                    "zvl32b",
+                    "zvl4096b",
+                    "zvl512b",
+                    "zvl64b",
+                    "zvl8192b",
+                ],
+            ),
+            (
+                "zvl8192b",
+                &[
+                    "zvl1024b", "zvl128b", "zvl2048b", "zvl256b", "zvl32b", "zvl4096b", "zvl512b",
+                    "zvl64b",
+                ],
+            ),
+        ],
+    ),
+    (
+        "riscv64",
+        &[
+            ("a", &["zaamo", "zalrsc"]),
+            ("b", &["zba", "zbb", "zbs"]),
+            ("c", &["zca"]),
+            ("d", &["f", "zicsr"]),
+            ("f", &["zicsr"]),
+            (
+                "rva23u64",
+                &[
+                    "a",
+                    "b",
+                    "c",
+                    "d",
+                    "f",
+                    "supm",
+                    "v",
+                    "za128rs",
+                    "za64rs",
+                    "zaamo",
+                    "zalrsc",
+                    "zawrs",
+                    "zba",
+                    "zbb",
+                    "zbs",
+                    "zca",
+                    "zcb",
+                    "zcmop",
+                    "zfa",
+                    "zfhmin",
+                    "zic64b",
+                    "zicbom",
+                    "zicbop",
+                    "zicboz",
+                    "ziccamoa",
+                    "ziccif",
+                    "zicclsm",
+                    "ziccrse",
+                    "zicntr",
+                    "zicond",
+                    "zicsr",
+                    "zihintntl",
+                    "zihintpause",
+                    "zihpm",
+                    "zimop",
+                    "zkt",
+                    "zvbb",
+                    "zve32f",
+                    "zve32x",
+                    "zve64d",
+                    "zve64f",
+                    "zve64x",
+                    "zvfhmin",
+                    "zvkb",
+                    "zvkt",
+                    "zvl128b",
+                    "zvl32b",
+                    "zvl64b",
+                ],
+            ),
+            (
+                "v",
+                &[
+                    "d", "f", "zicsr", "zve32f", "zve32x", "zve64d", "zve64f", "zve64x", "zvl128b",
+                    "zvl32b", "zvl64b",
+                ],
+            ),
+            ("za64rs", &["za128rs"]),
+            ("zabha", &["zaamo"]),
+            ("zacas", &["zaamo"]),
+            ("zbc", &["zbkc"]),
+            ("zcb", &["zca"]),
+            ("zcmop", &["zca"]),
+            ("zdinx", &["zfinx", "zicsr"]),
+            ("zfa", &["f", "zicsr"]),
+            ("zfbfmin", &["f", "zicsr"]),
+            ("zfh", &["f", "zfhmin", "zicsr"]),
+            ("zfhmin", &["f", "zicsr"]),
+            ("zfinx", &["zicsr"]),
+            ("zhinx", &["zfinx", "zhinxmin", "zicsr"]),
+            ("zhinxmin", &["zfinx", "zicsr"]),
+            ("zicntr", &["zicsr"]),
+            ("zihpm", &["zicsr"]),
+            (
+                "zk",
+                &[
+                    "zbkb",
+                    "zbkc",
+                    "zbkx",
+                    "zkn",
+                    "zknd",
+                    "zkne",
+                    "zkne_or_zknd",
+                    "zknh",
+                    "zkr",
+                    "zkt",
+                ],
+            ),
+            (
+                "zkn",
+                &[
+                    "zbkb",
+                    "zbkc",
+                    "zbkx",
+                    "zknd",
+                    "zkne",
+                    "zkne_or_zknd",
+                    "zknh",
+                ],
+            ),
+            ("zknd", &["zkne_or_zknd"]),
+            ("zkne", &["zkne_or_zknd"]),
+            ("zks", &["zbkb", "zbkc", "zbkx", "zksed", "zksh"]),
+            ("zvbb", &["zicsr", "zve32x", "zvkb", "zvl32b"]),
+            ("zvbc", &["zicsr", "zve32x", "zve64x", "zvl32b", "zvl64b"]),
+            ("zve32f", &["f", "zicsr", "zve32x", "zvl32b"]),
+            ("zve32x", &["zicsr", "zvl32b"]),
+            (
+                "zve64d",
+                &[
+                    "d", "f", "zicsr", "zve32f", "zve32x", "zve64f", "zve64x", "zvl32b", "zvl64b",
+                ],
+            ),
+            (
+                "zve64f",
+                &[
+                    "f", "zicsr", "zve32f", "zve32x", "zve64x", "zvl32b", "zvl64b",
+                ],
+            ),
+            ("zve64x", &["zicsr", "zve32x", "zvl32b", "zvl64b"]),
+            ("zvfbfmin", &["f", "zicsr", "zve32f", "zve32x", "zvl32b"]),
+            (
+                "zvfbfwma",
+                &[
+                    "f", "zfbfmin", "zicsr", "zve32f", "zve32x", "zvfbfmin", "zvl32b",
+                ],
+            ),
+            (
+                "zvfh",
+                &[
+                    "f", "zfhmin", "zicsr", "zve32f", "zve32x", "zvfhmin", "zvl32b",
+                ],
+            ),
+            ("zvfhmin", &["f", "zicsr", "zve32f", "zve32x", "zvl32b"]),
+            ("zvkb", &["zicsr", "zve32x", "zvl32b"]),
+            ("zvkg", &["zicsr", "zve32x", "zvl32b"]),
+            (
+                "zvkn",
+                &[
+                    "zicsr", "zve32x", "zve64x", "zvkb", "zvkned", "zvknha", "zvknhb", "zvkt",
+                    "zvl32b", "zvl64b",
+                ],
+            ),
+            (
+                "zvknc",
+                &[
+                    "zicsr", "zvbc", "zve32x", "zve64x", "zvkb", "zvkn", "zvkned", "zvknha",
+                    "zvknhb", "zvkt", "zvl32b", "zvl64b",
+                ],
+            ),
+            ("zvkned", &["zicsr", "zve32x", "zvl32b"]),
+            (
+                "zvkng",
+                &[
+                    "zicsr", "zve32x", "zve64x", "zvkb", "zvkg", "zvkn", "zvkned", "zvknha",
+                    "zvknhb", "zvkt", "zvl32b", "zvl64b",
+                ],
+            ),
+            ("zvknha", &["zicsr", "zve32x", "zvl32b"]),
+            (
+                "zvknhb",
+                &["zicsr", "zve32x", "zve64x", "zvknha", "zvl32b", "zvl64b"],
+            ),
+            (
+                "zvks",
+                &[
+                    "zicsr", "zve32x", "zvkb", "zvksed", "zvksh", "zvkt", "zvl32b",
+                ],
+            ),
+            (
+                "zvksc",
+                &[
+                    "zicsr", "zvbc", "zve32x", "zve64x", "zvkb", "zvks", "zvksed", "zvksh", "zvkt",
+                    "zvl32b", "zvl64b",
+                ],
+            ),
+            ("zvksed", &["zicsr", "zve32x", "zvl32b"]),
+            (
+                "zvksg",
+                &[
+                    "zicsr", "zve32x", "zvkb", "zvkg", "zvks", "zvksed", "zvksh", "zvkt", "zvl32b",
+                ],
+            ),
+            ("zvksh", &["zicsr", "zve32x", "zvl32b"]),
+            (
+                "zvl1024b",
+                &["zvl128b", "zvl256b", "zvl32b", "zvl512b", "zvl64b"],
+            ),
+            ("zvl128b", &["zvl32b", "zvl64b"]),
+            (
+                "zvl16384b",
+                &[
+                    "zvl1024b", "zvl128b", "zvl2048b", "zvl256b", "zvl32b", "zvl4096b", "zvl512b",
+                    "zvl64b", "zvl8192b",
+                ],
+            ),
+            (
+                "zvl2048b",
+                &[
+                    "zvl1024b", "zvl128b", "zvl256b", "zvl32b", "zvl512b", "zvl64b",
+                ],
+            ),
+            ("zvl256b", &["zvl128b", "zvl32b", "zvl64b"]),
+            (
+                "zvl32768b",
+                &[
+                    "zvl1024b",
+                    "zvl128b",
+                    "zvl16384b",
+                    "zvl2048b",
+                    "zvl256b",
+                    "zvl32b",
+                    "zvl4096b",
+                    "zvl512b",
+                    "zvl64b",
+                    "zvl8192b",
+                ],
+            ),
+            (
+                "zvl4096b",
+                &[
+                    "zvl1024b", "zvl128b", "zvl2048b", "zvl256b", "zvl32b", "zvl512b", "zvl64b",
+                ],
+            ),
+            ("zvl512b", &["zvl128b", "zvl256b", "zvl32b", "zvl64b"]),
+            ("zvl64b", &["zvl32b"]),
+            (
+                "zvl65536b",
+                &[
+                    "zvl1024b",
+                    "zvl128b",
+                    "zvl16384b",
+                    "zvl2048b",
+                    "zvl256b",
+                    "zvl32768b",
+                    "zvl32b",
+                    "zvl4096b",
+                    "zvl512b",
+                    "zvl64b",
+                    "zvl8192b",
+                ],
+            ),
+            (
+                "zvl8192b",
+                &[
+                    "zvl1024b", "zvl128b", "zvl2048b", "zvl256b", "zvl32b", "zvl4096b", "zvl512b",
+                    "zvl64b",
+                ],
+            ),
+        ],
+    ),
+    (
+        "s390x",
+        &[
+            (
+                "message-security-assist-extension8",
+                &["message-security-assist-extension3"],
+            ),
+            (
+                "message-security-assist-extension9",
+                &[
+                    "message-security-assist-extension3",
+                    "message-security-assist-extension4",
+                ],
+            ),
+            ("nnp-assist", &["vector"]),
+            ("vector-enhancements-1", &["vector"]),
+            (
+                "vector-enhancements-2",
+                &["vector", "vector-enhancements-1"],
+            ),
+            (
+                "vector-enhancements-3",
+                &["vector", "vector-enhancements-1", "vector-enhancements-2"],
+            ),
+            ("vector-packed-decimal", &["vector"]),
+            (
+                "vector-packed-decimal-enhancement",
+                &["vector", "vector-packed-decimal"],
+            ),
+            (
+                "vector-packed-decimal-enhancement-2",
+                &[
+                    "vector",
+                    "vector-packed-decimal",
+                    "vector-packed-decimal-enhancement",
+                ],
+            ),
+            (
+                "vector-packed-decimal-enhancement-3",
+                &[
+                    "vector",
+                    "vector-packed-decimal",
+                    "vector-packed-decimal-enhancement",
+                    "vector-packed-decimal-enhancement-2",
+                ],
+            ),
+        ],
+    ),
+    (
+        "wasm32",
+        &[("gc", &["reference-types"]), ("relaxed-simd", &["simd128"])],
+    ),
+    ("wasm64", &[("relaxed-simd", &["simd128"])]),
+    (
+        "x86",
+        &[
+            ("aes", &["sse", "sse2"]),
+            ("amx-avx512", &["amx-tile"]),
+            ("amx-bf16", &["amx-tile"]),
+            ("amx-complex", &["amx-tile"]),
+            ("amx-fp16", &["amx-tile"]),
+            ("amx-fp8", &["amx-tile"]),
+            ("amx-int8", &["amx-tile"]),
+            ("amx-movrs", &["amx-tile"]),
+            ("amx-tf32", &["amx-tile"]),
+            ("avx", &["sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3"]),
+            (
+                "avx10.1",
+                &[
+                    "avx",
+                    "avx2",
+                    "avx512bf16",
+                    "avx512bitalg",
+                    "avx512bw",
+                    "avx512cd",
+                    "avx512dq",
+                    "avx512f",
+                    "avx512fp16",
+                    "avx512ifma",
+                    "avx512vbmi",
+                    "avx512vbmi2",
+                    "avx512vl",
+                    "avx512vnni",
+                    "avx512vpopcntdq",
+                    "f16c",
+                    "fma",
+                    "sse",
+                    "sse2",
+                    "sse3",
+                    "sse4.1",
+                    "sse4.2",
+                    "ssse3",
+                ],
+            ),
+            (
+                "avx10.2",
+                &[
+                    "avx",
+                    "avx10.1",
+                    "avx2",
+                    "avx512bf16",
+                    "avx512bitalg",
+                    "avx512bw",
+                    "avx512cd",
+                    "avx512dq",
+                    "avx512f",
+                    "avx512fp16",
+                    "avx512ifma",
+                    "avx512vbmi",
+                    "avx512vbmi2",
+                    "avx512vl",
+                    "avx512vnni",
+                    "avx512vpopcntdq",
+                    "f16c",
+                    "fma",
+                    "sse",
+                    "sse2",
+                    "sse3",
+                    "sse4.1",
+                    "sse4.2",
+                    "ssse3",
+                ],
+            ),
+            (
+                "avx2",
+                &["avx", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3"],
+            ),
+            (
+                "avx512bf16",
+                &[
+                    "avx", "avx2", "avx512bw", "avx512f", "f16c", "fma", "sse", "sse2", "sse3",
+                    "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512bitalg",
+                &[
+                    "avx", "avx2", "avx512bw", "avx512f", "f16c", "fma", "sse", "sse2", "sse3",
+                    "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512bw",
+                &[
+                    "avx", "avx2", "avx512f", "f16c", "fma", "sse", "sse2", "sse3", "sse4.1",
+                    "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512cd",
+                &[
+                    "avx", "avx2", "avx512f", "f16c", "fma", "sse", "sse2", "sse3", "sse4.1",
+                    "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512dq",
+                &[
+                    "avx", "avx2", "avx512f", "f16c", "fma", "sse", "sse2", "sse3", "sse4.1",
+                    "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512f",
+                &[
+                    "avx", "avx2", "f16c", "fma", "sse", "sse2", "sse3", "sse4.1", "sse4.2",
+                    "ssse3",
+                ],
+            ),
+            (
+                "avx512fp16",
+                &[
+                    "avx", "avx2", "avx512bw", "avx512f", "f16c", "fma", "sse", "sse2", "sse3",
+                    "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512ifma",
+                &[
+                    "avx", "avx2", "avx512f", "f16c", "fma", "sse", "sse2", "sse3", "sse4.1",
+                    "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512vbmi",
+                &[
+                    "avx", "avx2", "avx512bw", "avx512f", "f16c", "fma", "sse", "sse2", "sse3",
+                    "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512vbmi2",
+                &[
+                    "avx", "avx2", "avx512bw", "avx512f", "f16c", "fma", "sse", "sse2", "sse3",
+                    "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512vl",
+                &[
+                    "avx", "avx2", "avx512f", "f16c", "fma", "sse", "sse2", "sse3", "sse4.1",
+                    "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512vnni",
+                &[
+                    "avx", "avx2", "avx512f", "f16c", "fma", "sse", "sse2", "sse3", "sse4.1",
+                    "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512vp2intersect",
+                &[
+                    "avx", "avx2", "avx512f", "f16c", "fma", "sse", "sse2", "sse3", "sse4.1",
+                    "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512vpopcntdq",
+                &[
+                    "avx", "avx2", "avx512f", "f16c", "fma", "sse", "sse2", "sse3", "sse4.1",
+                    "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avxifma",
+                &[
+                    "avx", "avx2", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avxneconvert",
+                &[
+                    "avx", "avx2", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avxvnni",
+                &[
+                    "avx", "avx2", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avxvnniint16",
+                &[
+                    "avx", "avx2", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avxvnniint8",
+                &[
+                    "avx", "avx2", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "f16c",
+                &["avx", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3"],
+            ),
+            (
+                "fma",
+                &["avx", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3"],
+            ),
+            ("gfni", &["sse", "sse2"]),
+            ("kl", &["sse", "sse2"]),
+            ("pclmulqdq", &["sse", "sse2"]),
+            ("sha", &["sse", "sse2"]),
+            (
+                "sha512",
+                &[
+                    "avx", "avx2", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "sm3",
+                &["avx", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3"],
+            ),
+            (
+                "sm4",
+                &[
+                    "avx", "avx2", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            ("sse2", &["sse"]),
+            ("sse3", &["sse", "sse2"]),
+            ("sse4.1", &["sse", "sse2", "sse3", "ssse3"]),
+            ("sse4.2", &["sse", "sse2", "sse3", "sse4.1", "ssse3"]),
+            ("sse4a", &["sse", "sse2", "sse3"]),
+            ("ssse3", &["sse", "sse2", "sse3"]),
+            (
+                "vaes",
+                &[
+                    "aes", "avx", "avx2", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "vpclmulqdq",
+                &[
+                    "avx",
+                    "pclmulqdq",
+                    "sse",
+                    "sse2",
+                    "sse3",
+                    "sse4.1",
+                    "sse4.2",
+                    "ssse3",
+                ],
+            ),
+            ("widekl", &["kl", "sse", "sse2"]),
+            (
+                "xop",
+                &[
+                    "avx", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "sse4a", "ssse3",
+                ],
+            ),
+            ("xsavec", &["xsave"]),
+            ("xsaveopt", &["xsave"]),
+            ("xsaves", &["xsave"]),
+        ],
+    ),
+    (
+        "x86_64",
+        &[
+            ("aes", &["sse", "sse2"]),
+            ("amx-avx512", &["amx-tile"]),
+            ("amx-bf16", &["amx-tile"]),
+            ("amx-complex", &["amx-tile"]),
+            ("amx-fp16", &["amx-tile"]),
+            ("amx-fp8", &["amx-tile"]),
+            ("amx-int8", &["amx-tile"]),
+            ("amx-movrs", &["amx-tile"]),
+            ("amx-tf32", &["amx-tile"]),
+            ("avx", &["sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3"]),
+            (
+                "avx10.1",
+                &[
+                    "avx",
+                    "avx2",
+                    "avx512bf16",
+                    "avx512bitalg",
+                    "avx512bw",
+                    "avx512cd",
+                    "avx512dq",
+                    "avx512f",
+                    "avx512fp16",
+                    "avx512ifma",
+                    "avx512vbmi",
+                    "avx512vbmi2",
+                    "avx512vl",
+                    "avx512vnni",
+                    "avx512vpopcntdq",
+                    "f16c",
+                    "fma",
+                    "sse",
+                    "sse2",
+                    "sse3",
+                    "sse4.1",
+                    "sse4.2",
+                    "ssse3",
+                ],
+            ),
+            (
+                "avx10.2",
+                &[
+                    "avx",
+                    "avx10.1",
+                    "avx2",
+                    "avx512bf16",
+                    "avx512bitalg",
+                    "avx512bw",
+                    "avx512cd",
+                    "avx512dq",
+                    "avx512f",
+                    "avx512fp16",
+                    "avx512ifma",
+                    "avx512vbmi",
+                    "avx512vbmi2",
+                    "avx512vl",
+                    "avx512vnni",
+                    "avx512vpopcntdq",
+                    "f16c",
+                    "fma",
+                    "sse",
+                    "sse2",
+                    "sse3",
+                    "sse4.1",
+                    "sse4.2",
+                    "ssse3",
+                ],
+            ),
+            (
+                "avx2",
+                &["avx", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3"],
+            ),
+            (
+                "avx512bf16",
+                &[
+                    "avx", "avx2", "avx512bw", "avx512f", "f16c", "fma", "sse", "sse2", "sse3",
+                    "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512bitalg",
+                &[
+                    "avx", "avx2", "avx512bw", "avx512f", "f16c", "fma", "sse", "sse2", "sse3",
+                    "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512bw",
+                &[
+                    "avx", "avx2", "avx512f", "f16c", "fma", "sse", "sse2", "sse3", "sse4.1",
+                    "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512cd",
+                &[
+                    "avx", "avx2", "avx512f", "f16c", "fma", "sse", "sse2", "sse3", "sse4.1",
+                    "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512dq",
+                &[
+                    "avx", "avx2", "avx512f", "f16c", "fma", "sse", "sse2", "sse3", "sse4.1",
+                    "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512f",
+                &[
+                    "avx", "avx2", "f16c", "fma", "sse", "sse2", "sse3", "sse4.1", "sse4.2",
+                    "ssse3",
+                ],
+            ),
+            (
+                "avx512fp16",
+                &[
+                    "avx", "avx2", "avx512bw", "avx512f", "f16c", "fma", "sse", "sse2", "sse3",
+                    "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512ifma",
+                &[
+                    "avx", "avx2", "avx512f", "f16c", "fma", "sse", "sse2", "sse3", "sse4.1",
+                    "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512vbmi",
+                &[
+                    "avx", "avx2", "avx512bw", "avx512f", "f16c", "fma", "sse", "sse2", "sse3",
+                    "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512vbmi2",
+                &[
+                    "avx", "avx2", "avx512bw", "avx512f", "f16c", "fma", "sse", "sse2", "sse3",
+                    "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512vl",
+                &[
+                    "avx", "avx2", "avx512f", "f16c", "fma", "sse", "sse2", "sse3", "sse4.1",
+                    "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512vnni",
+                &[
+                    "avx", "avx2", "avx512f", "f16c", "fma", "sse", "sse2", "sse3", "sse4.1",
+                    "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512vp2intersect",
+                &[
+                    "avx", "avx2", "avx512f", "f16c", "fma", "sse", "sse2", "sse3", "sse4.1",
+                    "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avx512vpopcntdq",
+                &[
+                    "avx", "avx2", "avx512f", "f16c", "fma", "sse", "sse2", "sse3", "sse4.1",
+                    "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avxifma",
+                &[
+                    "avx", "avx2", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avxneconvert",
+                &[
+                    "avx", "avx2", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avxvnni",
+                &[
+                    "avx", "avx2", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avxvnniint16",
+                &[
+                    "avx", "avx2", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "avxvnniint8",
+                &[
+                    "avx", "avx2", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "f16c",
+                &["avx", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3"],
+            ),
+            (
+                "fma",
+                &["avx", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3"],
+            ),
+            ("gfni", &["sse", "sse2"]),
+            ("kl", &["sse", "sse2"]),
+            ("pclmulqdq", &["sse", "sse2"]),
+            ("sha", &["sse", "sse2"]),
+            (
+                "sha512",
+                &[
+                    "avx", "avx2", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "sm3",
+                &["avx", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3"],
+            ),
+            (
+                "sm4",
+                &[
+                    "avx", "avx2", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            ("sse2", &["sse"]),
+            ("sse3", &["sse", "sse2"]),
+            ("sse4.1", &["sse", "sse2", "sse3", "ssse3"]),
+            ("sse4.2", &["sse", "sse2", "sse3", "sse4.1", "ssse3"]),
+            ("sse4a", &["sse", "sse2", "sse3"]),
+            ("ssse3", &["sse", "sse2", "sse3"]),
+            (
+                "vaes",
+                &[
+                    "aes", "avx", "avx2", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "ssse3",
+                ],
+            ),
+            (
+                "vpclmulqdq",
+                &[
+                    "avx",
+                    "pclmulqdq",
+                    "sse",
+                    "sse2",
+                    "sse3",
+                    "sse4.1",
+                    "sse4.2",
+                    "ssse3",
+                ],
+            ),
+            ("widekl", &["kl", "sse", "sse2"]),
+            (
+                "xop",
+                &[
+                    "avx", "sse", "sse2", "sse3", "sse4.1", "sse4.2", "sse4a", "ssse3",
+                ],
+            ),
+            ("xsavec", &["xsave"]),
+            ("xsaveopt", &["xsave"]),
+            ("xsaves", &["xsave"]),
+        ],
+    ),
+];
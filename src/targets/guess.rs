@@ -0,0 +1,200 @@
+use super::*;
+use alloc::{borrow::ToOwned, vec::Vec};
+
+/// Architectures that rustc names differently than its targets that start
+/// with the same characters, eg. `arm64e` is aarch64, not arm
+const ARCH_OVERRIDES: &[(&str, &str)] = &[("arm64", "aarch64"), ("amd64", "x86_64")];
+
+/// Operating systems that are named differently in triples than in
+/// `target_os`, eg. `x86_64-apple-darwin`
+const OS_ALIASES: &[(&str, &str)] = &[
+    ("darwin", "macos"),
+    ("macosx", "macos"),
+    ("wasip1", "wasi"),
+    ("wasip2", "wasi"),
+    ("wasip3", "wasi"),
+];
+
+/// Vendors that are named differently in triples than in `target_vendor`,
+/// eg. `xtensa-esp32-none-elf`
+const VENDOR_ALIASES: &[(&str, &str)] = &[
+    ("esp", "espressif"),
+    ("esp32", "espressif"),
+    ("esp32s2", "espressif"),
+    ("esp32s3", "espressif"),
+];
+
+/// The environments that are combined with an ABI in the last component of
+/// a triple, eg. `gnueabihf`
+const ENVS: &[&str] = &[
+    "uclibc", "newlib", "relibc", "musl", "msvc", "ohos", "gnu", "sgx",
+];
+
+impl TargetInfo {
+    /// Guesses the information of a target from its triple, eg. for custom
+    /// targets that rustc doesn't know, so that expressions can still be
+    /// matched against them without a target spec.
+    ///
+    /// Builtin triples, including [renamed](canonical_triple) ones, return
+    /// the builtin target. Otherwise the triple is decomposed with rustc's
+    /// naming conventions, ie. `<arch>-<vendor>-<os>-<env><abi>`, where the
+    /// vendor or environment can be left out. The architecture is mapped with
+    /// [`Arch::from_sub_arch`], and matched with the builtin targets that
+    /// start with the same architecture, eg. `riscv32imafc` with
+    /// `riscv32imac`, which provide the pointer width, endianness, atomics,
+    /// and panic strategy.
+    /// The families are those of the operating system and architecture, see
+    /// [`Families::for_target`].
+    ///
+    /// Returns `None` if the architecture isn't similar to that of any
+    /// builtin target.
+    ///
+    /// ```
+    /// use cfg_expr::targets::{Arch, Endian, Families, Os, TargetInfo, Vendor};
+    ///
+    /// let ti = TargetInfo::guess_from_triple("riscv32imafc-acme-none-elf").unwrap();
+    /// assert_eq!(ti.arch, Arch::riscv32);
+    /// assert_eq!(ti.vendor, Some(Vendor::new("acme")));
+    /// assert_eq!(ti.os, None);
+    /// assert_eq!(ti.pointer_width, 32);
+    ///
+    /// let ti = TargetInfo::guess_from_triple("armv7-acme-linux-musleabihf").unwrap();
+    /// assert_eq!(ti.os, Some(Os::linux));
+    /// assert_eq!(ti.env.as_ref().map(|env| env.as_str()), Some("musl"));
+    /// assert_eq!(ti.abi.as_ref().map(|abi| abi.as_str()), Some("eabihf"));
+    /// assert_eq!(ti.families, Families::unix);
+    ///
+    /// let ti = TargetInfo::guess_from_triple("powerpc64le-linux-gnu").unwrap();
+    /// assert_eq!(ti.endian, Endian::little);
+    /// assert_eq!(ti.vendor, Some(Vendor::unknown));
+    ///
+    /// assert!(TargetInfo::guess_from_triple("z80-unknown-none").is_none());
+    /// ```
+    pub fn guess_from_triple(triple: &str) -> Option<TargetInfo> {
+        match get_builtin_target_by_triple(triple) {
+            Some(builtin) => Some(builtin.clone()),
+            None => guess(triple),
+        }
+    }
+}
+
+/// Decomposes the triple, without looking it up in the builtin targets
+pub(crate) fn guess(triple: &str) -> Option<TargetInfo> {
+    let mut components = triple.split('-');
+    let arch_component = components.next()?;
+    let rest: Vec<&str> = components.collect();
+
+    // The builtin targets with the longest common architecture prefix
+    let arch_prefix = ARCH_OVERRIDES
+        .iter()
+        .find(|(prefix, _)| arch_component.starts_with(prefix))
+        .map_or(arch_component, |(_, arch)| arch);
+    let similar: Vec<&TargetInfo> = (1..=arch_prefix.len())
+        .rev()
+        .filter(|&len| arch_prefix.is_char_boundary(len))
+        .map(|len| {
+            ALL_BUILTINS
+                .iter()
+                .filter(|ti| first_component(ti) == &arch_prefix[..len])
+                .collect::<Vec<_>>()
+        })
+        .find(|similar| !similar.is_empty())?;
+
+    let is_os = |os: &str| {
+        let os = os_name(os);
+        os == "none"
+            || ALL_BUILTINS
+                .iter()
+                .any(|ti| ti.os.as_ref().map_or(false, |o| o.as_str() == os))
+    };
+
+    // The vendor is left out of some triples, eg. `x86_64-linux-android`
+    let (vendor, rest) = match rest.as_slice() {
+        [os, ..] if rest.len() <= 2 && *os != "unknown" && is_os(os) => ("unknown", &rest[..]),
+        [vendor, rest @ ..] => (*vendor, rest),
+        [] => ("unknown", &rest[..]),
+    };
+
+    let (os, env_abi) = match rest {
+        [] | ["none", ..] => (None, rest.get(1).copied()),
+        // eg. `x86_64-linux-android` or `arm-linux-androideabi`
+        ["linux", android] | [android] if android.starts_with("android") => {
+            (Some("android"), Some(&android[7..]))
+        }
+        // eg. `x86_64-apple-macosx10.12.0` or `wasm32-wasip2`
+        [os, ..] => (Some(os_name(os)), rest.get(1).copied()),
+    };
+
+    let (env, abi) = match env_abi {
+        // Bare metal targets use `elf` for the object format
+        Some("elf" | "") | None => (None, None),
+        Some(env_abi) => match ENVS.iter().find(|env| env_abi.starts_with(*env)) {
+            Some(env) => {
+                let abi = env_abi[env.len()..].trim_start_matches('_');
+                (Some(*env), Some(abi).filter(|abi| !abi.is_empty()))
+            }
+            None => (None, Some(env_abi)),
+        },
+    };
+
+    // The builtin target that is most similar, to fill in the fields that
+    // aren't part of the triple. Targets without an abi are preferred over
+    // ones with a different abi, which can change the layout, eg. `gnux32`
+    let reference = similar
+        .iter()
+        .copied()
+        .max_by_key(|ti| {
+            (
+                ti.os.as_ref().map(Os::as_str) == os,
+                ti.env.as_ref().map(Env::as_str) == env,
+                ti.abi.as_ref().map(Abi::as_str) == abi,
+                ti.abi.is_none(),
+                first_component(ti) == arch_component,
+            )
+        })
+        .unwrap_or(similar[0]);
+
+    let arch = Arch::from_sub_arch(arch_component).unwrap_or_else(|| reference.arch.clone());
+    let families = Families::for_target(os.unwrap_or("none"), arch.as_str());
+
+    let pointer_width = match abi {
+        Some("x32" | "ilp32") => 32,
+        _ if arch_component.starts_with("arm64_32") => 32,
+        _ => reference.pointer_width,
+    };
+
+    Some(TargetInfo {
+        triple: Triple::new(triple.to_owned()),
+        os: os.map(|os| Os::new(os.to_owned())),
+        abi: abi.map(|abi| Abi::new(abi.to_owned())),
+        arch,
+        env: env.map(|env| Env::new(env.to_owned())),
+        vendor: Some(Vendor::new(
+            VENDOR_ALIASES
+                .iter()
+                .find(|(alias, _)| *alias == vendor)
+                .map_or(vendor, |(_, name)| name)
+                .to_owned(),
+        )),
+        families,
+        pointer_width,
+        endian: reference.endian,
+        has_atomics: reference.has_atomics.clone(),
+        has_atomics_load_store: reference.has_atomics_load_store.clone(),
+        has_atomics_equal_alignment: reference.has_atomics_equal_alignment.clone(),
+        panic: reference.panic.clone(),
+    })
+}
+
+fn first_component(ti: &TargetInfo) -> &str {
+    ti.triple.as_str().split('-').next().unwrap_or_default()
+}
+
+/// The name of the os component in `target_os`, without a version
+fn os_name(os: &str) -> &str {
+    let os = OsVersion::split_os(os).0;
+    OS_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == os)
+        .map_or(os, |(_, name)| name)
+}
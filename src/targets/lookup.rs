@@ -0,0 +1,157 @@
+use super::*;
+use crate::expr::levenshtein;
+use alloc::{vec, vec::Vec};
+
+/// The builtin targets that a triple, which might be abbreviated or misspelt,
+/// most likely refers to, see [`lookup_builtin_target`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TargetLookup {
+    /// The target that most likely matches the triple
+    pub best: &'static TargetInfo,
+    /// The other targets that match the triple, from the most to the least
+    /// likely, which is empty if the triple is an exact match
+    pub alternates: Vec<&'static TargetInfo>,
+    /// True if the normalized triple is builtin, or was renamed to a builtin
+    /// triple, see [`get_builtin_target_by_triple`]
+    pub exact: bool,
+}
+
+impl TargetLookup {
+    /// The triples of the best match and the alternates, eg. for a "did you
+    /// mean" message
+    pub fn triples(&self) -> impl Iterator<Item = &'static str> + '_ {
+        core::iter::once(self.best)
+            .chain(self.alternates.iter().copied())
+            .map(|ti| ti.triple.as_str())
+    }
+}
+
+/// Component spellings that are common outside of rustc's target names, eg.
+/// in Docker platforms or CI matrices, and what rustc calls them
+const SYNONYMS: &[(&str, &str)] = &[
+    ("amd64", "x86_64"),
+    ("arm64", "aarch64"),
+    ("macos", "darwin"),
+    ("x64", "x86_64"),
+];
+
+/// Looks up the builtin target for a triple that might not be exactly the
+/// same as the target's triple, eg. in hand written configuration.
+///
+/// The triple is trimmed and lowercased, and then looked up with
+/// [`get_builtin_target_by_triple`]. If that fails, the components of the
+/// triple, ie. the parts separated by `-`, are matched in order against the
+/// components of each builtin triple, so that components like the vendor can
+/// be left out, eg. `x86_64-linux-gnu` matches `x86_64-unknown-linux-gnu`. A
+/// component matches if it is the same, a common alternative spelling, eg.
+/// `amd64` for `x86_64`, or a slight misspelling.
+///
+/// The matches are ordered by the number of misspellings, then the number of
+/// components that were left out, then the [tier](TargetInfo::support) of
+/// the target, so that the most commonly used target is preferred, eg.
+/// `x86_64-windows-msvc` matches `x86_64-pc-windows-msvc` before
+/// `x86_64-uwp-windows-msvc`.
+///
+/// ```
+/// use cfg_expr::targets::lookup_builtin_target;
+///
+/// let lookup = lookup_builtin_target(" X86_64-Unknown-Linux-GNU\n").unwrap();
+/// assert_eq!(lookup.best.triple.as_str(), "x86_64-unknown-linux-gnu");
+/// assert!(lookup.exact);
+///
+/// let lookup = lookup_builtin_target("amd64-linux-gnu").unwrap();
+/// assert_eq!(lookup.best.triple.as_str(), "x86_64-unknown-linux-gnu");
+/// assert!(!lookup.exact);
+///
+/// let lookup = lookup_builtin_target("x86_64-windows-msvc").unwrap();
+/// assert_eq!(lookup.best.triple.as_str(), "x86_64-pc-windows-msvc");
+/// assert_eq!(lookup.alternates[0].triple.as_str(), "x86_64-uwp-windows-msvc");
+///
+/// assert!(lookup_builtin_target("not-a-target").is_none());
+/// ```
+pub fn lookup_builtin_target(triple: &str) -> Option<TargetLookup> {
+    let normalized = triple.trim().to_lowercase();
+
+    if let Some(best) = get_builtin_target_by_triple(&normalized) {
+        return Some(TargetLookup {
+            best,
+            alternates: Vec::new(),
+            exact: true,
+        });
+    }
+
+    let components: Vec<&str> = normalized.split('-').collect();
+
+    let mut matches: Vec<_> = ALL_BUILTINS
+        .iter()
+        .filter_map(|ti| {
+            let target: Vec<&str> = ti.triple.as_str().split('-').collect();
+            let typos = match_components(&components, &target)?;
+            let tier = ti.support().map_or(u8::MAX, |support| support.tier);
+
+            Some(((typos, target.len() - components.len(), tier), ti))
+        })
+        .collect();
+
+    // The sort is stable, so ties are in the order of the triples
+    matches.sort_by_key(|(rank, _)| *rank);
+
+    let mut matches = matches.into_iter().map(|(_, ti)| ti);
+    let best = matches.next()?;
+
+    Some(TargetLookup {
+        best,
+        alternates: matches.collect(),
+        exact: false,
+    })
+}
+
+/// Matches every component of the input, in order, to a component of the
+/// target, returning the fewest total edits the input components need to
+/// match, or `None` if they can't all be matched
+fn match_components(input: &[&str], target: &[&str]) -> Option<usize> {
+    if input.len() > target.len() {
+        return None;
+    }
+
+    // The fewest edits to match the input components so far to the first `j`
+    // target components, skipping the target components that don't match
+    let mut row: Vec<Option<usize>> = vec![Some(0); target.len() + 1];
+
+    for inp in input {
+        let mut next = vec![None; target.len() + 1];
+
+        for (j, targ) in target.iter().enumerate() {
+            let matched = row[j].and_then(|prev| Some(prev + edits(inp, targ)?));
+            let skipped = next[j];
+
+            next[j + 1] = match (matched, skipped) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            };
+        }
+
+        row = next;
+    }
+
+    row[target.len()]
+}
+
+/// The number of edits for the input component to match the target
+/// component, with the same limit as suggestions for misspelt keys
+fn edits(input: &str, target: &str) -> Option<usize> {
+    if input == target
+        || SYNONYMS
+            .iter()
+            .any(|(syn, name)| *syn == input && *name == target)
+    {
+        return Some(0);
+    }
+
+    if input.chars().next() != target.chars().next() {
+        return None;
+    }
+
+    let dist = levenshtein(input, target);
+    (dist <= (target.len() / 4).max(1)).then(|| dist)
+}
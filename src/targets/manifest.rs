@@ -0,0 +1,130 @@
+use crate::{
+    error::{ParseError, Reason},
+    expr::Platform,
+};
+use alloc::{borrow::ToOwned, string::String};
+use core::ops::Range;
+
+/// Parses the `<platform>` key of a `[target.<platform>]` table in a
+/// `Cargo.toml`, as it is written in the manifest, ie. either a bare key,
+/// which can only be a triple, eg. `x86_64-unknown-linux-gnu`, or a quoted
+/// key, which is unquoted with TOML's rules before it is parsed with
+/// [`Platform::parse`], eg. `'cfg(unix)'` or `"cfg(target_os = \"linux\")"`.
+///
+/// Errors in the quoting point at the key, and errors in the platform point
+/// at the unquoted platform.
+///
+/// ```
+/// use cfg_expr::{expr::Platform, targets::parse_cargo_target_key, Expression};
+///
+/// assert_eq!(
+///     parse_cargo_target_key("x86_64-unknown-linux-gnu").unwrap(),
+///     Platform::Triple("x86_64-unknown-linux-gnu".to_owned())
+/// );
+/// assert_eq!(
+///     parse_cargo_target_key(r#"'cfg(target_os = "linux")'"#).unwrap(),
+///     parse_cargo_target_key(r#""cfg(target_os = \"linux\")""#).unwrap(),
+/// );
+///
+/// // Parentheses aren't allowed in bare keys
+/// assert!(parse_cargo_target_key("cfg(unix)").is_err());
+/// ```
+pub fn parse_cargo_target_key(key: &str) -> Result<Platform, ParseError> {
+    let error = |start: usize, end: usize, reason| ParseError {
+        original: key.to_owned(),
+        span: start..end,
+        reason,
+    };
+
+    let trimmed = key.trim_start_matches([' ', '\t']);
+    let start = key.len() - trimmed.len();
+    let trimmed = trimmed.trim_end_matches([' ', '\t']);
+    let end = start + trimmed.len();
+
+    let (platform, rest) = match trimmed.chars().next() {
+        None => return Err(error(0, key.len(), Reason::Empty)),
+        Some('\'') => {
+            let close = trimmed[1..]
+                .find(['\'', '\n'])
+                .filter(|&i| trimmed[1 + i..].starts_with('\''))
+                .ok_or_else(|| error(start, start + 1, Reason::UnclosedQuotes))?;
+
+            (trimmed[1..1 + close].to_owned(), 2 + close)
+        }
+        Some('"') => unescape(&trimmed[1..]).map_err(|invalid| match invalid {
+            Some(escape) => error(
+                start + 1 + escape.start,
+                start + 1 + escape.end,
+                Reason::InvalidCharacters,
+            ),
+            None => error(start, start + 1, Reason::UnclosedQuotes),
+        })?,
+        Some(_) => {
+            if let Some((i, c)) = trimmed
+                .char_indices()
+                .find(|(_, c)| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_')))
+            {
+                return Err(error(
+                    start + i,
+                    start + i + c.len_utf8(),
+                    Reason::InvalidCharacters,
+                ));
+            }
+
+            (trimmed.to_owned(), trimmed.len())
+        }
+    };
+
+    if rest < trimmed.len() {
+        return Err(error(start + rest, end, Reason::InvalidCharacters));
+    }
+
+    Platform::parse(&platform)
+}
+
+/// Unescapes the contents of a TOML basic string, up to its closing quote,
+/// returning it and the length of the string including both quotes, or the
+/// span of an invalid escape, or `None` if the string isn't closed
+fn unescape(s: &str) -> Result<(String, usize), Option<Range<usize>>> {
+    let mut out = String::new();
+    let mut chars = s.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Ok((out, i + 2)),
+            '\n' => break,
+            '\\' => {
+                let escaped = match chars.next().map(|(_, c)| c) {
+                    Some('b') => '\u{8}',
+                    Some('t') => '\t',
+                    Some('n') => '\n',
+                    Some('f') => '\u{c}',
+                    Some('r') => '\r',
+                    Some('"') => '"',
+                    Some('\\') => '\\',
+                    Some(u @ ('u' | 'U')) => {
+                        let len = if u == 'u' { 4 } else { 8 };
+                        s.get(i + 2..i + 2 + len)
+                            .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+                            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                            .and_then(char::from_u32)
+                            .map(|c| {
+                                for _ in 0..len {
+                                    chars.next();
+                                }
+                                c
+                            })
+                            .ok_or(Some(i..i + 2))?
+                    }
+                    Some(c) => return Err(Some(i..i + 1 + c.len_utf8())),
+                    None => break,
+                };
+
+                out.push(escaped);
+            }
+            c => out.push(c),
+        }
+    }
+
+    Err(None)
+}
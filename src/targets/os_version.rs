@@ -0,0 +1,78 @@
+/// The version of the operating system that is encoded in some triples, eg.
+/// the deployment target in `x86_64-apple-macosx10.12.0` or
+/// `arm64-apple-ios17.0`, see [`TargetMatcher::os_version`](crate::expr::TargetMatcher::os_version)
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OsVersion {
+    /// The major version
+    pub major: u16,
+    /// The minor version
+    pub minor: u16,
+    /// The patch version, which is 0 if it isn't specified
+    pub patch: u16,
+}
+
+impl OsVersion {
+    /// Splits the operating system component of a triple into the name of
+    /// the operating system and its version, if it has one.
+    ///
+    /// The version needs at least a major and a minor version, so that
+    /// components that end with a number, eg. `solid_asp3`, aren't split.
+    ///
+    /// ```
+    /// use cfg_expr::targets::OsVersion;
+    ///
+    /// assert_eq!(
+    ///     OsVersion::split_os("macosx10.12.0"),
+    ///     ("macosx", Some(OsVersion { major: 10, minor: 12, patch: 0 })),
+    /// );
+    /// assert_eq!(
+    ///     OsVersion::split_os("ios17.2"),
+    ///     ("ios", Some(OsVersion { major: 17, minor: 2, patch: 0 })),
+    /// );
+    /// assert_eq!(OsVersion::split_os("solid_asp3"), ("solid_asp3", None));
+    /// assert_eq!(OsVersion::split_os("linux"), ("linux", None));
+    /// ```
+    pub fn split_os(os: &str) -> (&str, Option<OsVersion>) {
+        let start = os.find(|c: char| c.is_ascii_digit()).unwrap_or(os.len());
+        let (name, version) = os.split_at(start);
+
+        if name.is_empty() {
+            return (os, None);
+        }
+
+        let mut parts = version.split('.').map(str::parse::<u16>);
+        let version = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(Ok(major)), Some(Ok(minor)), patch, None) => match patch {
+                None => Some(OsVersion {
+                    major,
+                    minor,
+                    patch: 0,
+                }),
+                Some(Ok(patch)) => Some(OsVersion {
+                    major,
+                    minor,
+                    patch,
+                }),
+                Some(Err(_)) => None,
+            },
+            _ => None,
+        };
+
+        match version {
+            Some(version) => (name, Some(version)),
+            None => (os, None),
+        }
+    }
+}
+
+impl core::fmt::Display for OsVersion {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// The version of the operating system component of a triple, ie. the third
+/// component, eg. `aarch64-apple-ios17.0`
+pub(crate) fn from_triple(triple: &str) -> Option<OsVersion> {
+    OsVersion::split_os(triple.split('-').nth(2)?).1
+}
@@ -0,0 +1,130 @@
+use super::{TargetInfo, Triple, ALL_BUILTINS};
+use alloc::{borrow::ToOwned, collections::BTreeMap};
+
+/// A set of targets made up of the [builtin](ALL_BUILTINS) targets, and
+/// custom targets that extend them, or override builtin targets with the
+/// same triple.
+///
+/// ```
+/// use cfg_expr::targets::{Arch, TargetInfo, TargetRegistry};
+///
+/// let mut registry = TargetRegistry::new();
+///
+/// let mut custom = registry.get_target_by_triple("x86_64-unknown-none").unwrap().clone();
+/// custom.triple = cfg_expr::targets::Triple::new("x86_64-acme-kernel");
+/// registry.register(custom);
+///
+/// assert_eq!(
+///     registry.get_target_by_triple("x86_64-acme-kernel").unwrap().arch,
+///     Arch::x86_64
+/// );
+/// assert!(registry.get_target_by_triple("x86_64-unknown-linux-gnu").is_some());
+/// assert_eq!(registry.iter().count(), cfg_expr::targets::ALL_BUILTINS.len() + 1);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TargetRegistry {
+    builtins: &'static [TargetInfo],
+    custom: BTreeMap<Triple, TargetInfo>,
+}
+
+impl Default for TargetRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TargetRegistry {
+    /// Creates a registry containing the builtin targets
+    pub fn new() -> Self {
+        Self::with_builtins(ALL_BUILTINS)
+    }
+
+    /// Creates a registry without any targets
+    pub fn empty() -> Self {
+        Self::with_builtins(&[])
+    }
+
+    /// Creates a registry containing the specified builtin targets, which
+    /// must be sorted by their triple
+    pub fn with_builtins(builtins: &'static [TargetInfo]) -> Self {
+        Self {
+            builtins,
+            custom: BTreeMap::new(),
+        }
+    }
+
+    /// Adds a custom target, overriding any builtin target with the same
+    /// triple. Returns the custom target that was previously registered with
+    /// the same triple, if any.
+    pub fn register(&mut self, target: TargetInfo) -> Option<TargetInfo> {
+        self.custom.insert(target.triple.clone(), target)
+    }
+
+    /// Removes the custom target with the specified triple, which restores
+    /// the builtin target with the same triple, if there is one
+    pub fn unregister(&mut self, triple: &str) -> Option<TargetInfo> {
+        self.custom.remove(&Triple::new(triple.to_owned()))
+    }
+
+    /// Returns true if the target with the specified triple is a custom
+    /// target rather than a builtin
+    pub fn is_custom(&self, triple: &str) -> bool {
+        self.custom.contains_key(&Triple::new(triple.to_owned()))
+    }
+
+    /// Attempts to find the `TargetInfo` for the specified target triple,
+    /// preferring custom targets to builtin ones
+    pub fn get_target_by_triple(&self, triple: &str) -> Option<&TargetInfo> {
+        let triple = Triple::new(triple.to_owned());
+        self.custom.get(&triple).or_else(|| self.builtin(&triple))
+    }
+
+    /// The number of targets in the registry
+    pub fn len(&self) -> usize {
+        self.builtins.len()
+            + self
+                .custom
+                .keys()
+                .filter(|triple| self.builtin(triple).is_none())
+                .count()
+    }
+
+    /// Returns true if the registry doesn't contain any targets
+    pub fn is_empty(&self) -> bool {
+        self.builtins.is_empty() && self.custom.is_empty()
+    }
+
+    fn builtin(&self, triple: &Triple) -> Option<&TargetInfo> {
+        self.builtins
+            .binary_search_by(|ti| ti.triple.cmp(triple))
+            .map(|i| &self.builtins[i])
+            .ok()
+    }
+
+    /// Iterates over all of the targets, in the order of their triples
+    pub fn iter(&self) -> impl Iterator<Item = &TargetInfo> + '_ {
+        let mut builtins = self.builtins.iter().peekable();
+        let mut custom = self.custom.values().peekable();
+
+        core::iter::from_fn(move || match (builtins.peek(), custom.peek()) {
+            (Some(builtin), Some(custom_ti)) => match builtin.triple.cmp(&custom_ti.triple) {
+                core::cmp::Ordering::Less => builtins.next(),
+                core::cmp::Ordering::Equal => {
+                    builtins.next();
+                    custom.next()
+                }
+                core::cmp::Ordering::Greater => custom.next(),
+            },
+            (Some(_), None) => builtins.next(),
+            (None, _) => custom.next(),
+        })
+    }
+}
+
+impl Extend<TargetInfo> for TargetRegistry {
+    fn extend<I: IntoIterator<Item = TargetInfo>>(&mut self, iter: I) {
+        for target in iter {
+            self.register(target);
+        }
+    }
+}
@@ -0,0 +1,1089 @@
+use super::*;
+use crate::error::RustcError;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ffi::OsString,
+    fmt::Write,
+    path::PathBuf,
+    process::{Command, Stdio},
+};
+
+/// An installed rustc that can be queried for the targets it supports, and
+/// the cfgs that are set for each of them.
+///
+/// ```no_run
+/// use cfg_expr::targets::rustc::Rustc;
+///
+/// let rustc = Rustc::new("rustc");
+/// let target = rustc.target_info("x86_64-unknown-linux-gnu").unwrap();
+/// assert_eq!(target.arch.as_str(), "x86_64");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Rustc {
+    path: PathBuf,
+    envs: Vec<(OsString, OsString)>,
+}
+
+impl Rustc {
+    /// Creates a new instance for the rustc at the specified path, which is
+    /// looked up in `PATH` if it is only a file name
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            envs: Vec::new(),
+        }
+    }
+
+    /// Creates a new instance for the rustc specified by the `RUSTC`
+    /// environment variable, as set by cargo for build scripts, falling back
+    /// to the `rustc` in `PATH`
+    pub fn from_env() -> Self {
+        Self::new(std::env::var_os("RUSTC").unwrap_or_else(|| "rustc".into()))
+    }
+
+    /// Sets an environment variable for every invocation of rustc
+    pub fn env(mut self, key: impl Into<OsString>, val: impl Into<OsString>) -> Self {
+        self.envs.push((key.into(), val.into()));
+        self
+    }
+
+    fn run(&self, args: &[&str], unstable: bool) -> Result<String, RustcError> {
+        let command = format!("{} {}", self.path.display(), args.join(" "));
+
+        let mut cmd = Command::new(&self.path);
+        cmd.envs(self.envs.iter().map(|(k, v)| (k, v)))
+            .stdin(Stdio::null());
+
+        // The atomic load/store and alignment cfgs are unstable, and so are
+        // only printed with unstable options
+        if unstable {
+            cmd.env("RUSTC_BOOTSTRAP", "1")
+                .args(["-Z", "unstable-options"]);
+        }
+
+        let output = cmd.args(args).output().map_err(|error| RustcError::Spawn {
+            command: command.clone(),
+            error,
+        })?;
+
+        if !output.status.success() {
+            return Err(RustcError::Failed {
+                command,
+                status: output.status,
+                stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+            });
+        }
+
+        String::from_utf8(output.stdout).map_err(|err| RustcError::InvalidOutput {
+            command,
+            reason: err.to_string(),
+        })
+    }
+
+    /// Retrieves the version of rustc, eg. `1.72.0`
+    pub fn version(&self) -> Result<String, RustcError> {
+        let output = self.run(&["--version"], false)?;
+
+        // rustc 1.72.0 (5680fa18f 2023-08-23)
+        output
+            .split(' ')
+            .nth(1)
+            .map(|version| version.trim().to_owned())
+            .ok_or_else(|| RustcError::InvalidOutput {
+                command: format!("{} --version", self.path.display()),
+                reason: format!("expected `rustc <version>`, found {output:?}"),
+            })
+    }
+
+    /// Retrieves the triples of all of the targets that rustc supports
+    pub fn target_list(&self) -> Result<Vec<String>, RustcError> {
+        Ok(self
+            .run(&["--print", "target-list"], false)?
+            .lines()
+            .map(|line| line.trim().to_owned())
+            .filter(|line| !line.is_empty())
+            .collect())
+    }
+
+    /// Retrieves the information for the specified target, and the target
+    /// features that are enabled for it by default, from the cfgs that rustc
+    /// sets for it
+    fn print_cfg(&self, triple: &str) -> Result<(TargetInfo, Vec<String>), RustcError> {
+        let args = ["--print", "cfg", "--target", triple];
+        let cfg = self.run(&args, true)?;
+
+        parse_cfg(triple, &cfg).map_err(|reason| RustcError::InvalidOutput {
+            command: format!("{} {}", self.path.display(), args.join(" ")),
+            reason,
+        })
+    }
+
+    /// Retrieves the information for the specified target from the cfgs
+    /// that rustc sets for it
+    pub fn target_info(&self, triple: &str) -> Result<TargetInfo, RustcError> {
+        self.print_cfg(triple).map(|(ti, _)| ti)
+    }
+
+    /// Retrieves the target features that rustc enables by default for the
+    /// specified target, eg. `sse2` for `x86_64-unknown-linux-gnu`
+    pub fn default_features(&self, triple: &str) -> Result<Vec<String>, RustcError> {
+        self.print_cfg(triple).map(|(_, features)| features)
+    }
+
+    /// Retrieves the target features that rustc supports for the specified
+    /// target, ie. the values that `target_feature` can have
+    pub fn target_features(&self, triple: &str) -> Result<Vec<String>, RustcError> {
+        let output = self.run(&["--print", "target-features", "--target", triple], false)?;
+
+        // The features supported by rustc come first, followed by the
+        // features that are only supported by LLVM after a blank line
+        Ok(output
+            .lines()
+            .skip_while(|line| !line.starts_with("Features supported by rustc"))
+            .skip(1)
+            .take_while(|line| !line.trim().is_empty())
+            .filter_map(|line| line.split_whitespace().next())
+            .map(str::to_owned)
+            .collect())
+    }
+
+    /// Retrieves the target features that are implied by enabling the
+    /// specified feature for the target, not including the feature itself,
+    /// or the features that are already enabled by default
+    pub fn implied_features(&self, triple: &str, feature: &str) -> Result<Vec<String>, RustcError> {
+        let (_, defaults) = self.print_cfg(triple)?;
+        self.implied_features_with(triple, feature, &defaults)
+    }
+
+    fn implied_features_with(
+        &self,
+        triple: &str,
+        feature: &str,
+        defaults: &[String],
+    ) -> Result<Vec<String>, RustcError> {
+        let enable = format!("target-feature=+{feature}");
+        let args = ["--print", "cfg", "--target", triple, "-C", &enable];
+        let cfg = self.run(&args, true)?;
+
+        let (_, features) =
+            parse_cfg(triple, &cfg).map_err(|reason| RustcError::InvalidOutput {
+                command: format!("{} {}", self.path.display(), args.join(" ")),
+                reason,
+            })?;
+
+        Ok(features
+            .into_iter()
+            .filter(|f| f != feature && !defaults.contains(f))
+            .collect())
+    }
+
+    /// Retrieves how well rustc supports the specified target, from the
+    /// metadata of its target spec, or `None` if the tier isn't documented,
+    /// eg. before rustc 1.74, which didn't have the metadata
+    pub fn support(&self, triple: &str) -> Result<Option<PlatformSupport>, RustcError> {
+        let args = ["--print", "target-spec-json", "--target", triple];
+        let spec = self.run(&args, true)?;
+
+        parse_support(&spec).map_err(|reason| RustcError::InvalidOutput {
+            command: format!("{} {}", self.path.display(), args.join(" ")),
+            reason,
+        })
+    }
+
+    /// Compares the targets, eg. the [builtin](ALL_BUILTINS) targets or a
+    /// [`TargetDb`](crate::targets::TargetDb), with the targets that rustc
+    /// supports, and the cfgs it sets for each of them, see
+    /// [`VerifyReport`]
+    pub fn verify(&self, targets: &[TargetInfo]) -> Result<VerifyReport, RustcError> {
+        let mut report = VerifyReport {
+            rustc_version: self.version()?,
+            missing: Vec::new(),
+            extra: Vec::new(),
+            mismatched: Vec::new(),
+        };
+
+        let triples = self.target_list()?;
+        for triple in &triples {
+            let expected =
+                if let Some(expected) = targets.iter().find(|ti| ti.triple.as_str() == triple) {
+                    expected
+                } else {
+                    report.missing.push(triple.clone());
+                    continue;
+                };
+
+            // The cfgs of targets that rustc can't print them for in the
+            // current environment, see `from_rustc`, aren't compared
+            let actual = match self.target_info(triple) {
+                Ok(actual) => actual,
+                Err(RustcError::Failed { .. }) => continue,
+                Err(err) => return Err(err),
+            };
+            for ((key, expected), (_, actual)) in
+                cfg_values(expected).into_iter().zip(cfg_values(&actual))
+            {
+                if expected != actual {
+                    report.mismatched.push(CfgMismatch {
+                        triple: triple.clone(),
+                        key,
+                        expected,
+                        actual,
+                    });
+                }
+            }
+        }
+
+        report.extra = targets
+            .iter()
+            .map(|ti| ti.triple.as_str())
+            .filter(|triple| !triples.iter().any(|t| t == triple))
+            .map(ToOwned::to_owned)
+            .collect();
+
+        Ok(report)
+    }
+
+    /// Retrieves the version, and the information for every target, of rustc
+    pub fn database(&self) -> Result<Database, RustcError> {
+        let rustc_version = self.version()?;
+
+        let mut targets = Vec::new();
+        let mut default_features = BTreeMap::new();
+        let mut support = BTreeMap::new();
+        for triple in self.target_list()? {
+            let (ti, features) = self.print_cfg(&triple)?;
+            targets.push(ti);
+            if let Some(sup) = self.support(&triple)? {
+                support.insert(triple.clone(), sup);
+            }
+            default_features.insert(triple, features);
+        }
+
+        // The implications are retrieved from the target of each arch with
+        // the fewest default features, as the features that are enabled by
+        // default are already enabled regardless of the implications
+        let mut baselines = BTreeMap::<&str, &str>::new();
+        for ti in &targets {
+            let triple = ti.triple.as_str();
+            let baseline = baselines.entry(ti.arch.as_str()).or_insert(triple);
+            if default_features[triple].len() < default_features[*baseline].len() {
+                *baseline = triple;
+            }
+        }
+
+        let mut implied_features = BTreeMap::new();
+        for (arch, triple) in baselines {
+            let defaults = &default_features[triple];
+            let mut implied = BTreeMap::new();
+
+            for feature in self.target_features(triple)? {
+                // Some features can't be enabled for every target
+                match self.implied_features_with(triple, &feature, defaults) {
+                    Ok(features) if !features.is_empty() => {
+                        implied.insert(feature, features);
+                    }
+                    Ok(_) | Err(RustcError::Failed { .. }) => {}
+                    Err(err) => return Err(err),
+                }
+            }
+
+            if !implied.is_empty() {
+                implied_features.insert(arch.to_owned(), implied);
+            }
+        }
+
+        Ok(Database {
+            rustc_version,
+            targets,
+            default_features,
+            implied_features,
+            support,
+        })
+    }
+}
+
+/// Parses the `key="value"` and bare flag lines printed by `--print cfg`
+fn parse_cfg(triple: &str, cfg: &str) -> Result<(TargetInfo, Vec<String>), String> {
+    let mut abi = None;
+    let mut arch = None;
+    let mut endian = None;
+    let mut env = None;
+    let mut families = Vec::new();
+    let mut os = None;
+    let mut pointer_width = None;
+    let mut vendor = None;
+    let mut panic = None;
+    let mut has_atomics = Vec::new();
+    let mut has_atomics_load_store = Vec::new();
+    let mut has_atomics_equal_alignment = Vec::new();
+    let mut features = Vec::new();
+
+    let non_empty = |val: &str| (!val.is_empty()).then(|| val.to_owned());
+
+    for line in cfg.lines() {
+        // Flags such as `unix` and `debug_assertions` are all implied by
+        // the key/value pairs
+        let (key, val) = match line.split_once('=') {
+            Some((key, val)) => (key, val),
+            None => continue,
+        };
+
+        let val = val
+            .strip_prefix('"')
+            .and_then(|val| val.strip_suffix('"'))
+            .ok_or_else(|| format!("expected a quoted value, found {line:?}"))?;
+
+        let atomic = || {
+            val.parse::<HasAtomic>()
+                .map_err(|err| format!("invalid {key}: {err}"))
+        };
+
+        match key {
+            "panic" => panic = Some(val.to_owned()),
+            "target_abi" => abi = non_empty(val),
+            "target_arch" => arch = Some(val.to_owned()),
+            "target_endian" => {
+                endian = Some(
+                    val.parse::<Endian>()
+                        .map_err(|_err| format!("invalid target_endian {val:?}"))?,
+                );
+            }
+            "target_env" => env = non_empty(val),
+            "target_family" => families.push(Family::new(val.to_owned())),
+            "target_has_atomic" => has_atomics.push(atomic()?),
+            "target_has_atomic_load_store" => has_atomics_load_store.push(atomic()?),
+            "target_has_atomic_equal_alignment" => has_atomics_equal_alignment.push(atomic()?),
+            "target_os" => os = (val != "none").then(|| val.to_owned()),
+            "target_pointer_width" => {
+                pointer_width = Some(
+                    val.parse::<u8>()
+                        .map_err(|_err| format!("invalid target_pointer_width {val:?}"))?,
+                );
+            }
+            "target_vendor" => vendor = non_empty(val),
+            "target_feature" => features.push(val.to_owned()),
+            // Other cfgs that aren't part of the target
+            // info, eg. the unstable `relocation_model`
+            _ => {}
+        }
+    }
+
+    features.sort_unstable();
+
+    let ti = TargetInfo {
+        triple: Triple::new(triple.to_owned()),
+        os: os.map(Os::new),
+        abi: abi.map(Abi::new),
+        arch: Arch::new(arch.ok_or("target had no target_arch")?),
+        env: env.map(Env::new),
+        vendor: vendor.map(Vendor::new),
+        families: Families::new(families),
+        pointer_width: pointer_width.ok_or("target had no target_pointer_width")?,
+        endian: endian.ok_or("target had no target_endian")?,
+        has_atomics: HasAtomics::new(has_atomics),
+        has_atomics_load_store: HasAtomics::new(has_atomics_load_store),
+        has_atomics_equal_alignment: HasAtomics::new(has_atomics_equal_alignment),
+        panic: Panic::new(panic.ok_or("target had no panic")?),
+    };
+
+    Ok((ti, features))
+}
+
+/// Parses the tier, host tools, and std support from the `metadata` object
+/// of the pretty printed JSON from `--print target-spec-json`, where each
+/// field is on its own line, and unknown values are `null`
+fn parse_support(spec: &str) -> Result<Option<PlatformSupport>, String> {
+    fn parse_bool(val: &str) -> Result<Option<bool>, String> {
+        match val {
+            "true" => Ok(Some(true)),
+            "false" => Ok(Some(false)),
+            "null" => Ok(None),
+            _ => Err(format!("expected a boolean or null, found {val:?}")),
+        }
+    }
+
+    let mut lines = spec
+        .lines()
+        .map(|line| line.trim().trim_end_matches(','))
+        .skip_while(|line| !line.starts_with("\"metadata\":"));
+
+    if lines.next().is_none() {
+        return Ok(None);
+    }
+
+    let mut tier = None;
+    let mut host_tools = None;
+    let mut std = None;
+    for line in lines.take_while(|line| !line.starts_with('}')) {
+        let (key, val) = line
+            .split_once(": ")
+            .ok_or_else(|| format!("expected a metadata field, found {line:?}"))?;
+
+        match key {
+            "\"tier\"" if val != "null" => {
+                tier = Some(
+                    val.parse()
+                        .map_err(|_err| format!("invalid tier {val:?}"))?,
+                );
+            }
+            "\"host_tools\"" => host_tools = parse_bool(val)?,
+            "\"std\"" => std = parse_bool(val)?,
+            _ => {}
+        }
+    }
+
+    Ok(tier.map(|tier| PlatformSupport {
+        tier,
+        host_tools,
+        std,
+    }))
+}
+
+/// The differences between a list of targets and the targets of a rustc,
+/// see [`Rustc::verify`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifyReport {
+    /// The version of rustc the targets were compared with
+    pub rustc_version: String,
+    /// The triples of the targets that rustc supports, but aren't in the list
+    pub missing: Vec<String>,
+    /// The triples of the targets in the list that rustc doesn't support
+    pub extra: Vec<String>,
+    /// The cfgs of the targets in the list that are different from the cfgs
+    /// that rustc sets for them
+    pub mismatched: Vec<CfgMismatch>,
+}
+
+impl VerifyReport {
+    /// Returns true if the list has exactly the same targets as rustc, with
+    /// the same cfgs
+    pub fn is_ok(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty() && self.mismatched.is_empty()
+    }
+}
+
+/// A cfg of a target that is different from the one rustc sets, see
+/// [`VerifyReport`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CfgMismatch {
+    /// The triple of the target
+    pub triple: String,
+    /// The name of the cfg, eg. `target_os`
+    pub key: &'static str,
+    /// The value of the target in the list, with multiple values, eg. for
+    /// `target_family`, separated by commas
+    pub expected: String,
+    /// The value rustc sets, in the same format
+    pub actual: String,
+}
+
+/// The values of the cfgs of the target, in the same order for every target
+fn cfg_values(ti: &TargetInfo) -> [(&'static str, String); 12] {
+    fn join<T: std::fmt::Display>(vals: &[T]) -> String {
+        vals.iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    [
+        ("target_arch", ti.arch.to_string()),
+        (
+            "target_os",
+            ti.os.as_ref().map_or("none", Os::as_str).to_owned(),
+        ),
+        (
+            "target_env",
+            ti.env.as_ref().map_or("", Env::as_str).to_owned(),
+        ),
+        (
+            "target_abi",
+            ti.abi.as_ref().map_or("", Abi::as_str).to_owned(),
+        ),
+        (
+            "target_vendor",
+            ti.vendor
+                .as_ref()
+                .map_or("unknown", Vendor::as_str)
+                .to_owned(),
+        ),
+        ("target_family", join(&ti.families)),
+        ("target_pointer_width", ti.pointer_width.to_string()),
+        ("target_endian", format!("{:?}", ti.endian)),
+        ("target_has_atomic", join(&ti.has_atomics)),
+        (
+            "target_has_atomic_load_store",
+            join(&ti.has_atomics_load_store),
+        ),
+        (
+            "target_has_atomic_equal_alignment",
+            join(&ti.has_atomics_equal_alignment),
+        ),
+        ("panic", ti.panic.to_string()),
+    ]
+}
+
+/// The information for every target supported by a specific version of rustc
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Database {
+    /// The version of rustc the targets were retrieved from
+    pub rustc_version: String,
+    /// The targets, in the order rustc listed them
+    pub targets: Vec<TargetInfo>,
+    /// The target features that are enabled by default for each target
+    pub default_features: BTreeMap<String, Vec<String>>,
+    /// The target features that are implied by each target feature, for each
+    /// arch
+    pub implied_features: BTreeMap<String, BTreeMap<String, Vec<String>>>,
+    /// How well rustc supports each target whose tier is documented
+    pub support: BTreeMap<String, PlatformSupport>,
+}
+
+impl Database {
+    /// Generates the Rust source for the database, in the same form as the
+    /// builtin targets, ie. an `ALL_BUILTINS` slice of [`TargetInfo`], and
+    /// associated constants for each of the values used by the targets.
+    ///
+    /// The source is meant to be written to a module that is a child of
+    /// [`crate::targets`] and formatted with rustfmt.
+    pub fn to_source(&self) -> String {
+        let mut out = String::with_capacity(64 * 1024);
+
+        out.push_str(
+            "/*
+ *
+ * AUTO-GENERATED BY ./update
+ * DO NOT MODIFY
+ *
+ * cargo run --manifest-path update/Cargo.toml
+ */
+
+#![allow(non_upper_case_globals, non_camel_case_types)]
+
+use super::*;
+
+",
+        );
+
+        writeln!(
+            out,
+            "pub(crate) const RUSTC_VERSION: &str = \"{}\";\n",
+            self.rustc_version
+        )
+        .unwrap();
+
+        out.push_str("pub const ALL_BUILTINS: &[TargetInfo] = &[\n");
+
+        let mut abis = BTreeSet::new();
+        let mut arches = BTreeSet::new();
+        let mut vendors = BTreeSet::new();
+        let mut oses = BTreeSet::new();
+        let mut envs = BTreeSet::new();
+        let mut families = BTreeSet::new();
+        let mut family_groups = BTreeSet::new();
+        let mut has_atomic_groups = BTreeSet::new();
+        let mut panics = BTreeSet::new();
+
+        fn insert<'a>(val: Option<&'a str>, typ: &str, set: &mut BTreeSet<&'a str>) -> String {
+            match val {
+                Some(val) => {
+                    set.insert(val);
+                    format!("Some({typ}::{})", ident(val))
+                }
+                None => "None".to_owned(),
+            }
+        }
+
+        let mut atomics = |group: &[HasAtomic]| {
+            if group.is_empty() {
+                "HasAtomics::new_const(&[])".to_owned()
+            } else {
+                has_atomic_groups.insert(group.to_vec());
+                format!("HasAtomics::{}", group_ident("atomic_", group))
+            }
+        };
+
+        for ti in &self.targets {
+            let families_str = if ti.families.is_empty() {
+                "Families::new_const(&[])".to_owned()
+            } else {
+                families.extend(ti.families.iter().map(|fam| fam.as_str()));
+                family_groups.insert(ti.families.to_vec());
+                format!("Families::{}", group_ident("", &ti.families))
+            };
+
+            arches.insert(ti.arch.as_str());
+            panics.insert(ti.panic.as_str());
+
+            writeln!(
+                out,
+                "    TargetInfo {{
+        triple: Triple::new_const(\"{triple}\"),
+        os: {os},
+        abi: {abi},
+        arch: Arch::{arch},
+        env: {env},
+        vendor: {vendor},
+        families: {families_str},
+        pointer_width: {width},
+        endian: Endian::{endian:?},
+        has_atomics: {has_atomics},
+        has_atomics_load_store: {has_atomics_load_store},
+        has_atomics_equal_alignment: {has_atomics_equal_alignment},
+        panic: Panic::{panic},
+    }},",
+                triple = ti.triple,
+                os = insert(ti.os.as_ref().map(Os::as_str), "Os", &mut oses),
+                abi = insert(ti.abi.as_ref().map(Abi::as_str), "Abi", &mut abis),
+                arch = ident(ti.arch.as_str()),
+                env = insert(ti.env.as_ref().map(Env::as_str), "Env", &mut envs),
+                vendor = insert(
+                    ti.vendor.as_ref().map(Vendor::as_str),
+                    "Vendor",
+                    &mut vendors
+                ),
+                width = ti.pointer_width,
+                endian = ti.endian,
+                has_atomics = atomics(&ti.has_atomics),
+                has_atomics_load_store = atomics(&ti.has_atomics_load_store),
+                has_atomics_equal_alignment = atomics(&ti.has_atomics_equal_alignment),
+                panic = ident(ti.panic.as_str()),
+            )
+            .unwrap();
+        }
+
+        out.push_str("];\n");
+
+        // The families of every target with the os, except `wasm`, which
+        // depends on the architecture
+        let mut os_families = BTreeMap::<_, BTreeSet<Family>>::new();
+        for ti in &self.targets {
+            os_families
+                .entry(ti.os.as_ref().map_or("none", Os::as_str))
+                .or_default()
+                .extend(
+                    ti.families
+                        .iter()
+                        .filter(|fam| **fam != Family::wasm)
+                        .cloned(),
+                );
+        }
+
+        out.push_str(
+            "
+/// The families of the builtin targets of each operating system, sorted by
+/// the operating system, where `none` is for the targets without one. The
+/// `wasm` family isn't included as it depends on the architecture, see
+/// [`Families::for_target`]
+pub const OS_FAMILIES: &[(Os, Families)] = &[
+",
+        );
+
+        for (os, group) in os_families {
+            let group: Vec<Family> = group.into_iter().collect();
+            let families_str = if group.is_empty() {
+                "Families::new_const(&[])".to_owned()
+            } else {
+                let families_str = format!("Families::{}", group_ident("", &group));
+                family_groups.insert(group);
+                families_str
+            };
+
+            writeln!(out, "    (Os::{}, {families_str}),", ident(os)).unwrap();
+        }
+
+        out.push_str("];\n");
+
+        for (set, documented) in [
+            (&mut abis, DOCUMENTED_ABIS),
+            (&mut arches, DOCUMENTED_ARCHES),
+            (&mut vendors, DOCUMENTED_VENDORS),
+            (&mut oses, DOCUMENTED_OSES),
+            (&mut families, DOCUMENTED_FAMILIES),
+            (&mut envs, DOCUMENTED_ENVS),
+            (&mut panics, DOCUMENTED_PANICS),
+        ] {
+            set.extend(documented.iter().copied());
+        }
+
+        write_impls(&mut out, "Abi", &abis);
+        write_impls(&mut out, "Arch", &arches);
+        write_impls(&mut out, "Vendor", &vendors);
+        write_impls(&mut out, "Os", &oses);
+        write_impls(&mut out, "Family", &families);
+        write_group_impls(
+            &mut out,
+            "Families",
+            "Family",
+            "__families_",
+            "",
+            family_groups.iter().map(|group| {
+                (
+                    group.as_slice(),
+                    group_values(group, "Family::", |fam| fam.as_str().to_owned()),
+                )
+            }),
+        );
+        write_impls(&mut out, "Env", &envs);
+        write_group_impls(
+            &mut out,
+            "HasAtomics",
+            "HasAtomic",
+            "__has_atomics_",
+            "atomic_",
+            has_atomic_groups.iter().map(|group| {
+                (
+                    group.as_slice(),
+                    group_values(group, "HasAtomic::", |ha| match ha {
+                        HasAtomic::IntegerSize(size) => format!("IntegerSize({size})"),
+                        HasAtomic::Pointer => "Pointer".to_owned(),
+                    }),
+                )
+            }),
+        );
+        write_impls(&mut out, "Panic", &panics);
+
+        out
+    }
+
+    /// Generates the Rust source for a snapshot of the targets, ie. a
+    /// `TARGETS` slice of [`TargetInfo`] that doesn't depend on the
+    /// associated constants of the builtin targets, so that the targets of
+    /// an older rustc can be included alongside them, see
+    /// [`TargetDb`](crate::targets::TargetDb).
+    ///
+    /// The source is meant to be written to a module that is a child of
+    /// `crate::targets::db` and formatted with rustfmt.
+    pub fn snapshot_source(&self) -> String {
+        let mut out = String::with_capacity(64 * 1024);
+
+        writeln!(
+            out,
+            "/*
+ *
+ * AUTO-GENERATED BY ./update
+ * DO NOT MODIFY
+ *
+ * cargo run --manifest-path update/Cargo.toml -- --snapshot
+ */
+
+#![allow(non_upper_case_globals)]
+
+use crate::targets::*;
+
+pub(crate) const RUSTC_VERSION: &str = \"{}\";
+
+pub(crate) const TARGETS: &[TargetInfo] = &[",
+            self.rustc_version
+        )
+        .unwrap();
+
+        // The identifier of a group, which can be empty
+        fn snapshot_ident<T: std::fmt::Display>(prefix: &str, group: &[T]) -> String {
+            if group.is_empty() {
+                format!("{prefix}none")
+            } else {
+                group_ident(prefix, group)
+            }
+        }
+
+        let mut family_groups = BTreeSet::new();
+        let mut has_atomic_groups = BTreeSet::new();
+
+        fn value(val: Option<&str>, typ: &str) -> String {
+            match val {
+                Some(val) => format!("Some({typ}::new_const(\"{val}\"))"),
+                None => "None".to_owned(),
+            }
+        }
+
+        let mut atomics = |group: &[HasAtomic]| {
+            has_atomic_groups.insert(group.to_vec());
+            format!(
+                "HasAtomics::new_const({})",
+                snapshot_ident("__has_atomics_", group)
+            )
+        };
+
+        for ti in &self.targets {
+            family_groups.insert(ti.families.to_vec());
+
+            writeln!(
+                out,
+                "    TargetInfo {{
+        triple: Triple::new_const(\"{triple}\"),
+        os: {os},
+        abi: {abi},
+        arch: Arch::new_const(\"{arch}\"),
+        env: {env},
+        vendor: {vendor},
+        families: Families::new_const({families}),
+        pointer_width: {width},
+        endian: Endian::{endian:?},
+        has_atomics: {has_atomics},
+        has_atomics_load_store: {has_atomics_load_store},
+        has_atomics_equal_alignment: {has_atomics_equal_alignment},
+        panic: Panic::new_const(\"{panic}\"),
+    }},",
+                triple = ti.triple,
+                os = value(ti.os.as_ref().map(Os::as_str), "Os"),
+                abi = value(ti.abi.as_ref().map(Abi::as_str), "Abi"),
+                arch = ti.arch,
+                env = value(ti.env.as_ref().map(Env::as_str), "Env"),
+                vendor = value(ti.vendor.as_ref().map(Vendor::as_str), "Vendor"),
+                families = snapshot_ident("__families_", &ti.families),
+                width = ti.pointer_width,
+                endian = ti.endian,
+                has_atomics = atomics(&ti.has_atomics),
+                has_atomics_load_store = atomics(&ti.has_atomics_load_store),
+                has_atomics_equal_alignment = atomics(&ti.has_atomics_equal_alignment),
+                panic = ti.panic,
+            )
+            .unwrap();
+        }
+
+        out.push_str("];\n\n");
+
+        // The groups are constants of their own, as the values of the
+        // fields can't be borrowed for 'static inside of a struct
+        for group in &family_groups {
+            writeln!(
+                out,
+                "const {}: &[Family] = &[{}];",
+                snapshot_ident("__families_", group),
+                group_values(group, "Family::", |fam| format!("new_const(\"{fam}\")"))
+            )
+            .unwrap();
+        }
+
+        for group in &has_atomic_groups {
+            writeln!(
+                out,
+                "const {}: &[HasAtomic] = &[{}];",
+                snapshot_ident("__has_atomics_", group),
+                group_values(group, "HasAtomic::", |ha| match ha {
+                    HasAtomic::IntegerSize(size) => format!("IntegerSize({size})"),
+                    HasAtomic::Pointer => "Pointer".to_owned(),
+                })
+            )
+            .unwrap();
+        }
+
+        out
+    }
+
+    /// Generates the Rust source for the default target features of each
+    /// target, ie. a `DEFAULT_FEATURES` slice of triples and their features,
+    /// sorted by triple, and the features implied by each feature, ie. an
+    /// `IMPLIED_FEATURES` slice of arches and their implications, sorted by
+    /// arch and feature
+    pub fn features_source(&self) -> String {
+        let mut out = String::with_capacity(16 * 1024);
+
+        out.push_str(
+            "/*
+ *
+ * AUTO-GENERATED BY ./update
+ * DO NOT MODIFY
+ *
+ * cargo run --manifest-path update/Cargo.toml
+ */
+
+pub(crate) const DEFAULT_FEATURES: &[(&str, &[&str])] = &[
+",
+        );
+
+        for (triple, features) in &self.default_features {
+            write!(out, "    (\"{triple}\", &[").unwrap();
+            write_strs(&mut out, features);
+            out.push_str("]),\n");
+        }
+
+        out.push_str(
+            "];
+
+/// The features implied by each feature, sorted by feature
+pub(crate) type Implications = &'static [(&'static str, &'static [&'static str])];
+
+pub(crate) const IMPLIED_FEATURES: &[(&str, Implications)] = &[
+",
+        );
+
+        for (arch, implied) in &self.implied_features {
+            writeln!(out, "    (\"{arch}\", &[").unwrap();
+            for (feature, features) in implied {
+                write!(out, "        (\"{feature}\", &[").unwrap();
+                write_strs(&mut out, features);
+                out.push_str("]),\n");
+            }
+            out.push_str("    ]),\n");
+        }
+
+        out.push_str("];\n");
+        out
+    }
+
+    /// Generates the Rust source for how well rustc supports each target,
+    /// ie. a `SUPPORT` slice of triples and their [`PlatformSupport`], sorted
+    /// by triple
+    pub fn support_source(&self) -> String {
+        let mut out = String::with_capacity(16 * 1024);
+
+        out.push_str(
+            "/*
+ *
+ * AUTO-GENERATED BY ./update
+ * DO NOT MODIFY
+ *
+ * cargo run --manifest-path update/Cargo.toml
+ */
+
+use super::PlatformSupport;
+
+pub(crate) const SUPPORT: &[(&str, PlatformSupport)] = &[
+",
+        );
+
+        for (triple, support) in &self.support {
+            writeln!(
+                out,
+                "    (\"{triple}\", PlatformSupport {{ tier: {}, host_tools: {:?}, std: {:?} }}),",
+                support.tier, support.host_tools, support.std
+            )
+            .unwrap();
+        }
+
+        out.push_str("];\n");
+        out
+    }
+}
+
+// The values documented in the reference, see
+// <https://doc.rust-lang.org/reference/conditional-compilation.html#set-configuration-options>,
+// which are known even if no builtin target uses them, eg. `target_os = "none"`
+// is used by every bare metal target, but its os is `None`
+const DOCUMENTED_ABIS: &[&str] = &["llvm", "eabihf", "abi64", "sim", "macabi"];
+const DOCUMENTED_ARCHES: &[&str] = &[
+    "x86",
+    "x86_64",
+    "mips",
+    "powerpc",
+    "powerpc64",
+    "arm",
+    "aarch64",
+];
+const DOCUMENTED_VENDORS: &[&str] = &["apple", "fortanix", "pc", "unknown"];
+const DOCUMENTED_OSES: &[&str] = &[
+    "windows",
+    "macos",
+    "ios",
+    "linux",
+    "android",
+    "freebsd",
+    "dragonfly",
+    "openbsd",
+    "netbsd",
+    "none",
+];
+const DOCUMENTED_FAMILIES: &[&str] = &["unix", "windows", "wasm"];
+const DOCUMENTED_ENVS: &[&str] = &["gnu", "msvc", "musl", "sgx"];
+const DOCUMENTED_PANICS: &[&str] = &["abort", "unwind"];
+
+/// Writes comma separated string literals
+fn write_strs(out: &mut String, strs: &[String]) {
+    for (i, s) in strs.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        write!(out, "\"{s}\"").unwrap();
+    }
+}
+
+/// Some values aren't valid identifiers, eg. the `vec-extabi` abi
+fn ident(val: &str) -> String {
+    val.replace('-', "_")
+}
+
+/// The identifier of a group of values, eg. `atomic_8_16_ptr`
+fn group_ident<T: std::fmt::Display>(prefix: &str, group: &[T]) -> String {
+    let mut id = prefix.to_owned();
+    for (i, val) in group.iter().enumerate() {
+        if i > 0 {
+            id.push('_');
+        }
+        write!(id, "{}", ident(&val.to_string())).unwrap();
+    }
+    id
+}
+
+fn group_values<T, S: std::fmt::Display>(
+    group: &[T],
+    prefix: &str,
+    value: impl Fn(&T) -> S,
+) -> String {
+    group
+        .iter()
+        .map(|val| format!("{prefix}{}", value(val)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn write_impls(out: &mut String, typ: &str, vals: &BTreeSet<&str>) {
+    writeln!(out, "\nimpl super::{typ} {{").unwrap();
+
+    for val in vals {
+        writeln!(
+            out,
+            "    pub const {}: {typ} = {typ}::new_const(\"{val}\");",
+            ident(val)
+        )
+        .unwrap();
+    }
+
+    writeln!(
+        out,
+        "\n    /// Every known value, ie. the values of the builtin targets, and the\n    \
+         /// values documented in the reference, sorted\n    \
+         pub const ALL_KNOWN: &[{typ}] = &[{}];",
+        vals.iter()
+            .map(|val| format!("{typ}::{}", ident(val)))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+    .unwrap();
+
+    out.push_str("}\n");
+}
+
+fn write_group_impls<'a, T: std::fmt::Display + 'a>(
+    out: &mut String,
+    typ: &str,
+    element: &str,
+    private_prefix: &str,
+    pub_prefix: &str,
+    groups: impl Iterator<Item = (&'a [T], String)> + Clone,
+) {
+    out.push('\n');
+    for (group, values) in groups.clone() {
+        writeln!(
+            out,
+            "const {}: &[{element}] = &[{values}];",
+            group_ident(private_prefix, group)
+        )
+        .unwrap();
+    }
+
+    writeln!(out, "\nimpl super::{typ} {{").unwrap();
+
+    for (group, _) in groups {
+        writeln!(
+            out,
+            "    pub const {}: {typ} = {typ}::new_const({});",
+            group_ident(pub_prefix, group),
+            group_ident(private_prefix, group)
+        )
+        .unwrap();
+    }
+
+    out.push_str("}\n");
+}
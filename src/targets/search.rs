@@ -0,0 +1,155 @@
+use super::*;
+use crate::expr::{TargetMatcher, TargetPredicate};
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+
+/// Searches the [builtin](ALL_BUILTINS) targets, see [`TargetSearch`]
+///
+/// ```
+/// use cfg_expr::targets::{search, Endian};
+///
+/// let linux: Vec<_> = search()
+///     .triple("aarch64-*-linux-*")
+///     .os("linux")
+///     .env("gnu")
+///     .map(|ti| ti.triple.as_str())
+///     .collect();
+///
+/// assert_eq!(linux, ["aarch64-unknown-linux-gnu", "aarch64-unknown-linux-gnu_ilp32"]);
+///
+/// assert!(search()
+///     .family("unix")
+///     .pointer_width(64)
+///     .endian(Endian::big)
+///     .any(|ti| ti.triple.as_str() == "s390x-unknown-linux-gnu"));
+/// ```
+pub fn search() -> TargetSearch<'static> {
+    TargetSearch::new(ALL_BUILTINS)
+}
+
+/// An iterator over the targets in a list that match every filter, in the
+/// order of the list.
+///
+/// The fields are matched the same way as the predicates of an expression,
+/// eg. `os("none")` matches the targets without an operating system.
+#[derive(Clone, Debug)]
+pub struct TargetSearch<'a> {
+    targets: core::slice::Iter<'a, TargetInfo>,
+    triple: Option<String>,
+    predicates: Vec<TargetPredicate>,
+}
+
+impl<'a> TargetSearch<'a> {
+    /// Searches the list of targets, eg. the targets queried from a specific
+    /// rustc
+    pub fn new(targets: &'a [TargetInfo]) -> Self {
+        Self {
+            targets: targets.iter(),
+            triple: None,
+            predicates: Vec::new(),
+        }
+    }
+
+    /// Only matches the triples that match the glob, where `*` matches any
+    /// number of characters and `?` matches a single character, eg.
+    /// `*-unknown-linux-*`
+    pub fn triple(mut self, glob: &str) -> Self {
+        self.triple = Some(glob.to_owned());
+        self
+    }
+
+    /// Only matches the targets that match the predicate
+    pub fn predicate(mut self, predicate: TargetPredicate) -> Self {
+        self.predicates.push(predicate);
+        self
+    }
+
+    /// Only matches the architecture, eg. `x86_64`
+    pub fn arch(self, arch: &str) -> Self {
+        self.predicate(TargetPredicate::Arch(Arch::new(arch.to_owned())))
+    }
+
+    /// Only matches the operating system, eg. `linux`, or `none`
+    pub fn os(self, os: &str) -> Self {
+        self.predicate(TargetPredicate::Os(Os::new(os.to_owned())))
+    }
+
+    /// Only matches the environment, eg. `gnu`, or an empty string
+    pub fn env(self, env: &str) -> Self {
+        self.predicate(TargetPredicate::Env(Env::new(env.to_owned())))
+    }
+
+    /// Only matches the targets in the family, eg. `unix`
+    pub fn family(self, family: &str) -> Self {
+        self.predicate(TargetPredicate::Family(Family::new(family.to_owned())))
+    }
+
+    /// Only matches the endianness
+    pub fn endian(self, endian: Endian) -> Self {
+        self.predicate(TargetPredicate::Endian(endian))
+    }
+
+    /// Only matches the pointer width, eg. `64`
+    pub fn pointer_width(self, pointer_width: u8) -> Self {
+        self.predicate(TargetPredicate::PointerWidth(pointer_width))
+    }
+
+    /// Returns true if the target matches every filter
+    pub fn matches(&self, target: &TargetInfo) -> bool {
+        self.triple
+            .as_ref()
+            .map_or(true, |glob| glob_matches(glob, target.triple.as_str()))
+            && self.predicates.iter().all(|tp| target.matches(tp))
+    }
+}
+
+impl<'a> Iterator for TargetSearch<'a> {
+    type Item = &'a TargetInfo;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(target) = self.targets.next() {
+            if self.matches(target) {
+                return Some(target);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.targets.size_hint().1)
+    }
+}
+
+/// Matches a glob where `*` matches any number of characters, and `?` a
+/// single character
+fn glob_matches(glob: &str, s: &str) -> bool {
+    let glob: Vec<char> = glob.chars().collect();
+    let s: Vec<char> = s.chars().collect();
+
+    let (mut g, mut i) = (0, 0);
+    // The position of the last `*`, and the position in the string it was
+    // matched at, to backtrack to if the rest of the glob doesn't match
+    let mut star = None;
+
+    while i < s.len() {
+        match glob.get(g) {
+            Some('*') => {
+                star = Some((g, i));
+                g += 1;
+            }
+            Some(&c) if c == '?' || c == s[i] => {
+                g += 1;
+                i += 1;
+            }
+            _ => match star {
+                Some((sg, si)) => {
+                    star = Some((sg, si + 1));
+                    g = sg + 1;
+                    i = si + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    glob[g..].iter().all(|&c| c == '*')
+}
@@ -0,0 +1,212 @@
+use super::TargetInfo;
+use crate::expr::{Expression, Logic, Predicate};
+use alloc::vec::Vec;
+
+const BITS: usize = u64::BITS as usize;
+
+/// A set of targets, stored as a bitset of their indices in a list of targets,
+/// eg. [`ALL_BUILTINS`](super::ALL_BUILTINS).
+///
+/// The set is also a [`Logic`], where `and` is the intersection, `or` is the
+/// union, and `not` is the complement, so evaluating an expression once with
+/// the set of targets each predicate matches yields every target that the
+/// expression matches, see [`Expression::eval_target_set`].
+///
+/// As [`Logic::top`] doesn't know the number of targets, indices past the
+/// stored ones all have the same membership, so the complement of the empty
+/// set contains every index.
+///
+/// ```
+/// use cfg_expr::targets::TargetSet;
+///
+/// let mut set = TargetSet::new();
+/// set.insert(1);
+/// set.insert(70);
+///
+/// assert!(set.contains(70));
+/// assert_eq!(set.iter(100).collect::<Vec<_>>(), [1, 70]);
+///
+/// use cfg_expr::expr::Logic;
+/// let complement = set.not();
+/// assert!(!complement.contains(1));
+/// assert!(complement.contains(1000));
+/// assert_eq!(complement.len(100), 98);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TargetSet {
+    words: Vec<u64>,
+    /// Whether the indices past the stored words are in the set
+    rest: bool,
+}
+
+impl TargetSet {
+    /// Creates an empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a set of every index
+    pub fn full() -> Self {
+        Self {
+            words: Vec::new(),
+            rest: true,
+        }
+    }
+
+    /// Creates the set of the targets in the list that match the predicate
+    pub fn matching<F>(targets: &[TargetInfo], mut predicate: F) -> Self
+    where
+        F: FnMut(&TargetInfo) -> bool,
+    {
+        let mut set = Self::new();
+        for (i, target) in targets.iter().enumerate() {
+            if predicate(target) {
+                set.insert(i);
+            }
+        }
+        set
+    }
+
+    fn fill(&self) -> u64 {
+        if self.rest {
+            u64::MAX
+        } else {
+            0
+        }
+    }
+
+    fn word(&self, i: usize) -> u64 {
+        self.words.get(i).copied().unwrap_or_else(|| self.fill())
+    }
+
+    /// Removes the trailing words that are the same as the rest, so that
+    /// sets with the same members are equal
+    fn normalize(mut self) -> Self {
+        let fill = self.fill();
+        while self.words.last() == Some(&fill) {
+            self.words.pop();
+        }
+        self
+    }
+
+    /// Combines the sets word by word
+    fn combine(self, other: Self, op: impl Fn(u64, u64) -> u64) -> Self {
+        let len = self.words.len().max(other.words.len());
+        let words = (0..len).map(|i| op(self.word(i), other.word(i))).collect();
+        let rest = op(self.fill(), other.fill()) != 0;
+
+        Self { words, rest }.normalize()
+    }
+
+    /// Adds the index to the set
+    pub fn insert(&mut self, index: usize) {
+        self.set(index, true);
+    }
+
+    /// Removes the index from the set
+    pub fn remove(&mut self, index: usize) {
+        self.set(index, false);
+    }
+
+    fn set(&mut self, index: usize, value: bool) {
+        let (word, bit) = (index / BITS, index % BITS);
+        if word >= self.words.len() {
+            if value == self.rest {
+                return;
+            }
+            self.words.resize(word + 1, self.fill());
+        }
+
+        if value {
+            self.words[word] |= 1 << bit;
+        } else {
+            self.words[word] &= !(1 << bit);
+        }
+
+        *self = core::mem::take(self).normalize();
+    }
+
+    /// Returns true if the index is in the set
+    pub fn contains(&self, index: usize) -> bool {
+        self.word(index / BITS) & (1 << (index % BITS)) != 0
+    }
+
+    /// Iterates over the indices in the set that are less than `len`, ie.
+    /// the number of targets the set is over
+    pub fn iter(&self, len: usize) -> impl Iterator<Item = usize> + '_ {
+        (0..len).filter(move |i| self.contains(*i))
+    }
+
+    /// The number of indices in the set that are less than `len`
+    pub fn len(&self, len: usize) -> usize {
+        self.iter(len).count()
+    }
+
+    /// Returns true if none of the indices less than `len` are in the set
+    pub fn is_empty(&self, len: usize) -> bool {
+        self.iter(len).next().is_none()
+    }
+
+    /// Iterates over the targets in the list whose indices are in the set
+    pub fn targets<'t>(
+        &'t self,
+        targets: &'t [TargetInfo],
+    ) -> impl Iterator<Item = &'t TargetInfo> {
+        self.iter(targets.len()).map(move |i| &targets[i])
+    }
+}
+
+impl Logic for TargetSet {
+    fn top() -> Self {
+        Self::full()
+    }
+
+    fn bottom() -> Self {
+        Self::new()
+    }
+
+    fn and(self, other: Self) -> Self {
+        self.combine(other, |a, b| a & b)
+    }
+
+    fn or(self, other: Self) -> Self {
+        self.combine(other, |a, b| a | b)
+    }
+
+    fn not(self) -> Self {
+        Self {
+            words: self.words.iter().map(|word| !word).collect(),
+            rest: !self.rest,
+        }
+    }
+}
+
+impl Expression {
+    /// Evaluates the expression against every target in the list at once,
+    /// returning the set of the targets that it matches, by their index.
+    ///
+    /// Each predicate is only evaluated once, against every target, and the
+    /// sets are combined with their [`Logic`]. As with
+    /// [`Self::eval_target`] without any enabled features, `target_feature`
+    /// predicates match the targets that enable the feature by default, and
+    /// every other predicate that isn't a target predicate is false.
+    ///
+    /// ```
+    /// use cfg_expr::{targets::ALL_BUILTINS, Expression};
+    ///
+    /// let expr = Expression::parse(r#"all(unix, not(target_pointer_width = "64"))"#).unwrap();
+    /// let set = expr.eval_target_set(ALL_BUILTINS);
+    ///
+    /// assert!(set.targets(ALL_BUILTINS).any(|ti| ti.triple.as_str() == "i686-unknown-linux-gnu"));
+    /// assert!(set.targets(ALL_BUILTINS).all(|ti| ti.pointer_width != 64));
+    /// ```
+    pub fn eval_target_set(&self, targets: &[TargetInfo]) -> TargetSet {
+        self.eval(|pred| match pred {
+            Predicate::Target(tp) => TargetSet::matching(targets, |target| tp.matches(target)),
+            Predicate::TargetFeature(feat) => {
+                TargetSet::matching(targets, |target| target.has_default_feature(feat))
+            }
+            _ => TargetSet::new(),
+        })
+    }
+}
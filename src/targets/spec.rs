@@ -0,0 +1,275 @@
+use super::*;
+use serde::{de::Error as _, Deserialize, Serialize};
+
+/// The subset of the fields of a rustc target spec that are used for cfgs.
+/// The defaults are the same as rustc uses for fields that are omitted.
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct Spec {
+    llvm_target: String,
+    arch: String,
+    #[serde(default = "default_os")]
+    os: String,
+    #[serde(default)]
+    env: String,
+    #[serde(default = "default_vendor")]
+    vendor: String,
+    #[serde(default)]
+    abi: String,
+    #[serde(default = "default_endian")]
+    target_endian: String,
+    target_pointer_width: PointerWidth,
+    target_family: Option<FamilyList>,
+    max_atomic_width: Option<u16>,
+    #[serde(default = "default_min_atomic_width")]
+    min_atomic_width: u16,
+    #[serde(default = "default_true")]
+    atomic_cas: bool,
+    #[serde(default = "default_panic")]
+    panic_strategy: String,
+    #[serde(default)]
+    data_layout: String,
+}
+
+fn default_os() -> String {
+    "none".to_owned()
+}
+
+fn default_vendor() -> String {
+    "unknown".to_owned()
+}
+
+fn default_endian() -> String {
+    "little".to_owned()
+}
+
+fn default_min_atomic_width() -> u16 {
+    8
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_panic() -> String {
+    "unwind".to_owned()
+}
+
+/// The fields that are written by [`TargetInfo::to_spec_json`], in the same
+/// order as rustc's `--print target-spec-json`
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct SpecOut<'a> {
+    #[serde(skip_serializing_if = "str::is_empty")]
+    abi: &'a str,
+    arch: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    atomic_cas: Option<bool>,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    env: &'a str,
+    llvm_target: &'a str,
+    max_atomic_width: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_atomic_width: Option<u16>,
+    os: &'a str,
+    panic_strategy: &'a str,
+    target_endian: &'a str,
+    target_family: Vec<&'a str>,
+    target_pointer_width: u8,
+    vendor: &'a str,
+}
+
+/// Older versions of rustc use a string for the pointer width
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PointerWidth {
+    Int(u8),
+    Str(String),
+}
+
+/// Older versions of rustc use a single string for the family
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FamilyList {
+    One(String),
+    Many(Vec<String>),
+}
+
+/// Retrieves the ABI alignment, in bits, of an integer or pointer of the
+/// specified size, from the target's LLVM data layout, using the same
+/// defaults as rustc for alignments that aren't specified
+fn abi_align(data_layout: &str, spec: &str, default: u64) -> u64 {
+    data_layout
+        .split('-')
+        .find_map(|part| {
+            let mut fields = part.strip_prefix(spec)?.split(':');
+            let first = fields.next()?;
+
+            // Pointers are `p[0]:<size>:<abi>`, integers are `i<size>:<abi>`
+            let abi = match (spec, first) {
+                ("p", "" | "0") => fields.nth(1)?,
+                (_, "") => fields.next()?,
+                _ => return None,
+            };
+
+            abi.parse().ok()
+        })
+        .unwrap_or(default)
+}
+
+impl TargetInfo {
+    /// Creates the information for a custom target from its rustc
+    /// [target spec](https://doc.rust-lang.org/rustc/targets/custom.html),
+    /// ie. the JSON file passed to `--target`. The triple is the spec's
+    /// `llvm-target`, as rustc otherwise uses the file name of the spec.
+    ///
+    /// The atomics are derived from the `max-atomic-width`,
+    /// `min-atomic-width` and `atomic-cas` fields, the same way as rustc
+    /// does, with the alignments for `target_has_atomic_equal_alignment`
+    /// taken from the `data-layout`.
+    ///
+    /// ```
+    /// use cfg_expr::targets::{Arch, Endian, HasAtomic, TargetInfo};
+    ///
+    /// let ti = TargetInfo::from_spec_json(r#"{
+    ///     "llvm-target": "thumbv7em-none-eabihf",
+    ///     "arch": "arm",
+    ///     "abi": "eabihf",
+    ///     "data-layout": "e-m:e-p:32:32-Fi8-i64:64-v128:64:128-a:0:32-n32-S64",
+    ///     "max-atomic-width": 32,
+    ///     "panic-strategy": "abort",
+    ///     "target-pointer-width": 32
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(ti.arch, Arch::arm);
+    /// assert_eq!(ti.os, None);
+    /// assert_eq!(ti.endian, Endian::little);
+    /// assert!(ti.has_atomics.contains(HasAtomic::Pointer));
+    /// assert!(!ti.has_atomics.contains(HasAtomic::IntegerSize(64)));
+    /// ```
+    pub fn from_spec_json(json: &str) -> Result<Self, serde_json::Error> {
+        let spec: Spec = serde_json::from_str(json)?;
+
+        let pointer_width = match spec.target_pointer_width {
+            PointerWidth::Int(pw) => pw,
+            PointerWidth::Str(pw) => pw.parse().map_err(|_err| {
+                serde_json::Error::custom(format!("invalid target-pointer-width {pw:?}"))
+            })?,
+        };
+
+        let endian = spec.target_endian.parse::<Endian>().map_err(|_err| {
+            serde_json::Error::custom(format!("invalid target-endian {:?}", spec.target_endian))
+        })?;
+
+        let families = match spec.target_family {
+            None => Vec::new(),
+            Some(FamilyList::One(fam)) => vec![fam],
+            Some(FamilyList::Many(fams)) => fams,
+        };
+
+        let max_atomic_width = spec.max_atomic_width.unwrap_or(pointer_width.into());
+
+        let mut has_atomics = Vec::new();
+        let mut has_atomics_equal_alignment = Vec::new();
+
+        for (size, align) in [
+            (8, abi_align(&spec.data_layout, "i8", 8)),
+            (16, abi_align(&spec.data_layout, "i16", 16)),
+            (32, abi_align(&spec.data_layout, "i32", 32)),
+            (64, abi_align(&spec.data_layout, "i64", 32)),
+            (128, abi_align(&spec.data_layout, "i128", 32)),
+        ] {
+            if size < spec.min_atomic_width || size > max_atomic_width {
+                continue;
+            }
+
+            has_atomics.push(HasAtomic::IntegerSize(size));
+            if u64::from(size) == align {
+                has_atomics_equal_alignment.push(HasAtomic::IntegerSize(size));
+            }
+
+            if size == u16::from(pointer_width) {
+                has_atomics.push(HasAtomic::Pointer);
+                if abi_align(&spec.data_layout, "p", 64) == u64::from(pointer_width) {
+                    has_atomics_equal_alignment.push(HasAtomic::Pointer);
+                }
+            }
+        }
+
+        let has_atomics_load_store = HasAtomics::new(has_atomics.iter().copied());
+        let non_empty = |val: String| (!val.is_empty()).then(|| val);
+
+        Ok(Self {
+            triple: Triple::new(spec.llvm_target),
+            os: (spec.os != "none").then(|| Os::new(spec.os)),
+            abi: non_empty(spec.abi).map(Abi::new),
+            arch: Arch::new(spec.arch),
+            env: non_empty(spec.env).map(Env::new),
+            vendor: non_empty(spec.vendor).map(Vendor::new),
+            families: Families::new(families.into_iter().map(Family::new)),
+            pointer_width,
+            endian,
+            // Without compare-and-swap, only loads and stores are supported
+            has_atomics: if spec.atomic_cas {
+                HasAtomics::new(has_atomics)
+            } else {
+                HasAtomics::new_const(&[])
+            },
+            has_atomics_load_store,
+            has_atomics_equal_alignment: HasAtomics::new(has_atomics_equal_alignment),
+            panic: Panic::new(spec.panic_strategy),
+        })
+    }
+
+    /// Writes the target as a rustc
+    /// [target spec](https://doc.rust-lang.org/rustc/targets/custom.html),
+    /// which is the inverse of [`Self::from_spec_json`].
+    ///
+    /// Only the fields that correspond to cfgs are written, so fields that
+    /// rustc also requires to build for the target, such as `data-layout`,
+    /// need to be added to the spec. As the `data-layout` is also used for
+    /// `target_has_atomic_equal_alignment`, that isn't round-tripped.
+    ///
+    /// ```
+    /// let ti = cfg_expr::targets::get_builtin_target_by_triple("thumbv7em-none-eabihf").unwrap();
+    /// let json = ti.to_spec_json();
+    ///
+    /// assert!(json.contains(r#""max-atomic-width": 32"#));
+    /// assert!(json.contains(r#""panic-strategy": "abort""#));
+    /// ```
+    pub fn to_spec_json(&self) -> String {
+        let sizes = |atomics: &HasAtomics| {
+            atomics
+                .iter()
+                .filter_map(|ha| match ha {
+                    HasAtomic::IntegerSize(size) => Some(*size),
+                    HasAtomic::Pointer => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let load_store = sizes(&self.has_atomics_load_store);
+
+        let spec = SpecOut {
+            abi: self.abi.as_ref().map_or("", Abi::as_str),
+            arch: self.arch.as_str(),
+            atomic_cas: (self.has_atomics.is_empty() && !load_store.is_empty()).then(|| false),
+            env: self.env.as_ref().map_or("", Env::as_str),
+            llvm_target: self.triple.as_str(),
+            max_atomic_width: load_store.iter().copied().max().unwrap_or(0),
+            min_atomic_width: load_store.iter().copied().min().filter(|min| *min != 8),
+            os: self.os.as_ref().map_or("none", Os::as_str),
+            panic_strategy: self.panic.as_str(),
+            target_endian: match self.endian {
+                Endian::big => "big",
+                Endian::little => "little",
+            },
+            target_family: self.families.iter().map(Family::as_str).collect(),
+            target_pointer_width: self.pointer_width,
+            vendor: self.vendor.as_ref().map_or("unknown", Vendor::as_str),
+        };
+
+        // Serializing a struct of strings and integers can't fail
+        serde_json::to_string_pretty(&spec).unwrap()
+    }
+}
@@ -0,0 +1,2572 @@
+/*
+ *
+ * AUTO-GENERATED BY ./update
+ * DO NOT MODIFY
+ *
+ * cargo run --manifest-path update/Cargo.toml
+ */
+
+use super::PlatformSupport;
+
+pub(crate) const SUPPORT: &[(&str, PlatformSupport)] = &[
+    (
+        "aarch64-apple-darwin",
+        PlatformSupport {
+            tier: 1,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-apple-ios",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-apple-ios-macabi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-apple-ios-sim",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-apple-tvos",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-apple-tvos-sim",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-apple-visionos",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-apple-visionos-sim",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-apple-watchos",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-apple-watchos-sim",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-kmc-solid_asp3",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-linux-android",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-nintendo-switch-freestanding",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "aarch64-pc-windows-gnullvm",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-pc-windows-msvc",
+        PlatformSupport {
+            tier: 1,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-unknown-freebsd",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-unknown-fuchsia",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-unknown-helenos",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-unknown-hermit",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-unknown-illumos",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-unknown-linux-gnu",
+        PlatformSupport {
+            tier: 1,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-unknown-linux-gnu_ilp32",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-unknown-linux-musl",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-unknown-linux-ohos",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-unknown-managarm-mlibc",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "aarch64-unknown-netbsd",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-unknown-none",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "aarch64-unknown-none-softfloat",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "aarch64-unknown-nto-qnx700",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-unknown-nto-qnx710",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-unknown-nto-qnx710_iosock",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-unknown-nto-qnx800",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-unknown-nuttx",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-unknown-openbsd",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-unknown-redox",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: None,
+        },
+    ),
+    (
+        "aarch64-unknown-teeos",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: None,
+        },
+    ),
+    (
+        "aarch64-unknown-trusty",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64-unknown-uefi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: None,
+        },
+    ),
+    (
+        "aarch64-uwp-windows-msvc",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: None,
+        },
+    ),
+    (
+        "aarch64-wrs-vxworks",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64_be-unknown-hermit",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64_be-unknown-linux-gnu",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64_be-unknown-linux-gnu_ilp32",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64_be-unknown-linux-musl",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64_be-unknown-netbsd",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "aarch64_be-unknown-none-softfloat",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "aarch64v8r-unknown-none",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "aarch64v8r-unknown-none-softfloat",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "amdgcn-amd-amdhsa",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "arm-linux-androideabi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "arm-unknown-linux-gnueabi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "arm-unknown-linux-gnueabihf",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "arm-unknown-linux-musleabi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "arm-unknown-linux-musleabihf",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "arm64_32-apple-watchos",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "arm64e-apple-darwin",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "arm64e-apple-ios",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "arm64e-apple-tvos",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "arm64ec-pc-windows-msvc",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "armeb-unknown-linux-gnueabi",
+        PlatformSupport {
+            tier: 3,
+            host_tools: None,
+            std: Some(true),
+        },
+    ),
+    (
+        "armebv7r-none-eabi",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "armebv7r-none-eabihf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "armv4t-none-eabi",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "armv4t-unknown-linux-gnueabi",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv5te-none-eabi",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "armv5te-unknown-linux-gnueabi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv5te-unknown-linux-musleabi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv5te-unknown-linux-uclibceabi",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv6-none-eabi",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "armv6-none-eabihf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "armv6-unknown-freebsd",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv6-unknown-netbsd-eabihf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv6k-nintendo-3ds",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: None,
+        },
+    ),
+    (
+        "armv7-linux-androideabi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv7-rtems-eabihf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv7-sony-vita-newlibeabihf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv7-unknown-freebsd",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv7-unknown-linux-gnueabi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv7-unknown-linux-gnueabihf",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv7-unknown-linux-musleabi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv7-unknown-linux-musleabihf",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv7-unknown-linux-ohos",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv7-unknown-linux-uclibceabi",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv7-unknown-linux-uclibceabihf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: None,
+            std: Some(true),
+        },
+    ),
+    (
+        "armv7-unknown-netbsd-eabihf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv7-unknown-trusty",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv7-wrs-vxworks-eabihf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: None,
+        },
+    ),
+    (
+        "armv7a-kmc-solid_asp3-eabi",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv7a-kmc-solid_asp3-eabihf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv7a-none-eabi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "armv7a-none-eabihf",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "armv7a-nuttx-eabi",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv7a-nuttx-eabihf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv7a-vex-v5",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv7k-apple-watchos",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv7r-none-eabi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "armv7r-none-eabihf",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "armv7s-apple-ios",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "armv8r-none-eabihf",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "avr-none",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "bpfeb-unknown-none",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "bpfel-unknown-none",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "csky-unknown-linux-gnuabiv2",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "csky-unknown-linux-gnuabiv2hf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "hexagon-unknown-linux-musl",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "hexagon-unknown-none-elf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "hexagon-unknown-qurt",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "i386-apple-ios",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "i586-unknown-linux-gnu",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "i586-unknown-linux-musl",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "i586-unknown-netbsd",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "i586-unknown-redox",
+        PlatformSupport {
+            tier: 3,
+            host_tools: None,
+            std: None,
+        },
+    ),
+    (
+        "i686-apple-darwin",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "i686-linux-android",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "i686-pc-nto-qnx700",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "i686-pc-windows-gnu",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "i686-pc-windows-gnullvm",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "i686-pc-windows-msvc",
+        PlatformSupport {
+            tier: 1,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "i686-unknown-freebsd",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "i686-unknown-haiku",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "i686-unknown-helenos",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "i686-unknown-hurd-gnu",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "i686-unknown-linux-gnu",
+        PlatformSupport {
+            tier: 1,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "i686-unknown-linux-musl",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "i686-unknown-netbsd",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "i686-unknown-openbsd",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "i686-unknown-uefi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: None,
+        },
+    ),
+    (
+        "i686-uwp-windows-gnu",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: None,
+        },
+    ),
+    (
+        "i686-uwp-windows-msvc",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: None,
+        },
+    ),
+    (
+        "i686-win7-windows-gnu",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "i686-win7-windows-msvc",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "i686-wrs-vxworks",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "loongarch32-unknown-none",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "loongarch32-unknown-none-softfloat",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "loongarch64-unknown-linux-gnu",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "loongarch64-unknown-linux-musl",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "loongarch64-unknown-linux-ohos",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "loongarch64-unknown-none",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "loongarch64-unknown-none-softfloat",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "m68k-unknown-linux-gnu",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "m68k-unknown-none-elf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "mips-mti-none-elf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: None,
+        },
+    ),
+    (
+        "mips-unknown-linux-gnu",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "mips-unknown-linux-musl",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "mips-unknown-linux-uclibc",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "mips64-openwrt-linux-musl",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "mips64-unknown-linux-gnuabi64",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "mips64-unknown-linux-muslabi64",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "mips64el-unknown-linux-gnuabi64",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "mips64el-unknown-linux-muslabi64",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "mipsel-mti-none-elf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: None,
+        },
+    ),
+    (
+        "mipsel-sony-psp",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "mipsel-sony-psx",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "mipsel-unknown-linux-gnu",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "mipsel-unknown-linux-musl",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "mipsel-unknown-linux-uclibc",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "mipsel-unknown-netbsd",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "mipsel-unknown-none",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "mipsisa32r6-unknown-linux-gnu",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "mipsisa32r6el-unknown-linux-gnu",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "mipsisa64r6-unknown-linux-gnuabi64",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "mipsisa64r6el-unknown-linux-gnuabi64",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "msp430-none-elf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "nvptx64-nvidia-cuda",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "powerpc-unknown-freebsd",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "powerpc-unknown-helenos",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "powerpc-unknown-linux-gnu",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "powerpc-unknown-linux-gnuspe",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "powerpc-unknown-linux-musl",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "powerpc-unknown-linux-muslspe",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "powerpc-unknown-netbsd",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "powerpc-unknown-openbsd",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "powerpc-wrs-vxworks",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "powerpc-wrs-vxworks-spe",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: None,
+        },
+    ),
+    (
+        "powerpc64-ibm-aix",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: None,
+        },
+    ),
+    (
+        "powerpc64-unknown-freebsd",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "powerpc64-unknown-linux-gnu",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "powerpc64-unknown-linux-musl",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "powerpc64-unknown-openbsd",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "powerpc64-wrs-vxworks",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "powerpc64le-unknown-freebsd",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "powerpc64le-unknown-linux-gnu",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "powerpc64le-unknown-linux-musl",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "riscv32-wrs-vxworks",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "riscv32e-unknown-none-elf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "riscv32em-unknown-none-elf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "riscv32emc-unknown-none-elf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "riscv32gc-unknown-linux-gnu",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "riscv32gc-unknown-linux-musl",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "riscv32i-unknown-none-elf",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "riscv32im-risc0-zkvm-elf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: None,
+        },
+    ),
+    (
+        "riscv32im-unknown-none-elf",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "riscv32ima-unknown-none-elf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "riscv32imac-esp-espidf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "riscv32imac-unknown-none-elf",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "riscv32imac-unknown-nuttx-elf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: None,
+            std: Some(true),
+        },
+    ),
+    (
+        "riscv32imac-unknown-xous-elf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: None,
+        },
+    ),
+    (
+        "riscv32imafc-esp-espidf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "riscv32imafc-unknown-none-elf",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "riscv32imafc-unknown-nuttx-elf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: None,
+            std: Some(true),
+        },
+    ),
+    (
+        "riscv32imc-esp-espidf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "riscv32imc-unknown-none-elf",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "riscv32imc-unknown-nuttx-elf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: None,
+            std: Some(true),
+        },
+    ),
+    (
+        "riscv64-linux-android",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "riscv64-wrs-vxworks",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "riscv64a23-unknown-linux-gnu",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "riscv64gc-unknown-freebsd",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "riscv64gc-unknown-fuchsia",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "riscv64gc-unknown-hermit",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "riscv64gc-unknown-linux-gnu",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "riscv64gc-unknown-linux-musl",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "riscv64gc-unknown-managarm-mlibc",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "riscv64gc-unknown-netbsd",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "riscv64gc-unknown-none-elf",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "riscv64gc-unknown-nuttx-elf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: None,
+            std: Some(true),
+        },
+    ),
+    (
+        "riscv64gc-unknown-openbsd",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "riscv64gc-unknown-redox",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "riscv64im-unknown-none-elf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "riscv64imac-unknown-none-elf",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "riscv64imac-unknown-nuttx-elf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: None,
+            std: Some(true),
+        },
+    ),
+    (
+        "s390x-unknown-linux-gnu",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "s390x-unknown-linux-musl",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "s390x-unknown-none-softfloat",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "sparc-unknown-linux-gnu",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "sparc-unknown-none-elf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "sparc64-unknown-helenos",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "sparc64-unknown-linux-gnu",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "sparc64-unknown-netbsd",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "sparc64-unknown-openbsd",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "sparcv9-sun-solaris",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "thumbv4t-none-eabi",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "thumbv5te-none-eabi",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "thumbv6-none-eabi",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "thumbv6m-none-eabi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "thumbv6m-nuttx-eabi",
+        PlatformSupport {
+            tier: 3,
+            host_tools: None,
+            std: Some(true),
+        },
+    ),
+    (
+        "thumbv7a-none-eabi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "thumbv7a-none-eabihf",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "thumbv7a-nuttx-eabi",
+        PlatformSupport {
+            tier: 3,
+            host_tools: None,
+            std: Some(true),
+        },
+    ),
+    (
+        "thumbv7a-nuttx-eabihf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: None,
+            std: Some(true),
+        },
+    ),
+    (
+        "thumbv7a-pc-windows-msvc",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: None,
+        },
+    ),
+    (
+        "thumbv7a-uwp-windows-msvc",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "thumbv7em-none-eabi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "thumbv7em-none-eabihf",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "thumbv7em-nuttx-eabi",
+        PlatformSupport {
+            tier: 3,
+            host_tools: None,
+            std: Some(true),
+        },
+    ),
+    (
+        "thumbv7em-nuttx-eabihf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: None,
+            std: Some(true),
+        },
+    ),
+    (
+        "thumbv7m-none-eabi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "thumbv7m-nuttx-eabi",
+        PlatformSupport {
+            tier: 3,
+            host_tools: None,
+            std: Some(true),
+        },
+    ),
+    (
+        "thumbv7neon-linux-androideabi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "thumbv7neon-unknown-linux-gnueabihf",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "thumbv7neon-unknown-linux-musleabihf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "thumbv7r-none-eabi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "thumbv7r-none-eabihf",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "thumbv8m.base-none-eabi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "thumbv8m.base-nuttx-eabi",
+        PlatformSupport {
+            tier: 3,
+            host_tools: None,
+            std: Some(true),
+        },
+    ),
+    (
+        "thumbv8m.main-none-eabi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "thumbv8m.main-none-eabihf",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "thumbv8m.main-nuttx-eabi",
+        PlatformSupport {
+            tier: 3,
+            host_tools: None,
+            std: Some(true),
+        },
+    ),
+    (
+        "thumbv8m.main-nuttx-eabihf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: None,
+            std: Some(true),
+        },
+    ),
+    (
+        "thumbv8r-none-eabihf",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "wasm32-unknown-emscripten",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "wasm32-unknown-unknown",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "wasm32-wali-linux-musl",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: None,
+        },
+    ),
+    (
+        "wasm32-wasip1",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "wasm32-wasip1-threads",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "wasm32-wasip2",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "wasm32-wasip3",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "wasm32v1-none",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "wasm64-unknown-unknown",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: None,
+        },
+    ),
+    (
+        "x86_64-apple-darwin",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-apple-ios",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-apple-ios-macabi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-apple-tvos",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-apple-watchos-sim",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-fortanix-unknown-sgx",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-linux-android",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-lynx-lynxos178",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "x86_64-pc-cygwin",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-pc-nto-qnx710",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-pc-nto-qnx710_iosock",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-pc-nto-qnx800",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-pc-solaris",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-pc-windows-gnu",
+        PlatformSupport {
+            tier: 1,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-pc-windows-gnullvm",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-pc-windows-msvc",
+        PlatformSupport {
+            tier: 1,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-unikraft-linux-musl",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-unknown-dragonfly",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-unknown-freebsd",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-unknown-fuchsia",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-unknown-haiku",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-unknown-helenos",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-unknown-hermit",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-unknown-hurd-gnu",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-unknown-illumos",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-unknown-l4re-uclibc",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: None,
+        },
+    ),
+    (
+        "x86_64-unknown-linux-gnu",
+        PlatformSupport {
+            tier: 1,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-unknown-linux-gnuasan",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-unknown-linux-gnux32",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-unknown-linux-musl",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-unknown-linux-none",
+        PlatformSupport {
+            tier: 3,
+            host_tools: None,
+            std: Some(false),
+        },
+    ),
+    (
+        "x86_64-unknown-linux-ohos",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-unknown-managarm-mlibc",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "x86_64-unknown-motor",
+        PlatformSupport {
+            tier: 3,
+            host_tools: None,
+            std: None,
+        },
+    ),
+    (
+        "x86_64-unknown-netbsd",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-unknown-none",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "x86_64-unknown-openbsd",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-unknown-redox",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-unknown-trusty",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-unknown-uefi",
+        PlatformSupport {
+            tier: 2,
+            host_tools: Some(false),
+            std: None,
+        },
+    ),
+    (
+        "x86_64-uwp-windows-gnu",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-uwp-windows-msvc",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-win7-windows-gnu",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-win7-windows-msvc",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64-wrs-vxworks",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(true),
+        },
+    ),
+    (
+        "x86_64h-apple-darwin",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(true),
+            std: Some(true),
+        },
+    ),
+    (
+        "xtensa-esp32-espidf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: None,
+            std: None,
+        },
+    ),
+    (
+        "xtensa-esp32-none-elf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "xtensa-esp32s2-espidf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: None,
+            std: None,
+        },
+    ),
+    (
+        "xtensa-esp32s2-none-elf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+    (
+        "xtensa-esp32s3-espidf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: None,
+            std: None,
+        },
+    ),
+    (
+        "xtensa-esp32s3-none-elf",
+        PlatformSupport {
+            tier: 3,
+            host_tools: Some(false),
+            std: Some(false),
+        },
+    ),
+];
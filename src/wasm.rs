@@ -0,0 +1,73 @@
+use crate::{
+    expr::FeatureSet,
+    targets::{get_builtin_target_by_triple, ALL_BUILTINS},
+    Expression,
+};
+use alloc::{borrow::ToOwned, format, string::String, vec::Vec};
+use wasm_bindgen::prelude::*;
+
+fn parse_expr(expr: &str) -> Result<Expression, JsError> {
+    Expression::parse(expr).map_err(|err| JsError::new(&err.to_string()))
+}
+
+/// Parses the expression, returning it in its canonical form
+///
+/// ```
+/// assert_eq!(
+///     cfg_expr::wasm::parse(r#"cfg(all(unix,feature="std"))"#).ok(),
+///     Some(r#"all(unix, feature = "std")"#.to_owned())
+/// );
+/// ```
+#[wasm_bindgen]
+pub fn parse(expr: &str) -> Result<String, JsError> {
+    parse_expr(expr).map(|expr| expr.to_string())
+}
+
+/// Evaluates the expression against the [builtin](ALL_BUILTINS) target with
+/// the triple, with the crate `features` enabled, see
+/// [`Expression::eval_target`]
+///
+/// ```
+/// use cfg_expr::wasm::eval_against_triple;
+///
+/// let expr = r#"all(target_os = "linux", feature = "std")"#;
+///
+/// assert!(eval_against_triple(expr, "x86_64-unknown-linux-gnu", vec!["std".to_owned()]).ok().unwrap());
+/// assert!(!eval_against_triple(expr, "x86_64-pc-windows-msvc", vec!["std".to_owned()]).ok().unwrap());
+/// ```
+#[wasm_bindgen(js_name = evalAgainstTriple)]
+pub fn eval_against_triple(
+    expr: &str,
+    triple: &str,
+    features: Vec<String>,
+) -> Result<bool, JsError> {
+    let expr = parse_expr(expr)?;
+    let target = get_builtin_target_by_triple(triple)
+        .ok_or_else(|| JsError::new(&format!("unknown target triple `{triple}`")))?;
+
+    let features: FeatureSet = features.into_iter().collect();
+    Ok(expr.eval_target(target, &features, &FeatureSet::new()))
+}
+
+/// Finds the triples of the [builtin](ALL_BUILTINS) targets that the
+/// expression matches, in order, see
+/// [`Expression::compile_target_matcher`]
+///
+/// ```
+/// let triples = cfg_expr::wasm::matching_targets(r#"all(target_os = "linux", target_arch = "x86_64")"#)
+///     .ok()
+///     .unwrap();
+///
+/// assert!(triples.contains(&"x86_64-unknown-linux-gnu".to_owned()));
+/// assert!(!triples.contains(&"aarch64-unknown-linux-gnu".to_owned()));
+/// ```
+#[wasm_bindgen(js_name = matchingTargets)]
+pub fn matching_targets(expr: &str) -> Result<Vec<String>, JsError> {
+    let matcher = parse_expr(expr)?.compile_target_matcher();
+
+    Ok(ALL_BUILTINS
+        .iter()
+        .filter(|target| matcher.matches(target))
+        .map(|target| target.triple.as_str().to_owned())
+        .collect())
+}
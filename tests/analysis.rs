@@ -0,0 +1,337 @@
+use cfg_expr::Expression;
+
+fn parse(text: &str) -> Expression {
+    Expression::parse(text).unwrap()
+}
+
+#[test]
+fn implies() {
+    for (a, b) in [
+        ("unix", "unix"),
+        ("unix", "target_family = \"unix\""),
+        ("all(a, b)", "a"),
+        ("a", "any(a, b)"),
+        ("all(a, b)", "any(a, c)"),
+        ("target_os = \"linux\"", "not(target_os = \"windows\")"),
+        (
+            "target_arch = \"x86\"",
+            "not(any(target_arch = \"arm\", target_arch = \"mips\"))",
+        ),
+        (
+            "target_pointer_width = \"64\"",
+            "not(target_pointer_width = \"32\")",
+        ),
+        ("target_endian = \"big\"", "not(target_endian = \"little\")"),
+        ("target_env = \"\"", "not(target_env = \"gnu\")"),
+        ("not(any(a, b))", "not(a)"),
+        ("any()", "a"),
+        ("a", "all()"),
+        ("all(a, not(a))", "b"),
+        ("b", "any(a, not(a))"),
+        ("all(any(a, b), not(b))", "a"),
+        ("all(unix, target_arch = \"x86_64\")", "any(unix, windows)"),
+        ("false", "a"),
+        ("a", "true"),
+        ("all(a, true)", "a"),
+        ("a", "any(a, false)"),
+    ] {
+        assert!(parse(a).implies(&parse(b)), "'{a}' should imply '{b}'");
+    }
+
+    for (a, b) in [
+        ("a", "b"),
+        ("a", "all(a, b)"),
+        ("any(a, b)", "a"),
+        ("not(all(a, b))", "not(a)"),
+        ("all()", "a"),
+        ("a", "any()"),
+        ("true", "a"),
+        ("a", "false"),
+        ("any(unix, windows)", "all(unix, target_arch = \"x86_64\")"),
+        // Predicates are independent of each other, other than single-valued
+        // target properties
+        ("target_os = \"linux\"", "unix"),
+        ("not(target_os = \"windows\")", "target_os = \"linux\""),
+        ("unix", "not(target_family = \"wasm\")"),
+        ("feature = \"a\"", "not(feature = \"b\")"),
+        ("target_feature = \"sse2\"", "not(target_feature = \"avx\")"),
+    ] {
+        assert!(!parse(a).implies(&parse(b)), "'{a}' should not imply '{b}'");
+    }
+}
+
+#[test]
+fn satisfiable_by_builtins() {
+    use cfg_expr::{expr::Unsatisfiable, targets::get_builtin_target_by_triple};
+
+    for text in [
+        "unix",
+        "all(windows, target_env = \"msvc\", target_arch = \"aarch64\")",
+        "all(target_os = \"none\", target_arch = \"arm\", panic = \"abort\")",
+        "all(target_family = \"wasm\", not(unix))",
+        "all(unix, feature = \"foo\", not(test), target_feature = \"sse2\")",
+        "any(all(windows, unix), target_os = \"linux\")",
+    ] {
+        let target = parse(text).is_satisfiable_by_any_builtin();
+        assert!(target.is_ok(), "'{text}' should be satisfiable");
+    }
+
+    for (text, reason) in [
+        (
+            "all(windows, target_os = \"linux\")",
+            Unsatisfiable::NoMatchingTarget,
+        ),
+        (
+            "all(target_pointer_width = \"64\", target_pointer_width = \"32\")",
+            Unsatisfiable::Contradiction,
+        ),
+        (
+            "all(any(target_os = \"linux\", target_os = \"macos\"), target_os = \"windows\")",
+            Unsatisfiable::Contradiction,
+        ),
+        ("target_os = \"not-an-os\"", Unsatisfiable::NoMatchingTarget),
+        ("any()", Unsatisfiable::Contradiction),
+        ("all(unix, not(unix))", Unsatisfiable::Contradiction),
+        (
+            "all(any(a, b), not(a), not(b))",
+            Unsatisfiable::Contradiction,
+        ),
+    ] {
+        assert_eq!(
+            parse(text).is_satisfiable_by_any_builtin(),
+            Err(reason),
+            "{text}"
+        );
+    }
+
+    let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    let windows = get_builtin_target_by_triple("x86_64-pc-windows-msvc").unwrap();
+    let targets = [linux.clone(), windows.clone()];
+
+    assert_eq!(
+        parse("all(unix, feature = \"a\")").is_satisfiable_by_any(&targets),
+        Ok(linux)
+    );
+    assert_eq!(
+        parse("not(target_os = \"linux\")").is_satisfiable_by_any(&targets),
+        Ok(windows)
+    );
+    assert_eq!(
+        parse("target_os = \"macos\"").is_satisfiable_by_any(&targets),
+        Err(Unsatisfiable::NoMatchingTarget)
+    );
+}
+
+#[test]
+fn lints() {
+    use cfg_expr::expr::LintKind::{AlwaysFalse, AlwaysTrue};
+
+    for (text, expected) in [
+        ("all(unix, feature = \"a\")", vec![]),
+        ("any()", vec![]),
+        ("all(a, true, any(false, b))", vec![]),
+        ("any(a, not(true))", vec![(AlwaysFalse, "not(true)")]),
+        ("all(a, any())", vec![(AlwaysFalse, "all(a, any())")]),
+        (
+            "any(unix, not(unix))",
+            vec![(AlwaysTrue, "any(unix, not(unix))")],
+        ),
+        ("any(a, not(a))", vec![(AlwaysTrue, "any(a, not(a))")]),
+        (
+            "all(a, any(unix, windows, not(any(unix, windows))))",
+            vec![(AlwaysTrue, "any(unix, windows, not(any(unix, windows)))")],
+        ),
+        (
+            "all(a, not(all(windows, target_os = \"linux\")))",
+            vec![(AlwaysTrue, "not(all(windows, target_os = \"linux\"))")],
+        ),
+        (
+            "any(a, all(windows, target_os = \"linux\"), target_os = \"nope\")",
+            vec![
+                (AlwaysFalse, "all(windows, target_os = \"linux\")"),
+                (AlwaysFalse, "target_os = \"nope\""),
+            ],
+        ),
+        (
+            "all(target_pointer_width = \"64\", target_pointer_width = \"8\")",
+            vec![(
+                AlwaysFalse,
+                "all(target_pointer_width = \"64\", target_pointer_width = \"8\")",
+            )],
+        ),
+    ] {
+        let expr = parse(text);
+        let lints: Vec<_> = expr
+            .lints()
+            .into_iter()
+            .map(|lint| (lint.kind, &expr.original()[lint.span]))
+            .collect();
+
+        assert_eq!(lints, expected, "{text}");
+    }
+}
+
+#[cfg(feature = "bdd")]
+#[test]
+fn bdds() {
+    use cfg_expr::expr::bdd::{Bdd, Universe};
+
+    let exprs = [
+        "unix",
+        "target_family = \"unix\"",
+        "all(a, b)",
+        "any(a, b)",
+        "any(a, c)",
+        "not(any(a, b))",
+        "not(all(a, b))",
+        "not(a)",
+        "all(any(a, b), not(b))",
+        "target_os = \"linux\"",
+        "not(target_os = \"windows\")",
+        "any(target_os = \"linux\", target_os = \"windows\")",
+        "all(target_os = \"linux\", target_os = \"windows\")",
+        "all(unix, target_arch = \"x86_64\")",
+        "any(unix, windows)",
+        "target_env = \"\"",
+        "not(target_env = \"gnu\")",
+        "all(a, not(a))",
+        "any(a, not(a))",
+        "true",
+        "false",
+    ];
+
+    let mut universe = Universe::new();
+    let compiled = exprs.map(|expr| universe.compile(&parse(expr)));
+
+    for (a, a_bdd) in exprs.iter().zip(compiled) {
+        for (b, b_bdd) in exprs.iter().zip(compiled) {
+            let implies = parse(a).implies(&parse(b));
+            assert_eq!(universe.implies(a_bdd, b_bdd), implies, "'{a}' => '{b}'");
+            assert_eq!(
+                universe.equivalent(a_bdd, b_bdd),
+                implies && parse(b).implies(&parse(a)),
+                "'{a}' <=> '{b}'"
+            );
+        }
+    }
+
+    // Diagrams are canonical, so equivalent expressions without exclusive
+    // predicates are the same diagram
+    let a = universe.compile(&parse("all(any(a, b), any(a, c))"));
+    let b = universe.compile(&parse("any(a, all(b, c))"));
+    assert_eq!(a, b);
+
+    let contradiction = universe.compile(&parse("all(a, not(a))"));
+    assert_eq!(contradiction, Bdd::FALSE);
+    assert!(Bdd::FALSE.is_constant());
+
+    let tautology = universe.compile(&parse("any(a, not(a))"));
+    assert_eq!(tautology, Bdd::TRUE);
+    assert!(universe.is_tautology(tautology));
+
+    // Different values of a single-valued property are only exclusive when
+    // checking assignments
+    let both = universe.compile(&parse(
+        "all(target_os = \"linux\", target_os = \"windows\")",
+    ));
+    assert_ne!(both, Bdd::FALSE);
+    assert!(!universe.is_satisfiable(both));
+    assert!(universe.satisfying_assignment(both).is_none());
+
+    let either = universe.compile(&parse(
+        "all(any(target_os = \"linux\", target_os = \"windows\"), not(target_os = \"linux\"))",
+    ));
+    // The assignment can also include the predicates of other single-valued
+    // properties, as they are constrained too
+    let assignment = universe.satisfying_assignment(either).unwrap();
+    assert_eq!(
+        assignment[..2],
+        [
+            ("target_os = \"linux\"", false),
+            ("target_os = \"windows\"", true)
+        ]
+    );
+
+    assert!(universe
+        .predicates()
+        .any(|pred| pred == "target_env = \"\""));
+    assert!(universe.node_count() > 2);
+}
+
+#[cfg(feature = "sat")]
+#[test]
+fn sat() {
+    let exprs = [
+        "unix",
+        "target_family = \"unix\"",
+        "all(a, b)",
+        "any(a, b)",
+        "any(a, c)",
+        "not(any(a, b))",
+        "not(all(a, b))",
+        "all(any(a, b), not(b))",
+        "target_os = \"linux\"",
+        "not(target_os = \"windows\")",
+        "all(target_os = \"linux\", target_os = \"windows\")",
+        "any(unix, windows)",
+        "all(unix, target_arch = \"x86_64\")",
+        "target_env = \"\"",
+        "not(target_env = \"gnu\")",
+        "all(a, not(a))",
+        "any(a, not(a))",
+        "all()",
+        "any()",
+        "true",
+        "false",
+    ];
+
+    for a in exprs {
+        let a_expr = parse(a);
+        let satisfiable = !a_expr.implies(&parse("false"));
+        assert_eq!(a_expr.is_satisfiable(), satisfiable, "{a}");
+
+        if let Some(assignment) = a_expr.satisfying_assignment() {
+            assert!(
+                a_expr.eval(|pred| assignment
+                    .iter()
+                    .find(|(assigned, _)| assigned == pred)
+                    .map_or(false, |(_, value)| *value)),
+                "{a} {assignment:?}"
+            );
+        }
+
+        for b in exprs {
+            let b_expr = parse(b);
+            assert_eq!(
+                a_expr.implies_sat(&b_expr),
+                a_expr.implies(&b_expr),
+                "'{a}' => '{b}'"
+            );
+            assert_eq!(
+                a_expr.is_equivalent(&b_expr),
+                a_expr.implies(&b_expr) && b_expr.implies(&a_expr),
+                "'{a}' <=> '{b}'"
+            );
+        }
+    }
+
+    // An expression whose DNF would have 2^20 clauses
+    let large = format!(
+        "all({})",
+        (0..20)
+            .map(|i| format!("any(a{i}, b{i})"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    let swapped = format!(
+        "all({})",
+        (0..20)
+            .rev()
+            .map(|i| format!("any(b{i}, a{i})"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    assert!(parse(&large).is_satisfiable());
+    assert!(parse(&large).is_equivalent(&parse(&swapped)));
+    assert!(!parse(&large).implies_sat(&parse("a0")));
+}
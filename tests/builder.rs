@@ -0,0 +1,387 @@
+use cfg_expr::{
+    expr::builder::{all, any, not, pred, try_pred},
+    targets::*,
+    Expression, Predicate as P, TargetPredicate as TP,
+};
+
+#[test]
+fn builds_predicates() {
+    let preds = [
+        P::Target(TP::Abi(Abi::new("eabihf"))),
+        P::Target(TP::Arch(Arch::x86_64)),
+        P::Target(TP::Endian(Endian::big)),
+        P::Target(TP::Env(Env::musl)),
+        P::Target(TP::Family(Family::unix)),
+        P::Target(TP::Family(Family::wasm)),
+        P::Target(TP::HasAtomic(HasAtomic::Pointer)),
+        P::Target(TP::Os(Os::linux)),
+        P::Target(TP::Panic(Panic::abort)),
+        P::Target(TP::PointerWidth(32)),
+        P::Target(TP::Vendor(Vendor::apple)),
+        P::Test,
+        P::DebugAssertions,
+        P::ProcMacro,
+        P::Feature("foo"),
+        P::TargetFeature("sse2"),
+        P::Flag("bare"),
+        P::KeyValue {
+            key: "key",
+            val: "value",
+        },
+    ];
+
+    for p in preds {
+        let built = pred(p.clone());
+        similar_asserts::assert_eq!(built.predicates().collect::<Vec<_>>(), vec![p.clone()]);
+
+        // The built expression is written in canonical form, so the original
+        // string must parse to the exact same predicate
+        assert_eq!(built.original(), built.to_string());
+        let parsed = Expression::parse(built.original()).unwrap();
+        similar_asserts::assert_eq!(parsed.predicates().collect::<Vec<_>>(), vec![p]);
+    }
+}
+
+#[test]
+fn rejects_unparseable_predicates() {
+    for p in [
+        P::Feature(r#"a"b"#),
+        P::TargetFeature(r#"""#),
+        P::Flag("two words"),
+        P::Flag("1st"),
+        P::Flag("all"),
+        P::KeyValue {
+            key: "a-b",
+            val: "c",
+        },
+        P::KeyValue {
+            key: "key",
+            val: r#"",bare,x=""#,
+        },
+    ] {
+        assert!(try_pred(p.clone()).is_err(), "{p:?}");
+        assert!(std::panic::catch_unwind(|| pred(p.clone())).is_err());
+    }
+
+    // Everything that is accepted can be parsed again
+    for p in [
+        P::Feature("a b"),
+        P::Feature(r"a\b"),
+        P::Flag("_x1"),
+        P::KeyValue {
+            key: "key",
+            val: "",
+        },
+    ] {
+        let built = try_pred(p.clone()).unwrap();
+        let parsed = Expression::parse(&built.to_string()).unwrap();
+        assert_eq!(parsed.predicates().collect::<Vec<_>>(), vec![p]);
+    }
+}
+
+#[test]
+fn builds_functions() {
+    let linux = pred(P::Target(TP::Os(Os::linux)));
+    let parsed = Expression::parse(r#"any( target_arch="x86", target_arch = "x86_64",)"#).unwrap();
+
+    let expr = all([
+        linux,
+        parsed,
+        not(pred(P::Feature("nope"))),
+        any([]),
+        all([]),
+    ]);
+    assert_eq!(
+        expr.to_string(),
+        r#"all(target_os = "linux", any(target_arch = "x86", target_arch = "x86_64"), not(feature = "nope"), any(), all())"#
+    );
+    assert_eq!(expr.original(), expr.to_string());
+
+    let x86_linux = get_builtin_target_by_triple("i686-unknown-linux-gnu").unwrap();
+    let x86_windows = get_builtin_target_by_triple("i686-pc-windows-msvc").unwrap();
+
+    let without_any = all([
+        pred(P::Target(TP::Os(Os::linux))),
+        pred(P::Target(TP::Arch(Arch::x86))),
+        not(pred(P::Feature("nope"))),
+    ]);
+
+    let eval = |expr: &Expression, target: &TargetInfo| {
+        expr.eval(|pred| match pred {
+            P::Target(tp) => tp.matches(target),
+            _ => false,
+        })
+    };
+
+    assert!(!eval(&expr, x86_linux));
+    assert!(eval(&without_any, x86_linux));
+    assert!(!eval(&without_any, x86_windows));
+}
+
+#[test]
+fn combinators() {
+    let parse = |s: &str| Expression::parse(s).unwrap();
+
+    let merged = parse("cfg(unix)")
+        .and(parse(
+            "any(target_arch = \"x86\", target_arch = \"x86_64\",)",
+        ))
+        .or(parse("windows").negate());
+
+    assert_eq!(
+        merged.to_string(),
+        r#"any(all(unix, any(target_arch = "x86", target_arch = "x86_64")), not(windows))"#
+    );
+    assert_eq!(merged.original(), merged.to_string());
+
+    let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    let windows = get_builtin_target_by_triple("x86_64-pc-windows-msvc").unwrap();
+    let eval = |target: &TargetInfo| {
+        merged.eval(|pred| match pred {
+            P::Target(tp) => tp.matches(target),
+            _ => false,
+        })
+    };
+
+    assert!(eval(linux));
+    assert!(!eval(windows));
+}
+
+#[test]
+fn map_predicates() {
+    use cfg_expr::expr::Rewrite;
+
+    let expr = Expression::parse(
+        r#"all(unix, not(feature = "a"), any(feature = "b", target_os = "linux"), not(test))"#,
+    )
+    .unwrap();
+
+    let rewritten = |f: &dyn Fn(&P<'_>) -> Rewrite<'static>| expr.map_predicates(f).to_string();
+
+    assert_eq!(rewritten(&|_| Rewrite::Keep), expr.to_string());
+    assert_eq!(
+        rewritten(&|pred| match pred {
+            P::Feature(_) => Rewrite::Drop,
+            _ => Rewrite::Keep,
+        }),
+        r#"all(unix, any(target_os = "linux"), not(test))"#
+    );
+    assert_eq!(
+        rewritten(&|pred| match pred {
+            P::Feature(_) => Rewrite::Constant(true),
+            P::Test => Rewrite::Constant(false),
+            _ => Rewrite::Keep,
+        }),
+        r#"all(unix, not(all()), any(all(), target_os = "linux"), not(any()))"#
+    );
+    assert_eq!(
+        rewritten(&|pred| match pred {
+            P::Target(TP::Os(_)) => Rewrite::Replace(P::Target(TP::Os(Os::macos))),
+            P::Test => Rewrite::Replace(P::Flag("miri")),
+            _ => Rewrite::Keep,
+        }),
+        r#"all(unix, not(feature = "a"), any(feature = "b", target_os = "macos"), not(miri))"#
+    );
+    assert_eq!(rewritten(&|_| Rewrite::Drop), "all()");
+
+    // Functions whose operands are all dropped are dropped as well, rather
+    // than becoming constants
+    let features = Expression::parse(r#"all(unix, any(feature = "b"), not(any(feature = "c")))"#)
+        .unwrap()
+        .map_predicates(|pred| match pred {
+            P::Feature(_) => Rewrite::Drop,
+            _ => Rewrite::Keep,
+        });
+    assert_eq!(features.to_string(), "all(unix)");
+    assert_eq!(
+        Expression::parse("any(all(), feature = \"a\")")
+            .unwrap()
+            .map_predicates(|pred| match pred {
+                P::Feature(_) => Rewrite::Drop,
+                _ => Rewrite::Keep,
+            })
+            .to_string(),
+        "any(all())"
+    );
+    assert_eq!(
+        Expression::parse("not(a)")
+            .unwrap()
+            .map_predicates(|_| Rewrite::Drop)
+            .to_string(),
+        "all()"
+    );
+
+    // Replacements can borrow from the expression being rewritten
+    let renamed = expr.map_predicates(|pred| match pred {
+        P::Feature(feat) => Rewrite::Replace(P::KeyValue {
+            key: "renamed",
+            val: feat,
+        }),
+        _ => Rewrite::Keep,
+    });
+    assert_eq!(
+        renamed.to_string(),
+        r#"all(unix, not(renamed = "a"), any(renamed = "b", target_os = "linux"), not(test))"#
+    );
+}
+
+#[cfg(feature = "codegen")]
+#[test]
+fn codegen() {
+    use quote::{quote, ToTokens};
+
+    // Checks that the expression generates the code, which is also compiled
+    // here to check that it builds the same expression
+    macro_rules! generates {
+        ($text:expr, $($code:tt)*) => {{
+            let expr = Expression::parse($text).unwrap();
+            assert_eq!(
+                expr.to_token_stream().to_string(),
+                quote!($($code)*).to_string(),
+            );
+
+            let built: Expression = $($code)*;
+            assert_eq!(built, expr);
+        }};
+    }
+
+    generates!(
+        r#"all(unix, target_pointer_width = "64", target_has_atomic = "ptr", target_endian = "big", any())"#,
+        ::cfg_expr::expr::builder::all([
+            ::cfg_expr::expr::builder::pred(::cfg_expr::Predicate::Target(
+                ::cfg_expr::TargetPredicate::Family(::cfg_expr::targets::Family::new_const("unix"))
+            )),
+            ::cfg_expr::expr::builder::pred(::cfg_expr::Predicate::Target(
+                ::cfg_expr::TargetPredicate::PointerWidth(64u8)
+            )),
+            ::cfg_expr::expr::builder::pred(::cfg_expr::Predicate::Target(
+                ::cfg_expr::TargetPredicate::HasAtomic(::cfg_expr::targets::HasAtomic::Pointer)
+            )),
+            ::cfg_expr::expr::builder::pred(::cfg_expr::Predicate::Target(
+                ::cfg_expr::TargetPredicate::Endian(::cfg_expr::targets::Endian::big)
+            )),
+            ::cfg_expr::expr::builder::any(::std::iter::empty::<::cfg_expr::Expression>())
+        ])
+    );
+
+    generates!(
+        r#"any(my_key = "v", not(version("1.70")), accessible(std::ptr), false)"#,
+        ::cfg_expr::expr::builder::any([
+            ::cfg_expr::expr::builder::pred(::cfg_expr::Predicate::KeyValue {
+                key: "my_key",
+                val: "v"
+            }),
+            ::cfg_expr::expr::builder::not(::cfg_expr::expr::builder::pred(
+                ::cfg_expr::Predicate::Version(::cfg_expr::expr::RustVersion {
+                    major: 1u16,
+                    minor: 70u16,
+                    patch: ::std::option::Option::None,
+                })
+            )),
+            ::cfg_expr::expr::builder::pred(::cfg_expr::Predicate::Accessible("std::ptr")),
+            ::cfg_expr::expr::builder::pred(::cfg_expr::Predicate::Boolean(false))
+        ])
+    );
+
+    let expr = Expression::parse(r#"cfg(any(windows,target_os = "linux"))"#).unwrap();
+    assert_eq!(
+        expr.to_cfg_tokens().to_string(),
+        quote!(any(windows, target_os = "linux")).to_string()
+    );
+
+    // Values are written as escaped string literals, rather than as is
+    let expr = Expression::parse(
+        r#"all(feature = "a\", not(version("1.70")), accessible(::std::ptr), true, any())"#,
+    )
+    .unwrap();
+    assert_eq!(
+        expr.to_cfg_tokens().to_string(),
+        quote!(all(
+            feature = "a\\",
+            not(version("1.70")),
+            accessible(::std::ptr),
+            true,
+            any()
+        ))
+        .to_string()
+    );
+}
+
+#[cfg(feature = "macros")]
+#[test]
+fn cfg_expr_macro() {
+    for (built, text) in [
+        (
+            cfg_expr::cfg_expr!(r#"cfg(all(unix, feature = "foo"))"#),
+            r#"all(unix, feature = "foo")"#,
+        ),
+        (
+            cfg_expr::cfg_expr!(
+                r#"any(target_os = "linux", not(target(arch = "x86_64", has_atomic = "64")), version("1.70.1"))"#
+            ),
+            r#"any(target_os = "linux", not(all(target_arch = "x86_64", target_has_atomic = "64")), version("1.70.1"))"#,
+        ),
+        (cfg_expr::cfg_expr!("any()"), "any()"),
+        (
+            cfg_expr::cfg_expr!(
+                " cfg ( all(not(accessible(::std::arch::x86_64),), target(os = \"linux\",), windows,) ) "
+            ),
+            r#"all(not(accessible(::std::arch::x86_64)), target(os = "linux"), windows)"#,
+        ),
+        (cfg_expr::cfg_expr!("\u{a0}cfg\u{3000}(unix)\u{2029}"), "unix"),
+    ] {
+        assert_eq!(*built, Expression::parse(text).unwrap());
+    }
+
+    // The expression is only parsed once
+    let cached = || cfg_expr::cfg_expr!("any(unix, windows)");
+    assert!(std::ptr::eq(cached(), cached()));
+}
+
+/// The compile time check uses the same lexer and grammar as the parser, so
+/// it accepts exactly the expressions whose syntax is valid
+#[cfg(feature = "macros")]
+#[test]
+fn cfg_expr_syntax_matches_parser() {
+    for text in [
+        "unix",
+        "cfg(unix)",
+        " cfg ( unix ) ",
+        "\u{85}unix\u{1680}",
+        "all()",
+        "all(unix,)",
+        "all(a), b",
+        r#"target(os = "linux", arch = "x86")"#,
+        r#"version("1.70")"#,
+        "accessible(::core::hint)",
+        "not(not(unix))",
+        "",
+        "cfg()",
+        "a, b",
+        "all",
+        "all(",
+        "all(unix))",
+        "not()",
+        "not(a, b)",
+        "not(a, all())",
+        "(unix)",
+        "unix(",
+        "a =",
+        "a = b",
+        r#"a = "b"#,
+        "a b",
+        "all(,)",
+        "all(a,,)",
+        "target(all(a))",
+        r#"version(1.70)"#,
+        r#"version("1.70", "1.71")"#,
+        "accessible(\"a\")",
+        "é",
+        "all(a é)",
+        "cfg(foo",
+        "foo)",
+    ] {
+        let checked = std::panic::catch_unwind(|| cfg_expr::expr::check_syntax(text)).is_ok();
+        assert_eq!(checked, Expression::parse(text).is_ok(), "{text:?}");
+    }
+}
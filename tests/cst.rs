@@ -0,0 +1,120 @@
+use cfg_expr::expr::{
+    cst::{NodeKind, SyntaxNode, SyntaxTree, TokenKind},
+    lexer::Token,
+};
+
+/// Flattens the tree into the kind and text of each node, in prefix order
+fn nodes<'a>(tree: &SyntaxTree<'a>) -> Vec<(NodeKind, &'a str)> {
+    fn walk<'a>(tree: &SyntaxTree<'a>, node: &SyntaxNode, nodes: &mut Vec<(NodeKind, &'a str)>) {
+        nodes.push((node.kind, tree.node_text(node)));
+        for child in &node.children {
+            walk(tree, child, nodes);
+        }
+    }
+
+    let mut nodes = Vec::new();
+    walk(tree, tree.root(), &mut nodes);
+    nodes
+}
+
+#[test]
+fn lossless() {
+    for text in [
+        "unix",
+        "  unix\t",
+        "cfg(unix)",
+        " cfg ( unix ) ",
+        "all()",
+        "all( )",
+        "all(unix,)",
+        "any(\n    target_os = \"linux\" ,\n    target_os=\"macos\",\n)",
+        "cfg(not( all ( a , b ) ))",
+        "all(version( \"1.70\" ), accessible(::std::ptr), target(os = \"linux\", arch = \"x86_64\"))",
+    ] {
+        let tree = SyntaxTree::parse(text).unwrap();
+        assert_eq!(tree.to_string(), text);
+        assert_eq!(tree.text(), text);
+
+        // Every byte is in exactly one token
+        let mut pos = 0;
+        for tok in tree.tokens() {
+            assert_eq!(tok.span.start, pos, "{text}");
+            assert!(!tok.span.is_empty(), "{text}");
+            pos = tok.span.end;
+        }
+        assert_eq!(pos, text.len());
+    }
+}
+
+#[test]
+fn nodes_and_tokens() {
+    let tree = SyntaxTree::parse(
+        " cfg(all( unix , not(feature = \"a\"), version(\"1.70\"), target(os = \"linux\") ))",
+    )
+    .unwrap();
+
+    assert_eq!(
+        nodes(&tree),
+        [
+            (
+                NodeKind::All,
+                "all( unix , not(feature = \"a\"), version(\"1.70\"), target(os = \"linux\") )"
+            ),
+            (NodeKind::Predicate, "unix"),
+            (NodeKind::Not, "not(feature = \"a\")"),
+            (NodeKind::Predicate, "feature = \"a\""),
+            (NodeKind::Predicate, "version(\"1.70\")"),
+            (NodeKind::Predicate, "target(os = \"linux\")"),
+        ]
+    );
+
+    let kinds: Vec<_> = tree.tokens().iter().take(5).map(|tok| &tok.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            &TokenKind::Whitespace,
+            &TokenKind::Cfg,
+            &TokenKind::Token(Token::OpenParen),
+            &TokenKind::Token(Token::All),
+            &TokenKind::Token(Token::OpenParen),
+        ]
+    );
+
+    let unix = &tree.root().children[0];
+    assert_eq!(
+        tree.tokens()[unix.tokens.clone()]
+            .iter()
+            .map(|tok| &tok.kind)
+            .collect::<Vec<_>>(),
+        [&TokenKind::Token(Token::Key("unix"))]
+    );
+}
+
+#[test]
+fn edits() {
+    // Only valid expressions can be parsed
+    assert!(SyntaxTree::parse("all(\n  target_os = 'x',\n)").is_err());
+
+    let text = "all(\n    feature = \"b\",\n    not( feature = \"a\" ),\n)";
+    let tree = SyntaxTree::parse(text).unwrap();
+
+    let not = &tree.root().children[1];
+    let feature_a = &not.children[0];
+
+    // Only the edited spans change
+    assert_eq!(
+        tree.edit([(feature_a.span.clone(), "feature = \"c\"")]),
+        "all(\n    feature = \"b\",\n    not( feature = \"c\" ),\n)"
+    );
+    assert_eq!(
+        tree.edit([
+            (not.span.clone(), "unix".to_owned()),
+            (tree.root().children[0].span.clone(), "windows".to_owned()),
+        ]),
+        "all(\n    windows,\n    unix,\n)"
+    );
+    assert_eq!(
+        tree.edit(Vec::<(std::ops::Range<usize>, &str)>::new()),
+        text
+    );
+}
@@ -6,7 +6,7 @@ use cfg_expr::{
 
 struct Target {
     builtin: &'static cfg_expr::targets::TargetInfo,
-    #[cfg(feature = "targets")]
+    #[cfg(feature = "target-lexicon")]
     lexicon: Option<target_lexicon::Triple>,
 }
 
@@ -14,7 +14,7 @@ impl Target {
     fn make(s: &str) -> Self {
         Self {
             builtin: get_builtin_target_by_triple(s).unwrap(),
-            #[cfg(feature = "targets")]
+            #[cfg(feature = "target-lexicon")]
             lexicon: {
                 // Hack to workaround the addition in 1.48.0 of this weird, non-conformant
                 // target triple, until https://github.com/bytecodealliance/target-lexicon/issues/63 is
@@ -29,6 +29,16 @@ impl Target {
                         environment: tl::Environment::Unknown,
                         binary_format: tl::BinaryFormat::Unknown,
                     }),
+                    // target-lexicon doesn't know these operating systems
+                    // and vendors, and parses them as a different component
+                    triple
+                        if triple.contains("-nuttx-")
+                            || triple.starts_with("armv7-rtems-")
+                            || triple.starts_with("wasm32-wali-")
+                            || triple == "wasm32-wasip3" =>
+                    {
+                        None
+                    }
                     triple => match triple.parse::<tl::Triple>() {
                         Ok(l) => Some(l),
                         Err(e) => {
@@ -51,9 +61,20 @@ macro_rules! tg_match {
             Predicate::Target(tg) => {
                 let tinfo = tg.matches($target.builtin);
 
-                #[cfg(feature = "targets")]
-                if !matches!(tg, TargetPredicate::HasAtomic(_))
-                    && !matches!(tg, TargetPredicate::Panic(_))
+                let owned = cfg_expr::targets::TargetInfoOwned::from($target.builtin);
+                assert_eq!(
+                    tinfo,
+                    tg.matches(&owned),
+                    "owned target didn't match builtin for predicate {tg:#?}"
+                );
+
+                #[cfg(feature = "target-lexicon")]
+                if !matches!(
+                    tg,
+                    TargetPredicate::HasAtomic(_)
+                        | TargetPredicate::HasAtomicLoadStore(_)
+                        | TargetPredicate::HasAtomicEqualAlignment(_)
+                ) && !matches!(tg, TargetPredicate::Panic(_))
                 {
                     if let Some(l) = &$target.lexicon {
                         let linfo = tg.matches(l);
@@ -78,9 +99,13 @@ macro_rules! tg_match {
             Predicate::Target(tg) => {
                 let tinfo = tg.matches($target.builtin);
 
-                #[cfg(feature = "targets")]
-                if !matches!(tg, TargetPredicate::HasAtomic(_))
-                    && !matches!(tg, TargetPredicate::Panic(_))
+                #[cfg(feature = "target-lexicon")]
+                if !matches!(
+                    tg,
+                    TargetPredicate::HasAtomic(_)
+                        | TargetPredicate::HasAtomicLoadStore(_)
+                        | TargetPredicate::HasAtomicEqualAlignment(_)
+                ) && !matches!(tg, TargetPredicate::Panic(_))
                 {
                     if let Some(l) = &$target.lexicon {
                         let linfo = tg.matches(l);
@@ -160,13 +185,17 @@ fn very_specific() {
         let t = Target::make(target.triple.as_str());
         assert_eq!(
             target.triple.as_str() == "i686-pc-windows-msvc"
-                || target.triple.as_str() == "i586-pc-windows-msvc",
+                || target.triple.as_str() == "i686-win7-windows-msvc",
             specific.eval(|pred| { tg_match!(pred, t, &["fxsr", "sse", "sse2"]) }),
             "expected true for i686-pc-windows-msvc, but got true for {}",
             target.triple,
         );
     }
 
+    // The removed i586 target resolves to its i686 replacement
+    let i586 = Target::make("i586-pc-windows-msvc");
+    assert!(specific.eval(|pred| { tg_match!(pred, i586, &["fxsr", "sse", "sse2"]) }));
+
     for target in all {
         let expr = format!(
             r#"cfg(
@@ -260,7 +289,7 @@ fn complex() {
 }
 
 #[test]
-fn unstable_target_abi() {
+fn target_abi() {
     let linux_gnu = Target::make("x86_64-unknown-linux-gnu");
     let linux_musl = Target::make("x86_64-unknown-linux-musl");
     let windows_msvc = Target::make("x86_64-pc-windows-msvc");
@@ -278,6 +307,8 @@ fn unstable_target_abi() {
         pointer_width: 64,
         endian: cfg_expr::targets::Endian::little,
         has_atomics: cfg_expr::targets::HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_load_store: cfg_expr::targets::HasAtomics::atomic_8_16_32_64_128_ptr,
+        has_atomics_equal_alignment: cfg_expr::targets::HasAtomics::atomic_8_16_32_64_128_ptr,
         panic: cfg_expr::targets::Panic::unwind,
     };
 
@@ -305,12 +336,83 @@ fn unstable_target_abi() {
         }
     }));
 
-    // Should *not* match any builtins at this point because target_abi isn't stable
+    // Should *not* match builtins without an abi
     assert!(!abi_pred.eval(|pred| tg_match!(pred, linux_gnu)));
     assert!(!abi_pred.eval(|pred| tg_match!(pred, linux_musl)));
     assert!(!abi_pred.eval(|pred| tg_match!(pred, mac)));
     assert!(!abi_pred.eval(|pred| tg_match!(pred, windows_msvc)));
     assert!(!abi_pred.eval(|pred| tg_match!(pred, android)));
+
+    // Should match builtins with the abi
+    let armv7_gnueabihf = Target::make("armv7-unknown-linux-gnueabihf");
+    assert!(abi_pred.eval(|pred| tg_match!(pred, armv7_gnueabihf)));
+
+    let sim = Expression::parse(r#"target_abi = "sim""#).unwrap();
+    let ios_sim = Target::make("aarch64-apple-ios-sim");
+    let ios_x86 = Target::make("x86_64-apple-ios");
+    let ios = Target::make("aarch64-apple-ios");
+    assert!(sim.eval(|pred| tg_match!(pred, ios_sim)));
+    assert!(sim.eval(|pred| tg_match!(pred, ios_x86)));
+    assert!(!sim.eval(|pred| tg_match!(pred, ios)));
+
+    // An empty abi matches targets without one
+    let no_abi = Expression::parse(r#"target_abi = """#).unwrap();
+    assert!(no_abi.eval(|pred| tg_match!(pred, linux_gnu)));
+    assert!(!no_abi.eval(|pred| tg_match!(pred, armv7_gnueabihf)));
+}
+
+#[test]
+fn atomics() {
+    let cas = Expression::parse(r#"target_has_atomic = "32""#).unwrap();
+    let load_store = Expression::parse(r#"target_has_atomic_load_store = "32""#).unwrap();
+    let aligned_64 = Expression::parse(r#"target_has_atomic_equal_alignment = "64""#).unwrap();
+
+    // Targets without compare-and-swap can still have atomic loads and stores
+    let thumbv6m = Target::make("thumbv6m-none-eabi");
+    assert!(!cas.eval(|pred| tg_match!(pred, thumbv6m)));
+    assert!(load_store.eval(|pred| tg_match!(pred, thumbv6m)));
+
+    let x86_64 = Target::make("x86_64-unknown-linux-gnu");
+    assert!(cas.eval(|pred| tg_match!(pred, x86_64)));
+    assert!(load_store.eval(|pred| tg_match!(pred, x86_64)));
+    assert!(aligned_64.eval(|pred| tg_match!(pred, x86_64)));
+
+    // 64-bit integers are only 4-byte aligned on 32-bit x86 linux
+    let i686 = Target::make("i686-unknown-linux-gnu");
+    assert!(!aligned_64.eval(|pred| tg_match!(pred, i686)));
+}
+
+#[test]
+fn panic_strategy() {
+    let abort = Expression::parse(r#"all(panic = "abort", not(panic = "unwind"))"#).unwrap();
+
+    for (triple, expected) in [
+        ("x86_64-unknown-linux-gnu", false),
+        ("aarch64-apple-darwin", false),
+        ("thumbv6m-none-eabi", true),
+        ("armv7a-none-eabi", true),
+        ("wasm32-unknown-unknown", true),
+    ] {
+        let target = Target::make(triple);
+        assert_eq!(
+            abort.eval(|pred| tg_match!(pred, target)),
+            expected,
+            "{triple}"
+        );
+    }
+
+    // Every builtin has exactly one of the two strategies
+    let either = Expression::parse(r#"any(panic = "abort", panic = "unwind")"#).unwrap();
+    for target in all {
+        assert!(
+            either.eval(|pred| match pred {
+                Predicate::Target(tp) => tp.matches(target),
+                _ => false,
+            }),
+            "{}",
+            target.triple
+        );
+    }
 }
 
 #[test]
@@ -321,6 +423,7 @@ fn wasm_family() {
     let wasm32_unknown = Target::make("wasm32-unknown-unknown");
     let wasm32_emscripten = Target::make("wasm32-unknown-emscripten");
     let wasm32_wasi = Target::make("wasm32-wasi");
+    let wasm32_wasip2 = Target::make("wasm32-wasip2");
     let wasm64_unknown = Target::make("wasm64-unknown-unknown");
 
     // All of the above targets match.
@@ -328,6 +431,7 @@ fn wasm_family() {
     assert!(wasm.eval(|pred| tg_match!(pred, wasm32_unknown)));
     assert!(wasm.eval(|pred| tg_match!(pred, wasm32_emscripten)));
     assert!(wasm.eval(|pred| tg_match!(pred, wasm32_wasi)));
+    assert!(wasm.eval(|pred| tg_match!(pred, wasm32_wasip2)));
     assert!(wasm.eval(|pred| tg_match!(pred, wasm64_unknown)));
 }
 
@@ -382,3 +486,1434 @@ fn features() {
         "all() with Some(true) and None evaluates to None"
     );
 }
+
+#[test]
+fn default_target_features() {
+    let simd = Expression::parse(
+        r#"any(all(target_arch = "x86_64", target_feature = "sse2"), all(target_arch = "aarch64", target_feature = "neon"))"#,
+    )
+    .unwrap();
+
+    for (triple, expected) in [
+        ("x86_64-unknown-linux-gnu", true),
+        ("aarch64-apple-darwin", true),
+        ("i686-pc-windows-msvc", false),
+        ("armv7-unknown-linux-gnueabihf", false),
+    ] {
+        let ti = get_builtin_target_by_triple(triple).unwrap();
+
+        assert_eq!(
+            simd.eval(|pred| match pred {
+                Predicate::Target(tp) => tp.matches(ti),
+                Predicate::TargetFeature(feat) => ti.has_default_feature(feat),
+                _ => false,
+            }),
+            expected,
+            "{triple}"
+        );
+    }
+}
+
+#[test]
+fn implied_target_features() {
+    use cfg_expr::targets::{expand_features, Arch};
+
+    let expr =
+        Expression::parse(r#"all(target_feature = "sse4.2", target_feature = "avx")"#).unwrap();
+
+    let enabled = expand_features(&Arch::x86_64, &["avx2", "fma"]);
+    assert!(expr.eval(|pred| match pred {
+        Predicate::TargetFeature(feat) => enabled.contains(feat),
+        _ => false,
+    }));
+
+    for window in enabled.windows(2) {
+        assert!(window[0] < window[1]);
+    }
+
+    // Implications are specific to each arch
+    assert_eq!(expand_features(&Arch::aarch64, &["avx2"]), ["avx2"]);
+    assert!(expand_features(&Arch::aarch64, &["sve2"]).contains(&"sve"));
+}
+
+#[test]
+fn eval_target() {
+    use cfg_expr::expr::FeatureSet;
+
+    let expr = Expression::parse(
+        r#"all(unix, any(feature = "std", feature = "alloc"), target_feature = "avx", not(debug_assertions))"#,
+    )
+    .unwrap();
+
+    let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    let windows = get_builtin_target_by_triple("x86_64-pc-windows-msvc").unwrap();
+
+    let std: FeatureSet = ["std"].into_iter().collect();
+    let avx2: FeatureSet = ["avx2"].into_iter().collect();
+
+    assert!(expr.eval_target(linux, &std, &avx2));
+    assert!(!expr.eval_target(windows, &std, &avx2));
+    assert!(!expr.eval_target(linux, &FeatureSet::new(), &avx2));
+    assert!(!expr.eval_target(linux, &std, &FeatureSet::new()));
+
+    // Default target features are always enabled
+    let sse2 = Expression::parse(r#"target_feature = "sse2""#).unwrap();
+    assert!(sse2.eval_target(linux, &FeatureSet::new(), &FeatureSet::new()));
+
+    // Build-specific cfgs are never set
+    let test = Expression::parse("any(test, debug_assertions, custom)").unwrap();
+    assert!(!test.eval_target(linux, &std, &avx2));
+}
+
+#[test]
+fn cfg_sets() {
+    use cfg_expr::{
+        error::Reason,
+        expr::{CfgSet, FeatureSet},
+    };
+
+    // Every cfg of a target matches the target
+    for ti in all {
+        let cfgs = CfgSet::for_target(ti, &FeatureSet::new(), &FeatureSet::new());
+
+        for (name, value) in cfgs.iter() {
+            let expr = match value {
+                Some(value) => format!(r#"{name} = "{value}""#),
+                None => name.to_owned(),
+            };
+            let expr = Expression::parse(&expr).unwrap();
+
+            assert!(
+                expr.eval(|pred| match pred {
+                    Predicate::Target(tp) => tp.matches(ti),
+                    Predicate::TargetFeature(feat) => ti.has_default_feature(feat),
+                    _ => false,
+                }),
+                "{} {expr}",
+                ti.triple
+            );
+            assert!(expr.eval_cfg_set(&cfgs));
+        }
+    }
+
+    let mut cfgs = CfgSet::new();
+    cfgs.insert_cfg("test").unwrap();
+    cfgs.insert_cfg(r#"foo="bar""#).unwrap();
+    cfgs.insert_cfg(r#"foo = "baz""#).unwrap();
+
+    assert_eq!(
+        cfgs.iter().collect::<Vec<_>>(),
+        [("foo", Some("bar")), ("foo", Some("baz")), ("test", None)]
+    );
+
+    let expr = Expression::parse(r#"all(test, foo = "bar", foo = "baz", not(foo))"#).unwrap();
+    assert!(expr.eval_cfg_set(&cfgs));
+
+    cfgs.set("test", false);
+    assert!(!expr.eval_cfg_set(&cfgs));
+
+    // A name can be both bare and have values
+    assert!(cfgs.insert("foo", None));
+    assert!(!cfgs.insert("foo", None));
+    assert!(!cfgs.insert("foo", Some("bar")));
+    assert_eq!(
+        cfgs.iter().collect::<Vec<_>>(),
+        [("foo", None), ("foo", Some("bar")), ("foo", Some("baz"))]
+    );
+    assert_eq!(cfgs.len(), 3);
+
+    assert!(cfgs.remove("foo", Some("bar")));
+    assert!(!cfgs.remove("foo", Some("bar")));
+    assert!(cfgs.remove("foo", None));
+    assert!(cfgs.remove("foo", Some("baz")));
+    assert!(!cfgs.remove("nope", None));
+    assert!(cfgs.is_empty());
+    assert_eq!(cfgs, CfgSet::new());
+
+    for (cfg, reason) in [
+        ("", Reason::Empty),
+        ("foo=", Reason::Unexpected(&["\"<value>\""])),
+        ("foo bar", Reason::Unexpected(&["="])),
+        (r#""bar""#, Reason::Unexpected(&["<name>"])),
+        (r#"foo="bar", baz"#, Reason::MultipleRootPredicates),
+        ("cfg(foo)", Reason::Unexpected(&["="])),
+    ] {
+        assert_eq!(
+            CfgSet::new().insert_cfg(cfg).unwrap_err().reason,
+            reason,
+            "{cfg}"
+        );
+    }
+}
+
+#[test]
+fn parses_rustc_print_cfg() {
+    use cfg_expr::expr::{CfgSet, FeatureSet};
+
+    let output = r#"debug_assertions
+panic="unwind"
+target_abi=""
+target_arch="x86_64"
+target_endian="little"
+target_env="gnu"
+target_family="unix"
+target_feature="fxsr"
+target_feature="sse"
+target_feature="sse2"
+target_feature="x87"
+target_has_atomic="16"
+target_has_atomic="32"
+target_has_atomic="64"
+target_has_atomic="8"
+target_has_atomic="ptr"
+target_has_atomic_equal_alignment="16"
+target_has_atomic_equal_alignment="32"
+target_has_atomic_equal_alignment="64"
+target_has_atomic_equal_alignment="8"
+target_has_atomic_equal_alignment="ptr"
+target_has_atomic_load_store="16"
+target_has_atomic_load_store="32"
+target_has_atomic_load_store="64"
+target_has_atomic_load_store="8"
+target_has_atomic_load_store="ptr"
+target_os="linux"
+target_pointer_width="64"
+target_vendor="unknown"
+unix
+"#;
+
+    let mut cfgs = CfgSet::from_rustc_print_cfg(output).unwrap();
+    assert!(cfgs.contains("debug_assertions", None));
+    assert!(cfgs.contains("target_feature", Some("sse2")));
+
+    // The same cfgs as the builtin target, apart from the build specific ones
+    cfgs.set("debug_assertions", false);
+    let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    assert_eq!(
+        cfgs,
+        CfgSet::for_target(linux, &FeatureSet::new(), &FeatureSet::new())
+    );
+
+    let err = CfgSet::from_rustc_print_cfg("unix\ntarget_os=linux\n").unwrap_err();
+    assert_eq!(&err.original[err.span], "linux");
+
+    // A cfg() isn't stripped, so the span isn't shifted by it
+    let err = CfgSet::from_rustc_print_cfg("unix\ncfg(foo = bar)\n").unwrap_err();
+    assert_eq!(&err.original[err.span], "(");
+}
+
+#[test]
+fn cargo_env_vars() {
+    use cfg_expr::expr::{CfgSet, FeatureSet};
+    use std::collections::BTreeMap;
+
+    // Cargo sets a variable for each cfg name, with the values joined by ,
+    for ti in all {
+        let features: FeatureSet = ["std"].into_iter().collect();
+        let expected = CfgSet::for_target(ti, &features, &FeatureSet::new());
+
+        let mut vars = BTreeMap::<String, Vec<&str>>::new();
+        for (name, value) in expected.iter() {
+            vars.entry(format!("CARGO_CFG_{}", name.to_uppercase()))
+                .or_default()
+                .extend(value);
+        }
+
+        let vars = vars.into_iter().map(|(key, vals)| (key, vals.join(",")));
+        assert_eq!(CfgSet::from_cargo_env_vars(vars), expected, "{}", ti.triple);
+    }
+
+    // The exact feature names are preferred over the mangled ones
+    let cfgs = CfgSet::from_cargo_env_vars([
+        ("CARGO_FEATURE_SERDE_JSON", "1"),
+        ("CARGO_CFG_FEATURE", "serde-json"),
+    ]);
+    assert_eq!(
+        cfgs.iter().collect::<Vec<_>>(),
+        [("feature", Some("serde-json"))]
+    );
+
+    let cfgs = CfgSet::from_cargo_env_vars([("CARGO_FEATURE_SERDE_JSON", "1")]);
+    assert_eq!(
+        cfgs.iter().collect::<Vec<_>>(),
+        [("feature", Some("serde_json"))]
+    );
+}
+
+#[test]
+fn platforms() {
+    use cfg_expr::{
+        error::Reason,
+        expr::{CfgSet, FeatureSet, Platform},
+    };
+
+    let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    let none = FeatureSet::new();
+
+    for (platform, expected) in [
+        ("x86_64-unknown-linux-gnu", true),
+        (" x86_64-unknown-linux-gnu ", true),
+        ("x86_64-unknown-linux-musl", false),
+        ("cfg(unix)", true),
+        (r#"cfg (target_os = "linux")"#, true),
+        ("cfg(windows)", false),
+        // Features aren't set for platforms
+        (r#"cfg(not(feature = "std"))"#, true),
+    ] {
+        let platform = Platform::parse(platform).unwrap();
+        assert_eq!(platform.matches(linux, &none), expected, "{platform}");
+
+        let cfgs = CfgSet::for_target(linux, &none, &none);
+        assert_eq!(
+            platform.matches_cfg_set("x86_64-unknown-linux-gnu", &cfgs),
+            expected,
+            "{platform}"
+        );
+    }
+
+    assert_eq!(
+        Platform::parse("x86_64-unknown-linux-gnu")
+            .unwrap()
+            .to_string(),
+        "x86_64-unknown-linux-gnu"
+    );
+    assert_eq!(
+        Platform::parse("cfg(all(unix,target_pointer_width=\"64\"))")
+            .unwrap()
+            .to_string(),
+        r#"cfg(all(unix, target_pointer_width = "64"))"#
+    );
+
+    for (platform, reason) in [
+        ("", Reason::Empty),
+        ("x86_64 linux", Reason::InvalidCharacters),
+        ("unix)", Reason::InvalidCharacters),
+    ] {
+        let err = Platform::parse(platform).unwrap_err();
+        assert_eq!(err.reason, reason, "{platform}");
+    }
+
+    assert!(Platform::parse("cfg(unix").is_err());
+}
+
+#[test]
+fn eval_options() {
+    use cfg_expr::expr::{CfgSet, EvalOptions, Platform};
+
+    let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+
+    // The output of `rustc --print cfg`, which is what cargo matches against
+    let print_cfg = CfgSet::from_rustc_print_cfg(
+        r#"debug_assertions
+panic="unwind"
+target_abi=""
+target_arch="x86_64"
+target_endian="little"
+target_env="gnu"
+target_family="unix"
+target_feature="fxsr"
+target_feature="sse"
+target_feature="sse2"
+target_has_atomic="16"
+target_has_atomic="32"
+target_has_atomic="64"
+target_has_atomic="8"
+target_has_atomic="ptr"
+target_os="linux"
+target_pointer_width="64"
+target_vendor="unknown"
+unix
+"#,
+    )
+    .unwrap();
+
+    let cargo = EvalOptions::cargo_compat();
+
+    // The target also has cfgs that are only printed by nightly rustc
+    let cfgs = cargo.cfg_set(linux);
+    for (name, value) in print_cfg.iter() {
+        assert!(cfgs.contains(name, value), "{name} {value:?}");
+    }
+
+    for (expr, expected) in [
+        ("debug_assertions", true),
+        ("not(debug_assertions)", false),
+        ("test", false),
+        ("proc_macro", false),
+        ("doc", false),
+        (r#"feature = "std""#, false),
+        (r#"not(feature = "std")"#, true),
+        ("tokio_unstable", false),
+        (r#"all(unix, target_feature = "sse2")"#, true),
+        (r#"target_feature = "avx2""#, false),
+    ] {
+        let expr = Expression::parse(expr).unwrap();
+        assert_eq!(expr.eval_with(linux, &cargo), expected, "{expr}");
+        assert_eq!(expr.eval_cfg_set(&print_cfg), expected, "{expr}");
+    }
+
+    let options = EvalOptions {
+        crate_features: ["std"].into_iter().collect(),
+        target_features: ["avx2"].into_iter().collect(),
+        test: true,
+        cfgs: {
+            let mut cfgs = CfgSet::new();
+            cfgs.insert_cfg("tokio_unstable").unwrap();
+            cfgs
+        },
+        ..EvalOptions::default()
+    };
+
+    for (expr, expected) in [
+        ("debug_assertions", false),
+        ("test", true),
+        (r#"feature = "std""#, true),
+        ("tokio_unstable", true),
+        // Implied by avx2
+        (r#"target_feature = "sse4.2""#, true),
+    ] {
+        let expr = Expression::parse(expr).unwrap();
+        assert_eq!(expr.eval_with(linux, &options), expected, "{expr}");
+    }
+
+    let platform = Platform::parse("cfg(debug_assertions)").unwrap();
+    assert!(platform.matches_with(linux, &cargo));
+    assert!(!platform.matches_with(linux, &EvalOptions::default()));
+}
+
+#[test]
+fn eval_context() {
+    use cfg_expr::expr::{DependencyKind, EvalContext, EvalOptions, Platform};
+
+    let host = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    let target = get_builtin_target_by_triple("wasm32-unknown-unknown").unwrap();
+    let options = EvalOptions::cargo_compat();
+
+    let unix = Expression::parse("unix").unwrap();
+    let wasm = Platform::parse(r#"cfg(target_arch = "wasm32")"#).unwrap();
+    let host_triple = Platform::parse("x86_64-unknown-linux-gnu").unwrap();
+
+    let check = |ctx: EvalContext<'_>, is_host: bool| {
+        assert_eq!(ctx.is_host(), is_host, "{ctx:?}");
+        assert_eq!(ctx.compile_target(), if is_host { host } else { target });
+        assert_eq!(ctx.eval(&unix, &options), is_host);
+        assert_eq!(ctx.matches(&wasm, &options), !is_host);
+        assert_eq!(ctx.matches(&host_triple, &options), is_host);
+    };
+
+    let normal = EvalContext::new(host, target, DependencyKind::Normal);
+    let dev = EvalContext::new(host, target, DependencyKind::Development);
+    let build = EvalContext::new(host, target, DependencyKind::Build);
+
+    check(normal, false);
+    check(dev, false);
+    check(build, true);
+
+    // Dependencies of a normal dependency follow their own kind
+    check(normal.transitive(false, DependencyKind::Normal), false);
+    check(normal.transitive(false, DependencyKind::Build), true);
+
+    // Every dependency of a proc-macro or build dependency is for the host
+    check(normal.transitive(true, DependencyKind::Normal), true);
+    check(build.transitive(false, DependencyKind::Normal), true);
+    check(
+        build
+            .transitive(false, DependencyKind::Normal)
+            .transitive(false, DependencyKind::Normal),
+        true,
+    );
+
+    // Without cross compiling, the host and the target are the same
+    let native = EvalContext::new(host, host, DependencyKind::Build);
+    assert!(native.eval(&unix, &options));
+    assert!(EvalContext::new(host, host, DependencyKind::Normal).eval(&unix, &options));
+}
+
+#[test]
+fn cargo_target_keys() {
+    use cfg_expr::{error::Reason, expr::Platform, targets::parse_cargo_target_key};
+
+    let cfg = |expr: &str| Platform::Cfg(Expression::parse(expr).unwrap());
+    let triple = |triple: &str| Platform::Triple(triple.to_owned());
+
+    for (key, expected) in [
+        (
+            "x86_64-unknown-linux-gnu",
+            triple("x86_64-unknown-linux-gnu"),
+        ),
+        (" thumbv7em-none-eabihf\t", triple("thumbv7em-none-eabihf")),
+        ("'x86_64-pc-windows-msvc'", triple("x86_64-pc-windows-msvc")),
+        ("'wasm32-wasip1.json'", triple("wasm32-wasip1.json")),
+        ("'cfg(unix)'", cfg("unix")),
+        (
+            r#"'cfg(target_os = "linux")'"#,
+            cfg(r#"target_os = "linux""#),
+        ),
+        (
+            r#""cfg(target_os = \"linux\")""#,
+            cfg(r#"target_os = "linux""#),
+        ),
+        (
+            r#""cfg(all(unix,\tfeature = \"a\"))""#,
+            cfg(r#"all(unix, feature = "a")"#),
+        ),
+        // Backslashes aren't escapes in literal strings
+        (r#"'cfg(my_key = "a\b")'"#, cfg(r#"my_key = "a\b""#)),
+    ] {
+        assert_eq!(parse_cargo_target_key(key).unwrap(), expected, "{key}");
+    }
+
+    for (key, span, reason) in [
+        ("", 0..0, Reason::Empty),
+        ("  ", 0..2, Reason::Empty),
+        ("cfg(unix)", 3..4, Reason::InvalidCharacters),
+        // Dots separate the parts of a dotted key
+        ("wasm32-wasip1.json", 13..14, Reason::InvalidCharacters),
+        ("'cfg(unix)", 0..1, Reason::UnclosedQuotes),
+        ("'cfg(unix)\n'", 0..1, Reason::UnclosedQuotes),
+        (r#""cfg(unix)\""#, 0..1, Reason::UnclosedQuotes),
+        (r#""cfg(\q)""#, 5..7, Reason::InvalidCharacters),
+        (r#""cfg(\u00zz)""#, 5..7, Reason::InvalidCharacters),
+        ("'cfg(unix)' extra", 11..17, Reason::InvalidCharacters),
+        ("''", 0..0, Reason::Empty),
+    ] {
+        let err = parse_cargo_target_key(key).unwrap_err();
+        assert_eq!((err.span, err.reason), (span, reason), "{key}");
+    }
+
+    // Errors in the platform itself point at the unquoted platform
+    let err = parse_cargo_target_key(r#""cfg(all(unix, \"a\"))""#).unwrap_err();
+    assert_eq!(err.original, r#"cfg(all(unix, "a"))"#);
+}
+
+#[test]
+fn matching_builtin_targets() {
+    let expr =
+        Expression::parse(r#"all(target_os = "windows", target_env = "msvc", feature = "std")"#)
+            .unwrap();
+
+    let matching = expr
+        .matching_builtin_targets(|_, pred| matches!(pred, Predicate::Feature("std")))
+        .collect::<Vec<_>>();
+
+    assert!(!matching.is_empty());
+    for ti in all {
+        let is_msvc = ti.os == Some(cfg_expr::targets::Os::windows)
+            && ti.env == Some(cfg_expr::targets::Env::msvc);
+        assert_eq!(
+            matching.iter().any(|m| m.triple == ti.triple),
+            is_msvc,
+            "{}",
+            ti.triple
+        );
+    }
+
+    // No targets match without the feature
+    assert_eq!(expr.matching_builtin_targets(|_, _| false).count(), 0);
+}
+
+#[test]
+fn explains_evaluation() {
+    use cfg_expr::expr::ExplainedNode;
+
+    let expr =
+        Expression::parse(r#"any(windows, all(unix, not(target_env = "gnu")), feature = "force")"#)
+            .unwrap();
+
+    let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    let explanation = expr.explain(linux, |pred| *pred == Predicate::Feature("force"));
+
+    // The result is the same as evaluating it
+    assert!(explanation.value);
+    assert_eq!(explanation.span, 0..expr.original().len());
+
+    let causes = explanation
+        .causes()
+        .iter()
+        .map(|ex| (ex.text, ex.value))
+        .collect::<Vec<_>>();
+    assert_eq!(causes, [(r#"feature = "force""#, true)]);
+
+    let operands = match &explanation.node {
+        ExplainedNode::Any(operands) => operands,
+        other => panic!("unexpected node {other:?}"),
+    };
+    assert_eq!(operands.len(), 3);
+    assert!(!operands[1].value);
+    assert_eq!(&expr.original()[operands[1].span.clone()], operands[1].text);
+
+    // The predicate under the not() is true, which made the all() false
+    let causes = operands[1].causes();
+    assert_eq!(causes.len(), 1);
+    assert_eq!(causes[0].text, r#"target_env = "gnu""#);
+    assert!(causes[0].value);
+
+    let musl = get_builtin_target_by_triple("x86_64-unknown-linux-musl").unwrap();
+    let explanation = expr.explain(musl, |_| false);
+    assert!(explanation.value);
+    assert_eq!(
+        explanation.to_string(),
+        r#"true  any(windows, all(unix, not(target_env = "gnu")), feature = "force")
+  false windows
+  true  all(unix, not(target_env = "gnu"))
+    true  unix
+    true  not(target_env = "gnu")
+      false target_env = "gnu"
+  false feature = "force"
+"#
+    );
+}
+
+#[test]
+fn failing_cores() {
+    let gnu = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+
+    for (expr, core) in [
+        // The smallest of the false operands is chosen
+        (
+            r#"all(any(windows, target_os = "macos"), target_env = "musl", test)"#,
+            Some(r#"target_env = "musl""#),
+        ),
+        // A true predicate under not() forces it to be false
+        (
+            r#"all(test, not(all(unix, target_env = "gnu")))"#,
+            Some(r#"all(unix, target_env = "gnu")"#),
+        ),
+        (r#"not(any(windows, unix))"#, Some("unix")),
+        (
+            r#"any(all(target_os = "macos"))"#,
+            Some(r#"target_os = "macos""#),
+        ),
+        ("false", Some("false")),
+        // True for the target
+        ("unix", None),
+        // Depends on the feature
+        (r#"any(windows, feature = "foo")"#, None),
+        (r#"all(unix, not(feature = "foo"))"#, None),
+    ] {
+        let actual = Expression::parse(expr)
+            .unwrap()
+            .failing_core(gnu)
+            .map(|ex| ex.text.to_owned());
+        assert_eq!(actual.as_deref(), core, "{expr}");
+    }
+}
+
+#[test]
+fn fallible_eval() {
+    let exprs = [
+        "unix",
+        "true",
+        "not(false)",
+        "all()",
+        "any()",
+        r#"all(unix, not(any(windows, feature = "a")), any(feature = "b", all(test, feature = "c")))"#,
+        r#"not(all(not(feature = "a"), any(feature = "b", not(feature = "c"), feature = "d")))"#,
+    ];
+
+    // Evaluates the same as eval() for every assignment of the flags
+    for expr in exprs {
+        let expr = Expression::parse(expr).unwrap();
+        let names = expr
+            .predicates()
+            .map(|pred| format!("{pred:?}"))
+            .collect::<Vec<_>>();
+
+        for bits in 0u32..1 << names.len() {
+            let value = |pred: &Predicate<'_>| {
+                let i = names
+                    .iter()
+                    .position(|n| *n == format!("{pred:?}"))
+                    .unwrap();
+                bits & (1 << i) != 0
+            };
+
+            assert_eq!(
+                expr.eval_result(|pred| Ok::<_, ()>(value(pred))),
+                Ok(expr.eval(value)),
+                "{expr} {bits:b}"
+            );
+        }
+    }
+
+    // Operands are evaluated from left to right, and stop at the first error
+    let expr = Expression::parse(
+        r#"all(feature = "a", any(feature = "b", feature = "c"), feature = "d")"#,
+    )
+    .unwrap();
+
+    let mut evaluated = Vec::new();
+    let result = expr.eval_result(|pred| match pred {
+        Predicate::Feature(feat) => {
+            evaluated.push((*feat).to_owned());
+            match *feat {
+                "c" => Err(format!("failed to look up {feat}")),
+                feat => Ok(feat == "a"),
+            }
+        }
+        _ => Ok(false),
+    });
+
+    assert_eq!(result, Err("failed to look up c".to_owned()));
+    assert_eq!(evaluated, ["a", "b", "c"]);
+}
+
+#[test]
+fn lazy_eval() {
+    let expr = Expression::parse(
+        r#"all(any(feature = "a", feature = "b"), not(all(feature = "c", feature = "d")), feature = "e")"#,
+    )
+    .unwrap();
+
+    for (enabled, expected, evaluated) in [
+        (&["a", "e"][..], true, &["a", "c", "e"][..]),
+        (&["b", "c", "d"], false, &["a", "b", "c", "d"]),
+        (&[], false, &["a", "b"]),
+    ] {
+        let mut actual = Vec::new();
+        let value = expr.eval_lazy(|pred| match pred {
+            Predicate::Feature(feat) => {
+                actual.push((*feat).to_owned());
+                enabled.contains(feat)
+            }
+            _ => false,
+        });
+
+        assert_eq!(value, expected, "{enabled:?}");
+        assert_eq!(
+            value,
+            expr.eval(|pred| match pred {
+                Predicate::Feature(feat) => enabled.contains(feat),
+                _ => false,
+            })
+        );
+        assert_eq!(actual, evaluated, "{enabled:?}");
+    }
+}
+
+#[test]
+fn compiled_matcher() {
+    use cfg_expr::expr::FeatureSet;
+
+    for expr in [
+        "unix",
+        r#"all(unix, target_pointer_width = "64")"#,
+        r#"any(target_os = "linux", all(windows, target_env = "msvc"))"#,
+        r#"not(not(target_arch = "x86_64"))"#,
+        r#"all(target_feature = "sse2", not(target_feature = "avx2"))"#,
+        r#"any(test, all(unix, not(feature = "std")), target_family = "wasm")"#,
+        r#"all(not(test), any(false, target_os = "none", debug_assertions), true)"#,
+    ] {
+        let parsed = Expression::parse(expr).unwrap();
+        let matcher = parsed.compile_target_matcher();
+        assert_eq!(matcher.constant(), None, "{expr}");
+
+        for target in all {
+            assert_eq!(
+                matcher.matches(target),
+                parsed.eval_target(target, &FeatureSet::new(), &FeatureSet::new()),
+                "{expr} {}",
+                target.triple
+            );
+        }
+    }
+
+    for (expr, constant) in [
+        ("test", false),
+        ("all(unix, test)", false),
+        ("any(not(test), windows)", true),
+        (r#"not(all(feature = "a", unix))"#, true),
+        ("all()", true),
+        ("any()", false),
+    ] {
+        let matcher = Expression::parse(expr).unwrap().compile_target_matcher();
+        assert_eq!(matcher.constant(), Some(constant), "{expr}");
+        assert!(all.iter().all(|target| matcher.matches(target) == constant));
+    }
+}
+
+#[test]
+fn cached_evaluator() {
+    use cfg_expr::expr::{CfgSet, Evaluator, FeatureSet};
+
+    let exprs = [
+        "unix",
+        r#"all(unix, feature = "std")"#,
+        r#"any(target_os = "linux", all(windows, target_env = "msvc"))"#,
+        r#"all(target_has_atomic = "64", not(feature = "std"))"#,
+        r#"any(unix, version("1.70"), accessible(std::ptr))"#,
+        r#"all(unix, target_os = "linux", target_has_atomic = "64")"#,
+    ]
+    .map(|expr| Expression::parse(expr).unwrap());
+
+    let features: FeatureSet = ["std"].into_iter().collect();
+    for target in all {
+        let cfgs = CfgSet::for_target(target, &features, &FeatureSet::new());
+        let expected = exprs
+            .iter()
+            .map(|expr| expr.eval_cfg_set(&cfgs))
+            .collect::<Vec<_>>();
+
+        let mut evaluator = Evaluator::new(&cfgs);
+        assert_eq!(evaluator.eval_all(&exprs), expected, "{}", target.triple);
+        // Evaluating again only uses the cache
+        let cached = evaluator.cached();
+        assert_eq!(evaluator.eval_all(&exprs), expected, "{}", target.triple);
+        assert_eq!(evaluator.cached(), cached);
+
+        evaluator.clear_cache();
+        assert_eq!(evaluator.cached(), 0);
+
+        let mut evaluator = Evaluator::for_target(target, &features, &FeatureSet::new());
+        assert_eq!(evaluator.cfgs(), &cfgs);
+        assert_eq!(evaluator.eval_all(&exprs), expected, "{}", target.triple);
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_eval() {
+    use cfg_expr::expr::FeatureSet;
+
+    let exprs = [
+        "unix",
+        r#"all(unix, target_pointer_width = "64")"#,
+        r#"any(target_os = "linux", all(windows, target_env = "msvc"))"#,
+        r#"all(target_feature = "sse2", not(feature = "std"))"#,
+    ]
+    .map(|expr| Expression::parse(expr).unwrap());
+
+    for expr in &exprs {
+        let expected = all
+            .iter()
+            .filter(|target| expr.eval_target(target, &FeatureSet::new(), &FeatureSet::new()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(expr.par_matching_targets(all), expected);
+    }
+
+    for target in all {
+        let expected = exprs
+            .iter()
+            .map(|expr| expr.eval_target(target, &FeatureSet::new(), &FeatureSet::new()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(Expression::evaluate_all_par(&exprs, target), expected);
+    }
+}
+
+#[test]
+fn target_sets() {
+    use cfg_expr::{
+        expr::{FeatureSet, Logic},
+        targets::TargetSet,
+    };
+
+    for expr in [
+        "unix",
+        r#"all(unix, target_pointer_width = "64")"#,
+        r#"any(target_os = "linux", all(windows, target_env = "msvc"))"#,
+        r#"not(any(target_arch = "x86_64", target_arch = "aarch64"))"#,
+        r#"all(target_feature = "sse2", not(target_feature = "avx2"))"#,
+        r#"any(test, all(unix, not(feature = "std")), target_family = "wasm")"#,
+        "any(not(test), unix)",
+    ] {
+        let parsed = Expression::parse(expr).unwrap();
+        let set = parsed.eval_target_set(all);
+
+        let expected = all
+            .iter()
+            .filter(|target| parsed.eval_target(target, &FeatureSet::new(), &FeatureSet::new()))
+            .collect::<Vec<_>>();
+        assert_eq!(set.targets(all).collect::<Vec<_>>(), expected, "{expr}");
+        assert_eq!(set.len(all.len()), expected.len());
+    }
+
+    let every = TargetSet::matching(all, |_| true).and(TargetSet::full());
+    assert_eq!(every.len(all.len()), all.len());
+
+    let mut a = TargetSet::new();
+    let mut b = TargetSet::new();
+    for i in (0..200).step_by(2) {
+        a.insert(i);
+    }
+    for i in (0..200).step_by(3) {
+        b.insert(i);
+    }
+
+    let both = a.clone().and(b.clone());
+    assert_eq!(
+        both.iter(200).collect::<Vec<_>>(),
+        (0..200).step_by(6).collect::<Vec<_>>()
+    );
+    let either = a.clone().or(b.clone());
+    assert_eq!(either.len(200), 100 + 67 - 34);
+    assert!(either.is_empty(0));
+
+    // De Morgan, and sets with the same members are equal however they were
+    // constructed
+    assert_eq!(both.clone().not(), a.clone().not().or(b.clone().not()));
+    assert_eq!(a.clone().not().not(), a);
+    assert_eq!(a.clone().or(a.clone().not()), TargetSet::full());
+    assert_eq!(a.clone().and(a.clone().not()), TargetSet::new());
+
+    let mut removed = a.clone();
+    for i in (0..200).step_by(2) {
+        removed.remove(i);
+    }
+    assert_eq!(removed, TargetSet::new());
+}
+
+#[test]
+fn search_builtins() {
+    use cfg_expr::targets::{search, Endian, TargetSearch};
+
+    fn triples(search: TargetSearch<'static>) -> Vec<&'static str> {
+        search.map(|ti| ti.triple.as_str()).collect()
+    }
+
+    // No filters matches every target
+    assert_eq!(search().count(), all.len());
+
+    let linux = triples(search().triple("*-unknown-linux-*"));
+    assert!(linux.contains(&"x86_64-unknown-linux-gnu"));
+    assert!(!linux.contains(&"x86_64-linux-android"));
+    assert!(linux.windows(2).all(|w| w[0] < w[1]));
+    for triple in &linux {
+        let ti = get_builtin_target_by_triple(triple).unwrap();
+        assert_eq!(
+            ti.os.as_ref().map(|os| os.as_str()),
+            Some("linux"),
+            "{triple}"
+        );
+    }
+
+    assert_eq!(
+        triples(search().triple("x86_64-*-linux-?nu")),
+        ["x86_64-unknown-linux-gnu"]
+    );
+    assert_eq!(
+        triples(search().triple("*windows*").arch("x86_64").env("msvc")),
+        [
+            "x86_64-pc-windows-msvc",
+            "x86_64-uwp-windows-msvc",
+            "x86_64-win7-windows-msvc"
+        ]
+    );
+    assert!(triples(search().triple("x86_64-unknown-linux")).is_empty());
+
+    // Fields are matched like the predicates of an expression
+    for ti in search().os("none") {
+        assert!(ti.os.is_none(), "{}", ti.triple);
+    }
+    assert!(search()
+        .os("none")
+        .any(|ti| ti.triple.as_str() == "thumbv7em-none-eabihf"));
+    assert!(search()
+        .family("wasm")
+        .env("")
+        .all(|ti| ti.arch.as_str().starts_with("wasm")));
+
+    let big = search()
+        .endian(Endian::big)
+        .pointer_width(64)
+        .family("unix");
+    assert!(triples(big.clone()).contains(&"powerpc64-unknown-linux-gnu"));
+    assert!(big
+        .clone()
+        .all(|ti| ti.endian == Endian::big && ti.pointer_width == 64));
+
+    // The same filters as an expression
+    let expr =
+        Expression::parse(r#"all(target_endian = "big", target_pointer_width = "64", unix)"#)
+            .unwrap();
+    assert_eq!(
+        triples(big),
+        all.iter()
+            .filter(|ti| expr.eval(|pred| match pred {
+                Predicate::Target(tp) => ti.matches(tp),
+                _ => false,
+            }))
+            .map(|ti| ti.triple.as_str())
+            .collect::<Vec<_>>()
+    );
+
+    // Other lists of targets can be searched as well
+    assert_eq!(TargetSearch::new(&all[..10]).count(), 10);
+}
+
+#[cfg(feature = "target-lexicon")]
+#[test]
+fn lexicon_triples() {
+    use cfg_expr::targets::Endian;
+    use target_lexicon::Triple;
+
+    // target-lexicon doesn't know every component that rustc uses
+    let converted: Vec<_> = all
+        .iter()
+        .filter_map(|ti| Some((ti, Triple::try_from(ti).ok()?)))
+        .collect();
+    assert!(converted.len() > all.len() * 4 / 5);
+
+    for (ti, triple) in converted {
+        assert_eq!(ti.to_triple().as_ref(), Ok(&triple));
+
+        // The converted triple matches the same as the target
+        for tp in [
+            TargetPredicate::Arch(ti.arch.clone()),
+            TargetPredicate::PointerWidth(ti.pointer_width),
+            TargetPredicate::Endian(ti.endian),
+            TargetPredicate::Endian(if ti.endian == Endian::big {
+                Endian::little
+            } else {
+                Endian::big
+            }),
+        ] {
+            assert_eq!(triple.matches(&tp), ti.matches(&tp), "{triple} {tp:?}");
+        }
+    }
+
+    let switch = get_builtin_target_by_triple("aarch64-nintendo-switch-freestanding").unwrap();
+    assert!(switch.to_triple().is_err());
+}
+
+#[cfg(feature = "target-lexicon")]
+#[test]
+fn lexicon_derived_cfgs() {
+    use target_lexicon::Triple;
+
+    // rustc sets these cfgs for the target rather than deriving them from
+    // the components of its triple
+    for (triple, expr) in [
+        (
+            "powerpc64-unknown-linux-gnu",
+            r#"all(target_abi = "elfv1", target_env = "gnu")"#,
+        ),
+        ("powerpc64-wrs-vxworks", r#"target_abi = "elfv1""#),
+        ("powerpc64-unknown-linux-musl", r#"target_abi = "elfv2""#),
+        ("powerpc64le-unknown-linux-gnu", r#"target_abi = "elfv2""#),
+        ("powerpc64-ibm-aix", r#"target_abi = "vec-extabi""#),
+        (
+            "armv7-unknown-freebsd",
+            r#"all(target_abi = "eabihf", target_env = "")"#,
+        ),
+        ("armv6k-nintendo-3ds", r#"target_abi = "eabihf""#),
+        (
+            "armv7-unknown-linux-ohos",
+            r#"all(target_abi = "eabi", target_env = "ohos")"#,
+        ),
+        ("mips64-openwrt-linux-musl", r#"target_abi = "abi64""#),
+        (
+            "aarch64-apple-ios-sim",
+            r#"all(target_abi = "sim", target_env = "sim")"#,
+        ),
+        ("x86_64-apple-ios", r#"target_env = "sim""#),
+        ("aarch64-apple-ios-macabi", r#"target_env = "macabi""#),
+        ("aarch64-apple-darwin", r#"target_env = """#),
+        ("x86_64-unknown-linux-none", r#"target_env = """#),
+        (
+            "wasm32-wasip1",
+            r#"all(target_os = "wasi", target_env = "p1")"#,
+        ),
+        (
+            "wasm32-wasip2",
+            r#"all(target_os = "wasi", target_env = "p2", target_family = "wasm")"#,
+        ),
+        ("xtensa-esp32s3-none-elf", r#"target_vendor = "espressif""#),
+    ] {
+        let lexicon: Triple = triple.parse().unwrap();
+        let builtin = get_builtin_target_by_triple(triple).unwrap();
+        let expr = Expression::parse(expr).unwrap();
+
+        assert!(
+            expr.eval(|pred| match pred {
+                Predicate::Target(tp) => lexicon.matches(tp),
+                _ => false,
+            }),
+            "{triple} {expr}"
+        );
+        assert!(
+            expr.eval(|pred| match pred {
+                Predicate::Target(tp) => builtin.matches(tp),
+                _ => false,
+            }),
+            "{triple} {expr}"
+        );
+    }
+}
+
+#[cfg(feature = "targets")]
+#[test]
+fn sub_architectures() {
+    use cfg_expr::targets::{Arch, TargetInfo};
+
+    for ti in all {
+        let sub_arch = ti.triple.as_str().split('-').next().unwrap();
+        assert_eq!(
+            Arch::from_sub_arch(sub_arch).as_ref(),
+            Some(&ti.arch),
+            "{ti:?}"
+        );
+    }
+
+    let arm = TargetPredicate::Arch(Arch::arm);
+    let riscv32 = TargetPredicate::Arch(Arch::riscv32);
+    let riscv64 = TargetPredicate::Arch(Arch::riscv64);
+
+    for (triple, tp) in [
+        ("thumbv7em-acme-none-eabihf", &arm),
+        ("armv7-acme-linux-gnueabihf", &arm),
+        ("armebv7r-acme-none-eabi", &arm),
+        ("riscv32imac-acme-none-elf", &riscv32),
+        ("riscv64gc-acme-linux-gnu", &riscv64),
+    ] {
+        let ti = TargetInfo::guess_from_triple(triple).unwrap();
+        assert!(ti.matches(tp), "{triple}");
+
+        #[cfg(feature = "target-lexicon")]
+        {
+            let lexicon: target_lexicon::Triple = triple.parse().unwrap();
+            assert!(lexicon.matches(tp), "{triple}");
+            assert!(
+                !lexicon.matches(&TargetPredicate::Arch(Arch::x86)),
+                "{triple}"
+            );
+        }
+    }
+}
+
+#[cfg(feature = "targets")]
+#[test]
+fn os_versions() {
+    use cfg_expr::targets::{Os, OsVersion, TargetInfo};
+
+    let macos = TargetPredicate::Os(Os::macos);
+    let sierra = OsVersion {
+        major: 10,
+        minor: 12,
+        patch: 0,
+    };
+
+    let builtin = get_builtin_target_by_triple("x86_64-apple-darwin").unwrap();
+    assert_eq!(builtin.os_version(), None);
+
+    let guessed = TargetInfo::guess_from_triple("x86_64-apple-macosx10.12.0").unwrap();
+    assert!(guessed.matches(&macos));
+    assert_eq!(guessed.vendor, builtin.vendor);
+    assert_eq!(guessed.os_version(), Some(sierra));
+
+    let ios = TargetInfo::guess_from_triple("aarch64-apple-ios17.2-sim").unwrap();
+    assert_eq!(ios.os, Some(Os::ios));
+    assert_eq!(
+        ios.os_version().map(|version| version.to_string()),
+        Some("17.2.0".to_owned())
+    );
+
+    // A custom predicate that only matches the deployment targets that are
+    // at least 10.15
+    let expr = Expression::parse(r#"all(target_os = "macos", catalina)"#).unwrap();
+    let eval = |target: &TargetInfo| {
+        expr.eval(|pred| match pred {
+            Predicate::Target(tp) => target.matches(tp),
+            Predicate::Flag("catalina") => target.os_version().map_or(false, |version| {
+                version
+                    >= OsVersion {
+                        major: 10,
+                        minor: 15,
+                        patch: 0,
+                    }
+            }),
+            _ => false,
+        })
+    };
+    assert!(!eval(&guessed));
+    assert!(eval(
+        &TargetInfo::guess_from_triple("aarch64-apple-macosx11.0.0").unwrap()
+    ));
+
+    #[cfg(feature = "target-lexicon")]
+    {
+        let lexicon: target_lexicon::Triple = "x86_64-apple-macosx10.12.0".parse().unwrap();
+        assert!(lexicon.matches(&macos));
+        assert_eq!(lexicon.os_version(), Some(sierra));
+    }
+}
+
+#[cfg(feature = "targets")]
+#[test]
+fn guess_triples() {
+    use cfg_expr::targets::{HasAtomic, TargetInfo};
+
+    // Builtin triples are exact
+    for triple in ["x86_64-unknown-linux-gnu", "thumbv7em-none-eabihf"] {
+        assert_eq!(
+            TargetInfo::guess_from_triple(triple).as_ref(),
+            get_builtin_target_by_triple(triple)
+        );
+    }
+
+    for (triple, expr, expected) in [
+        (
+            "riscv32imafc-unknown-none-elf",
+            r#"all(target_arch = "riscv32", target_os = "none", target_env = "", target_pointer_width = "32", not(unix))"#,
+            true,
+        ),
+        (
+            "x86_64-acme-linux-gnu",
+            r#"all(target_arch = "x86_64", target_vendor = "acme", target_os = "linux", target_env = "gnu", unix)"#,
+            true,
+        ),
+        (
+            "aarch64-acme-linux-musl",
+            r#"all(target_arch = "aarch64", target_env = "musl", target_endian = "little", target_has_atomic = "64")"#,
+            true,
+        ),
+        (
+            "armebv7r-acme-none-eabihf",
+            r#"all(target_arch = "arm", target_endian = "big", target_abi = "eabihf", panic = "abort")"#,
+            true,
+        ),
+        (
+            "x86_64-acme-windows-msvc",
+            r#"all(windows, target_env = "msvc", not(unix))"#,
+            true,
+        ),
+        (
+            "arm64-apple-macos",
+            r#"all(target_arch = "aarch64", target_os = "macos", target_vendor = "apple")"#,
+            true,
+        ),
+        (
+            "wasm32-acme-wasi",
+            r#"all(target_family = "wasm", target_os = "wasi")"#,
+            true,
+        ),
+        (
+            "x86_64-unknown-linux-gnux32",
+            r#"all(target_pointer_width = "32", target_abi = "x32")"#,
+            true,
+        ),
+        ("x86_64-acme-linux-gnu", "windows", false),
+        ("riscv32imafc-unknown-none-elf", "unix", false),
+    ] {
+        let ti = TargetInfo::guess_from_triple(triple).unwrap();
+        let expr = Expression::parse(expr).unwrap();
+
+        assert_eq!(
+            expr.eval(|pred| match pred {
+                Predicate::Target(tp) => ti.matches(tp),
+                _ => false,
+            }),
+            expected,
+            "{triple} {expr} {ti:?}"
+        );
+    }
+
+    let ti = TargetInfo::guess_from_triple("mips64el-acme-linux-gnuabi64").unwrap();
+    assert_eq!(ti.triple.as_str(), "mips64el-acme-linux-gnuabi64");
+    assert!(ti.has_atomics.contains(HasAtomic::IntegerSize(64)));
+
+    assert!(TargetInfo::guess_from_triple("").is_none());
+    assert!(TargetInfo::guess_from_triple("z80-acme-none").is_none());
+}
+
+#[test]
+fn lookup_builtins() {
+    use cfg_expr::targets::lookup_builtin_target;
+
+    let best =
+        |triple: &str| lookup_builtin_target(triple).map(|lookup| lookup.best.triple.as_str());
+
+    for (triple, expected) in [
+        ("x86_64-unknown-linux-gnu", "x86_64-unknown-linux-gnu"),
+        ("  aarch64-APPLE-darwin\t", "aarch64-apple-darwin"),
+        ("x86_64-linux-gnu", "x86_64-unknown-linux-gnu"),
+        ("aarch64-linux-gnu", "aarch64-unknown-linux-gnu"),
+        ("arm64-apple-macos", "aarch64-apple-darwin"),
+        ("x64-windows-msvc", "x86_64-pc-windows-msvc"),
+        ("x86_64-windows-gnu", "x86_64-pc-windows-gnu"),
+        ("x86_64-unknown-linux-gnux", "x86_64-unknown-linux-gnu"),
+        ("x86_64-unknown-linx-musl", "x86_64-unknown-linux-musl"),
+        ("wasm32-emscripten", "wasm32-unknown-emscripten"),
+        ("thumbv7em-none-eabihf", "thumbv7em-none-eabihf"),
+    ] {
+        assert_eq!(best(triple), Some(expected), "{triple}");
+    }
+
+    let lookup = lookup_builtin_target("x86_64-linux-gnu").unwrap();
+    assert!(!lookup.exact);
+    assert_eq!(lookup.triples().next(), Some("x86_64-unknown-linux-gnu"));
+    assert!(lookup.alternates.is_empty());
+
+    // Exact matches don't have alternates, even if the triple is also a
+    // part of other triples
+    let lookup = lookup_builtin_target("x86_64-linux-android").unwrap();
+    assert!(lookup.exact);
+    assert!(lookup.alternates.is_empty());
+
+    // Every match has the components in order
+    let lookup = lookup_builtin_target("apple-ios").unwrap();
+    assert!(lookup.alternates.len() > 1);
+    for triple in lookup.triples() {
+        assert!(triple.contains("-apple-ios"), "{triple}");
+    }
+
+    assert_eq!(best("windows-x86_64"), None);
+    assert_eq!(best("x86_64-unknown-linux-gnu-extra"), None);
+    assert_eq!(best(""), None);
+}
+
+#[test]
+fn target_dbs() {
+    use cfg_expr::{expr::RustVersion, targets::TargetDb};
+
+    let latest = TargetDb::latest();
+    assert_eq!(TargetDb::all().last(), Some(&latest));
+    assert_eq!(latest.targets(), all);
+
+    for db in TargetDb::all() {
+        for window in db.targets().windows(2) {
+            assert!(window[0].triple < window[1].triple);
+        }
+        assert_eq!(db.registry().iter().count(), db.targets().len());
+    }
+
+    // Each database is found by its version, and they are sorted by it
+    for (i, db) in TargetDb::all().iter().enumerate() {
+        assert_eq!(TargetDb::for_rustc_version(db.rustc_version()), Some(*db));
+
+        if let Some(newer) = TargetDb::all().get(i + 1) {
+            let version = |db: &TargetDb| -> RustVersion { db.rustc_version().parse().unwrap() };
+            assert!(version(db) < version(newer));
+        }
+    }
+
+    #[cfg(feature = "rustc-1-72")]
+    {
+        let old = TargetDb::for_rustc_version("1.74").unwrap();
+        assert_eq!(old.rustc_version(), "1.72.0");
+        assert_eq!(TargetDb::for_rustc_version("1.72.0"), Some(old));
+        assert!(old
+            .get_target_by_triple("asmjs-unknown-emscripten")
+            .is_some());
+        assert!(old.get_target_by_triple("wasm32-wasip2").is_none());
+        assert_eq!(
+            old.search()
+                .os("wasi")
+                .map(|ti| ti.triple.as_str())
+                .collect::<Vec<_>>(),
+            ["wasm32-wasi"]
+        );
+    }
+
+    #[cfg(feature = "rustc-1-80")]
+    {
+        let old = TargetDb::for_rustc_version("1.80").unwrap();
+        assert_eq!(old.rustc_version(), "1.80.0");
+        assert_eq!(TargetDb::for_rustc_version("1.82.0"), Some(old));
+        assert_eq!(
+            old.search()
+                .os("wasi")
+                .map(|ti| ti.triple.as_str())
+                .collect::<Vec<_>>(),
+            [
+                "wasm32-wasi",
+                "wasm32-wasip1",
+                "wasm32-wasip1-threads",
+                "wasm32-wasip2"
+            ]
+        );
+    }
+
+    // Versions older than every database use the oldest one, which is the
+    // builtin targets if no older database is enabled
+    assert_eq!(
+        TargetDb::for_rustc_version("1.69"),
+        TargetDb::all().first().copied()
+    );
+    #[cfg(not(any(
+        feature = "rustc-1-70",
+        feature = "rustc-1-72",
+        feature = "rustc-1-75",
+        feature = "rustc-1-80"
+    )))]
+    assert_eq!(TargetDb::for_rustc_version("1.75"), Some(latest));
+}
+
+#[cfg(feature = "wasm")]
+#[test]
+fn wasm_bindings() {
+    use cfg_expr::{expr::FeatureSet, wasm};
+
+    let text = r#"any(all(target_os = "linux", feature = "std"), target_family = "wasm")"#;
+    let expr = Expression::parse(text).unwrap();
+    let std: FeatureSet = ["std"].into_iter().collect();
+
+    assert_eq!(wasm::parse(text).ok().as_deref(), Some(text));
+
+    for target in all {
+        assert_eq!(
+            wasm::eval_against_triple(text, target.triple.as_str(), vec!["std".to_owned()]).ok(),
+            Some(expr.eval_target(target, &std, &FeatureSet::new())),
+        );
+    }
+
+    let expected: Vec<_> = all
+        .iter()
+        .filter(|target| expr.eval_target(target, &FeatureSet::new(), &FeatureSet::new()))
+        .map(|target| target.triple.as_str())
+        .collect();
+    assert_eq!(wasm::matching_targets(text).ok().unwrap(), expected);
+}
+
+#[cfg(feature = "capi")]
+#[test]
+fn c_abi() {
+    #![allow(unsafe_code)]
+
+    use cfg_expr::capi::*;
+    use std::ffi::{CStr, CString};
+
+    let expr = CString::new(r#"all(target_os = "linux", feature = "std")"#).unwrap();
+    let linux = CString::new("x86_64-unknown-linux-gnu").unwrap();
+    let windows = CString::new("x86_64-pc-windows-msvc").unwrap();
+    let std = CString::new("std").unwrap();
+    let features = [std.as_ptr()];
+
+    unsafe {
+        let parsed = cfg_expr_parse(expr.as_ptr());
+        assert!(!parsed.is_null());
+
+        assert_eq!(
+            cfg_expr_eval_triple(parsed, linux.as_ptr(), features.as_ptr(), 1),
+            1
+        );
+        assert_eq!(
+            cfg_expr_eval_triple(parsed, linux.as_ptr(), std::ptr::null(), 0),
+            0
+        );
+        assert_eq!(
+            cfg_expr_eval_triple(parsed, windows.as_ptr(), features.as_ptr(), 1),
+            0
+        );
+
+        let unknown = CString::new("x86_64-unknown-nope").unwrap();
+        assert_eq!(
+            cfg_expr_eval_triple(parsed, unknown.as_ptr(), std::ptr::null(), 0),
+            -1
+        );
+        assert_eq!(
+            CStr::from_ptr(cfg_expr_last_error()).to_str().unwrap(),
+            "unknown target triple `x86_64-unknown-nope`"
+        );
+
+        cfg_expr_free(parsed);
+
+        let invalid = CString::new("all(unix").unwrap();
+        assert!(cfg_expr_parse(invalid.as_ptr()).is_null());
+        assert!(CStr::from_ptr(cfg_expr_last_error())
+            .to_str()
+            .unwrap()
+            .contains("unclosed parens"));
+    }
+}
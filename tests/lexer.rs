@@ -62,3 +62,84 @@ fn handle_cfg_keywords() {
         ]
     );
 }
+
+#[test]
+fn spans() {
+    let text = r#"cfg( any(feature = "a", accessible(::std::ptr)) )"#;
+    let lexer = Lexer::new(text);
+    let start = lexer.start();
+
+    let tokens: Vec<_> = lexer
+        .map(|lt| {
+            let (token, span) = lt.unwrap().into();
+            (token, &text[span.start + start..span.end + start])
+        })
+        .collect();
+
+    assert_eq!(
+        tokens,
+        [
+            (Token::Any, "any"),
+            (Token::OpenParen, "("),
+            (Token::Key("feature"), "feature"),
+            (Token::Equals, "="),
+            (Token::Value("a"), "\"a\""),
+            (Token::Comma, ","),
+            (Token::Key("accessible"), "accessible"),
+            (Token::OpenParen, "("),
+            (Token::Key("::std::ptr"), "::std::ptr"),
+            (Token::CloseParen, ")"),
+            (Token::CloseParen, ")"),
+        ]
+    );
+}
+
+#[test]
+fn error_positions() {
+    let lexed: Vec<_> = Lexer::new("all(a, $, \"b")
+        .map(|lt| lt.map(|lt| lt.token).map_err(|err| err.span))
+        .collect();
+
+    // Lexing continues after an invalid character, but not after an unclosed
+    // quote
+    assert_eq!(
+        lexed,
+        [
+            Ok(Token::All),
+            Ok(Token::OpenParen),
+            Ok(Token::Key("a")),
+            Ok(Token::Comma),
+            Err(7..8),
+            Ok(Token::Comma),
+            Err(10..12),
+        ]
+    );
+
+    // The span of an invalid character covers all of its bytes
+    let lexed: Vec<_> = Lexer::new("a, é")
+        .map(|lt| lt.map(|lt| lt.token).map_err(|err| err.span))
+        .collect();
+    assert_eq!(lexed, [Ok(Token::Key("a")), Ok(Token::Comma), Err(3..5)]);
+}
+
+#[test]
+fn unicode_whitespace() {
+    for c in (0..=char::MAX as u32).filter_map(char::from_u32) {
+        let text = format!("{c}cfg{c}(a{c}={c}\"b\"{c}){c}");
+        let lexed: Vec<_> = Lexer::new(&text).map(|lt| lt.map(|lt| lt.token)).collect();
+
+        if c.is_whitespace() {
+            assert_eq!(
+                lexed,
+                [
+                    Ok(Token::Key("a")),
+                    Ok(Token::Equals),
+                    Ok(Token::Value("b"))
+                ],
+                "{c:?}"
+            );
+        } else {
+            assert_ne!(lexed.len(), 3, "{c:?}");
+        }
+    }
+}
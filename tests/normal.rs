@@ -0,0 +1,244 @@
+use cfg_expr::{Expression, Predicate};
+
+/// Evaluates both expressions for every possible assignment of the predicates
+/// they contain, checking that they always agree
+fn assert_equivalent(a: &Expression, b: &Expression) {
+    let mut preds: Vec<Predicate<'_>> = Vec::new();
+    for pred in a.predicates().chain(b.predicates()) {
+        if !preds.contains(&pred) {
+            preds.push(pred);
+        }
+    }
+
+    assert!(preds.len() < 16, "too many predicates to enumerate");
+
+    for assignment in 0u32..1 << preds.len() {
+        let eval = |expr: &Expression| {
+            expr.eval(|pred| {
+                let i = preds.iter().position(|p| p == pred).unwrap();
+                assignment & (1 << i) != 0
+            })
+        };
+
+        assert_eq!(
+            eval(a),
+            eval(b),
+            "'{a}' and '{b}' differ for assignment {assignment:#b}"
+        );
+    }
+}
+
+const EXPRESSIONS: &[&str] = &[
+    "unix",
+    "not(unix)",
+    "all()",
+    "any()",
+    "not(all())",
+    "not(any())",
+    "all(a, b, c)",
+    "any(a, b, c)",
+    "all(a, any(b, c))",
+    "any(a, all(b, c))",
+    "not(all(a, any(b, not(c))))",
+    "all(any(a, b), any(c, d), not(any(a, e)))",
+    "any(all(a, not(a)), all(any(b, c), any(not(d), e)))",
+    r#"all(unix, any(target_arch = "x86", target_arch = "arm"), not(any(test, feature = "a")))"#,
+];
+
+#[test]
+fn dnf() {
+    for text in EXPRESSIONS {
+        let expr = Expression::parse(text).unwrap();
+        let dnf = expr.to_dnf();
+
+        assert_equivalent(&expr, &dnf);
+
+        // Ensure the result is flat, ie any(all(<literals>), ...)
+        let canonical = dnf.to_string();
+        let inner = canonical
+            .strip_prefix("any(")
+            .and_then(|s| s.strip_suffix(')'))
+            .unwrap();
+        assert!(!inner.contains("any("), "{canonical}");
+        assert!(!inner.contains("not(all(") && !inner.contains("not(not("));
+        assert!(inner.is_empty() || inner.starts_with("all("));
+    }
+
+    assert_eq!(
+        Expression::parse("a").unwrap().to_dnf().to_string(),
+        "any(all(a))"
+    );
+    assert_eq!(
+        Expression::parse("all()").unwrap().to_dnf().to_string(),
+        "any(all())"
+    );
+    assert_eq!(
+        Expression::parse("any()").unwrap().to_dnf().to_string(),
+        "any()"
+    );
+    assert_eq!(
+        Expression::parse("all(any(a, b), any(c, a))")
+            .unwrap()
+            .to_dnf()
+            .to_string(),
+        "any(all(a, c), all(a), all(b, c), all(b, a))"
+    );
+}
+
+#[test]
+fn cnf() {
+    for text in EXPRESSIONS {
+        let expr = Expression::parse(text).unwrap();
+        let cnf = expr.to_cnf();
+
+        assert_equivalent(&expr, &cnf);
+
+        // Ensure the result is flat, ie all(any(<literals>), ...)
+        let canonical = cnf.to_string();
+        let inner = canonical
+            .strip_prefix("all(")
+            .and_then(|s| s.strip_suffix(')'))
+            .unwrap();
+        assert!(!inner.contains("all("), "{canonical}");
+        assert!(!inner.contains("not(any(") && !inner.contains("not(not("));
+        assert!(inner.is_empty() || inner.starts_with("any("));
+    }
+
+    assert_eq!(
+        Expression::parse("a").unwrap().to_cnf().to_string(),
+        "all(any(a))"
+    );
+    assert_eq!(
+        Expression::parse("all()").unwrap().to_cnf().to_string(),
+        "all()"
+    );
+    assert_eq!(
+        Expression::parse("any()").unwrap().to_cnf().to_string(),
+        "all(any())"
+    );
+    assert_eq!(
+        Expression::parse("not(all(a, not(b)))")
+            .unwrap()
+            .to_cnf()
+            .to_string(),
+        "all(any(not(a), b))"
+    );
+}
+
+#[test]
+fn simplify() {
+    for text in EXPRESSIONS {
+        let expr = Expression::parse(text).unwrap();
+        assert_equivalent(&expr, &expr.simplify());
+    }
+
+    for (text, simplified) in [
+        ("not(not(a))", "a"),
+        ("not(not(not(a)))", "not(a)"),
+        ("all(a)", "a"),
+        ("any(all(any(a)))", "a"),
+        ("all(a, all(b, all(c)), d)", "all(a, b, c, d)"),
+        ("any(a, any(b, all(c, d)))", "any(a, b, all(c, d))"),
+        ("all(a, b, a, not(c), not(c))", "all(a, b, not(c))"),
+        ("all(a, any())", "any()"),
+        ("all(a, all())", "a"),
+        ("any(a, all())", "all()"),
+        ("any(a, any())", "a"),
+        ("all(a, not(a))", "any()"),
+        ("any(not(b), a, b)", "all()"),
+        ("not(all())", "any()"),
+        ("not(any(all(), a))", "any()"),
+        ("all(any(a, not(any())), b)", "b"),
+        ("unix", "unix"),
+        ("target_family = \"windows\"", "windows"),
+    ] {
+        assert_eq!(
+            Expression::parse(text).unwrap().simplify().to_string(),
+            simplified,
+            "{text}"
+        );
+    }
+}
+
+#[test]
+fn canonicalize() {
+    for text in EXPRESSIONS {
+        let expr = Expression::parse(text).unwrap();
+        let canonical = expr.canonicalize();
+        assert_equivalent(&expr, &canonical);
+
+        // Canonicalizing is idempotent, and the canonical string parses to
+        // the same expression
+        assert_eq!(canonical.canonicalize().original(), canonical.original());
+        assert_eq!(Expression::parse(canonical.original()).unwrap(), canonical);
+    }
+
+    for group in [
+        &[
+            "all(a, b)",
+            "all(b, a)",
+            "all(b, all(a))",
+            "cfg( all(a,b) )",
+        ][..],
+        &[
+            r#"any(not(feature = "x"), all(unix, target_os = "linux"))"#,
+            r#"any(all(target_os="linux", target_family="unix"), not(not(not(feature="x"))))"#,
+            r#"any(any(all(target_os = "linux", unix)), not(feature = "x"), any())"#,
+        ],
+        &[
+            "all(any(c, b, a), any(a, b))",
+            "all(any(b, a), any(a, c, b))",
+            "all(any(b, a), any(a, any(c, b)), any(a, b))",
+        ],
+        &[
+            "any(a, not(all(b, c)), not(all(c, b)))",
+            "any(not(all(c, b)), a)",
+            "any(any(not(all(c, all(b)))), a, a)",
+        ],
+    ] {
+        let canonical = Expression::parse(group[0]).unwrap().canonicalize();
+        for text in group {
+            assert_eq!(
+                Expression::parse(text).unwrap().canonicalize().original(),
+                canonical.original(),
+                "{text}"
+            );
+        }
+    }
+
+    assert_eq!(
+        Expression::parse("all(any(c, b, a), any(a, b))")
+            .unwrap()
+            .canonicalize()
+            .original(),
+        "all(any(a, b), any(a, b, c))"
+    );
+}
+
+#[test]
+fn partial_eval() {
+    let expr = Expression::parse(
+        r#"all(unix, any(feature = "a", not(feature = "b")), not(all(test, feature = "c")))"#,
+    )
+    .unwrap();
+
+    let residual = |known: &[(&str, bool)]| {
+        expr.partial_eval(|pred| match pred {
+            Predicate::Feature(feat) => known
+                .iter()
+                .find_map(|(name, val)| (name == feat).then_some(*val)),
+            Predicate::Target(_) => Some(true),
+            _ => None,
+        })
+        .to_string()
+    };
+
+    assert_eq!(
+        residual(&[]),
+        r#"all(any(feature = "a", not(feature = "b")), not(all(test, feature = "c")))"#
+    );
+    assert_eq!(residual(&[("a", true)]), r#"not(all(test, feature = "c"))"#);
+    assert_eq!(residual(&[("a", true), ("c", true)]), "not(test)");
+    assert_eq!(residual(&[("a", false), ("b", true)]), "any()");
+    assert_eq!(residual(&[("a", true), ("c", false)]), "all()");
+}
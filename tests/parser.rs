@@ -2,7 +2,7 @@ use cfg_expr::{
     error::Reason,
     expr::{Predicate as P, TargetPredicate as TP},
     targets::*,
-    Expression, ParseError,
+    Expression,
 };
 
 macro_rules! test_validate {
@@ -28,25 +28,17 @@ macro_rules! err {
     ($text:expr => $reason:ident @ $range:expr) => {
         let act_err = Expression::parse($text).unwrap_err();
 
-        let expected = ParseError {
-            original: $text.to_owned(),
-            span: $range,
-            reason: Reason::$reason,
-        };
+        let expected = ($text.to_owned(), $range, Reason::$reason);
 
-        similar_asserts::assert_eq!(expected, act_err);
+        similar_asserts::assert_eq!(expected, (act_err.original, act_err.span, act_err.reason));
     };
 
     ($text:expr => $unexpected:expr; $range:expr) => {
         let act_err = Expression::parse($text).unwrap_err();
 
-        let expected = ParseError {
-            original: $text.to_owned(),
-            span: $range,
-            reason: Reason::Unexpected($unexpected),
-        };
+        let expected = ($text.to_owned(), $range, Reason::Unexpected($unexpected));
 
-        similar_asserts::assert_eq!(expected, act_err);
+        similar_asserts::assert_eq!(expected, (act_err.original, act_err.span, act_err.reason));
     };
 }
 
@@ -105,6 +97,8 @@ fn handles_single_predicate() {
         "target_has_atomic = \"4\"" => [P::Target(TP::HasAtomic(HasAtomic::IntegerSize(4)))],
         "target_has_atomic = \"64\"" => [P::Target(TP::HasAtomic(HasAtomic::IntegerSize(64)))],
         "target_has_atomic = \"128\" " => [P::Target(TP::HasAtomic(HasAtomic::IntegerSize(128)))],
+        "target_has_atomic_load_store = \"32\"" => [P::Target(TP::HasAtomicLoadStore(HasAtomic::IntegerSize(32)))],
+        "target_has_atomic_equal_alignment = \"ptr\"" => [P::Target(TP::HasAtomicEqualAlignment(HasAtomic::Pointer))],
         "panic = \"unwind\"" => [P::Target(TP::Panic(Panic("unwind".into())))],
         "panic = \"abort\"" => [P::Target(TP::Panic(Panic("abort".into())))],
     ]);
@@ -129,31 +123,26 @@ fn fails_invalid_funcs() {
 
 #[test]
 fn ensures_not_has_one_predicate() {
+    let err = Expression::parse("not()").unwrap_err();
     assert_eq!(
-        Expression::parse("not()").unwrap_err(),
-        ParseError {
-            original: "not()".to_owned(),
-            span: 0..5,
-            reason: Reason::InvalidNot(0),
-        }
+        (err.original.as_str(), err.span, err.reason),
+        ("not()", 0..5, Reason::InvalidNot(0))
     );
 
+    let err = Expression::parse("not(key_one, key_two)").unwrap_err();
     assert_eq!(
-        Expression::parse("not(key_one, key_two)").unwrap_err(),
-        ParseError {
-            original: "not(key_one, key_two)".to_owned(),
-            span: 0..21,
-            reason: Reason::InvalidNot(2),
-        }
+        (err.original.as_str(), err.span, err.reason),
+        ("not(key_one, key_two)", 0..21, Reason::InvalidNot(2))
     );
 
+    let err = Expression::parse("any(not(not(key_one, key_two)))").unwrap_err();
     assert_eq!(
-        Expression::parse("any(not(not(key_one, key_two)))").unwrap_err(),
-        ParseError {
-            original: "any(not(not(key_one, key_two)))".to_owned(),
-            span: 8..29,
-            reason: Reason::InvalidNot(2),
-        }
+        (err.original.as_str(), err.span, err.reason),
+        (
+            "any(not(not(key_one, key_two)))",
+            8..29,
+            Reason::InvalidNot(2)
+        )
     );
 
     test_validate!(ok [
@@ -164,3 +153,1572 @@ fn ensures_not_has_one_predicate() {
         "not(not(not(key = \"value\",)))" => [P::KeyValue { key: "key", val: "value" }],
     ]);
 }
+
+#[test]
+fn round_trips() {
+    for text in [
+        "key",
+        "cfg(key)",
+        " key  = \"val\"",
+        "unix",
+        "target_family = \"windows\"",
+        "target_family = \"wasm\"",
+        "all()",
+        "any()",
+        "not(key,)",
+        "all(unix, target_arch = \"x86_64\", target_pointer_width = \"64\")",
+        "any(target_endian = \"big\", target_has_atomic = \"ptr\", panic = \"abort\")",
+        "all(test, debug_assertions, proc_macro, feature = \"foo\", target_feature = \"sse2\")",
+        "all(any(not(target_os = \"ios\"), target_env = \"\"), not(any(all(), any())))",
+        "cfg(all(any(target_vendor = \"apple\", target_abi = \"eabihf\"),not(not(key))),)",
+    ] {
+        let expr = Expression::parse(text).unwrap();
+        let canonical = expr.to_string();
+        let reparsed = Expression::parse(&canonical).unwrap();
+
+        assert_eq!(canonical, reparsed.to_string(), "{text}");
+        assert!(
+            expr.predicates().eq(reparsed.predicates()),
+            "predicates differ for '{text}' => '{canonical}'"
+        );
+    }
+
+    assert_eq!(
+        Expression::parse("all( unix ,target_family=\"windows\",)")
+            .unwrap()
+            .to_string(),
+        "all(unix, windows)"
+    );
+
+    // Operands keep their source order, even around nested functions
+    assert_eq!(
+        Expression::parse("all(a, not(b), any(c, d), e)")
+            .unwrap()
+            .to_string(),
+        "all(a, not(b), any(c, d), e)"
+    );
+}
+
+#[test]
+fn tree_view() {
+    use cfg_expr::expr::ExprTree as T;
+
+    let expr =
+        Expression::parse(r#"any(not(all(unix, feature = "a")), all(), key = "v")"#).unwrap();
+
+    similar_asserts::assert_eq!(
+        expr.root(),
+        T::Any(vec![
+            T::Not(Box::new(T::All(vec![
+                T::Predicate(P::Target(TP::Family(Family::unix))),
+                T::Predicate(P::Feature("a")),
+            ]))),
+            T::All(vec![]),
+            T::Predicate(P::KeyValue {
+                key: "key",
+                val: "v"
+            }),
+        ])
+    );
+
+    similar_asserts::assert_eq!(
+        Expression::parse("key").unwrap().root(),
+        T::Predicate(P::Flag("key"))
+    );
+}
+
+#[test]
+fn visits_in_order() {
+    use cfg_expr::expr::Visitor;
+
+    #[derive(Default)]
+    struct Events(Vec<String>);
+
+    impl<'a> Visitor<'a> for Events {
+        fn visit_predicate(&mut self, pred: &P<'a>) {
+            self.0.push(format!("{pred:?}"));
+        }
+
+        fn visit_all(&mut self, num: usize) {
+            self.0.push(format!("enter all {num}"));
+        }
+
+        fn exit_all(&mut self, num: usize) {
+            self.0.push(format!("exit all {num}"));
+        }
+
+        fn visit_any(&mut self, num: usize) {
+            self.0.push(format!("enter any {num}"));
+        }
+
+        fn exit_any(&mut self, num: usize) {
+            self.0.push(format!("exit any {num}"));
+        }
+
+        fn visit_not(&mut self) {
+            self.0.push("enter not".to_owned());
+        }
+
+        fn exit_not(&mut self) {
+            self.0.push("exit not".to_owned());
+        }
+    }
+
+    let mut events = Events::default();
+    Expression::parse("all(a, not(any(b, c)), any())")
+        .unwrap()
+        .visit(&mut events);
+
+    assert_eq!(
+        events.0,
+        [
+            "enter all 3",
+            "Flag(\"a\")",
+            "enter not",
+            "enter any 2",
+            "Flag(\"b\")",
+            "Flag(\"c\")",
+            "exit any 2",
+            "exit not",
+            "enter any 0",
+            "exit any 0",
+            "exit all 3",
+        ]
+    );
+}
+
+#[test]
+fn structural_eq_and_hash() {
+    use std::collections::HashSet;
+
+    let mut set = HashSet::new();
+
+    for expr in [
+        "all(unix, feature = \"a\")",
+        "all( target_family = \"unix\" ,feature=\"a\",)",
+        "cfg(all(unix, feature = \"a\"))",
+        "all(feature = \"a\", unix)",
+        "any(unix, feature = \"a\")",
+        "all(unix, feature = \"b\")",
+        "all(all(unix), feature = \"a\")",
+    ] {
+        set.insert(Expression::parse(expr).unwrap());
+    }
+
+    assert_eq!(set.len(), 5);
+    assert!(set.contains(&Expression::parse("all(feature=\"a\",unix)").unwrap()));
+    assert!(!set.contains(&Expression::parse("unix").unwrap()));
+}
+
+#[test]
+fn clones_are_independent() {
+    let expr = Expression::parse(r#"all(target_os = "linux", feature = "a", not(bare))"#).unwrap();
+    let cloned = expr.clone();
+    drop(expr);
+
+    assert_eq!(
+        cloned.original(),
+        r#"all(target_os = "linux", feature = "a", not(bare))"#
+    );
+    assert_eq!(
+        cloned.predicates().collect::<Vec<_>>(),
+        [
+            P::Target(TP::Os(Os::linux)),
+            P::Feature("a"),
+            P::Flag("bare"),
+        ]
+    );
+}
+
+#[test]
+fn owned_predicates() {
+    use cfg_expr::expr::PredicateOwned as PO;
+
+    let owned: Vec<PO> = {
+        let expr = Expression::parse(
+            r#"all(target_os = "linux", test, feature = "a", target_feature = "sse2", bare, key = "val")"#,
+        )
+        .unwrap();
+        expr.predicates().map(PO::from).collect()
+    };
+
+    assert_eq!(
+        owned,
+        [
+            PO::Target(TP::Os(Os::linux)),
+            PO::Test,
+            PO::Feature("a".to_owned()),
+            PO::TargetFeature("sse2".to_owned()),
+            PO::Flag("bare".to_owned()),
+            PO::KeyValue {
+                key: "key".to_owned(),
+                val: "val".to_owned()
+            },
+        ]
+    );
+
+    assert_eq!(owned[2].as_predicate(), P::Feature("a"));
+    assert_eq!(
+        P::from(&owned[5]),
+        P::KeyValue {
+            key: "key",
+            val: "val"
+        }
+    );
+    assert_eq!(owned[4], P::Flag("bare"));
+    assert_eq!(P::Flag("bare"), owned[4]);
+}
+
+#[test]
+fn borrows_known_values() {
+    use std::borrow::Cow;
+
+    let expr = Expression::parse(
+        r#"all(target_arch = "x86_64", target_os = "none", target_family = "wasm", target_env = "madeup")"#,
+    )
+    .unwrap();
+
+    let borrowed: Vec<_> = expr
+        .predicates()
+        .map(|pred| match pred {
+            P::Target(
+                TP::Arch(Arch(val)) | TP::Os(Os(val)) | TP::Family(Family(val)) | TP::Env(Env(val)),
+            ) => matches!(val, Cow::Borrowed(_)),
+            _ => unreachable!(),
+        })
+        .collect();
+
+    // Known values aren't allocated for each expression
+    assert_eq!(borrowed, [true, true, true, false]);
+}
+
+#[test]
+fn spans() {
+    let expr = Expression::parse(
+        r#"cfg(all( unix , target_pointer_width="64", not(any(test, feature = "a")), key = "val" ))"#,
+    )
+    .unwrap();
+    let original = expr.original();
+
+    assert_eq!(
+        expr.predicates_with_spans()
+            .map(|(_, span)| &original[span])
+            .collect::<Vec<_>>(),
+        [
+            "unix",
+            r#"target_pointer_width="64""#,
+            "test",
+            r#"feature = "a""#,
+            r#"key = "val""#
+        ]
+    );
+
+    assert_eq!(
+        expr.functions_with_spans()
+            .map(|(_, span)| &original[span])
+            .collect::<Vec<_>>(),
+        [
+            r#"any(test, feature = "a")"#,
+            r#"not(any(test, feature = "a"))"#,
+            original,
+        ]
+    );
+
+    // Expressions that are written rather than parsed also have spans
+    for expr in [expr.simplify(), expr.to_dnf()] {
+        let original = expr.original();
+
+        for (pred, span) in expr.predicates_with_spans() {
+            assert_eq!(
+                Expression::parse(&original[span]).unwrap().root(),
+                cfg_expr::expr::ExprTree::Predicate(pred)
+            );
+        }
+
+        for (_, span) in expr.functions_with_spans() {
+            let func = &original[span];
+            assert!(
+                func.starts_with("all(") || func.starts_with("any(") || func.starts_with("not(")
+            );
+            assert!(func.ends_with(')'));
+        }
+    }
+}
+
+#[test]
+fn error_codes_and_rendering() {
+    let codes: Vec<_> = [
+        "not()",
+        "all(",
+        "any())",
+        "",
+        "a, b",
+        "target_pointer_width = \"x\"",
+    ]
+    .iter()
+    .map(|text| Expression::parse(text).unwrap_err().code())
+    .collect();
+    assert_eq!(
+        codes,
+        ["E0001", "E0003", "E0004", "E0007", "E0010", "E0009"]
+    );
+
+    // Columns are counted in characters, and errors at the end of the string
+    // still get an underline
+    similar_asserts::assert_eq!(
+        Expression::parse("all(feature = \"ü\", target_os =").unwrap_err().render(),
+        "error[E0008]: expected a `\"<value>\"` here\n --> 1:31\n  |\n  | all(feature = \"ü\", target_os =\n  |                               ^\n"
+    );
+
+    // Invalid multibyte characters are underlined as a single character
+    let err = Expression::parse("all(é, 日本)").unwrap_err();
+    assert_eq!(err.span, 4..6);
+    similar_asserts::assert_eq!(
+        err.render(),
+        "error[E0008]: expected one of `<key>`, `all`, `any`, `not` here\n --> 1:5\n  |\n  | all(é, 日本)\n  |     ^\n"
+    );
+    similar_asserts::assert_eq!(
+        err.to_string(),
+        "all(é, 日本)\n    ^ expected one of `<key>`, `all`, `any`, `not` here"
+    );
+
+    // Spans that split a character are clamped to its start
+    let mut err = err;
+    err.span = 5..9;
+    assert_eq!(err.render().lines().last(), Some("  |     ^^^"));
+    assert_eq!(
+        err.to_string().lines().last(),
+        Some("    ^^^ expected one of `<key>`, `all`, `any`, `not` here")
+    );
+}
+
+#[test]
+fn recovers_from_errors() {
+    macro_rules! recovers {
+        ($text:expr => $expected:expr) => {
+            let (expr, errors) = Expression::parse_with_recovery($text);
+            assert!(expr.is_none(), "{}", $text);
+
+            // The first error is always the same as the one from a regular parse
+            let first = Expression::parse($text).unwrap_err();
+            assert_eq!(
+                (&first.span, &first.reason),
+                (&errors[0].span, &errors[0].reason)
+            );
+
+            similar_asserts::assert_eq!(
+                errors
+                    .into_iter()
+                    .map(|err| (err.reason, err.span))
+                    .collect::<Vec<_>>(),
+                $expected,
+                "{}",
+                $text
+            );
+        };
+    }
+
+    recovers!(r#"all(target_os = "linux", feature, target_pointer_width = "x")"# => [
+        (Reason::Unexpected(&["= \"<feature_name>\""]), 25..32),
+        (Reason::InvalidInteger, 58..59),
+    ]);
+    recovers!("any(a b c, not(d e), f ! g)" => [
+        (Reason::Unexpected(&["=", ",", ")"]), 6..7),
+        (Reason::Unexpected(&["=", ",", ")"]), 17..18),
+        (Reason::Unexpected(&["<key>", "all", "any", "not"]), 23..24),
+    ]);
+    recovers!("all(a(b), not(), c =)" => [
+        (Reason::Unexpected(&["=", ",", ")"]), 5..6),
+        (Reason::InvalidNot(0), 10..15),
+        (Reason::Unexpected(&["\""]), 20..21),
+    ]);
+    recovers!("all(a, not(b, c), any(d)))" => [
+        (Reason::InvalidNot(2), 7..16),
+        (Reason::UnopenedParens, 25..26),
+    ]);
+    recovers!(r#"all(a, b = "c"# => [
+        (Reason::UnclosedQuotes, 11..13),
+        (Reason::UnclosedParens, 3..13),
+    ]);
+    recovers!("a, b = " => [
+        (Reason::Unexpected(&["\"<value>\""]), 7..7),
+        (Reason::MultipleRootPredicates, 0..7),
+    ]);
+    recovers!(" " => [(Reason::Empty, 0..1)]);
+
+    let (expr, errors) = Expression::parse_with_recovery(r#"all(unix, not(feature = "a"))"#);
+    assert!(errors.is_empty());
+    assert_eq!(
+        expr.unwrap(),
+        Expression::parse(r#"all(unix, not(feature = "a"))"#).unwrap()
+    );
+}
+
+#[test]
+fn suggests_misspelled_keys() {
+    let err = Expression::parse(r#"target_oss = "linux""#).unwrap_err();
+    assert_eq!(
+        (err.reason, err.span),
+        (Reason::UnknownKey(Some("target_os")), 0..10)
+    );
+    let err = Expression::parse("target_pointerwidth").unwrap_err();
+    assert_eq!(
+        (err.reason, err.span),
+        (Reason::UnknownKey(Some("target_pointer_width")), 0..19)
+    );
+    let err = Expression::parse(r#"target_nope = "x""#).unwrap_err();
+    assert_eq!((err.reason, err.span), (Reason::UnknownKey(None), 0..11));
+
+    assert_eq!(
+        Expression::parse(r#"target_oss = "x""#)
+            .unwrap_err()
+            .reason
+            .to_string(),
+        "unknown key, did you mean `target_os`?"
+    );
+
+    // Other custom keys are allowed, even if they are similar to a known key,
+    // as rustc allows them, but validating reports the similar ones
+    test_validate!(ok [
+        "all(docsrs, loom, linux, nightly, tokio_unstable)" => [
+            P::Flag("docsrs"),
+            P::Flag("loom"),
+            P::Flag("linux"),
+            P::Flag("nightly"),
+            P::Flag("tokio_unstable"),
+        ],
+        r#"all(tests, unic, docs, mir, featurre = "x")"# => [
+            P::Flag("tests"),
+            P::Flag("unic"),
+            P::Flag("docs"),
+            P::Flag("mir"),
+            P::KeyValue { key: "featurre", val: "x" },
+        ],
+    ]);
+
+    let expr = Expression::parse(r#"all(unix, featurre = "x", any(tests, docsrs))"#).unwrap();
+    let errors: Vec<_> = expr
+        .validate()
+        .unwrap_err()
+        .into_iter()
+        .map(|err| (err.reason, err.span))
+        .collect();
+    assert_eq!(
+        errors,
+        [
+            (Reason::UnknownKey(Some("feature")), 10..18),
+            (Reason::UnknownKey(Some("test")), 30..35),
+        ]
+    );
+    assert!(Expression::parse("all(docsrs, loom)")
+        .unwrap()
+        .validate()
+        .is_ok());
+}
+
+#[test]
+fn validates_target_values() {
+    // Every value used by a builtin target is valid, as are the values used
+    // for targets that don't have an os, env, etc.
+    for ti in ALL_BUILTINS {
+        let expr = format!(
+            r#"all(target_arch = "{}", target_os = "{}", target_env = "{}", target_abi = "{}", target_vendor = "{}", panic = "{}", any({}))"#,
+            ti.arch,
+            ti.os.as_ref().map_or("none", |os| os.as_str()),
+            ti.env.as_ref().map_or("", |env| env.as_str()),
+            ti.abi.as_ref().map_or("", |abi| abi.as_str()),
+            ti.vendor.as_ref().map_or("unknown", |ven| ven.as_str()),
+            ti.panic,
+            ti.families
+                .iter()
+                .map(|fam| format!(r#"target_family = "{fam}""#))
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+
+        Expression::parse(&expr).unwrap().validate().unwrap();
+    }
+
+    let expr = Expression::parse(
+        r#"all(unix, target_os = "linuxx", any(target_arch = "x86-64", target_family = "wasm"), feature = "linuxx", target_vendor = "zzz")"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        expr.validate()
+            .unwrap_err()
+            .into_iter()
+            .map(|err| (err.reason, err.span))
+            .collect::<Vec<_>>(),
+        [
+            (Reason::UnknownValue(Some("linux")), 23..29),
+            (Reason::UnknownValue(Some("x86_64")), 51..57),
+            (Reason::UnknownValue(None), 122..125),
+        ]
+    );
+}
+
+#[test]
+fn parses_attributes() {
+    use cfg_expr::expr::CfgAttr;
+
+    for attr in [
+        "#[cfg(unix)]",
+        "#![cfg(unix)]",
+        " # [ cfg ( unix ) ] ",
+        "#[cfg(\n    unix,\n)]",
+    ] {
+        let expr = Expression::parse_attribute(attr).unwrap();
+        assert_eq!(
+            expr.predicates().collect::<Vec<_>>(),
+            [P::Target(TP::Family(Family::unix))],
+            "{attr}"
+        );
+    }
+
+    for (attr, span) in [
+        ("cfg(unix)", 0..9),
+        ("#[cfg_attr(unix, test)]", 0..23),
+        ("#[cfg(unix)", 6..11),
+        ("#[cfg(unix]", 6..11),
+    ] {
+        let err = Expression::parse_attribute(attr).unwrap_err();
+        assert_eq!(
+            (err.reason, err.span),
+            (Reason::Unexpected(&["#[cfg(<predicate>)]"]), span),
+            "{attr}"
+        );
+    }
+
+    // Errors in the predicate point into the whole attribute
+    let err = Expression::parse_attribute("#[cfg(  all(unix, target_os = ))]").unwrap_err();
+    assert_eq!(err.original, "#[cfg(  all(unix, target_os = ))]");
+    assert_eq!(
+        (err.reason, err.span),
+        (Reason::Unexpected(&["\""]), 30..31)
+    );
+
+    let attr =
+        CfgAttr::parse("#[cfg_attr(all(unix, feature = \"a\"), path = \"unix.rs\")]").unwrap();
+    assert_eq!(
+        attr.predicate,
+        Expression::parse("all(unix, feature = \"a\")").unwrap()
+    );
+    assert_eq!(attr.attributes, ["path = \"unix.rs\""]);
+
+    let attr =
+        CfgAttr::parse("#![cfg_attr(docsrs, feature(doc_cfg), allow(unused, \"),\"),)]").unwrap();
+    assert_eq!(attr.predicate.original(), "docsrs");
+    assert_eq!(
+        attr.attributes,
+        ["feature(doc_cfg)", "allow(unused, \"),\")"]
+    );
+
+    let attr = CfgAttr::parse("#[cfg_attr(test,)]").unwrap();
+    assert!(attr.attributes.is_empty());
+
+    let err = CfgAttr::parse("#[cfg_attr(test)]").unwrap_err();
+    assert_eq!((err.reason, err.span), (Reason::Unexpected(&[","]), 15..15));
+
+    let err = CfgAttr::parse("#[cfg_attr(any(test, target_oss = \"a\"), inline)]").unwrap_err();
+    assert_eq!(
+        (err.reason, err.span),
+        (Reason::UnknownKey(Some("target_os")), 21..31)
+    );
+}
+
+#[test]
+fn cfg_wrapper() {
+    for text in [
+        "cfg(unix)",
+        "  cfg(unix)\n",
+        "cfg ( unix )",
+        "cfg(\n    unix,\n)",
+    ] {
+        let expr = Expression::parse(text).unwrap();
+        assert_eq!(
+            expr.predicates().collect::<Vec<_>>(),
+            [P::Target(TP::Family(Family::unix))],
+            "{text:?}"
+        );
+        assert!(!expr.original().contains("cfg"), "{text:?}");
+    }
+
+    // Errors point into the string that was passed, including the wrapper
+    let err = Expression::parse(r#" cfg(all(unix, target_os = ))"#).unwrap_err();
+    assert_eq!(err.original, r#" cfg(all(unix, target_os = ))"#);
+    assert_eq!(
+        (err.reason, err.span),
+        (Reason::Unexpected(&["\""]), 27..28)
+    );
+
+    let (_, errors) = Expression::parse_with_recovery("cfg(any(a b, not()))");
+    assert_eq!(
+        errors
+            .into_iter()
+            .map(|err| (err.reason, err.span))
+            .collect::<Vec<_>>(),
+        [
+            (Reason::Unexpected(&["=", ",", ")"]), 10..11),
+            (Reason::InvalidNot(0), 13..18),
+        ]
+    );
+}
+
+#[test]
+fn version_predicate() {
+    use cfg_expr::expr::RustVersion;
+
+    let v = |major, minor, patch| {
+        P::Version(RustVersion {
+            major,
+            minor,
+            patch,
+        })
+    };
+
+    test_validate!(ok [
+        r#"version("1.47")"# => [v(1, 47, None)],
+        r#"all(version( "1.47.1" ), unix)"# => [v(1, 47, Some(1)), P::Target(TP::Family(Family::unix))],
+        r#"not(version("1.0"),)"# => [v(1, 0, None)],
+        // Only the call form is the version predicate
+        "version" => [P::Flag("version")],
+        r#"version = "1.47""# => [P::KeyValue { key: "version", val: "1.47" }],
+    ]);
+
+    for (text, reason, span) in [
+        (r#"version("1.x")"#, Reason::InvalidVersion, 9..12),
+        (r#"version("1")"#, Reason::InvalidVersion, 9..10),
+        (r#"version("1.2.3.4")"#, Reason::InvalidVersion, 9..16),
+        (r#"version("v1.2")"#, Reason::InvalidVersion, 9..13),
+        ("version()", Reason::Unexpected(&["\"<version>\""]), 8..9),
+        (
+            r#"version("1.2" "1.3")"#,
+            Reason::Unexpected(&[")"]),
+            14..19,
+        ),
+        (
+            r#"version(unix)"#,
+            Reason::Unexpected(&["\"<version>\""]),
+            8..12,
+        ),
+        (r#"all(version("1.2")"#, Reason::UnclosedParens, 3..18),
+        (r#"version("1.2""#, Reason::UnclosedParens, 0..13),
+        (
+            r#"version("1.2") = "x""#,
+            Reason::Unexpected(&[")", ","]),
+            15..16,
+        ),
+        (r#"foo("1.2")"#, Reason::Unexpected(&["=", ",", ")"]), 3..4),
+    ] {
+        let err = Expression::parse(text).unwrap_err();
+        assert_eq!((err.reason, err.span), (reason, span), "{text}");
+    }
+
+    let (_, errors) =
+        Expression::parse_with_recovery(r#"any(version(unix), version(), version("x"), a b)"#);
+    assert_eq!(
+        errors
+            .into_iter()
+            .map(|err| (err.reason, err.span))
+            .collect::<Vec<_>>(),
+        [
+            (Reason::Unexpected(&["\"<version>\""]), 12..16),
+            (Reason::Unexpected(&["\"<version>\""]), 27..28),
+            (Reason::InvalidVersion, 39..40),
+            (Reason::Unexpected(&["=", ",", ")"]), 46..47),
+        ]
+    );
+
+    let expr = Expression::parse(r#"all(version("1.47"), not(version("1.70.1")))"#).unwrap();
+    assert_eq!(
+        expr.to_string(),
+        r#"all(version("1.47"), not(version("1.70.1")))"#
+    );
+    assert_eq!(expr.simplify().original(), expr.original());
+
+    for (rustc, expected) in [
+        ("1.46.0", false),
+        ("1.47.0", true),
+        ("1.70.0", true),
+        ("1.70.1", false),
+    ] {
+        let rustc: RustVersion = rustc.parse().unwrap();
+        assert_eq!(
+            expr.eval(|pred| match pred {
+                P::Version(version) => version.matches(&rustc),
+                _ => false,
+            }),
+            expected,
+            "{rustc}"
+        );
+    }
+}
+
+#[test]
+fn accessible_predicate() {
+    test_validate!(ok [
+        "accessible(::std::arch::x86_64::_mm_foo)" => [P::Accessible("::std::arch::x86_64::_mm_foo")],
+        "any(accessible( core::hint::black_box ), unix)" => [P::Accessible("core::hint::black_box"), P::Target(TP::Family(Family::unix))],
+        "not(accessible(Foo))" => [P::Accessible("Foo")],
+        // Only the call form is the accessible predicate
+        "accessible" => [P::Flag("accessible")],
+    ]);
+
+    for (text, reason, span) in [
+        ("accessible()", Reason::Unexpected(&["<path>"]), 11..12),
+        (
+            r#"accessible("std::mem")"#,
+            Reason::Unexpected(&["<path>"]),
+            11..21,
+        ),
+        ("accessible(a b)", Reason::Unexpected(&[")"]), 13..14),
+        (
+            "accessible(std::)",
+            Reason::Unexpected(&["<key>", "all", "any", "not"]),
+            14..15,
+        ),
+        ("accessible(std::mem", Reason::UnclosedParens, 0..19),
+        ("std::mem", Reason::InvalidCharacters, 0..8),
+        ("all(unix, ::std)", Reason::InvalidCharacters, 10..15),
+    ] {
+        let err = Expression::parse(text).unwrap_err();
+        assert_eq!((err.reason, err.span), (reason, span), "{text}");
+    }
+
+    let expr = Expression::parse("all(accessible(::std::mem::take),not(unix))").unwrap();
+    assert_eq!(
+        expr.predicates_with_spans()
+            .map(|(_, span)| &expr.original()[span])
+            .collect::<Vec<_>>(),
+        ["accessible(::std::mem::take)", "unix"]
+    );
+    assert_eq!(
+        expr.to_string(),
+        "all(accessible(::std::mem::take), not(unix))"
+    );
+    assert_eq!(
+        expr.simplify().to_string(),
+        "all(accessible(::std::mem::take), not(unix))"
+    );
+
+    let owned = cfg_expr::expr::PredicateOwned::from(expr.predicates().next().unwrap());
+    assert_eq!(
+        owned,
+        cfg_expr::expr::PredicateOwned::Accessible("::std::mem::take".to_owned())
+    );
+
+    assert!(expr.eval(|pred| match pred {
+        P::Accessible(path) => *path == "::std::mem::take",
+        _ => false,
+    }));
+}
+
+#[test]
+fn sanitize_predicate() {
+    test_validate!(ok [
+        r#"sanitize = "address""# => [P::Sanitize("address")],
+        r#"any(sanitize = "thread", sanitize = "memory", not(sanitize = "leak"))"# => [
+            P::Sanitize("thread"),
+            P::Sanitize("memory"),
+            P::Sanitize("leak"),
+        ],
+    ]);
+
+    let err = Expression::parse("all(sanitize)").unwrap_err();
+    assert_eq!(
+        (err.reason, err.span),
+        (Reason::Unexpected(&["= \"<sanitizer>\""]), 4..12)
+    );
+
+    let err = Expression::parse(r#"sanitise = "address""#)
+        .unwrap()
+        .validate()
+        .unwrap_err();
+    assert_eq!(err[0].reason, Reason::UnknownKey(Some("sanitize")));
+
+    let expr = Expression::parse(r#"all(sanitize="address",unix)"#).unwrap();
+    assert_eq!(expr.to_string(), r#"all(sanitize = "address", unix)"#);
+
+    let pred = expr.predicates().next().unwrap();
+    assert_eq!(pred.matches_sanitizers(&["address", "leak"]), Some(true));
+    assert_eq!(pred.matches_sanitizers(&["thread"]), Some(false));
+    assert_eq!(P::Test.matches_sanitizers(&["address"]), None);
+    assert_eq!(
+        cfg_expr::expr::PredicateOwned::from(pred),
+        cfg_expr::expr::PredicateOwned::Sanitize("address".to_owned())
+    );
+}
+
+#[test]
+fn builtin_flags() {
+    test_validate!(ok [
+        "target_thread_local" => [P::TargetThreadLocal],
+        "any(doc, doctest)" => [P::Doc, P::Doctest],
+        "not(miri)" => [P::Miri],
+        "fuzzing" => [P::Fuzzing],
+        "all(overflow_checks, ub_checks)" => [P::OverflowChecks, P::UbChecks],
+    ]);
+
+    for text in [
+        r#"target_thread_local = "yes""#,
+        r#"doc = "x""#,
+        r#"miri = "1""#,
+        r#"ub_checks = """#,
+    ] {
+        let err = Expression::parse(text).unwrap_err();
+        assert_eq!(err.reason, Reason::Unexpected(&[]), "{text}");
+    }
+
+    let expr = Expression::parse("any(doc,not(ub_checks))").unwrap();
+    assert_eq!(expr.to_string(), "any(doc, not(ub_checks))");
+    assert_eq!(
+        cfg_expr::expr::PredicateOwned::from(expr.predicates().next().unwrap()),
+        cfg_expr::expr::PredicateOwned::Doc
+    );
+}
+
+#[test]
+fn target_shorthand() {
+    test_validate!(ok [
+        r#"target(os = "linux", arch = "x86_64")"# => [
+            P::Target(TP::Os(Os::linux)),
+            P::Target(TP::Arch(Arch::x86_64)),
+        ],
+        r#"any(unix, target(env = "gnu", has_atomic = "64",))"# => [
+            P::Target(TP::Family(Family::unix)),
+            P::Target(TP::Env(Env::gnu)),
+            P::Target(TP::HasAtomic(HasAtomic::IntegerSize(64))),
+        ],
+        r#"not(target(feature = "sse2"))"# => [P::TargetFeature("sse2")],
+    ]);
+
+    // It is the same as an all() of the target predicates
+    let expr =
+        Expression::parse(r#"any(feature = "a", target(os = "linux", arch = "arm"))"#).unwrap();
+    assert_eq!(
+        expr,
+        Expression::parse(r#"any(feature = "a", all(target_os = "linux", target_arch = "arm"))"#)
+            .unwrap()
+    );
+    assert_eq!(
+        expr.functions_with_spans()
+            .map(|(func, span)| (func, &expr.original()[span]))
+            .collect::<Vec<_>>(),
+        [
+            (
+                cfg_expr::expr::Func::All(2),
+                r#"target(os = "linux", arch = "arm")"#
+            ),
+            (
+                cfg_expr::expr::Func::Any(2),
+                r#"any(feature = "a", target(os = "linux", arch = "arm"))"#
+            ),
+        ]
+    );
+
+    for (text, reason, span) in [
+        (
+            "target(os)",
+            Reason::Unexpected(&["= \"<target_cfg_value>\""]),
+            7..9,
+        ),
+        (
+            r#"target(all(os = "linux"))"#,
+            Reason::Unexpected(&["<key>", ")"]),
+            7..10,
+        ),
+        (
+            r#"target(os = "linux", target(arch = "arm"))"#,
+            Reason::Unexpected(&["=", ",", ")"]),
+            27..28,
+        ),
+        (
+            r#"target(oss = "linux")"#,
+            Reason::UnknownKey(Some("os")),
+            7..10,
+        ),
+        (r#"target(os = "linux""#, Reason::UnclosedParens, 6..19),
+    ] {
+        let err = Expression::parse(text).unwrap_err();
+        assert_eq!((err.reason, err.span), (reason, span), "{text}");
+    }
+}
+
+#[test]
+fn validates_against_check_cfg() {
+    use cfg_expr::expr::{CheckCfg, ExpectedValues};
+
+    let mut check_cfg = CheckCfg::parse(r#"cfg(feature, values("std", "serde",))"#).unwrap();
+    check_cfg.add("cfg(docsrs, nightly)").unwrap();
+    check_cfg
+        .add(r#"cfg(nightly, values("2024", none()))"#)
+        .unwrap();
+    check_cfg.add("cfg(any_value, values(any()))").unwrap();
+
+    assert_eq!(
+        check_cfg.expected_values("nightly"),
+        Some(&ExpectedValues::Some(
+            [None, Some("2024".to_owned())].into_iter().collect()
+        ))
+    );
+    assert_eq!(
+        check_cfg.expected_values("any_value"),
+        Some(&ExpectedValues::Any)
+    );
+    assert!(check_cfg.is_expected("nightly", None));
+    assert!(check_cfg.is_expected("nightly", Some("2024")));
+    assert!(!check_cfg.is_expected("docsrs", Some("2024")));
+    assert!(check_cfg.is_expected("any_value", None));
+    assert!(!check_cfg.is_expected("unknown", None));
+
+    Expression::parse(
+        r#"all(unix, target_os = "linux", feature = "std", any(docsrs, nightly = "2024"), any_value = "x")"#,
+    )
+    .unwrap()
+    .validate_against(&check_cfg)
+    .unwrap();
+
+    let expr = Expression::parse(
+        r#"any(feature = "alloc", docsrs = "yes", nightly = "2025", unknown, test)"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        expr.validate_against(&check_cfg)
+            .unwrap_err()
+            .into_iter()
+            .map(|err| (err.reason, &expr.original()[err.span]))
+            .collect::<Vec<_>>(),
+        [
+            (Reason::UnexpectedCfgValue, "alloc"),
+            (Reason::UnexpectedCfgValue, "yes"),
+            (Reason::UnexpectedCfgValue, "2025"),
+            (Reason::UnexpectedCfgName, "unknown"),
+        ]
+    );
+
+    // any() expects every name
+    let check_cfg = CheckCfg::parse("cfg(any())").unwrap();
+    expr.validate_against(&check_cfg).unwrap();
+
+    for (spec, reason, span) in [
+        ("feature", Reason::Unexpected(&["cfg("]), 0..7),
+        (
+            r#"cfg(feature, values("std"), docsrs)"#,
+            Reason::Unexpected(&[")"]),
+            28..34,
+        ),
+        (
+            r#"cfg(feature, values(std))"#,
+            Reason::Unexpected(&["\"<value>\"", "none()", "any()", ")"]),
+            20..23,
+        ),
+        (
+            "cfg(all())",
+            Reason::Unexpected(&["<name>", "any()", "values()"]),
+            4..7,
+        ),
+    ] {
+        let err = CheckCfg::parse(spec).unwrap_err();
+        assert_eq!((err.reason, err.span), (reason, span), "{spec}");
+    }
+}
+
+#[test]
+fn boolean_literals() {
+    test_validate!(ok [
+        "true" => [P::Boolean(true)],
+        "any(false, unix)" => [P::Boolean(false), P::Target(TP::Family(Family::unix))],
+    ]);
+
+    let err = Expression::parse(r#"true = "1""#).unwrap_err();
+    assert_eq!((err.reason, err.span), (Reason::Unexpected(&[]), 8..9));
+
+    // Literals are folded without being passed to the caller
+    for (text, expected) in [
+        ("true", true),
+        ("false", false),
+        ("not(false)", true),
+        ("all(true, unix)", true),
+        ("all(false, unix)", false),
+        ("any(false, windows)", false),
+    ] {
+        let expr = Expression::parse(text).unwrap();
+        assert_eq!(
+            expr.eval(|pred| match pred {
+                P::Target(TP::Family(fam)) => *fam == Family::unix,
+                other => panic!("unexpected predicate {other:?}"),
+            }),
+            expected,
+            "{text}"
+        );
+    }
+
+    let expr = Expression::parse("any(all(true, unix), false, test)").unwrap();
+    assert_eq!(expr.to_string(), "any(all(true, unix), false, test)");
+    assert_eq!(expr.simplify().to_string(), "any(unix, test)");
+    assert_eq!(
+        Expression::parse("all(unix, not(true))")
+            .unwrap()
+            .simplify()
+            .to_string(),
+        "any()"
+    );
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn arbitrary_round_trips() {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    // A simple LCG, so the data is random but the same on every run
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut data = vec![0u8; 4096];
+
+    for _ in 0..500 {
+        for byte in &mut data {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            *byte = (state >> 56) as u8;
+        }
+
+        let expr = Expression::arbitrary(&mut Unstructured::new(&data)).unwrap();
+
+        let displayed = expr.to_string();
+        assert_eq!(Expression::parse(&displayed).unwrap(), expr, "{displayed}");
+        assert_eq!(Expression::parse(expr.original()).unwrap(), expr);
+    }
+
+    // Running out of data still produces a valid expression
+    assert!(Expression::arbitrary(&mut Unstructured::new(&[])).is_ok());
+}
+
+#[test]
+fn resource_limits() {
+    use cfg_expr::expr::ParseOptions;
+
+    let limits = ParseOptions::default()
+        .max_len(Some(64))
+        .max_depth(Some(2))
+        .max_predicates(Some(3));
+
+    for text in [
+        "unix",
+        "all(unix, not(test))",
+        "cfg(any(a, b, c))",
+        r#"all(version("1.70"), accessible(std::ptr), target(os = "linux"))"#,
+    ] {
+        assert!(
+            Expression::parse_with_options(text, &limits).is_ok(),
+            "{text}"
+        );
+    }
+
+    for (text, reason, span) in [
+        (
+            "all(unix, windows, target_os = \"linux\", target_arch = \"x86_64\", test)",
+            Reason::TooLong(64),
+            64..69,
+        ),
+        ("all(any(not(unix)))", Reason::TooDeep(2), 11..12),
+        ("cfg(all(any(not(unix))))", Reason::TooDeep(2), 15..16),
+        ("any(a, b, c, d)", Reason::TooManyPredicates(3), 13..14),
+        (
+            r#"all(a, b, version("1.70"), accessible(std::ptr))"#,
+            Reason::TooManyPredicates(3),
+            38..46,
+        ),
+    ] {
+        let err = Expression::parse_with_options(text, &limits).unwrap_err();
+        assert_eq!(err.reason, reason, "{text}");
+        assert_eq!(err.span, span, "{text}");
+    }
+
+    let err = Expression::parse_with_options(&format!("{}é", "a".repeat(63)), &limits).unwrap_err();
+    assert_eq!(err.span, 63..65);
+    assert!(err.render().ends_with(" ^\n"));
+
+    // The limits are checked before the expression is parsed, so they apply
+    // even if it is invalid
+    let deep = format!("{}unix{}", "not(".repeat(100_000), ")".repeat(99_999));
+    assert_eq!(
+        Expression::parse_with_options(&deep, &ParseOptions::untrusted())
+            .unwrap_err()
+            .reason,
+        Reason::TooLong(64 * 1024)
+    );
+    let deep = format!("{}unix{}", "not(".repeat(100), ")".repeat(99));
+    assert_eq!(
+        Expression::parse_with_options(&deep, &ParseOptions::untrusted())
+            .unwrap_err()
+            .code(),
+        "E0019"
+    );
+}
+
+#[test]
+fn format() {
+    use cfg_expr::expr::FormatOptions;
+
+    let text = r#"cfg(all( any(target_os = "macos",target_os="ios" ), not(feature = "b"), feature = "a" ))"#;
+    let expr = Expression::parse(text).unwrap();
+
+    // The defaults are the same as Display
+    assert_eq!(expr.format(&FormatOptions::default()), expr.to_string());
+
+    let compact = FormatOptions {
+        spaces_around_equals: false,
+        space_after_comma: false,
+        sort_operands: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        expr.format(&compact),
+        r#"all(any(target_os="ios",target_os="macos"),feature="a",not(feature="b"))"#
+    );
+
+    let narrow = FormatOptions {
+        indent: 2,
+        max_width: Some(50),
+        ..Default::default()
+    };
+    assert_eq!(
+        expr.format(&narrow),
+        r#"all(
+  any(target_os = "macos", target_os = "ios"),
+  not(feature = "b"),
+  feature = "a",
+)"#
+    );
+
+    // Operands are split until they fit, or are predicates, and the trailing
+    // comma is counted as part of the line
+    let narrowest = FormatOptions {
+        max_width: Some(20),
+        trailing_comma: false,
+        ..Default::default()
+    };
+    assert_eq!(
+        expr.format(&narrowest),
+        r#"all(
+    any(
+        target_os = "macos",
+        target_os = "ios"
+    ),
+    not(
+        feature = "b"
+    ),
+    feature = "a"
+)"#
+    );
+
+    // Empty functions and predicates are never split
+    for text in ["all()", "any()", "a_very_long_flag_name"] {
+        let expr = Expression::parse(text).unwrap();
+        assert_eq!(expr.format(&narrowest), text);
+    }
+
+    // The output is stable, and is a valid expression
+    let reordered = Expression::parse(
+        r#"all(feature="a", not(feature="b"), any(target_os="ios", target_os="macos"))"#,
+    )
+    .unwrap();
+    for options in [FormatOptions::pretty(), compact, narrow, narrowest] {
+        let formatted = expr.format(&options);
+        assert_eq!(
+            Expression::parse(&formatted).unwrap().format(&options),
+            formatted
+        );
+
+        if options.sort_operands {
+            assert_eq!(reordered.format(&options), formatted);
+        } else {
+            assert_eq!(Expression::parse(&formatted).unwrap(), expr);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn interning() {
+    use cfg_expr::expr::ExpressionPool;
+    use std::collections::HashSet;
+
+    let mut pool = ExpressionPool::new();
+    assert!(pool.is_empty());
+
+    let texts = [
+        "unix",
+        "target_family = \"unix\"",
+        "cfg(unix)",
+        "all(unix, feature = \"a\")",
+        "all( unix , feature=\"a\", )",
+        "all(feature = \"a\", unix)",
+        "unix",
+    ];
+    let ids: Vec<_> = texts
+        .iter()
+        .map(|text| pool.intern(text).unwrap())
+        .collect();
+
+    // Only the order of the operands makes the expressions distinct
+    assert_eq!(pool.len(), 3);
+    assert_eq!(ids.iter().collect::<HashSet<_>>().len(), 3);
+    assert_eq!(ids[0], ids[1]);
+    assert_eq!(ids[0], ids[6]);
+    assert_eq!(ids[3], ids[4]);
+    assert_ne!(ids[3], ids[5]);
+
+    // Handles are the interned expression, in the order they were interned
+    assert_eq!(
+        *ids[4],
+        Expression::parse("all(unix, feature = \"a\")").unwrap()
+    );
+    assert_eq!(
+        pool.iter().map(|id| id.original()).collect::<Vec<_>>(),
+        [
+            "unix",
+            "all(unix, feature = \"a\")",
+            "all(feature = \"a\", unix)"
+        ]
+    );
+
+    let expr = Expression::parse("cfg(all(feature = \"a\", unix))").unwrap();
+    assert_eq!(pool.get(&expr), Some(&ids[5]));
+    assert_eq!(pool.insert(expr), ids[5]);
+    assert!(pool.get(&Expression::parse("windows").unwrap()).is_none());
+
+    assert_eq!(
+        pool.intern("all(unix").unwrap_err().reason,
+        Reason::UnclosedParens
+    );
+    assert_eq!(pool.len(), 3);
+}
+
+#[test]
+fn borrowed() {
+    for text in [
+        "unix",
+        "cfg(all(unix, target_arch = \"x86_64\"))",
+        "any(feature = \"a\", not(all(test, version(\"1.70\"), accessible(std::ptr))))",
+        "target(os = \"linux\", env = \"gnu\")",
+    ] {
+        let owned = Expression::parse(text).unwrap();
+        let borrowed = Expression::parse_borrowed(text).unwrap();
+
+        assert_eq!(borrowed.original(), owned.original());
+        assert!(text.contains(borrowed.original()));
+        assert!(borrowed.predicates().eq(owned.predicates()));
+        assert_eq!(
+            borrowed.eval(|pred| matches!(pred, P::Feature(_) | P::Target(_))),
+            owned.eval(|pred| matches!(pred, P::Feature(_) | P::Target(_))),
+        );
+        assert_eq!(Expression::from(borrowed.clone()), owned);
+        assert_eq!(borrowed.into_owned().to_string(), owned.to_string());
+    }
+
+    assert_eq!(
+        Expression::parse_borrowed("cfg(all(unix,))")
+            .unwrap()
+            .original(),
+        "all(unix,)"
+    );
+
+    let err = Expression::parse_borrowed("all(unix, target_os = \"linux\"").unwrap_err();
+    assert_eq!(
+        err,
+        Expression::parse("all(unix, target_os = \"linux\"").unwrap_err()
+    );
+}
+
+#[test]
+fn from_str() {
+    for text in [
+        "unix",
+        "cfg(unix)",
+        "all(feature = \"a\", not(test))",
+        " any() ",
+    ] {
+        let expr = Expression::parse(text).unwrap();
+
+        assert_eq!(text.parse::<Expression>().unwrap(), expr);
+        assert_eq!(Expression::try_from(text).unwrap(), expr);
+
+        let from_string = Expression::try_from(text.to_owned()).unwrap();
+        assert_eq!(from_string, expr);
+        assert_eq!(from_string.original(), expr.original());
+    }
+
+    for text in ["", "all(", "cfg(unix"] {
+        let err = Expression::parse(text).unwrap_err();
+
+        assert_eq!(text.parse::<Expression>().unwrap_err(), err);
+        assert_eq!(Expression::try_from(text).unwrap_err(), err);
+        assert_eq!(Expression::try_from(text.to_owned()).unwrap_err(), err);
+    }
+}
+
+#[test]
+fn predicate_display_and_order() {
+    use std::collections::BTreeSet;
+
+    let expr = Expression::parse(
+        r#"all(target_os="linux", unix, target_family = "wasm", feature = "b", test, feature = "a", a_flag, key = "value", target_has_atomic = "ptr", version("1.70"), accessible(std::ptr), true)"#,
+    )
+    .unwrap();
+
+    // Every predicate is written in its canonical form, which parses to the
+    // same predicate
+    for pred in expr.predicates() {
+        let written = pred.to_string();
+        let reparsed = Expression::parse(&written).unwrap();
+        assert!(reparsed.predicates().eq([pred.clone()]));
+
+        if let P::Target(tp) = &pred {
+            assert_eq!(tp.to_string(), written);
+        }
+    }
+
+    let sorted: Vec<_> = expr
+        .predicates()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|pred| pred.to_string())
+        .collect();
+    assert_eq!(
+        sorted,
+        [
+            "unix",
+            r#"target_family = "wasm""#,
+            r#"target_has_atomic = "ptr""#,
+            r#"target_os = "linux""#,
+            "test",
+            r#"feature = "a""#,
+            r#"feature = "b""#,
+            "true",
+            "a_flag",
+            r#"key = "value""#,
+            r#"version("1.70")"#,
+            "accessible(std::ptr)",
+        ]
+    );
+
+    assert!(TP::PointerWidth(32) < TP::PointerWidth(64));
+    assert!(P::Version("1.70".parse().unwrap()) < P::Version("1.70.1".parse().unwrap()));
+}
+
+#[test]
+fn key_values() {
+    let expr = Expression::parse(
+        r#"all(target_family = "unix", windows, target_family = "wasm", target_endian = "big", target_has_atomic = "ptr", panic = "abort", test, feature = "a", target_feature = "sse2", flag, key = "value", version("1.70"), accessible(std::ptr), false)"#,
+    )
+    .unwrap();
+
+    let parts: Vec<_> = expr
+        .predicates()
+        .map(|pred| {
+            let (key, val) = pred.as_key_value();
+            (key.to_owned(), val.map(|val| val.into_owned()))
+        })
+        .collect();
+
+    let expected = [
+        ("unix", None),
+        ("windows", None),
+        ("target_family", Some("wasm")),
+        ("target_endian", Some("big")),
+        ("target_has_atomic", Some("ptr")),
+        ("panic", Some("abort")),
+        ("test", None),
+        ("feature", Some("a")),
+        ("target_feature", Some("sse2")),
+        ("flag", None),
+        ("key", Some("value")),
+        ("version", Some("1.70")),
+        ("accessible", Some("std::ptr")),
+        ("false", None),
+    ];
+    assert_eq!(
+        parts,
+        expected.map(|(key, val)| (key.to_owned(), val.map(str::to_owned)))
+    );
+
+    // Target predicates always have a value
+    let target_parts: Vec<_> = expr
+        .predicates()
+        .filter_map(|pred| match pred {
+            P::Target(tp) => Some(tp.into_parts()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        target_parts,
+        [
+            ("target_family", "unix".into()),
+            ("target_family", "windows".into()),
+            ("target_family", "wasm".into()),
+            ("target_endian", "big".into()),
+            ("target_has_atomic", "ptr".into()),
+            ("panic", "abort".into()),
+        ]
+    );
+}
+
+#[test]
+fn predicate_classes() {
+    let expr = Expression::parse(
+        r#"all(windows, target_os = "windows", any(feature = "a", target_feature = "crt-static", not(feature = "a")), target_family = "windows", feature = "b", target_arch = "x86", target_feature = "sse2", target_feature = "crt-static", key = "value")"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        expr.target_predicates().collect::<Vec<_>>(),
+        [
+            TP::Family(Family::windows),
+            TP::Os(Os::windows),
+            TP::Arch(Arch::x86),
+        ]
+    );
+    assert_eq!(expr.features().collect::<Vec<_>>(), ["a", "b"]);
+    assert_eq!(
+        expr.target_features().collect::<Vec<_>>(),
+        ["crt-static", "sse2"]
+    );
+
+    let expr = Expression::parse("any(test, key = \"value\")").unwrap();
+    assert_eq!(expr.target_predicates().count(), 0);
+    assert_eq!(expr.features().count(), 0);
+    assert_eq!(expr.target_features().count(), 0);
+}
+
+#[test]
+fn stats() {
+    use cfg_expr::expr::{PredicateCounts, Stats};
+
+    let text = r#"cfg(all(unix, target_os = "linux", any(feature = "a", not(all(test, miri, sanitize = "address"))), target_feature = "sse2", my_flag, my_key = "v", true, version("1.70"), accessible(std::ptr), any()))"#;
+    let expr = Expression::parse(text).unwrap();
+
+    assert_eq!(
+        expr.stats(),
+        Stats {
+            len: text.len() - "cfg()".len(),
+            depth: 4,
+            predicates: PredicateCounts {
+                target: 2,
+                feature: 1,
+                target_feature: 1,
+                builtin: 3,
+                boolean: 1,
+                version: 1,
+                accessible: 1,
+                flag: 1,
+                key_value: 1,
+            },
+            all: 2,
+            any: 2,
+            not: 1,
+        }
+    );
+    assert_eq!(expr.stats().predicates.total(), expr.predicates().count());
+
+    let stats = Expression::parse("unix").unwrap().stats();
+    assert_eq!((stats.depth, stats.predicates.total()), (0, 1));
+    assert_eq!(Expression::parse("all()").unwrap().stats().depth, 1);
+}
+
+#[test]
+fn dot() {
+    let expr =
+        Expression::parse(r#"cfg(all(any(), not(my_key = "a\b"), version("1.70")))"#).unwrap();
+
+    assert_eq!(
+        expr.to_dot(),
+        r#"digraph {
+    n0 [label="any\n4..9"];
+    n1 [label="my_key = \"a\\b\"\n15..29"];
+    n2 [label="not\n11..30"];
+    n3 [label="version(\"1.70\")\n32..47"];
+    n4 [label="all\n0..48"];
+    n2 -> n1;
+    n4 -> n0;
+    n4 -> n2;
+    n4 -> n3;
+}
+"#
+    );
+
+    // Every node but the root has exactly one edge to it
+    let expr = Expression::parse(
+        r#"any(unix, all(target_os = "linux", not(any(a, b, c))), target(os = "macos", arch = "aarch64"))"#,
+    )
+    .unwrap();
+    let dot = expr.to_dot();
+    let nodes = dot.matches("[label=").count();
+    let edges = dot.matches(" -> ").count();
+    assert_eq!(edges, nodes - 1);
+}
+
+#[cfg(feature = "clap")]
+#[test]
+fn clap_value_parsers() {
+    use cfg_expr::expr::Platform;
+    use clap::{value_parser, Arg, Command};
+
+    let cmd = Command::new("tool")
+        .arg(
+            Arg::new("filter")
+                .long("filter")
+                .value_parser(value_parser!(Expression)),
+        )
+        .arg(
+            Arg::new("platform")
+                .long("filter-platform")
+                .value_parser(value_parser!(Platform)),
+        );
+
+    let matches = cmd
+        .clone()
+        .try_get_matches_from([
+            "tool",
+            "--filter",
+            r#"any(windows, feature = "a")"#,
+            "--filter-platform",
+            r#"cfg(all(unix, target_arch = "x86_64"))"#,
+        ])
+        .unwrap();
+
+    assert_eq!(
+        matches.get_one::<Expression>("filter"),
+        Some(&Expression::parse(r#"any(windows, feature = "a")"#).unwrap())
+    );
+    assert_eq!(
+        matches.get_one::<Platform>("platform"),
+        Some(&Platform::parse(r#"cfg(all(unix, target_arch = "x86_64"))"#).unwrap())
+    );
+
+    let matches = cmd
+        .clone()
+        .try_get_matches_from(["tool", "--filter-platform", "x86_64-unknown-linux-gnu"])
+        .unwrap();
+    assert_eq!(
+        matches.get_one::<Platform>("platform"),
+        Some(&Platform::Triple("x86_64-unknown-linux-gnu".to_owned()))
+    );
+
+    let err = cmd
+        .try_get_matches_from([
+            "tool",
+            "--filter-platform",
+            "cfg(all(unix, target_arch = x86_64))",
+        ])
+        .unwrap_err();
+    assert_eq!(err.kind(), clap::error::ErrorKind::ValueValidation);
+    assert!(err.to_string().contains(
+        r#"invalid value for '--filter-platform <platform>':
+cfg(all(unix, target_arch = x86_64))
+                            ^^^^^^ expected a `"` here
+"#
+    ));
+}
@@ -0,0 +1,191 @@
+#![cfg(feature = "rustc")]
+
+use cfg_expr::targets::{
+    get_builtin_target_by_triple,
+    rustc::{CfgMismatch, Database, Rustc},
+    PlatformSupport,
+};
+
+#[test]
+fn queries_targets() {
+    let rustc = Rustc::from_env();
+
+    let version = rustc.version().unwrap();
+    assert!(version.starts_with("1."), "{version}");
+
+    let targets = rustc.target_list().unwrap();
+    assert!(targets.iter().any(|t| t == "x86_64-unknown-linux-gnu"));
+
+    let features = rustc.default_features("x86_64-unknown-linux-gnu").unwrap();
+    assert!(features.iter().any(|f| f == "sse2"), "{features:?}");
+
+    let features = rustc.target_features("x86_64-unknown-linux-gnu").unwrap();
+    assert!(features.iter().any(|f| f == "avx2"), "{features:?}");
+
+    let implied = rustc
+        .implied_features("x86_64-unknown-linux-gnu", "avx2")
+        .unwrap();
+    assert!(implied.iter().any(|f| f == "avx"), "{implied:?}");
+    // Features enabled by default aren't included
+    assert!(!implied.iter().any(|f| f == "sse2"), "{implied:?}");
+
+    // The fields that are stable between rustc versions match the builtins
+    for triple in ["x86_64-unknown-linux-gnu", "wasm32-unknown-unknown"] {
+        let actual = rustc.target_info(triple).unwrap();
+        let expected = get_builtin_target_by_triple(triple).unwrap();
+
+        assert_eq!(actual.triple, expected.triple);
+        assert_eq!(actual.os, expected.os);
+        assert_eq!(actual.arch, expected.arch);
+        assert_eq!(actual.env, expected.env);
+        assert_eq!(actual.families, expected.families);
+        assert_eq!(actual.pointer_width, expected.pointer_width);
+        assert_eq!(actual.endian, expected.endian);
+        assert_eq!(actual.has_atomics, expected.has_atomics);
+    }
+
+    // The tier is only in the metadata of newer versions of rustc
+    if let Some(support) = rustc.support("x86_64-unknown-linux-gnu").unwrap() {
+        assert_eq!(support.tier, 1);
+        assert_eq!(support.host_tools, Some(true));
+    }
+
+    let err = rustc.target_info("not-a-target").unwrap_err();
+    assert!(err.to_string().contains("--target not-a-target"), "{err}");
+
+    let err = Rustc::new("not-a-rustc").version().unwrap_err();
+    assert!(err.to_string().starts_with("failed to run"), "{err}");
+}
+
+#[test]
+fn discovers_targets() {
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_owned());
+    let targets = cfg_expr::targets::from_rustc(rustc).unwrap();
+
+    for window in targets.windows(2) {
+        assert!(window[0].triple < window[1].triple);
+    }
+
+    let linux = targets
+        .iter()
+        .find(|ti| ti.triple == "x86_64-unknown-linux-gnu")
+        .unwrap();
+
+    let expr = cfg_expr::Expression::parse(
+        r#"all(unix, target_arch = "x86_64", target_has_atomic = "64")"#,
+    )
+    .unwrap();
+    assert!(expr.eval(|pred| match pred {
+        cfg_expr::Predicate::Target(tp) => tp.matches(linux),
+        _ => false,
+    }));
+
+    assert!(cfg_expr::targets::from_rustc("not-a-rustc").is_err());
+}
+
+#[test]
+fn generates_source() {
+    let database = Database {
+        rustc_version: "1.72.0".to_owned(),
+        targets: vec![
+            get_builtin_target_by_triple("powerpc64-ibm-aix")
+                .unwrap()
+                .clone(),
+            get_builtin_target_by_triple("wasm32-unknown-emscripten")
+                .unwrap()
+                .clone(),
+        ],
+        default_features: [(
+            "powerpc64-ibm-aix".to_owned(),
+            vec!["altivec".to_owned(), "vsx".to_owned()],
+        )]
+        .into_iter()
+        .collect(),
+        implied_features: [(
+            "x86".to_owned(),
+            [("avx".to_owned(), vec!["sse".to_owned(), "sse2".to_owned()])]
+                .into_iter()
+                .collect(),
+        )]
+        .into_iter()
+        .collect(),
+        support: [(
+            "powerpc64-ibm-aix".to_owned(),
+            PlatformSupport {
+                tier: 3,
+                host_tools: None,
+                std: Some(true),
+            },
+        )]
+        .into_iter()
+        .collect(),
+    };
+
+    let source = database.to_source();
+
+    for expected in [
+        r#"pub(crate) const RUSTC_VERSION: &str = "1.72.0";"#,
+        r#"triple: Triple::new_const("powerpc64-ibm-aix"),"#,
+        "abi: Some(Abi::vec_extabi),",
+        "families: Families::unix_wasm,",
+        "endian: Endian::big,",
+        "has_atomics: HasAtomics::atomic_8_16_32_64_ptr,",
+        r#"pub const vec_extabi: Abi = Abi::new_const("vec-extabi");"#,
+        "const __families_unix_wasm: &[Family] = &[Family::unix, Family::wasm];",
+        "pub const unix_wasm: Families = Families::new_const(__families_unix_wasm);",
+        "pub const atomic_8_16_32_64_ptr: HasAtomics = HasAtomics::new_const(__has_atomics_8_16_32_64_ptr);",
+    ] {
+        assert!(source.contains(expected), "{expected}\n{source}");
+    }
+
+    let source = database.features_source();
+    assert!(source.contains(r#"("powerpc64-ibm-aix", &["altivec", "vsx"]),"#));
+    assert!(source.contains(r#"("avx", &["sse", "sse2"]),"#));
+
+    let source = database.support_source();
+    assert!(source.contains(
+        r#"("powerpc64-ibm-aix", PlatformSupport { tier: 3, host_tools: None, std: Some(true) }),"#
+    ));
+}
+
+#[test]
+fn verifies_builtins() {
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_owned());
+    let report = cfg_expr::targets::verify_against_rustc(rustc).unwrap();
+
+    // The builtins can only be expected to match the rustc they were
+    // generated from
+    if report.rustc_version == cfg_expr::targets::RUSTC_VERSION {
+        assert!(report.is_ok(), "{report:#?}");
+    }
+
+    let rustc = Rustc::from_env();
+
+    let mut linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu")
+        .unwrap()
+        .clone();
+    linux.os = Some(cfg_expr::targets::Os::freebsd);
+    let mut custom = linux.clone();
+    custom.triple = cfg_expr::targets::Triple::new("x86_64-acme-none");
+
+    let report = rustc.verify(&[custom, linux]).unwrap();
+    assert!(!report.is_ok());
+    assert!(report
+        .missing
+        .iter()
+        .any(|triple| triple == "aarch64-unknown-linux-gnu"));
+    assert!(!report
+        .missing
+        .iter()
+        .any(|triple| triple == "x86_64-unknown-linux-gnu"));
+    assert_eq!(report.extra, ["x86_64-acme-none"]);
+    assert_eq!(
+        report.mismatched,
+        [CfgMismatch {
+            triple: "x86_64-unknown-linux-gnu".to_owned(),
+            key: "target_os",
+            expected: "freebsd".to_owned(),
+            actual: "linux".to_owned(),
+        }]
+    );
+}
@@ -0,0 +1,204 @@
+#![cfg(feature = "serde")]
+
+use cfg_expr::Expression;
+
+#[test]
+fn expression_round_trips() {
+    let expr = Expression::parse(r#"cfg(all(unix, target_arch = "x86_64"))"#).unwrap();
+
+    let json = serde_json::to_string(&expr).unwrap();
+    assert_eq!(json, r#""all(unix, target_arch = \"x86_64\")""#);
+
+    let deserialized: Expression = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, expr);
+
+    let err = serde_json::from_str::<Expression>(r#""all(unix""#).unwrap_err();
+    assert!(err.to_string().contains("unclosed parens"), "{err}");
+}
+
+#[test]
+fn target_info_round_trips() {
+    use cfg_expr::targets::*;
+
+    for target in ALL_BUILTINS {
+        let json = serde_json::to_string(target).unwrap();
+        let deserialized: TargetInfo = serde_json::from_str(&json).unwrap();
+        assert_eq!(&deserialized, target);
+    }
+
+    let linux = get_builtin_target_by_triple("x86_64-unknown-linux-gnu").unwrap();
+    similar_asserts::assert_eq!(
+        serde_json::to_value(linux).unwrap(),
+        serde_json::json!({
+            "triple": "x86_64-unknown-linux-gnu",
+            "os": "linux",
+            "abi": null,
+            "arch": "x86_64",
+            "env": "gnu",
+            "vendor": "unknown",
+            "families": ["unix"],
+            "pointer_width": 64,
+            "endian": "little",
+            "has_atomics": ["8", "16", "32", "64", "ptr"],
+            "has_atomics_load_store": ["8", "16", "32", "64", "ptr"],
+            "has_atomics_equal_alignment": ["8", "16", "32", "64", "ptr"],
+            "panic": "unwind",
+        })
+    );
+
+    // Sets don't need to be sorted when deserializing
+    let families: Families = serde_json::from_str(r#"["wasm", "unix"]"#).unwrap();
+    assert_eq!(families, Families::unix_wasm);
+    let atomics: HasAtomics = serde_json::from_str(r#"["ptr", "8", "32", "16"]"#).unwrap();
+    assert_eq!(atomics, HasAtomics::atomic_8_16_32_ptr);
+
+    assert!(serde_json::from_str::<HasAtomic>(r#""huge""#).is_err());
+    assert!(serde_json::from_str::<Endian>(r#""middle""#).is_err());
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json_ast_round_trips() {
+    for text in [
+        "unix",
+        "cfg(all())",
+        "any()",
+        r#"all(target_family = "unix", windows, target_os = "none", target_env = "", true)"#,
+        r#"any(feature = "a", not(all(test, my_flag, my_key = "v", sanitize = "address")))"#,
+        r#"all(version("1.70.1"), accessible(::std::ptr), target_has_atomic = "ptr", target_pointer_width = "64")"#,
+        r#"target(os = "linux", arch = "x86_64")"#,
+    ] {
+        let expr = Expression::parse(text).unwrap();
+        let json = expr.to_json_ast();
+
+        let read = Expression::from_json_ast(&json).unwrap();
+        assert_eq!(read, expr, "{json}");
+        assert_eq!(read.to_json_ast(), json);
+
+        // The tree is plain JSON
+        let _: serde_json::Value = serde_json::from_str(&json).unwrap();
+    }
+
+    assert_eq!(
+        Expression::parse("not(any())").unwrap().to_json_ast(),
+        r#"{"not":{"any":[]}}"#
+    );
+
+    for json in [
+        r#"{"all": {"cfg": {"key": "unix"}}}"#,
+        r#"{"cfg": {"key": "unix", "extra": 1}}"#,
+        r#"{"nand": []}"#,
+        r#"{"cfg": {"key": "target_os"}}"#,
+        r#"{"version": "one"}"#,
+    ] {
+        assert!(Expression::from_json_ast(json).is_err(), "{json}");
+    }
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn cargo_metadata() {
+    use cfg_expr::{
+        expr::Platform,
+        metadata::{DependencyKind, Metadata},
+        targets::get_builtin_target_by_triple,
+    };
+
+    let metadata = Metadata::from_json(
+        r#"{
+            "packages": [
+                {
+                    "name": "app",
+                    "version": "0.1.0",
+                    "id": "path+file:///ws/app#0.1.0",
+                    "source": null,
+                    "dependencies": [
+                        {
+                            "name": "libc",
+                            "source": "registry+https://github.com/rust-lang/crates.io-index",
+                            "req": "^0.2",
+                            "kind": null,
+                            "rename": null,
+                            "optional": false,
+                            "uses_default_features": true,
+                            "features": [],
+                            "target": "cfg(unix)",
+                            "registry": null
+                        },
+                        {
+                            "name": "windows-sys",
+                            "req": "^0.52",
+                            "kind": null,
+                            "rename": "winsys",
+                            "optional": true,
+                            "uses_default_features": false,
+                            "features": ["Win32_Foundation"],
+                            "target": "cfg(all(windows, target_env = \"msvc\"))"
+                        },
+                        {
+                            "name": "core-foundation",
+                            "req": "^0.9",
+                            "kind": "dev",
+                            "target": "aarch64-apple-darwin"
+                        },
+                        {
+                            "name": "cc",
+                            "req": "^1.0",
+                            "kind": "build",
+                            "target": null
+                        }
+                    ]
+                },
+                {
+                    "name": "libc",
+                    "version": "0.2.150",
+                    "id": "registry+https://github.com/rust-lang/crates.io-index#libc@0.2.150",
+                    "dependencies": []
+                }
+            ],
+            "workspace_members": ["path+file:///ws/app#0.1.0"],
+            "resolve": null,
+            "version": 1
+        }"#,
+    )
+    .unwrap();
+
+    let app = metadata.package(&metadata.workspace_members[0]).unwrap();
+    assert_eq!(app.name, "app");
+
+    let winsys = &app.dependencies[1];
+    assert_eq!(winsys.rename.as_deref(), Some("winsys"));
+    assert!(winsys.optional);
+    assert!(!winsys.uses_default_features);
+    assert_eq!(app.dependencies[2].kind, DependencyKind::Development);
+    assert_eq!(
+        app.dependencies[2].target,
+        Some(Platform::Triple("aarch64-apple-darwin".to_owned()))
+    );
+    assert_eq!(app.dependencies[3].kind, DependencyKind::Build);
+
+    for (triple, expected) in [
+        ("x86_64-unknown-linux-gnu", &["libc", "cc"][..]),
+        ("x86_64-pc-windows-msvc", &["windows-sys", "cc"]),
+        ("x86_64-pc-windows-gnu", &["cc"]),
+        ("aarch64-apple-darwin", &["libc", "core-foundation", "cc"]),
+    ] {
+        let target = get_builtin_target_by_triple(triple).unwrap();
+
+        let deps: Vec<_> = app
+            .dependencies_for(target)
+            .map(|dep| dep.name.as_str())
+            .collect();
+        assert_eq!(deps, expected, "{triple}");
+
+        let filtered = metadata.for_target(target);
+        assert_eq!(filtered.packages[0].dependencies.len(), expected.len());
+        assert_eq!(filtered.packages[1], metadata.packages[1]);
+    }
+
+    let err = Metadata::from_json(
+        r#"{"packages": [{"name": "a", "version": "1.0.0", "id": "a", "dependencies": [{"name": "b", "req": "*", "target": "cfg(unix"}]}]}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().starts_with("cfg(unix\n"), "{err}");
+}
@@ -0,0 +1,106 @@
+#![cfg(feature = "json")]
+
+use cfg_expr::{
+    targets::{Families, Family, HasAtomic, HasAtomics, Panic, TargetInfo},
+    Expression, Predicate,
+};
+
+#[test]
+fn parses_custom_targets() {
+    let ti = TargetInfo::from_spec_json(
+        r#"{
+            "llvm-target": "riscv32-unknown-none",
+            "arch": "riscv32",
+            "os": "my-kernel",
+            "vendor": "acme",
+            "target-family": "unix",
+            "target-endian": "little",
+            "target-pointer-width": "32",
+            "data-layout": "e-m:e-p:32:32-i64:64-n32-S128",
+            "max-atomic-width": 32,
+            "atomic-cas": false,
+            "panic-strategy": "abort"
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(ti.triple.as_str(), "riscv32-unknown-none");
+    assert_eq!(ti.os.as_ref().unwrap().as_str(), "my-kernel");
+    assert_eq!(ti.vendor.as_ref().unwrap().as_str(), "acme");
+    assert_eq!(ti.env, None);
+    assert_eq!(ti.abi, None);
+    assert_eq!(ti.families, Families::new([Family::unix]));
+    assert_eq!(ti.pointer_width, 32);
+    assert_eq!(ti.panic, Panic::abort);
+
+    // Only loads and stores are supported without compare-and-swap
+    let atomics = HasAtomics::new([
+        HasAtomic::IntegerSize(8),
+        HasAtomic::IntegerSize(16),
+        HasAtomic::IntegerSize(32),
+        HasAtomic::Pointer,
+    ]);
+    assert!(ti.has_atomics.is_empty());
+    assert_eq!(ti.has_atomics_load_store, atomics);
+    assert_eq!(ti.has_atomics_equal_alignment, atomics);
+
+    let expr = Expression::parse(
+        r#"all(unix, target_os = "my-kernel", target_has_atomic_load_store = "32", not(target_has_atomic = "32"), panic = "abort")"#,
+    )
+    .unwrap();
+    assert!(expr.eval(|pred| match pred {
+        Predicate::Target(tp) => tp.matches(&ti),
+        _ => false,
+    }));
+
+    // The defaults are the same as rustc's
+    let ti = TargetInfo::from_spec_json(
+        r#"{"llvm-target": "x86_64-unknown-none", "arch": "x86_64", "target-pointer-width": 64}"#,
+    )
+    .unwrap();
+
+    assert_eq!(ti.os, None);
+    assert_eq!(ti.vendor.as_ref().unwrap().as_str(), "unknown");
+    assert!(ti.families.is_empty());
+    assert_eq!(ti.panic, Panic::unwind);
+    assert!(ti.has_atomics.contains(HasAtomic::IntegerSize(64)));
+    assert!(ti.has_atomics.contains(HasAtomic::Pointer));
+    // i64 is only 32-bit aligned by default
+    assert!(!ti
+        .has_atomics_equal_alignment
+        .contains(HasAtomic::IntegerSize(64)));
+
+    for (json, err) in [
+        (r#"{"arch": "x86_64"}"#, "missing field `llvm-target`"),
+        (
+            r#"{"llvm-target": "x", "arch": "x", "target-pointer-width": "wide"}"#,
+            "invalid target-pointer-width \"wide\"",
+        ),
+        (
+            r#"{"llvm-target": "x", "arch": "x", "target-pointer-width": 32, "target-endian": "middle"}"#,
+            "invalid target-endian \"middle\"",
+        ),
+    ] {
+        let actual = TargetInfo::from_spec_json(json).unwrap_err().to_string();
+        assert!(actual.contains(err), "{actual}");
+    }
+}
+
+#[test]
+fn round_trips_builtins() {
+    for builtin in cfg_expr::targets::ALL_BUILTINS {
+        // Specs can only describe targets that support compare-and-swap for
+        // all of the sizes that support loads and stores, or none of them
+        if !builtin.has_atomics.is_empty() && builtin.has_atomics != builtin.has_atomics_load_store
+        {
+            continue;
+        }
+
+        let mut ti = TargetInfo::from_spec_json(&builtin.to_spec_json()).unwrap();
+
+        // The alignments come from the data-layout, which isn't written
+        ti.has_atomics_equal_alignment = builtin.has_atomics_equal_alignment.clone();
+
+        similar_asserts::assert_eq!(builtin, &ti);
+    }
+}